@@ -1,9 +1,13 @@
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::Duration;
 
 use bridge_e2e_traits::client::EcdsaClient;
 use client_beacon::client::ApiSupplier;
-use client_contracts::PosaLightClient;
+use client_contracts::{FailoverHttp, PosaLightClient};
+use ecdsa_pair::signer::REMOTE_SIGNER_PREFIX;
+use relay_e2e::ecdsa::events::{ChainEcdsaEvents, EcdsaEvents, EcdsaEventsSourceType};
+use relay_e2e::trigger::RelayTrigger;
 use relay_e2e::types::ethereum::FastEthereumAccount;
 use serde::{Deserialize, Deserializer, Serialize};
 use subquery::types::BridgeName;
@@ -11,21 +15,25 @@ use subquery::{Subquery, SubqueryComponent, SubqueryConfig};
 use thegraph::Thegraph;
 use thegraph::ThegraphComponent;
 use thegraph::ThegraphConfig;
-use web3::transports::Http;
 use web3::types::Address;
 use web3::Web3;
 
 use crate::bridge::BridgeBus;
 
+// Polling interval(seconds) of header relay, used when beacon events are not subscribed
+const HEADER_RELAY_INTERVAL: u64 = 10;
+
 #[derive(Clone, Debug)]
 pub struct BridgeConfig<T: EcdsaClient> {
     pub name: String,
     pub general: GeneralConfig,
     pub darwinia_evm: EVMChainConfig,
-    pub substrate_client: T,
+    // Darwinia client signing extrinsics by the ECDSA authority key, only present with `ecdsa`
+    pub substrate_client: Option<T>,
+    pub ecdsa: Option<EcdsaConfig>,
     pub ethereum: ExecutionLayerInfoConfig,
     pub beacon: BeaconApiConfig,
-    pub substrate_index: Subquery,
+    pub ecdsa_events: EcdsaEvents<T>,
     pub evm_index: Thegraph,
 }
 
@@ -52,6 +60,17 @@ pub struct GeneralConfig {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ExecutionLayerInfoConfig {
     pub endpoint: String,
+    // Websocket endpoint, subscribe new heads and message events to relay immediately
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ws_endpoint: Option<String>,
+    // Backup endpoints, requests fail over to them when an endpoint is unreachable
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backup_endpoints: Vec<String>,
+    // Number of endpoints which must agree on critical reads(light client block number, lane nonces).
+    // Reads of the latest state are pinned to the highest block reached by this many endpoints,
+    // so endpoints a block apart still agree.
+    #[serde(default = "default_read_quorum")]
+    pub read_quorum: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contract_address: Option<String>,
     #[serde(deserialize_with = "evm_secret_key_from_str")]
@@ -62,17 +81,34 @@ pub struct ExecutionLayerInfoConfig {
     pub posa_light_client_address: String,
     pub max_gas_price: String,
     pub etherscan_api_key: String,
+    // Minimum balance(wei) of relayer, submitting is paused when below it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reserve_balance: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BeaconApiConfig {
     pub endpoint: String,
     pub api_supplier: ApiSupplier,
+    // Subscribe finality events of beacon node to relay headers immediately
+    #[serde(default)]
+    pub subscribe_events: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EVMChainConfig {
     pub endpoint: String,
+    // Websocket endpoint, subscribe new heads and message events to relay immediately
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ws_endpoint: Option<String>,
+    // Backup endpoints, requests fail over to them when an endpoint is unreachable
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backup_endpoints: Vec<String>,
+    // Number of endpoints which must agree on critical reads(light client block number, lane nonces).
+    // Reads of the latest state are pinned to the highest block reached by this many endpoints,
+    // so endpoints a block apart still agree.
+    #[serde(default = "default_read_quorum")]
+    pub read_quorum: usize,
     pub contract_address: String,
     #[serde(deserialize_with = "evm_secret_key_from_str")]
     pub private_key: String,
@@ -82,30 +118,63 @@ pub struct EVMChainConfig {
     pub lane_message_committer_address: String,
     pub fee_market_address: String,
     pub max_gas_price: String,
+    // Minimum balance(wei) of relayer, submitting is paused when below it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reserve_balance: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EcdsaConfig {
+    // ECDSA authority key, only used to sign the collecting rounds, never used to relay
+    #[serde(deserialize_with = "evm_secret_key_from_str")]
+    pub private_key: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IndexConfig {
-    pub substrate_chain: SubqueryConfig,
+    // Only required when ECDSA authority events are read from subquery
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub substrate_chain: Option<SubqueryConfig>,
     pub evm_chain: ThegraphConfig,
+    // Source of ECDSA authority events, `subquery` or `chain`
+    #[serde(default)]
+    pub ecdsa_events: EcdsaEventsSourceType,
+    // Max number of darwinia blocks scanned by one lookup when ECDSA authority events are read from chain
+    #[serde(default = "default_ecdsa_events_scan_range")]
+    pub ecdsa_events_scan_range: u32,
 }
 
 impl EVMChainConfig {
+    /// Decrypt `keystore:<id>` private key from keystore
+    pub fn resolve_keys(mut self) -> color_eyre::Result<Self> {
+        self.private_key = resolve_evm_private_key(&self.private_key)?;
+        Ok(self)
+    }
+
     pub fn to_ethereum_account(&self) -> FastEthereumAccount {
         FastEthereumAccount::new(&self.private_key)
     }
 
-    pub fn to_web3_client(&self) -> color_eyre::Result<Web3<Http>> {
-        let transport = Http::new(&self.endpoint)?;
-        let client = Web3::new(transport);
+    pub fn to_transport(&self) -> color_eyre::Result<FailoverHttp> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        Ok(FailoverHttp::new(&endpoints)?.with_read_quorum(self.read_quorum)?)
+    }
+
+    pub fn to_web3_client(&self) -> color_eyre::Result<Web3<FailoverHttp>> {
+        let client = Web3::new(self.to_transport()?);
         Ok(client)
     }
 }
 
 impl ExecutionLayerInfoConfig {
+    /// Decrypt `keystore:<id>` private key from keystore
+    pub fn resolve_keys(mut self) -> color_eyre::Result<Self> {
+        self.private_key = resolve_evm_private_key(&self.private_key)?;
+        Ok(self)
+    }
+
     pub fn to_posa_client(&self) -> color_eyre::Result<PosaLightClient> {
-        let transport = Http::new(&self.endpoint)?;
-        let client = Web3::new(transport);
+        let client = self.to_web3_client()?;
         let address = Address::from_str(&self.posa_light_client_address)?;
         Ok(PosaLightClient::new(&client, address)?)
     }
@@ -114,16 +183,77 @@ impl ExecutionLayerInfoConfig {
         FastEthereumAccount::new(&self.private_key)
     }
 
-    pub fn to_web3_client(&self) -> color_eyre::Result<Web3<Http>> {
-        let transport = Http::new(&self.endpoint)?;
-        let client = Web3::new(transport);
+    pub fn to_transport(&self) -> color_eyre::Result<FailoverHttp> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        Ok(FailoverHttp::new(&endpoints)?.with_read_quorum(self.read_quorum)?)
+    }
+
+    pub fn to_web3_client(&self) -> color_eyre::Result<Web3<FailoverHttp>> {
+        let client = Web3::new(self.to_transport()?);
         Ok(client)
     }
 }
 
+impl EcdsaConfig {
+    pub fn new(private_key: &str) -> Self {
+        Self {
+            private_key: trim_evm_private_key(private_key),
+        }
+    }
+
+    /// Decrypt `keystore:<id>` private key from keystore
+    pub fn resolve_keys(mut self) -> color_eyre::Result<Self> {
+        self.private_key = resolve_evm_private_key(&self.private_key)?;
+        Ok(self)
+    }
+
+    pub fn to_authority_account(&self) -> FastEthereumAccount {
+        FastEthereumAccount::new(&self.private_key)
+    }
+}
+
+impl BeaconApiConfig {
+    pub fn to_relay_trigger(&self) -> RelayTrigger {
+        let mut trigger = RelayTrigger::new(Duration::from_secs(HEADER_RELAY_INTERVAL));
+        if self.subscribe_events {
+            trigger.subscribe_beacon_events(
+                &self.endpoint,
+                self.api_supplier,
+                &["finalized_checkpoint", "light_client_finality_update"],
+            );
+        }
+        trigger
+    }
+}
+
 impl IndexConfig {
-    pub fn to_substrate_subquery(&self, bridge_name: BridgeName) -> Subquery {
-        SubqueryComponent::component(self.substrate_chain.clone(), bridge_name)
+    pub fn to_substrate_subquery(&self, bridge_name: BridgeName) -> color_eyre::Result<Subquery> {
+        let config = self.substrate_chain.clone().ok_or_else(|| {
+            color_eyre::eyre::eyre!("The substrate_chain of index is required by subquery")
+        })?;
+        Ok(SubqueryComponent::component(config, bridge_name))
+    }
+
+    /// Lookup of ECDSA authority events, the client is required to read them from chain
+    pub fn to_ecdsa_events<T: EcdsaClient>(
+        &self,
+        bridge_name: BridgeName,
+        client: Option<T>,
+    ) -> color_eyre::Result<EcdsaEvents<T>> {
+        let events = match self.ecdsa_events {
+            EcdsaEventsSourceType::Subquery => {
+                EcdsaEvents::Subquery(self.to_substrate_subquery(bridge_name)?)
+            }
+            EcdsaEventsSourceType::Chain => {
+                let client = client.ok_or_else(|| {
+                    color_eyre::eyre::eyre!(
+                        "Missing darwinia client to scan ECDSA authority events"
+                    )
+                })?;
+                EcdsaEvents::Chain(ChainEcdsaEvents::new(client, self.ecdsa_events_scan_range))
+            }
+        };
+        Ok(events)
     }
 
     pub fn to_evm_thegraph(
@@ -134,12 +264,38 @@ impl IndexConfig {
     }
 }
 
+fn default_read_quorum() -> usize {
+    1
+}
+
+fn default_ecdsa_events_scan_range() -> u32 {
+    // about one day of darwinia blocks
+    14400
+}
+
+fn resolve_evm_private_key(private_key: &str) -> color_eyre::Result<String> {
+    let private_key = support_keystore::resolve(private_key)?;
+    Ok(trim_evm_private_key(&private_key))
+}
+
+// Remote signers are kept as is, `remote:<url>#<public key>`
+fn trim_evm_private_key(private_key: &str) -> String {
+    if private_key.starts_with(REMOTE_SIGNER_PREFIX) {
+        private_key.to_string()
+    } else {
+        private_key
+            .strip_prefix("0x")
+            .unwrap_or(private_key)
+            .to_string()
+    }
+}
+
 fn evm_secret_key_from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
 where
     T: FromStr,
     T::Err: Display,
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?.replace("0x", "");
+    let s = trim_evm_private_key(&String::deserialize(deserializer)?);
     T::from_str(&s).map_err(serde::de::Error::custom)
 }
@@ -0,0 +1,106 @@
+use support_command_kv::{KvKey, KvSchema, KvValueType};
+
+/// Kv schema of the bridge namespace, keep it in sync with the trackers of
+/// [`crate::service::ecdsa_relay`] and the journal of `relay_e2e::ecdsa::journal`
+pub const KV_SCHEMA: KvSchema = KvSchema::new(&[
+    KvKey::new(
+        "scan.darwinia.collecting-message.current",
+        KvValueType::Unsigned,
+        "Last scanned darwinia block of the tracker of collecting new message root signatures",
+    ),
+    KvKey::new(
+        "scan.darwinia.collecting-message.planned",
+        KvValueType::Unsigned,
+        "Block to scan from on the next round, replaces current once it's read",
+    ),
+    KvKey::new(
+        "scan.darwinia.collecting-message.running",
+        KvValueType::Bool,
+        "Scanning is paused if it isn't true",
+    ),
+    KvKey::new(
+        "scan.darwinia.collected-message.current",
+        KvValueType::Unsigned,
+        "Last scanned darwinia block of the tracker of collected new message root signatures",
+    ),
+    KvKey::new(
+        "scan.darwinia.collected-message.planned",
+        KvValueType::Unsigned,
+        "Block to scan from on the next round, replaces current once it's read",
+    ),
+    KvKey::new(
+        "scan.darwinia.collected-message.running",
+        KvValueType::Bool,
+        "Scanning is paused if it isn't true",
+    ),
+    KvKey::new(
+        "scan.darwinia.collecting-authorities.current",
+        KvValueType::Unsigned,
+        "Last scanned darwinia block of the tracker of collecting authorities change signatures",
+    ),
+    KvKey::new(
+        "scan.darwinia.collecting-authorities.planned",
+        KvValueType::Unsigned,
+        "Block to scan from on the next round, replaces current once it's read",
+    ),
+    KvKey::new(
+        "scan.darwinia.collecting-authorities.running",
+        KvValueType::Bool,
+        "Scanning is paused if it isn't true",
+    ),
+    KvKey::new(
+        "scan.darwinia.collected-authorities.current",
+        KvValueType::Unsigned,
+        "Last scanned darwinia block of the tracker of collected authorities change signatures",
+    ),
+    KvKey::new(
+        "scan.darwinia.collected-authorities.planned",
+        KvValueType::Unsigned,
+        "Block to scan from on the next round, replaces current once it's read",
+    ),
+    KvKey::new(
+        "scan.darwinia.collected-authorities.running",
+        KvValueType::Bool,
+        "Scanning is paused if it isn't true",
+    ),
+    KvKey::new(
+        "journal.darwinia.collecting-message",
+        KvValueType::Json,
+        "Last signature submitted for a new message root",
+    ),
+    KvKey::new(
+        "journal.darwinia.collecting-authorities",
+        KvValueType::Json,
+        "Last signature submitted for an authorities change",
+    ),
+]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trackers() {
+        for tracker in [
+            "collecting-message",
+            "collected-message",
+            "collecting-authorities",
+            "collected-authorities",
+        ] {
+            for (key, value_type) in [
+                ("current", KvValueType::Unsigned),
+                ("planned", KvValueType::Unsigned),
+                ("running", KvValueType::Bool),
+            ] {
+                let kv_key = KV_SCHEMA
+                    .find(format!("scan.darwinia.{tracker}.{key}"))
+                    .unwrap();
+                assert_eq!(kv_key.value_type, value_type);
+            }
+        }
+        assert!(KV_SCHEMA
+            .find("scan.darwinia.collecting-messages.current")
+            .is_none());
+        assert!(KV_SCHEMA.find("scan.darwinia.collected.running").is_none());
+    }
+}
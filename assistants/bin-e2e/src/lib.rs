@@ -1,4 +1,6 @@
 pub mod bridge;
 pub mod config;
+pub mod kv;
+pub mod preflight;
 pub mod service;
 pub mod task;
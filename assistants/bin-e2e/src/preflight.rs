@@ -0,0 +1,331 @@
+use std::str::FromStr;
+
+use client_common_traits::ChainIdentity;
+use client_contracts::{BeaconLightClient, FailoverHttp, Inbound, Outbound};
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+use web3::types::{Address, U256};
+use web3::Web3;
+
+use support_terminal::output;
+
+use crate::config::{EVMChainConfig, ExecutionLayerInfoConfig};
+
+/// Chains the bridge is built for, an endpoint of another chain fails the pre-flight
+#[derive(Clone, Debug)]
+pub struct PreflightChains {
+    /// Name of the darwinia-like chain, used in the report
+    pub darwinia: &'static str,
+    /// Name of the ethereum chain, used in the report
+    pub ethereum: &'static str,
+    /// Runtime spec name of the darwinia-like chain, see `ClientCommon::SPEC_NAME`
+    pub spec_name: &'static str,
+    /// Genesis hash of the darwinia-like chain, see `ClientCommon::GENESIS_HASH`
+    pub genesis_hash: Option<&'static str>,
+    /// Chain id of the evm of darwinia-like chain
+    pub darwinia_chain_id: u64,
+    /// Chain id of the ethereum execution layer
+    pub ethereum_chain_id: u64,
+}
+
+/// A check of pre-flight, the result is the detail of passed or failed check
+#[derive(Debug, Clone)]
+pub struct PreflightCheck {
+    pub chain: String,
+    pub check: String,
+    pub result: Result<String, String>,
+    /// A failed optional check is a warning, it doesn't refuse to start the bridge
+    pub optional: bool,
+}
+
+/// Checks of endpoints, light clients and lanes before starting the bridge
+#[derive(Debug, Clone, Default)]
+pub struct PreflightReport {
+    checks: Vec<PreflightCheck>,
+}
+
+impl PreflightReport {
+    pub fn checks(&self) -> &[PreflightCheck] {
+        &self.checks
+    }
+
+    pub fn failed(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|v| v.result.is_err() && !v.optional)
+            .count()
+    }
+
+    fn push(&mut self, chain: &str, check: impl Into<String>, result: Result<String, String>) {
+        self.checks.push(PreflightCheck {
+            chain: chain.to_string(),
+            check: check.into(),
+            result,
+            optional: false,
+        });
+    }
+
+    fn push_optional(
+        &mut self,
+        chain: &str,
+        check: impl Into<String>,
+        result: Result<String, String>,
+    ) {
+        self.checks.push(PreflightCheck {
+            chain: chain.to_string(),
+            check: check.into(),
+            result,
+            optional: true,
+        });
+    }
+
+    /// At least one endpoint of the chain should be healthy
+    fn push_healthy(
+        &mut self,
+        chain: &str,
+        check: impl Into<String>,
+        healthy: usize,
+        total: usize,
+    ) {
+        let result = if healthy == 0 {
+            Err("No healthy endpoint".to_string())
+        } else {
+            Ok(format!("{healthy} of {total} endpoints are healthy"))
+        };
+        self.push(chain, check, result);
+    }
+
+    /// The chain id of evm endpoints should be the expected one, an unreachable endpoint is a
+    /// warning since it's skipped by failover
+    async fn check_evm_endpoints(
+        &mut self,
+        chain: &str,
+        label: &str,
+        endpoints: Vec<String>,
+        expected_chain_id: u64,
+    ) {
+        let total = endpoints.len();
+        let mut healthy = 0;
+        for endpoint in endpoints {
+            let check = format!("{label} {endpoint}");
+            match chain_id(&endpoint).await {
+                Ok(chain_id) if chain_id != expected_chain_id.into() => self.push(
+                    chain,
+                    check,
+                    Err(format!(
+                        "Expect chain id {expected_chain_id}, but it's {chain_id}"
+                    )),
+                ),
+                Ok(chain_id) => {
+                    healthy += 1;
+                    self.push(chain, check, Ok(format!("chain id {chain_id}")));
+                }
+                Err(e) => self.push_optional(chain, check, Err(e)),
+            }
+        }
+        self.push_healthy(chain, format!("healthy {label}s"), healthy, total);
+    }
+
+    /// Print the report
+    pub fn output(&self) {
+        let mut table = Table::new();
+        table.max_column_width = 80;
+        table.style = TableStyle::simple();
+        for check in &self.checks {
+            let (status, detail) = match &check.result {
+                Ok(v) => ("ok", v),
+                Err(e) if check.optional => ("warning", e),
+                Err(e) => ("failed", e),
+            };
+            table.add_row(Row::new(vec![
+                TableCell::new_with_alignment(&check.chain, 1, Alignment::Left),
+                TableCell::new_with_alignment(&check.check, 1, Alignment::Left),
+                TableCell::new_with_alignment(status, 1, Alignment::Left),
+                TableCell::new_with_alignment(detail, 1, Alignment::Left),
+            ]));
+        }
+        output::output_text(table.render());
+    }
+
+    /// Refuse to start the bridge if any check failed
+    pub fn ensure(&self) -> color_eyre::Result<()> {
+        let failed = self.failed();
+        if failed != 0 {
+            return Err(color_eyre::eyre::eyre!(
+                "{} pre-flight checks failed, refuse to start the bridge",
+                failed
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Check every endpoint of the bridge, the light clients and the lanes. The identities of
+/// substrate endpoints are queried by the client crate of the darwinia-like chain.
+pub async fn preflight(
+    darwinia_evm: &EVMChainConfig,
+    ethereum: &ExecutionLayerInfoConfig,
+    chains: &PreflightChains,
+    substrate_identities: Vec<(String, Result<ChainIdentity, String>)>,
+) -> color_eyre::Result<PreflightReport> {
+    let mut report = PreflightReport::default();
+
+    let total = substrate_identities.len();
+    let mut healthy = 0;
+    let mut seen_genesis_hash = None;
+    for (endpoint, identity) in substrate_identities {
+        let check = format!("endpoint {endpoint}");
+        match identity {
+            Ok(identity) => {
+                let result = identity.check(
+                    chains.spec_name,
+                    chains.genesis_hash,
+                    &mut seen_genesis_hash,
+                );
+                if result.is_ok() {
+                    healthy += 1;
+                }
+                report.push(chains.darwinia, check, result);
+            }
+            Err(e) => report.push_optional(chains.darwinia, check, Err(e)),
+        }
+    }
+    if total != 0 {
+        report.push_healthy(chains.darwinia, "healthy endpoints", healthy, total);
+    }
+
+    let darwinia_endpoints = [
+        vec![darwinia_evm.endpoint.clone()],
+        darwinia_evm.backup_endpoints.clone(),
+    ]
+    .concat();
+    report
+        .check_evm_endpoints(
+            chains.darwinia,
+            "evm endpoint",
+            darwinia_endpoints,
+            chains.darwinia_chain_id,
+        )
+        .await;
+    let ethereum_endpoints = [
+        vec![ethereum.endpoint.clone()],
+        ethereum.backup_endpoints.clone(),
+    ]
+    .concat();
+    report
+        .check_evm_endpoints(
+            chains.ethereum,
+            "endpoint",
+            ethereum_endpoints,
+            chains.ethereum_chain_id,
+        )
+        .await;
+
+    let darwinia_client = darwinia_evm.to_web3_client()?;
+    let ethereum_client = ethereum.to_web3_client()?;
+
+    let result = match beacon_light_client(&darwinia_client, &darwinia_evm.contract_address) {
+        Ok(client) => match client.finalized_header().await {
+            Ok(header) if header.slot == 0 => {
+                Err("No finalized header is imported, the light client isn't initialized".into())
+            }
+            Ok(header) => Ok(format!("finalized header slot: {}", header.slot)),
+            Err(e) => Err(format!("{e:?}")),
+        },
+        Err(e) => Err(e),
+    };
+    report.push(chains.darwinia, "beacon light client", result);
+
+    let result = match ethereum.to_posa_client() {
+        Ok(client) => match (client.get_relayers().await, client.get_threshold().await) {
+            (Ok(relayers), _) if relayers.is_empty() => {
+                Err("No relayer is set, the light client isn't initialized".into())
+            }
+            (Ok(relayers), Ok(threshold)) => Ok(format!(
+                "relayers: {}, threshold: {}",
+                relayers.len(),
+                threshold
+            )),
+            (Err(e), _) | (_, Err(e)) => Err(format!("{e:?}")),
+        },
+        Err(e) => Err(format!("{e:?}")),
+    };
+    report.push(chains.ethereum, "posa light client", result);
+
+    let result = check_lane(
+        &darwinia_client,
+        &darwinia_evm.outbound_address,
+        &ethereum_client,
+        &ethereum.inbound_address,
+    )
+    .await;
+    report.push(
+        chains.darwinia,
+        format!("lane to {}", chains.ethereum),
+        result,
+    );
+    let result = check_lane(
+        &ethereum_client,
+        &ethereum.outbound_address,
+        &darwinia_client,
+        &darwinia_evm.inbound_address,
+    )
+    .await;
+    report.push(
+        chains.ethereum,
+        format!("lane to {}", chains.darwinia),
+        result,
+    );
+
+    Ok(report)
+}
+
+async fn chain_id(endpoint: &str) -> Result<U256, String> {
+    let transport = FailoverHttp::new(&[endpoint]).map_err(|e| format!("{e:?}"))?;
+    Web3::new(transport)
+        .eth()
+        .chain_id()
+        .await
+        .map_err(|e| format!("{e:?}"))
+}
+
+fn beacon_light_client(
+    client: &Web3<FailoverHttp>,
+    address: &str,
+) -> Result<BeaconLightClient, String> {
+    let address = Address::from_str(address).map_err(|e| format!("Wrong address: {e:?}"))?;
+    BeaconLightClient::new(client, address).map_err(|e| format!("{e:?}"))
+}
+
+/// The outbound lane at source and the inbound lane at target should point to each other
+async fn check_lane(
+    source_client: &Web3<FailoverHttp>,
+    outbound_address: &str,
+    target_client: &Web3<FailoverHttp>,
+    inbound_address: &str,
+) -> Result<String, String> {
+    let outbound_address =
+        Address::from_str(outbound_address).map_err(|e| format!("Wrong address: {e:?}"))?;
+    let inbound_address =
+        Address::from_str(inbound_address).map_err(|e| format!("Wrong address: {e:?}"))?;
+    let outbound = Outbound::new(source_client, outbound_address).map_err(|e| format!("{e:?}"))?;
+    let inbound = Inbound::new(target_client, inbound_address).map_err(|e| format!("{e:?}"))?;
+    let outbound_lane = outbound
+        .get_lane_info()
+        .await
+        .map_err(|e| format!("Can not read outbound lane: {e:?}"))?;
+    let inbound_lane = inbound
+        .get_lane_info(None)
+        .await
+        .map_err(|e| format!("Can not read inbound lane: {e:?}"))?;
+    let (this_chain, this_lane, bridged_chain, bridged_lane) = outbound_lane;
+    if inbound_lane != (bridged_chain, bridged_lane, this_chain, this_lane) {
+        return Err(format!(
+            "The outbound lane {outbound_lane:?} doesn't match the inbound lane {inbound_lane:?}"
+        ));
+    }
+    Ok(format!(
+        "chain {this_chain} lane {this_lane} -> chain {bridged_chain} lane {bridged_lane}"
+    ))
+}
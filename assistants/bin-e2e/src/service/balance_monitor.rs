@@ -0,0 +1,138 @@
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use bridge_e2e_traits::client::EcdsaClient;
+use client_contracts::{FeeMarket, SimpleFeeMarket};
+use lifeline::dyn_bus::DynBus;
+use lifeline::{Lifeline, Service, Task};
+use relay_e2e::keepstate;
+use support_lifeline::service::BridgeService;
+use web3::types::{Address, U256};
+
+use crate::bridge::BridgeBus;
+use crate::config::BridgeConfig;
+
+/// Interval(seconds) between two relayer balance checks
+const BALANCE_CHECK_INTERVAL: u64 = 60;
+
+#[derive(Debug)]
+pub struct BalanceMonitorService<T: EcdsaClient> {
+    _greet: Lifeline,
+    _ecdsa: PhantomData<T>,
+}
+
+impl<T: EcdsaClient> BridgeService for BalanceMonitorService<T> {}
+
+impl<T: EcdsaClient> Service for BalanceMonitorService<T> {
+    type Bus = BridgeBus;
+    type Lifeline = color_eyre::Result<Self>;
+
+    fn spawn(bus: &Self::Bus) -> Self::Lifeline {
+        let bridge_config: BridgeConfig<T> = bus.storage().clone_resource()?;
+        let _greet = Self::try_task("relayer-balance-monitor", async move {
+            loop {
+                if let Err(error) = check_darwinia_relayer(&bridge_config).await {
+                    tracing::error!(
+                        target: "darwinia-eth",
+                        "[balance-monitor] Failed to check relayer balance at darwinia: {:?}",
+                        error
+                    );
+                }
+                if let Err(error) = check_ethereum_relayer(&bridge_config).await {
+                    tracing::error!(
+                        target: "darwinia-eth",
+                        "[balance-monitor] Failed to check relayer balance at ethereum: {:?}",
+                        error
+                    );
+                }
+                tokio::time::sleep(std::time::Duration::from_secs(BALANCE_CHECK_INTERVAL)).await;
+            }
+        });
+        Ok(Self {
+            _greet,
+            _ecdsa: Default::default(),
+        })
+    }
+}
+
+async fn check_darwinia_relayer<T: EcdsaClient>(
+    config: &BridgeConfig<T>,
+) -> color_eyre::Result<()> {
+    let client = config.darwinia_evm.to_web3_client()?;
+    let relayer = config.darwinia_evm.to_ethereum_account().address()?;
+    let balance = client.eth().balance(relayer, None).await?;
+    let fee_market = FeeMarket::new(
+        &client,
+        Address::from_str(&config.darwinia_evm.fee_market_address)?,
+    )?;
+    let collateral = fee_market.balance_of(relayer).await?;
+    update_submit_state(
+        keepstate::DARWINIA_CHAIN,
+        relayer,
+        balance,
+        collateral,
+        config.darwinia_evm.reserve_balance.as_deref(),
+    )
+}
+
+async fn check_ethereum_relayer<T: EcdsaClient>(
+    config: &BridgeConfig<T>,
+) -> color_eyre::Result<()> {
+    let client = config.ethereum.to_web3_client()?;
+    let relayer = config.ethereum.to_ethereum_account().address()?;
+    let balance = client.eth().balance(relayer, None).await?;
+    let fee_market = SimpleFeeMarket::new(
+        &client,
+        Address::from_str(&config.ethereum.fee_market_address)?,
+    )?;
+    let collateral = fee_market.balance_of(relayer).await?;
+    update_submit_state(
+        keepstate::ETHEREUM_CHAIN,
+        relayer,
+        balance,
+        collateral,
+        config.ethereum.reserve_balance.as_deref(),
+    )
+}
+
+fn update_submit_state(
+    chain: &str,
+    relayer: Address,
+    balance: U256,
+    collateral: U256,
+    reserve_balance: Option<&str>,
+) -> color_eyre::Result<()> {
+    tracing::info!(
+        target: "darwinia-eth",
+        "[balance-monitor] [{}] relayer {:?} balance: {}, fee market collateral: {}",
+        chain,
+        relayer,
+        balance,
+        collateral,
+    );
+    let paused = match reserve_balance {
+        Some(reserve) => balance < U256::from_dec_str(reserve)?,
+        None => false,
+    };
+    let previous = keepstate::is_submit_paused(chain, &relayer);
+    if paused && !previous {
+        tracing::warn!(
+            target: "darwinia-eth",
+            "[balance-monitor] [{}] relayer {:?} balance {} is below reserve {:?}, pause submitting",
+            chain,
+            relayer,
+            balance,
+            reserve_balance,
+        );
+    }
+    if !paused && previous {
+        tracing::info!(
+            target: "darwinia-eth",
+            "[balance-monitor] [{}] relayer {:?} balance recovered, resume submitting",
+            chain,
+            relayer,
+        );
+    }
+    keepstate::set_submit_paused(chain, relayer, paused);
+    Ok(())
+}
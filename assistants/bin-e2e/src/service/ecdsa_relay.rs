@@ -6,19 +6,21 @@ use lifeline::{Lifeline, Service, Task};
 
 use component_state::state::BridgeState;
 use relay_e2e::ecdsa::ecdsa_scanner::EcdsaScanner;
+use relay_e2e::ecdsa::journal::SignatureJournal;
 use relay_e2e::error::{RelayError, RelayResult};
 use support_lifeline::service::BridgeService;
 use support_tracker::Tracker;
 
 use crate::bridge::BridgeBus;
 use crate::config::BridgeConfig;
-use relay_e2e::ecdsa::{ecdsa_scanner::EcdsaScanType, types::EcdsaSource};
+use relay_e2e::ecdsa::ecdsa_scanner::EcdsaScanType;
+use relay_e2e::ecdsa::types::{EcdsaAuthority, EcdsaSource};
 
 #[derive(Debug)]
 pub struct ECDSARelayService<T: EcdsaClient> {
-    _greet_collecting_message: Lifeline,
+    _greet_collecting_message: Option<Lifeline>,
     _greet_collected_message: Lifeline,
-    _greet_collecting_authorities: Lifeline,
+    _greet_collecting_authorities: Option<Lifeline>,
     _greet_collected_authorities: Lifeline,
     _ecdsa: PhantomData<T>,
 }
@@ -32,17 +34,28 @@ impl<T: EcdsaClient> Service for ECDSARelayService<T> {
     fn spawn(bus: &Self::Bus) -> Self::Lifeline {
         let bridge_config: BridgeConfig<T> = bus.storage().clone_resource()?;
         let state = bus.storage().clone_resource::<BridgeState>()?;
-        let microkv = state.microkv_with_namespace(bridge_config.name.clone());
+        let namespace = state.namespace(&bridge_config.name);
+        let journal = SignatureJournal::new(namespace.clone());
         let tracker_collecting_message =
-            Tracker::new(microkv.clone(), "scan.darwinia.collecting-message");
+            Tracker::new(namespace.clone(), "scan.darwinia.collecting-message");
         let tracker_collected_message =
-            Tracker::new(microkv.clone(), "scan.darwinia.collected-message");
+            Tracker::new(namespace.clone(), "scan.darwinia.collected-message");
         let tracker_collecting_authorities =
-            Tracker::new(microkv.clone(), "scan.darwinia.collecting-authorities");
+            Tracker::new(namespace.clone(), "scan.darwinia.collecting-authorities");
         let tracker_collected_authorities =
-            Tracker::new(microkv, "scan.darwinia.collected-authorities");
-        let mut ecdsa_source = Self::get_ecdsa_source(bridge_config.clone())?;
-        let _greet_collecting_message =
+            Tracker::new(namespace, "scan.darwinia.collected-authorities");
+        // collecting rounds are signed by authorities only
+        let is_authority =
+            bridge_config.substrate_client.is_some() && bridge_config.ecdsa.is_some();
+        if !is_authority {
+            tracing::info!(
+                target: "bin-e2e",
+                module = "ecdsa",
+                "no authority key configured, skip signing collecting rounds",
+            );
+        }
+        let mut ecdsa_source = Self::get_ecdsa_source(bridge_config.clone(), journal.clone())?;
+        let _greet_collecting_message = is_authority.then(|| {
             Self::try_task("substrate-to-eth-ecdsa-collecting-message", async move {
                 ecdsa_source
                     .start(
@@ -51,8 +64,9 @@ impl<T: EcdsaClient> Service for ECDSARelayService<T> {
                     )
                     .await;
                 Ok(())
-            });
-        let mut ecdsa_source = Self::get_ecdsa_source(bridge_config.clone())?;
+            })
+        });
+        let mut ecdsa_source = Self::get_ecdsa_source(bridge_config.clone(), journal.clone())?;
         let _greet_collected_message =
             Self::try_task("substrate-to-eth-ecdsa-collected-message", async move {
                 ecdsa_source
@@ -63,20 +77,22 @@ impl<T: EcdsaClient> Service for ECDSARelayService<T> {
                     .await;
                 Ok(())
             });
-        let mut ecdsa_source = Self::get_ecdsa_source(bridge_config.clone())?;
-        let _greet_collecting_authorities = Self::try_task(
-            "substrate-to-eth-ecdsa-collecting-authorities",
-            async move {
-                ecdsa_source
-                    .start(
-                        tracker_collecting_authorities.clone(),
-                        EcdsaScanType::CollectingAuthority,
-                    )
-                    .await;
-                Ok(())
-            },
-        );
-        let mut ecdsa_source = Self::get_ecdsa_source(bridge_config)?;
+        let mut ecdsa_source = Self::get_ecdsa_source(bridge_config.clone(), journal.clone())?;
+        let _greet_collecting_authorities = is_authority.then(|| {
+            Self::try_task(
+                "substrate-to-eth-ecdsa-collecting-authorities",
+                async move {
+                    ecdsa_source
+                        .start(
+                            tracker_collecting_authorities.clone(),
+                            EcdsaScanType::CollectingAuthority,
+                        )
+                        .await;
+                    Ok(())
+                },
+            )
+        });
+        let mut ecdsa_source = Self::get_ecdsa_source(bridge_config, journal)?;
         let _greet_collected_authorities =
             Self::try_task("substrate-to-eth-ecdsa-collected-authorities", async move {
                 ecdsa_source
@@ -98,8 +114,11 @@ impl<T: EcdsaClient> Service for ECDSARelayService<T> {
 }
 
 impl<T: EcdsaClient> ECDSARelayService<T> {
-    fn get_ecdsa_source(config: BridgeConfig<T>) -> RelayResult<EcdsaSource<T>> {
-        let subquery = config.substrate_index;
+    fn get_ecdsa_source(
+        config: BridgeConfig<T>,
+        journal: SignatureJournal,
+    ) -> RelayResult<EcdsaSource<T>> {
+        let events = config.ecdsa_events;
         let client_darwinia_web3 = config
             .darwinia_evm
             .to_web3_client()
@@ -108,23 +127,28 @@ impl<T: EcdsaClient> ECDSARelayService<T> {
             .ethereum
             .to_web3_client()
             .map_err(|e| RelayError::Custom(format!("{}", e)))?;
-        let client_darwinia_substrate = config.substrate_client;
+        let authority = match (config.substrate_client, config.ecdsa) {
+            (Some(client), Some(ecdsa)) => Some(EcdsaAuthority {
+                client,
+                account: ecdsa.to_authority_account(),
+                journal,
+            }),
+            _ => None,
+        };
         let client_posa = config
             .ethereum
             .to_posa_client()
             .map_err(|e| RelayError::Custom(format!("{}", e)))?;
-        let darwinia_evm_account = config.darwinia_evm.to_ethereum_account();
         let ethereum_account = config.ethereum.to_ethereum_account();
         let minimal_interval = config.general.header_relay_minimum_interval;
         Ok(EcdsaSource {
             block: None,
             client_darwinia_web3,
             client_eth_web3,
-            subquery,
+            events,
             client_posa,
-            client_darwinia_substrate,
+            authority,
             ethereum_account,
-            darwinia_evm_account,
             minimal_interval,
         })
     }
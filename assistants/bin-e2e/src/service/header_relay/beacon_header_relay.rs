@@ -75,13 +75,14 @@ impl<T: EcdsaClient> EthereumToDarwiniaHeaderRelayService<T> {
         channel_rx: Receiver<EthereumHeader>,
     ) -> color_eyre::Result<BeaconHeaderRelayRunner<EthLightClient, EthereumHeader>> {
         let darwinia_client = EthLightClient::new(
-            &config.darwinia_evm.endpoint,
+            config.darwinia_evm.to_transport()?,
             Address::from_str(&config.darwinia_evm.contract_address)?,
             &config.darwinia_evm.private_key,
             U256::from_dec_str(&config.darwinia_evm.max_gas_price)?,
         )?;
         let eth_client = BeaconApiClient::new(&config.beacon.endpoint, config.beacon.api_supplier)?;
         Ok(BeaconHeaderRelayRunner {
+            trigger: config.beacon.to_relay_trigger(),
             eth_light_client: darwinia_client,
             beacon_api_client: eth_client,
             minimal_interval: config.general.header_relay_minimum_interval,
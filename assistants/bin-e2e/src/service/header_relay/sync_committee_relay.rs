@@ -57,7 +57,7 @@ impl<T: EcdsaClient> Service for SyncCommitteeUpdateService<T> {
 impl<T: EcdsaClient> SyncCommitteeUpdateService<T> {
     async fn start(config: BridgeConfig<T>) -> color_eyre::Result<()> {
         let darwinia_client = EthLightClient::new(
-            &config.darwinia_evm.endpoint,
+            config.darwinia_evm.to_transport()?,
             Address::from_str(&config.darwinia_evm.contract_address)?,
             &config.darwinia_evm.private_key,
             U256::from_dec_str(&config.darwinia_evm.max_gas_price)?,
@@ -66,6 +66,7 @@ impl<T: EcdsaClient> SyncCommitteeUpdateService<T> {
         let mut update_manager = SyncCommitteeRelayRunner {
             eth_light_client: darwinia_client,
             beacon_api_client: eth_client,
+            trigger: config.beacon.to_relay_trigger(),
         };
 
         update_manager.start().await?;
@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use bridge_e2e_traits::client::{EcdsaClient, OnDemandHeader};
 use lifeline::dyn_bus::DynBus;
+use relay_e2e::keepstate;
 use relay_e2e::message::darwinia_message_client::DarwiniaMessageClient;
 use relay_e2e::message::ethereum_message_client::EthMessageClient;
 use relay_e2e::message::message_relay_runner::{ChannelState, MessageRelayRunner};
@@ -11,6 +12,7 @@ use web3::types::{Address, U256};
 
 use crate::bridge::BridgeBus;
 use crate::config::BridgeConfig;
+use crate::service::message_relay::{confirmation_trigger, delivery_trigger};
 use crate::service::header_relay::types::{DarwiniaHeader, EthereumHeader};
 use lifeline::{Lifeline, Service, Task, Bus};
 use support_lifeline::service::BridgeService;
@@ -96,8 +98,8 @@ where
     O2: OnDemandHeader,
 {
     let eth_message_client = EthMessageClient::new_with_simple_fee_market(
-        "Eth",
-        &config.ethereum.endpoint,
+        keepstate::ETHEREUM_CHAIN,
+        config.ethereum.to_transport()?,
         Address::from_str(&config.ethereum.inbound_address)?,
         Address::from_str(&config.ethereum.outbound_address)?,
         Address::from_str(&config.ethereum.fee_market_address)?,
@@ -107,8 +109,8 @@ where
         &config.ethereum.etherscan_api_key,
     )?;
     let darwinia_message_client = DarwiniaMessageClient::new_with_fee_market(
-        "Substrate",
-        &config.darwinia_evm.endpoint,
+        keepstate::DARWINIA_CHAIN,
+        config.darwinia_evm.to_transport()?,
         &config.beacon.endpoint,
         config.beacon.api_supplier,
         Address::from_str(&config.darwinia_evm.inbound_address)?,
@@ -133,8 +135,15 @@ where
 }
 
 async fn start_delivery<T: EcdsaClient>(config: BridgeConfig<T>) -> color_eyre::Result<()> {
+    let source_ws_endpoint = config.darwinia_evm.ws_endpoint.clone();
+    let target_ws_endpoint = config.ethereum.ws_endpoint.clone();
     let mut service =
         message_relay_client_builder::<_, DarwiniaHeader, EthereumHeader>(config, None, None)?;
+    let trigger = delivery_trigger(
+        &service,
+        source_ws_endpoint.as_ref(),
+        target_ws_endpoint.as_ref(),
+    )?;
     loop {
         if let Err(error) = service.message_relay().await {
             tracing::error!(
@@ -144,7 +153,7 @@ async fn start_delivery<T: EcdsaClient>(config: BridgeConfig<T>) -> color_eyre::
             );
             return Err(error.into());
         }
-        tokio::time::sleep(std::time::Duration::from_secs(15)).await;
+        trigger.wait().await;
     }
 }
 
@@ -152,11 +161,18 @@ async fn start_confirmation<T: EcdsaClient>(
     config: BridgeConfig<T>,
     confirm_channel_tx: Sender<EthereumHeader>,
 ) -> color_eyre::Result<()> {
+    let source_ws_endpoint = config.darwinia_evm.ws_endpoint.clone();
+    let target_ws_endpoint = config.ethereum.ws_endpoint.clone();
     let mut service = message_relay_client_builder::<_, DarwiniaHeader, EthereumHeader>(
         config,
         None,
         Some(confirm_channel_tx),
     )?;
+    let trigger = confirmation_trigger(
+        &service,
+        source_ws_endpoint.as_ref(),
+        target_ws_endpoint.as_ref(),
+    )?;
     loop {
         if let Err(error) = service.message_confirm().await {
             tracing::error!(
@@ -166,6 +182,6 @@ async fn start_confirmation<T: EcdsaClient>(
             );
             return Err(error.into());
         }
-        tokio::time::sleep(std::time::Duration::from_secs(15)).await;
+        trigger.wait().await;
     }
 }
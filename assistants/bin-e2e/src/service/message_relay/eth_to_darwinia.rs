@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use bridge_e2e_traits::client::{EcdsaClient, OnDemandHeader};
 use lifeline::dyn_bus::DynBus;
+use relay_e2e::keepstate;
 use relay_e2e::message::darwinia_message_client::DarwiniaMessageClient;
 use relay_e2e::message::ethereum_message_client::EthMessageClient;
 use relay_e2e::message::message_relay_runner::{ChannelState, MessageRelayRunner};
@@ -11,6 +12,7 @@ use web3::types::{Address, U256};
 
 use crate::bridge::BridgeBus;
 use crate::config::BridgeConfig;
+use crate::service::message_relay::{confirmation_trigger, delivery_trigger};
 use crate::service::header_relay::types::{DarwiniaHeader, EthereumHeader};
 use lifeline::{Bus, Lifeline, Service, Task};
 use support_lifeline::service::BridgeService;
@@ -97,8 +99,8 @@ where
     O2: OnDemandHeader,
 {
     let eth_message_client = EthMessageClient::new_with_simple_fee_market(
-        "Eth",
-        &config.ethereum.endpoint,
+        keepstate::ETHEREUM_CHAIN,
+        config.ethereum.to_transport()?,
         Address::from_str(&config.ethereum.inbound_address)?,
         Address::from_str(&config.ethereum.outbound_address)?,
         Address::from_str(&config.ethereum.fee_market_address)?,
@@ -108,8 +110,8 @@ where
         &config.ethereum.etherscan_api_key,
     )?;
     let darwinia_message_client = DarwiniaMessageClient::new_with_fee_market(
-        "Substrate",
-        &config.darwinia_evm.endpoint,
+        keepstate::DARWINIA_CHAIN,
+        config.darwinia_evm.to_transport()?,
         &config.beacon.endpoint,
         config.beacon.api_supplier,
         Address::from_str(&config.darwinia_evm.inbound_address)?,
@@ -137,7 +139,15 @@ async fn start_delivery<T: EcdsaClient>(
     config: BridgeConfig<T>,
     channel_tx: Sender<EthereumHeader>,
 ) -> color_eyre::Result<()> {
-    let mut message_relay_service = message_relay_client_builder::<_, _, DarwiniaHeader>(config, Some(channel_tx)).await?;
+    let source_ws_endpoint = config.ethereum.ws_endpoint.clone();
+    let target_ws_endpoint = config.darwinia_evm.ws_endpoint.clone();
+    let mut message_relay_service =
+        message_relay_client_builder::<_, _, DarwiniaHeader>(config, Some(channel_tx)).await?;
+    let trigger = delivery_trigger(
+        &message_relay_service,
+        source_ws_endpoint.as_ref(),
+        target_ws_endpoint.as_ref(),
+    )?;
     loop {
         if let Err(error) = message_relay_service.message_relay().await {
             tracing::error!(
@@ -147,13 +157,20 @@ async fn start_delivery<T: EcdsaClient>(
             );
             return Err(error.into());
         }
-        tokio::time::sleep(std::time::Duration::from_secs(15)).await;
+        trigger.wait().await;
     }
 }
 
 async fn start_confirmation<T: EcdsaClient>(config: BridgeConfig<T>) -> color_eyre::Result<()> {
+    let source_ws_endpoint = config.ethereum.ws_endpoint.clone();
+    let target_ws_endpoint = config.darwinia_evm.ws_endpoint.clone();
     let mut message_relay_service =
         message_relay_client_builder::<_, EthereumHeader, DarwiniaHeader>(config, None).await?;
+    let trigger = confirmation_trigger(
+        &message_relay_service,
+        source_ws_endpoint.as_ref(),
+        target_ws_endpoint.as_ref(),
+    )?;
     loop {
         if let Err(error) = message_relay_service.message_confirm().await {
             tracing::error!(
@@ -163,6 +180,6 @@ async fn start_confirmation<T: EcdsaClient>(config: BridgeConfig<T>) -> color_ey
             );
             return Err(error.into());
         }
-        tokio::time::sleep(std::time::Duration::from_secs(15)).await;
+        trigger.wait().await;
     }
 }
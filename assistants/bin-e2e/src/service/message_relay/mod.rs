@@ -1,2 +1,63 @@
 pub mod darwinia_to_eth;
 pub mod eth_to_darwinia;
+
+use std::time::Duration;
+
+use bridge_e2e_traits::client::{MessageClient, MessageEventsQuery, OnDemandHeader};
+use relay_e2e::message::message_relay_runner::MessageRelayRunner;
+use relay_e2e::trigger::RelayTrigger;
+
+/// Polling interval(seconds) of message relay, used when no subscription is available
+const MESSAGE_RELAY_INTERVAL: u64 = 15;
+
+/// Wakes delivery when the source outbound accepts messages or the target produces blocks
+pub fn delivery_trigger<S0, S1, O1, O2>(
+    runner: &MessageRelayRunner<S0, S1, O1, O2>,
+    source_ws_endpoint: Option<&String>,
+    target_ws_endpoint: Option<&String>,
+) -> color_eyre::Result<RelayTrigger>
+where
+    S0: MessageClient + MessageEventsQuery,
+    S1: MessageClient + MessageEventsQuery,
+    O1: OnDemandHeader,
+    O2: OnDemandHeader,
+{
+    let mut trigger = RelayTrigger::new(Duration::from_secs(MESSAGE_RELAY_INTERVAL));
+    if let Some(endpoint) = source_ws_endpoint {
+        trigger.subscribe_contract_event(
+            endpoint,
+            &runner.source.outbound().contract,
+            "MessageAccepted",
+        )?;
+    }
+    if let Some(endpoint) = target_ws_endpoint {
+        trigger.subscribe_new_heads(endpoint);
+    }
+    Ok(trigger)
+}
+
+/// Wakes confirmation when the target inbound dispatches messages or the source produces blocks
+pub fn confirmation_trigger<S0, S1, O1, O2>(
+    runner: &MessageRelayRunner<S0, S1, O1, O2>,
+    source_ws_endpoint: Option<&String>,
+    target_ws_endpoint: Option<&String>,
+) -> color_eyre::Result<RelayTrigger>
+where
+    S0: MessageClient + MessageEventsQuery,
+    S1: MessageClient + MessageEventsQuery,
+    O1: OnDemandHeader,
+    O2: OnDemandHeader,
+{
+    let mut trigger = RelayTrigger::new(Duration::from_secs(MESSAGE_RELAY_INTERVAL));
+    if let Some(endpoint) = target_ws_endpoint {
+        trigger.subscribe_contract_event(
+            endpoint,
+            &runner.target.inbound().contract,
+            "MessageDispatched",
+        )?;
+    }
+    if let Some(endpoint) = source_ws_endpoint {
+        trigger.subscribe_new_heads(endpoint);
+    }
+    Ok(trigger)
+}
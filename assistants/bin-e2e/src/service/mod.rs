@@ -1,3 +1,4 @@
+pub mod balance_monitor;
 pub mod ecdsa_relay;
 pub mod header_relay;
 pub mod message_relay;
@@ -8,6 +8,7 @@ use support_lifeline::task::TaskStack;
 
 use crate::bridge::BridgeBus;
 use crate::config::BridgeConfig;
+use crate::service::balance_monitor::BalanceMonitorService;
 use crate::service::ecdsa_relay::ECDSARelayService;
 use crate::service::header_relay::beacon_header_relay::EthereumToDarwiniaHeaderRelayService;
 use crate::service::header_relay::sync_committee_relay::SyncCommitteeUpdateService;
@@ -25,11 +26,13 @@ impl<T: EcdsaClient> BridgeTask<T> {
         let bus = BridgeBus::default();
         let state = BridgeState::new(StateOptions {
             db_name: bridge_config.name.clone(),
+            backend: None,
         })?;
 
         bus.store_resource::<BridgeState>(state);
         let mut stack = TaskStack::new(bus);
         stack.bus().store_resource(bridge_config.clone());
+        stack.spawn_service::<BalanceMonitorService<T>>()?;
         if bridge_config.general.enable_beacon_header_relay {
             stack.spawn_service::<EthereumToDarwiniaHeaderRelayService<T>>()?;
         }
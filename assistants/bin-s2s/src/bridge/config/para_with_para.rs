@@ -1,8 +1,11 @@
+use client_common_traits::ClientCommon;
 use lifeline::Storage;
 use serde::{Deserialize, Serialize};
 
 use crate::bridge::config::{ParaWithParaConfig, RelayConfig};
 use crate::bridge::BridgeBus;
+use crate::error::BinS2SResult;
+use crate::preflight::PreflightReport;
 use crate::traits::{S2SParaBridgeRelayChainInfo, S2SParaBridgeSoloChainInfo, SubqueryInfo};
 
 /// Para with para bridge config
@@ -47,6 +50,47 @@ impl<
 {
 }
 
+impl<
+        SRCI: S2SParaBridgeRelayChainInfo,
+        SPCI: S2SParaBridgeSoloChainInfo,
+        TRCI: S2SParaBridgeRelayChainInfo,
+        TPCI: S2SParaBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > BridgeConfig<SRCI, SPCI, TRCI, TPCI, SI>
+{
+    /// Check endpoints, bridge initialization and lanes of all chains
+    pub async fn preflight(&self) -> BinS2SResult<PreflightReport> {
+        let client_source_para = self.chain.source_para.client().await?;
+        let client_source_relay = self.chain.source_relay.client().await?;
+        let client_target_para = self.chain.target_para.client().await?;
+        let client_target_relay = self.chain.target_relay.client().await?;
+
+        let mut report = PreflightReport::default();
+        report.check_identity(&client_source_para).await;
+        report.check_identity(&client_source_relay).await;
+        report.check_identity(&client_target_para).await;
+        report.check_identity(&client_target_relay).await;
+        report.check_runtime(&client_source_para).await;
+        report.check_runtime(&client_source_relay).await;
+        report.check_runtime(&client_target_para).await;
+        report.check_runtime(&client_target_relay).await;
+        report
+            .check_initialized(&client_source_para, <TRCI::Client as ClientCommon>::CHAIN)
+            .await;
+        report
+            .check_initialized(&client_target_para, <SRCI::Client as ClientCommon>::CHAIN)
+            .await;
+        let lanes = self.relay.raw_lanes();
+        report
+            .check_lanes(&client_source_para, &client_target_para, &lanes)
+            .await;
+        report
+            .check_lanes(&client_target_para, &client_source_para, &lanes)
+            .await;
+        Ok(report)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChainConfig<
     SRCI: S2SParaBridgeRelayChainInfo,
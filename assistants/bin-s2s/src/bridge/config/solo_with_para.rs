@@ -1,8 +1,11 @@
+use client_common_traits::ClientCommon;
 use lifeline::Storage;
 use serde::{Deserialize, Serialize};
 
 use crate::bridge::config::{RelayConfig, SoloWithParaConfig};
 use crate::bridge::BridgeBus;
+use crate::error::BinS2SResult;
+use crate::preflight::PreflightReport;
 use crate::traits::{
     S2SParaBridgeRelayChainInfo, S2SParaBridgeSoloChainInfo, S2SSoloBridgeSoloChainInfo,
     SubqueryInfo,
@@ -47,6 +50,39 @@ impl<
 {
 }
 
+impl<
+        SCI: S2SParaBridgeSoloChainInfo,
+        RCI: S2SParaBridgeRelayChainInfo,
+        PCI: S2SSoloBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > BridgeConfig<SCI, RCI, PCI, SI>
+{
+    /// Check endpoints, bridge initialization and lanes of all chains
+    pub async fn preflight(&self) -> BinS2SResult<PreflightReport> {
+        let client_solo = self.chain.solo.client().await?;
+        let client_relay = self.chain.relay.client().await?;
+        let client_para = self.chain.para.client().await?;
+
+        let mut report = PreflightReport::default();
+        report.check_identity(&client_solo).await;
+        report.check_identity(&client_relay).await;
+        report.check_identity(&client_para).await;
+        report.check_runtime(&client_solo).await;
+        report.check_runtime(&client_relay).await;
+        report.check_runtime(&client_para).await;
+        report
+            .check_initialized(&client_solo, <RCI::Client as ClientCommon>::CHAIN)
+            .await;
+        report
+            .check_initialized(&client_para, <SCI::Client as ClientCommon>::CHAIN)
+            .await;
+        let lanes = self.relay.raw_lanes();
+        report.check_lanes(&client_solo, &client_para, &lanes).await;
+        report.check_lanes(&client_para, &client_solo, &lanes).await;
+        Ok(report)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChainConfig<
     SCI: S2SParaBridgeSoloChainInfo,
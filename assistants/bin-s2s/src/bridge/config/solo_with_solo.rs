@@ -1,8 +1,11 @@
+use client_common_traits::ClientCommon;
 use lifeline::Storage;
 use serde::{Deserialize, Serialize};
 
 use crate::bridge::config::RelayConfig;
 use crate::bridge::BridgeBus;
+use crate::error::BinS2SResult;
+use crate::preflight::PreflightReport;
 use crate::traits::{S2SSoloBridgeSoloChainInfo, SubqueryInfo};
 
 /// Solo with solo bridge config
@@ -33,6 +36,36 @@ impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: Subqu
 {
 }
 
+impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: SubqueryInfo>
+    BridgeConfig<SCI, TCI, SI>
+{
+    /// Check endpoints, bridge initialization and lanes of both chains
+    pub async fn preflight(&self) -> BinS2SResult<PreflightReport> {
+        let client_source = self.chain.source.client().await?;
+        let client_target = self.chain.target.client().await?;
+
+        let mut report = PreflightReport::default();
+        report.check_identity(&client_source).await;
+        report.check_identity(&client_target).await;
+        report.check_runtime(&client_source).await;
+        report.check_runtime(&client_target).await;
+        report
+            .check_initialized(&client_source, <TCI::Client as ClientCommon>::CHAIN)
+            .await;
+        report
+            .check_initialized(&client_target, <SCI::Client as ClientCommon>::CHAIN)
+            .await;
+        let lanes = self.relay.raw_lanes();
+        report
+            .check_lanes(&client_source, &client_target, &lanes)
+            .await;
+        report
+            .check_lanes(&client_target, &client_source, &lanes)
+            .await;
+        Ok(report)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChainConfig<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo> {
     /// Source chain
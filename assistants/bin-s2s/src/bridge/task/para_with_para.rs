@@ -8,9 +8,9 @@ use crate::bridge::config::para_with_para::BridgeConfig;
 use crate::bridge::BridgeBus;
 use crate::service::feemarket::FeemarketService;
 use crate::service::para_with_para::{
-    SourceToTargetMessageRelayService, SourceToTargetParaHeadRelayService,
-    SourceToTargetRelaychainGrandpaRelayService, SubscribeService,
-    TargetToSourceMessageRelayService, TargetToSourceParaHeadRelayService,
+    BalanceMonitorService, RuntimeMonitorService, SourceToTargetMessageRelayService,
+    SourceToTargetParaHeadRelayService, SourceToTargetRelaychainGrandpaRelayService,
+    SubscribeService, TargetToSourceMessageRelayService, TargetToSourceParaHeadRelayService,
     TargetToSourceRelaychainGrandpaRelayService,
 };
 use crate::traits::{S2SParaBridgeRelayChainInfo, S2SParaBridgeSoloChainInfo, SubqueryInfo};
@@ -46,6 +46,8 @@ impl<
         let mut stack = TaskStack::new(bus);
         stack.bus().store_resource(bridge_config);
         stack.spawn_service::<FeemarketService>()?;
+        stack.spawn_service::<BalanceMonitorService<SRCI, SPCI, TRCI, TPCI, SI>>()?;
+        stack.spawn_service::<RuntimeMonitorService<SRCI, SPCI, TRCI, TPCI, SI>>()?;
         stack.spawn_service::<SubscribeService<SRCI, SPCI, TRCI, TPCI, SI>>()?;
         stack.spawn_service::<SourceToTargetParaHeadRelayService<SRCI, SPCI, TRCI, TPCI, SI>>()?;
         stack.spawn_service::<SourceToTargetRelaychainGrandpaRelayService<SRCI, SPCI, TRCI, TPCI, SI>>()?;
@@ -8,8 +8,9 @@ use crate::bridge::config::solo_with_para::BridgeConfig;
 use crate::bridge::BridgeBus;
 use crate::service::feemarket::FeemarketService;
 use crate::service::solo_with_para::{
-    ParaHeadToSolochainRelayService, ParachainToSolochainMessageRelayService,
-    RelaychainToSolochainHeaderRelayService, SolochainToParachainHeaderRelayService,
+    BalanceMonitorService, ParaHeadToSolochainRelayService,
+    ParachainToSolochainMessageRelayService, RelaychainToSolochainHeaderRelayService,
+    RuntimeMonitorService, SolochainToParachainHeaderRelayService,
     SolochainToParachainMessageRelayService, SubscribeService,
 };
 use crate::traits::{
@@ -44,6 +45,8 @@ impl<
         stack.bus().store_resource(bridge_config);
         stack.spawn_service::<SubscribeService<SCI, RCI, PCI, SI>>()?;
         stack.spawn_service::<FeemarketService>()?;
+        stack.spawn_service::<BalanceMonitorService<SCI, RCI, PCI, SI>>()?;
+        stack.spawn_service::<RuntimeMonitorService<SCI, RCI, PCI, SI>>()?;
         stack.spawn_service::<SolochainToParachainHeaderRelayService<SCI, RCI, PCI, SI>>()?;
         stack.spawn_service::<RelaychainToSolochainHeaderRelayService<SCI, RCI, PCI, SI>>()?;
         stack.spawn_service::<ParaHeadToSolochainRelayService<SCI, RCI, PCI, SI>>()?;
@@ -7,7 +7,9 @@ use support_lifeline::task::TaskStack;
 use crate::bridge::config::solo_with_solo::BridgeConfig;
 use crate::bridge::BridgeBus;
 use crate::service::feemarket::FeemarketService;
-use crate::service::solo_with_solo::SubscribeService;
+use crate::service::solo_with_solo::{
+    BalanceMonitorService, RuntimeMonitorService, SubscribeService,
+};
 use crate::service::solo_with_solo::{
     SourceToTargetHeaderRelayService, SourceToTargetMessageRelayService,
     TargetToSourceHeaderRelayService, TargetToSourceMessageRelayService,
@@ -35,6 +37,8 @@ impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: Subqu
         stack.bus().store_resource(bridge_config);
         stack.spawn_service::<SubscribeService<SCI, TCI, SI>>()?;
         stack.spawn_service::<FeemarketService>()?;
+        stack.spawn_service::<BalanceMonitorService<SCI, TCI, SI>>()?;
+        stack.spawn_service::<RuntimeMonitorService<SCI, TCI, SI>>()?;
         stack.spawn_service::<SourceToTargetHeaderRelayService<SCI, TCI, SI>>()?;
         stack.spawn_service::<TargetToSourceHeaderRelayService<SCI, TCI, SI>>()?;
         stack.spawn_service::<SourceToTargetMessageRelayService<SCI, TCI, SI>>()?;
@@ -0,0 +1,307 @@
+use std::ops::RangeInclusive;
+
+use bridge_s2s_traits::client::S2SClientRelay;
+use bridge_s2s_traits::types::bp_messages::MessageKey;
+use client_common_traits::ClientCommon;
+use feemarket_s2s_traits::api::FeemarketApiRelay;
+use serde::{Deserialize, Serialize};
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+
+use support_terminal::output::{self, OutputFormat};
+
+use crate::error::{BinS2SError, BinS2SResult};
+
+/// Messages of a lane, read from the source chain and the target chain
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LaneInspection {
+    pub lane: String,
+    pub source_chain: String,
+    pub target_chain: String,
+    /// Outbound lane data at source chain
+    pub outbound: OutboundLaneInspection,
+    /// Inbound lane data at target chain
+    pub inbound: InboundLaneInspection,
+    pub messages: Vec<MessageInspection>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OutboundLaneInspection {
+    pub oldest_unpruned_nonce: u64,
+    pub latest_received_nonce: u64,
+    pub latest_generated_nonce: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InboundLaneInspection {
+    pub last_delivered_nonce: u64,
+    pub last_confirmed_nonce: u64,
+    pub unrewarded_relayer_entries: usize,
+}
+
+/// Relay status of a message
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MessageStatus {
+    /// Not sent yet
+    NotSent,
+    /// Sent at source chain, waiting for delivery
+    Pending,
+    /// Delivered to target chain, waiting for confirmation
+    Delivered,
+    /// Delivery confirmed at source chain
+    Confirmed,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MessageInspection {
+    pub nonce: u64,
+    pub status: MessageStatus,
+    /// Delivery and dispatch fee, none if the message is pruned or not sent
+    pub fee: Option<u128>,
+    pub payload: Option<PayloadInspection>,
+    pub order: Option<OrderInspection>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PayloadInspection {
+    pub spec_version: u32,
+    pub weight: u64,
+    pub origin: String,
+    pub dispatch_fee_payment: String,
+    /// Encoded call in hex
+    pub call: String,
+    /// Call decoded by the runtime metadata of target chain, none if it can't be decoded,
+    /// e.g. the message is built for another runtime version
+    pub decoded_call: Option<String>,
+}
+
+/// Fee market order of a message
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderInspection {
+    pub sent_time: String,
+    pub confirm_time: Option<String>,
+    /// Assigned relayers with fee and valid range
+    pub assigned_relayers: Vec<String>,
+}
+
+/// Read lane data, payload and fee market order of the messages in the nonce range
+pub async fn inspect_lane<SC, TC>(
+    client_source: &SC,
+    client_target: &TC,
+    lane: [u8; 4],
+    nonces: RangeInclusive<u64>,
+) -> BinS2SResult<LaneInspection>
+where
+    SC: S2SClientRelay + FeemarketApiRelay,
+    TC: S2SClientRelay,
+{
+    if nonces.is_empty() {
+        return Err(BinS2SError::Custom(format!(
+            "Wrong nonces {:?}, the end nonce is less than the begin nonce",
+            nonces
+        )));
+    }
+    let outbound_lane_data = client_source
+        .outbound_lanes(lane, None)
+        .await
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+    let inbound_lane_data = client_target
+        .inbound_lanes(lane, None)
+        .await
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+    let last_delivered_nonce = inbound_lane_data.last_delivered_nonce();
+
+    let mut messages = Vec::new();
+    for nonce in nonces {
+        let status = if nonce > outbound_lane_data.latest_generated_nonce {
+            MessageStatus::NotSent
+        } else if nonce <= outbound_lane_data.latest_received_nonce {
+            MessageStatus::Confirmed
+        } else if nonce <= last_delivered_nonce {
+            MessageStatus::Delivered
+        } else {
+            MessageStatus::Pending
+        };
+        let message_data = client_source
+            .outbound_messages(
+                MessageKey {
+                    lane_id: lane,
+                    nonce,
+                },
+                None,
+            )
+            .await
+            .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+        let (fee, payload) = match message_data {
+            Some(message_data) => {
+                let payload = client_source
+                    .decode_message_payload(&message_data.payload)
+                    .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+                let decoded_call = client_target.decode_dispatch_call(&payload.call).ok();
+                (
+                    Some(message_data.fee),
+                    Some(PayloadInspection {
+                        spec_version: payload.spec_version,
+                        weight: payload.weight,
+                        origin: payload.origin,
+                        dispatch_fee_payment: payload.dispatch_fee_payment,
+                        call: array_bytes::bytes2hex("0x", &payload.call),
+                        decoded_call,
+                    }),
+                )
+            }
+            None => (None, None),
+        };
+        let order = client_source
+            .order(lane, nonce)
+            .await
+            .map_err(|e| BinS2SError::Client(format!("{e:?}")))?
+            .map(|order| OrderInspection {
+                sent_time: format!("{:?}", order.sent_time),
+                confirm_time: order.confirm_time.map(|v| format!("{v:?}")),
+                assigned_relayers: order
+                    .assigned_relayers
+                    .iter()
+                    .map(|relayer| {
+                        format!(
+                            "{:?} (fee: {:?}, valid range: {:?})",
+                            relayer.id, relayer.fee, relayer.valid_range
+                        )
+                    })
+                    .collect(),
+            });
+        messages.push(MessageInspection {
+            nonce,
+            status,
+            fee,
+            payload,
+            order,
+        });
+    }
+
+    Ok(LaneInspection {
+        lane: array_bytes::bytes2hex("0x", lane),
+        source_chain: <SC as ClientCommon>::CHAIN.to_string(),
+        target_chain: <TC as ClientCommon>::CHAIN.to_string(),
+        outbound: OutboundLaneInspection {
+            oldest_unpruned_nonce: outbound_lane_data.oldest_unpruned_nonce,
+            latest_received_nonce: outbound_lane_data.latest_received_nonce,
+            latest_generated_nonce: outbound_lane_data.latest_generated_nonce,
+        },
+        inbound: InboundLaneInspection {
+            last_delivered_nonce,
+            last_confirmed_nonce: inbound_lane_data.last_confirmed_nonce,
+            unrewarded_relayer_entries: inbound_lane_data.relayers.len(),
+        },
+        messages,
+    })
+}
+
+/// Print the lane inspection
+pub fn output_lane_inspection(
+    inspection: &LaneInspection,
+    out: OutputFormat,
+) -> color_eyre::Result<()> {
+    match out {
+        OutputFormat::Raw => {
+            output::output_text(format!(
+                "lane {} ({} -> {}), outbound: {:?}, inbound: {:?}",
+                inspection.lane,
+                inspection.source_chain,
+                inspection.target_chain,
+                inspection.outbound,
+                inspection.inbound,
+            ));
+            for message in &inspection.messages {
+                output::output_text(format!("{message:?}"));
+            }
+        }
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(inspection)?;
+            output::output_text(json);
+        }
+        OutputFormat::Table => {
+            let mut table = Table::new();
+            table.max_column_width = 80;
+            table.style = TableStyle::simple();
+            let mut add_row = |key: &str, value: String| {
+                table.add_row(Row::new(vec![
+                    TableCell::new_with_alignment(key, 1, Alignment::Left),
+                    TableCell::new_with_alignment(value, 1, Alignment::Left),
+                ]));
+            };
+            add_row(
+                "lane",
+                format!(
+                    "{} ({} -> {})",
+                    inspection.lane, inspection.source_chain, inspection.target_chain
+                ),
+            );
+            let outbound = &inspection.outbound;
+            add_row(
+                "outbound lane",
+                format!(
+                    "oldest unpruned: {}, latest received: {}, latest generated: {}",
+                    outbound.oldest_unpruned_nonce,
+                    outbound.latest_received_nonce,
+                    outbound.latest_generated_nonce,
+                ),
+            );
+            let inbound = &inspection.inbound;
+            add_row(
+                "inbound lane",
+                format!(
+                    "last delivered: {}, last confirmed: {}, unrewarded relayer entries: {}",
+                    inbound.last_delivered_nonce,
+                    inbound.last_confirmed_nonce,
+                    inbound.unrewarded_relayer_entries,
+                ),
+            );
+            output::output_text(table.render());
+
+            for message in &inspection.messages {
+                let mut table = Table::new();
+                table.max_column_width = 80;
+                table.style = TableStyle::simple();
+                let mut add_row = |key: &str, value: String| {
+                    table.add_row(Row::new(vec![
+                        TableCell::new_with_alignment(key, 1, Alignment::Left),
+                        TableCell::new_with_alignment(value, 1, Alignment::Left),
+                    ]));
+                };
+                add_row("nonce", message.nonce.to_string());
+                add_row("status", format!("{:?}", message.status));
+                add_row(
+                    "fee",
+                    message.fee.map(|v| v.to_string()).unwrap_or_default(),
+                );
+                if let Some(payload) = &message.payload {
+                    add_row("spec version", payload.spec_version.to_string());
+                    add_row("weight", payload.weight.to_string());
+                    add_row("origin", payload.origin.clone());
+                    add_row("dispatch fee payment", payload.dispatch_fee_payment.clone());
+                    add_row("call", payload.call.clone());
+                    add_row(
+                        "decoded call",
+                        payload
+                            .decoded_call
+                            .clone()
+                            .unwrap_or_else(|| "<can't decode by target metadata>".to_string()),
+                    );
+                }
+                if let Some(order) = &message.order {
+                    add_row("order sent time", order.sent_time.clone());
+                    add_row(
+                        "order confirm time",
+                        order.confirm_time.clone().unwrap_or_default(),
+                    );
+                    add_row("assigned relayers", order.assigned_relayers.join("\n"));
+                }
+                output::output_text(table.render());
+            }
+        }
+    }
+    Ok(())
+}
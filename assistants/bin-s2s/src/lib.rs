@@ -1,5 +1,9 @@
 pub mod bridge;
 pub mod error;
+pub mod inspect;
+#[cfg(feature = "para-with-para")]
+pub mod manual;
+pub mod preflight;
 pub mod traits;
 pub mod types;
 
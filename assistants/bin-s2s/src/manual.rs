@@ -0,0 +1,161 @@
+use std::ops::RangeInclusive;
+
+use feemarket_s2s::relay::basic::BasicRelayStrategy;
+use relay_s2s::header::RelaychainHeaderRunner;
+use relay_s2s::message::{BridgeParachainDeliveryRunner, BridgeParachainReceivingRunner};
+use relay_s2s::types::{
+    LaneId, MessageDeliveryInput, MessageReceivingInput, RelaychainHeaderInput,
+};
+
+use support_terminal::output;
+
+use crate::bridge::config::para_with_para::BridgeConfig;
+use crate::error::BinS2SResult;
+use crate::traits::{S2SParaBridgeRelayChainInfo, S2SParaBridgeSoloChainInfo, SubqueryInfo};
+
+pub use relay_s2s::types::{HeaderSelector, ManualRelayOptions, ManualRelayReport};
+
+/// One direction of para with para bridge, used to relay by hand once
+pub struct ParaWithParaManualRelay<
+    RCI: S2SParaBridgeRelayChainInfo,
+    SPCI: S2SParaBridgeSoloChainInfo,
+    TPCI: S2SParaBridgeSoloChainInfo,
+    SI: SubqueryInfo,
+> {
+    pub source_relay: RCI,
+    pub source_para: SPCI,
+    pub target_para: TPCI,
+    pub index_source_relay: SI,
+    pub index_source_para: SI,
+    pub index_target_para: SI,
+    pub source_para_id: u32,
+    pub target_para_id: u32,
+    pub enable_mandatory: bool,
+}
+
+impl<
+        SRCI: S2SParaBridgeRelayChainInfo,
+        SPCI: S2SParaBridgeSoloChainInfo,
+        TRCI: S2SParaBridgeRelayChainInfo,
+        TPCI: S2SParaBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > BridgeConfig<SRCI, SPCI, TRCI, TPCI, SI>
+{
+    pub fn source_to_target_manual(self) -> ParaWithParaManualRelay<SRCI, SPCI, TPCI, SI> {
+        ParaWithParaManualRelay {
+            source_relay: self.chain.source_relay,
+            source_para: self.chain.source_para,
+            target_para: self.chain.target_para,
+            index_source_relay: self.index.source_relay,
+            index_source_para: self.index.source_para,
+            index_target_para: self.index.target_para,
+            source_para_id: self.para_config.source_para_id,
+            target_para_id: self.para_config.target_para_id,
+            enable_mandatory: self.relay.enable_mandatory,
+        }
+    }
+
+    pub fn target_to_source_manual(self) -> ParaWithParaManualRelay<TRCI, TPCI, SPCI, SI> {
+        ParaWithParaManualRelay {
+            source_relay: self.chain.target_relay,
+            source_para: self.chain.target_para,
+            target_para: self.chain.source_para,
+            index_source_relay: self.index.target_relay,
+            index_source_para: self.index.target_para,
+            index_target_para: self.index.source_para,
+            source_para_id: self.para_config.target_para_id,
+            target_para_id: self.para_config.source_para_id,
+            enable_mandatory: self.relay.enable_mandatory,
+        }
+    }
+}
+
+impl<
+        RCI: S2SParaBridgeRelayChainInfo,
+        SPCI: S2SParaBridgeSoloChainInfo,
+        TPCI: S2SParaBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > ParaWithParaManualRelay<RCI, SPCI, TPCI, SI>
+{
+    /// Relay the source relay chain header with its justification to target chain
+    pub async fn relay_header(
+        &self,
+        block: HeaderSelector,
+        dry_run: bool,
+    ) -> BinS2SResult<ManualRelayReport> {
+        let input = RelaychainHeaderInput {
+            client_relaychain: self.source_relay.client().await?,
+            client_solochain: self.target_para.client().await?,
+            subquery_relaychain: self.index_source_relay.subquery()?,
+            subquery_parachain: self.index_source_para.subquery()?,
+            index_origin_type: self.target_para.origin_type(),
+            enable_mandatory: self.enable_mandatory,
+        };
+        let runner = RelaychainHeaderRunner::new(input);
+        Ok(runner.relay_header(block, dry_run).await?)
+    }
+
+    /// Deliver messages of the nonces to target chain
+    pub async fn deliver(
+        &self,
+        lane: LaneId,
+        nonces: RangeInclusive<u64>,
+        options: &ManualRelayOptions,
+    ) -> BinS2SResult<ManualRelayReport> {
+        let client_source = self.source_para.client().await?;
+        let client_target = self.target_para.client().await?;
+        let input = MessageDeliveryInput {
+            lanes: vec![lane],
+            nonces_limit: 11,
+            relayer_account: self.source_para.account()?,
+            client_source: client_source.clone(),
+            client_target: client_target.clone(),
+            submitters_target: self.target_para.submitters(client_target).await?,
+            subquery_source: self.index_source_para.subquery()?,
+            subquery_target: self.index_target_para.subquery()?,
+            relay_block_origin: self.target_para.origin_type(),
+            relay_strategy: BasicRelayStrategy::new(client_source, self.source_para.account()?),
+        };
+        let runner = BridgeParachainDeliveryRunner::new(input, self.source_para_id);
+        Ok(runner.deliver(lane, nonces, options).await?)
+    }
+
+    /// Confirm the delivered messages at source chain
+    pub async fn confirm(
+        &self,
+        lane: LaneId,
+        options: &ManualRelayOptions,
+    ) -> BinS2SResult<ManualRelayReport> {
+        let client_source = self.source_para.client().await?;
+        let input = MessageReceivingInput {
+            lanes: vec![lane],
+            relayer_account: self.source_para.account()?,
+            client_source: client_source.clone(),
+            client_target: self.target_para.client().await?,
+            submitters_source: self.source_para.submitters(client_source).await?,
+            subquery_source: self.index_source_para.subquery()?,
+            subquery_target: self.index_target_para.subquery()?,
+        };
+        let runner = BridgeParachainReceivingRunner::new(input, self.target_para_id);
+        Ok(runner.confirm(lane, options).await?)
+    }
+}
+
+/// Print the report of manual relay
+pub fn output_manual_report(report: &ManualRelayReport, options: &ManualRelayOptions) {
+    output::output_text(format!("proof size: {} bytes", report.proof_size));
+    if let Some(weight) = report.dispatch_weight {
+        output::output_text(format!("dispatch weight: {}", weight));
+    }
+    if report.rejected_by_strategy {
+        output::output_text(
+            "the relay strategy decided not to relay, use --ignore-strategy to relay anyway",
+        );
+        return;
+    }
+    match &report.extrinsic_hash {
+        Some(hash) => output::output_text(format!("submitted: {}", hash)),
+        None if options.dry_run => output::output_text("dry run, nothing submitted"),
+        None => {}
+    }
+}
@@ -0,0 +1,194 @@
+use bridge_s2s_traits::client::{S2SClientGeneric, S2SClientRelay, S2SClientRuntime};
+use client_common_traits::ClientCommon;
+use serde::{Deserialize, Serialize};
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+
+use support_terminal::output;
+
+use crate::error::{BinS2SError, BinS2SResult};
+
+/// A check of pre-flight, the result is the detail of passed or failed check
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PreflightCheck {
+    pub chain: String,
+    pub check: String,
+    pub result: Result<String, String>,
+    /// A failed optional check is a warning, it doesn't refuse to start the bridge
+    pub optional: bool,
+}
+
+/// Checks before starting the bridge, the misconfigured endpoints are found here instead of
+/// failing inside relays with storage decoding errors
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PreflightReport {
+    checks: Vec<PreflightCheck>,
+}
+
+impl PreflightReport {
+    pub fn checks(&self) -> &[PreflightCheck] {
+        &self.checks
+    }
+
+    pub fn failed(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|v| v.result.is_err() && !v.optional)
+            .count()
+    }
+
+    fn push(&mut self, chain: &str, check: String, result: Result<String, String>) {
+        self.checks.push(PreflightCheck {
+            chain: chain.to_string(),
+            check,
+            result,
+            optional: false,
+        });
+    }
+
+    fn push_optional(&mut self, chain: &str, check: String, result: Result<String, String>) {
+        self.checks.push(PreflightCheck {
+            chain: chain.to_string(),
+            check,
+            result,
+            optional: true,
+        });
+    }
+
+    /// Connect every endpoint of the client, the runtime and genesis hash should be the ones the
+    /// client is generated for. An unreachable endpoint is a warning since it's skipped by
+    /// failover, but at least one endpoint should be healthy
+    pub async fn check_identity<C: S2SClientGeneric>(&mut self, client: &C) {
+        let chain = <C as ClientCommon>::CHAIN;
+        let identities = client.chain_identities().await;
+        let total = identities.len();
+        let mut healthy = 0;
+        let mut seen_genesis_hash = None;
+        for (endpoint, identity) in identities {
+            let check = format!("endpoint {endpoint}");
+            match identity {
+                Ok(identity) => {
+                    let result = identity.check(
+                        <C as ClientCommon>::SPEC_NAME,
+                        <C as ClientCommon>::GENESIS_HASH,
+                        &mut seen_genesis_hash,
+                    );
+                    if result.is_ok() {
+                        healthy += 1;
+                    }
+                    self.push(chain, check, result);
+                }
+                Err(e) => self.push_optional(chain, check, Err(format!("{e:?}"))),
+            }
+        }
+        let result = if healthy == 0 {
+            Err("No healthy endpoint".to_string())
+        } else {
+            Ok(format!("{healthy} of {total} endpoints are healthy"))
+        };
+        self.push(chain, "healthy endpoints".to_string(), result);
+    }
+
+    /// The storages and calls used by the client are compatible with the runtime metadata
+    pub async fn check_runtime<C: S2SClientRuntime>(&mut self, client: &C) {
+        let result = match client.incompatible_runtime_items().await {
+            Ok(items) if items.is_empty() => Ok("compatible with the client".to_string()),
+            Ok(items) => Err(items.join("; ")),
+            Err(e) => Err(format!("{e:?}")),
+        };
+        self.push(
+            <C as ClientCommon>::CHAIN,
+            "runtime compatibility".to_string(),
+            result,
+        );
+    }
+
+    /// The headers of bridged chain are initialized at the chain of client
+    pub async fn check_initialized<C: S2SClientRelay>(&mut self, client: &C, bridged_chain: &str) {
+        let result = match client.best_target_finalized(None).await {
+            Ok(Some((number, hash))) => Ok(format!(
+                "best finalized {bridged_chain} header: {number:?} ({hash:?})"
+            )),
+            Ok(None) => Err(format!(
+                "The bridge of {bridged_chain} isn't initialized, run `init` first"
+            )),
+            Err(e) => Err(format!("{e:?}")),
+        };
+        self.push(
+            <C as ClientCommon>::CHAIN,
+            "bridge initialized".to_string(),
+            result,
+        );
+    }
+
+    /// The lanes can be read from outbound lanes of source chain and inbound lanes of target
+    /// chain, the storage is decoded by the runtime metadata of clients
+    pub async fn check_lanes<SC: S2SClientRelay, TC: S2SClientRelay>(
+        &mut self,
+        client_source: &SC,
+        client_target: &TC,
+        lanes: &[[u8; 4]],
+    ) {
+        for lane in lanes {
+            let lane_hex = array_bytes::bytes2hex("0x", lane);
+            let result = match client_source.outbound_lanes(*lane, None).await {
+                Ok(v) => Ok(format!(
+                    "latest generated nonce: {}, latest received nonce: {}",
+                    v.latest_generated_nonce, v.latest_received_nonce
+                )),
+                Err(e) => Err(format!("{e:?}")),
+            };
+            self.push(
+                <SC as ClientCommon>::CHAIN,
+                format!("outbound lane {lane_hex}"),
+                result,
+            );
+            let result = match client_target.inbound_lanes(*lane, None).await {
+                Ok(v) => Ok(format!(
+                    "last delivered nonce: {}, last confirmed nonce: {}",
+                    v.last_delivered_nonce(),
+                    v.last_confirmed_nonce
+                )),
+                Err(e) => Err(format!("{e:?}")),
+            };
+            self.push(
+                <TC as ClientCommon>::CHAIN,
+                format!("inbound lane {lane_hex}"),
+                result,
+            );
+        }
+    }
+
+    /// Print the report
+    pub fn output(&self) {
+        let mut table = Table::new();
+        table.max_column_width = 80;
+        table.style = TableStyle::simple();
+        for check in &self.checks {
+            let (status, detail) = match &check.result {
+                Ok(v) => ("ok", v),
+                Err(e) if check.optional => ("warning", e),
+                Err(e) => ("failed", e),
+            };
+            table.add_row(Row::new(vec![
+                TableCell::new_with_alignment(&check.chain, 1, Alignment::Left),
+                TableCell::new_with_alignment(&check.check, 1, Alignment::Left),
+                TableCell::new_with_alignment(status, 1, Alignment::Left),
+                TableCell::new_with_alignment(detail, 1, Alignment::Left),
+            ]));
+        }
+        output::output_text(table.render());
+    }
+
+    /// Refuse to start the bridge if any check failed
+    pub fn ensure(&self) -> BinS2SResult<()> {
+        let failed = self.failed();
+        if failed != 0 {
+            return Err(BinS2SError::Custom(format!(
+                "{failed} pre-flight checks failed, refuse to start the bridge"
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use std::future::Future;
+
+use bridge_s2s_traits::types::bp_runtime::Chain;
+use client_common_traits::ClientCommon;
+use feemarket_s2s_traits::api::FeemarketApiBalance;
+
+use crate::error::{BinS2SError, BinS2SResult};
+
+/// Interval (seconds) between two relayer balance checks
+pub const BALANCE_CHECK_INTERVAL: u64 = 60;
+
+/// Balance monitor of a chain. The client is built at the first check and reused to query
+/// balances, it's dropped after a failed query so the next check connects again.
+pub struct BalanceMonitor<C> {
+    client: Option<C>,
+}
+
+impl<C> Default for BalanceMonitor<C> {
+    fn default() -> Self {
+        Self { client: None }
+    }
+}
+
+impl<C: FeemarketApiBalance> BalanceMonitor<C> {
+    /// Check the fee market collateral of relayer account and free balances of signer accounts
+    /// in the order of submitters, the client is built by `client` only if there isn't one. The
+    /// signer below reserve pauses submitting to this chain, an extra signer below reserve is
+    /// only skipped by messages relay. A failed query is logged and doesn't stop checking other
+    /// accounts.
+    pub async fn check(
+        &mut self,
+        client: impl Future<Output = BinS2SResult<C>>,
+        account: BinS2SResult<<C::Chain as Chain>::AccountId>,
+        signer_accounts: BinS2SResult<Vec<<C::Chain as Chain>::AccountId>>,
+        reserve_balance: Option<<C::Chain as Chain>::Balance>,
+    ) {
+        let chain = <C as ClientCommon>::CHAIN;
+        let (account, signer_accounts) = match (account, signer_accounts) {
+            (Ok(account), Ok(signer_accounts)) => (account, signer_accounts),
+            (Err(e), _) | (_, Err(e)) => {
+                tracing::error!(
+                    target: "bin-s2s",
+                    "[balance] [{}] failed to check relayer balance: {:?}",
+                    chain,
+                    e,
+                );
+                return;
+            }
+        };
+        if self.client.is_none() {
+            match client.await {
+                Ok(client) => self.client = Some(client),
+                Err(e) => {
+                    tracing::error!(
+                        target: "bin-s2s",
+                        "[balance] [{}] failed to check relayer balance: {:?}",
+                        chain,
+                        e,
+                    );
+                    return;
+                }
+            }
+        }
+        let client = self.client.as_ref().expect("The client is built");
+        let mut failed = false;
+        if let Err(e) = check_relayer_collateral(client, account.clone()).await {
+            failed = true;
+            tracing::error!(
+                target: "bin-s2s",
+                "[balance] [{}] failed to check collateral of relayer {:?}: {:?}",
+                chain,
+                account,
+                e,
+            );
+        }
+        for (index, signer) in signer_accounts.into_iter().enumerate() {
+            if let Err(e) =
+                check_signer_balance(client, index, signer.clone(), reserve_balance).await
+            {
+                failed = true;
+                tracing::error!(
+                    target: "bin-s2s",
+                    "[balance] [{}] failed to check balance of signer {:?}: {:?}",
+                    chain,
+                    signer,
+                    e,
+                );
+            }
+        }
+        if failed {
+            self.client = None;
+        }
+    }
+}
+
+/// Query fee market collateral of relayer account, it's the real account in proxy mode
+async fn check_relayer_collateral<C: FeemarketApiBalance>(
+    client: &C,
+    account: <C::Chain as Chain>::AccountId,
+) -> BinS2SResult<()> {
+    let relayer = client
+        .relayer_info(account.clone())
+        .await
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+    tracing::info!(
+        target: "bin-s2s",
+        "[balance] [{}] relayer {:?} fee market collateral: {:?}",
+        <C as ClientCommon>::CHAIN,
+        account,
+        relayer.map(|v| v.collateral),
+    );
+    Ok(())
+}
+
+/// Query free balance of signer account, update the paused state of the submitter at index
+async fn check_signer_balance<C: FeemarketApiBalance>(
+    client: &C,
+    index: usize,
+    account: <C::Chain as Chain>::AccountId,
+    reserve_balance: Option<<C::Chain as Chain>::Balance>,
+) -> BinS2SResult<()> {
+    let chain = <C as ClientCommon>::CHAIN;
+    let free_balance = client
+        .free_balance(account.clone())
+        .await
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+    tracing::info!(
+        target: "bin-s2s",
+        "[balance] [{}] signer {:?} free balance: {:?}, reserve: {:?}",
+        chain,
+        account,
+        free_balance,
+        reserve_balance,
+    );
+
+    let paused = is_below_reserve(&free_balance, reserve_balance.as_ref());
+    let previous = if index == 0 {
+        relay_s2s::keepstate::is_submit_paused(chain)
+    } else {
+        relay_s2s::keepstate::is_signer_paused(chain, index)
+    };
+    if paused && !previous {
+        tracing::warn!(
+            target: "bin-s2s",
+            "[balance] [{}] signer {:?} free balance {:?} is below reserve {:?}, pause submitting",
+            chain,
+            account,
+            free_balance,
+            reserve_balance,
+        );
+    }
+    if !paused && previous {
+        tracing::info!(
+            target: "bin-s2s",
+            "[balance] [{}] signer {:?} free balance recovered, resume submitting",
+            chain,
+            account,
+        );
+    }
+    if index == 0 {
+        relay_s2s::keepstate::set_submit_paused(chain, paused);
+    } else {
+        relay_s2s::keepstate::set_signer_paused(chain, index, paused);
+    }
+    Ok(())
+}
+
+/// Submitting is paused when the free balance is below the reserve, never without a reserve
+fn is_below_reserve<B: PartialOrd>(free_balance: &B, reserve_balance: Option<&B>) -> bool {
+    match reserve_balance {
+        Some(reserve) => free_balance < reserve,
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_below_reserve() {
+        assert!(!is_below_reserve(&0u128, None));
+        assert!(is_below_reserve(&99u128, Some(&100)));
+        assert!(!is_below_reserve(&100u128, Some(&100)));
+        assert!(!is_below_reserve(&101u128, Some(&100)));
+    }
+}
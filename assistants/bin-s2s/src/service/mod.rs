@@ -1,6 +1,8 @@
+pub mod balance;
 pub mod feemarket;
 #[cfg(feature = "para-with-para")]
 pub mod para_with_para;
+pub mod runtime;
 #[cfg(feature = "solo-with-para")]
 pub mod solo_with_para;
 #[cfg(feature = "solo-with-solo")]
@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+
+use lifeline::dyn_bus::DynBus;
+use lifeline::{Lifeline, Service, Task};
+
+use support_lifeline::service::BridgeService;
+
+use crate::bridge::config::para_with_para::BridgeConfig;
+use crate::bridge::BridgeBus;
+use crate::service::balance::{BalanceMonitor, BALANCE_CHECK_INTERVAL};
+use crate::traits::{S2SParaBridgeRelayChainInfo, S2SParaBridgeSoloChainInfo, SubqueryInfo};
+
+#[derive(Debug)]
+pub struct BalanceMonitorService<
+    SRCI: S2SParaBridgeRelayChainInfo,
+    SPCI: S2SParaBridgeSoloChainInfo,
+    TRCI: S2SParaBridgeRelayChainInfo,
+    TPCI: S2SParaBridgeSoloChainInfo,
+    SI: SubqueryInfo,
+> {
+    _greet: Lifeline,
+    _source_relay_chain_info: PhantomData<SRCI>,
+    _source_para_chain_info: PhantomData<SPCI>,
+    _target_relay_chain_info: PhantomData<TRCI>,
+    _target_para_chain_info: PhantomData<TPCI>,
+    _subquery_info: PhantomData<SI>,
+}
+
+impl<
+        SRCI: S2SParaBridgeRelayChainInfo,
+        SPCI: S2SParaBridgeSoloChainInfo,
+        TRCI: S2SParaBridgeRelayChainInfo,
+        TPCI: S2SParaBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > BridgeService for BalanceMonitorService<SRCI, SPCI, TRCI, TPCI, SI>
+{
+}
+
+impl<
+        SRCI: S2SParaBridgeRelayChainInfo,
+        SPCI: S2SParaBridgeSoloChainInfo,
+        TRCI: S2SParaBridgeRelayChainInfo,
+        TPCI: S2SParaBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > Service for BalanceMonitorService<SRCI, SPCI, TRCI, TPCI, SI>
+{
+    type Bus = BridgeBus;
+    type Lifeline = color_eyre::Result<Self>;
+
+    fn spawn(bus: &Self::Bus) -> Self::Lifeline {
+        let bridge_config: BridgeConfig<SRCI, SPCI, TRCI, TPCI, SI> =
+            bus.storage().clone_resource()?;
+        let config_chain = bridge_config.chain;
+        let task_name = format!(
+            "{}-{}-balance-monitor-service",
+            config_chain.source_para.chain().name(),
+            config_chain.target_para.chain().name(),
+        );
+        let _greet = Self::try_task(&task_name, async move {
+            let mut source = BalanceMonitor::default();
+            let mut target = BalanceMonitor::default();
+            loop {
+                source
+                    .check(
+                        config_chain.source_para.client(),
+                        config_chain.source_para.account(),
+                        config_chain.source_para.signer_accounts(),
+                        config_chain.source_para.reserve_balance(),
+                    )
+                    .await;
+                target
+                    .check(
+                        config_chain.target_para.client(),
+                        config_chain.target_para.account(),
+                        config_chain.target_para.signer_accounts(),
+                        config_chain.target_para.reserve_balance(),
+                    )
+                    .await;
+                tokio::time::sleep(std::time::Duration::from_secs(BALANCE_CHECK_INTERVAL)).await;
+            }
+        });
+        Ok(Self {
+            _greet,
+            _source_relay_chain_info: Default::default(),
+            _source_para_chain_info: Default::default(),
+            _target_relay_chain_info: Default::default(),
+            _target_para_chain_info: Default::default(),
+            _subquery_info: Default::default(),
+        })
+    }
+}
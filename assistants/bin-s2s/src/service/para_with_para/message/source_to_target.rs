@@ -166,11 +166,13 @@ impl<
 
         let lanes = relay_config.raw_lanes();
 
+        let client_source = config_chain.source_para.client().await?;
         let input = MessageReceivingInput {
             lanes,
             relayer_account: config_chain.source_para.account()?,
-            client_source: config_chain.source_para.client().await?,
+            client_source: client_source.clone(),
             client_target: config_chain.target_para.client().await?,
+            submitters_source: config_chain.source_para.submitters(client_source).await?,
             subquery_source: config_index.source_para.subquery()?,
             subquery_target: config_index.target_para.subquery()?,
         };
@@ -198,7 +200,11 @@ impl<
             nonces_limit: 11,
             relayer_account: input.relayer_account,
             client_source: input.client_source,
-            client_target: input.client_target,
+            client_target: input.client_target.clone(),
+            submitters_target: config_chain
+                .target_para
+                .submitters(input.client_target)
+                .await?,
             subquery_source: input.subquery_source,
             subquery_target: input.subquery_target,
             relay_block_origin: config_chain.target_para.origin_type(),
@@ -1,7 +1,11 @@
+pub use self::balance::*;
 pub use self::header::*;
 pub use self::message::*;
+pub use self::runtime::*;
 pub use self::subscribe::*;
 
+mod balance;
 mod header;
 mod message;
+mod runtime;
 mod subscribe;
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use lifeline::dyn_bus::DynBus;
+use lifeline::{Lifeline, Service, Task};
+
+use support_lifeline::service::BridgeService;
+
+use crate::bridge::config::para_with_para::BridgeConfig;
+use crate::bridge::BridgeBus;
+use crate::service::runtime::{RuntimeMonitor, RUNTIME_CHECK_INTERVAL};
+use crate::traits::{S2SParaBridgeRelayChainInfo, S2SParaBridgeSoloChainInfo, SubqueryInfo};
+
+#[derive(Debug)]
+pub struct RuntimeMonitorService<
+    SRCI: S2SParaBridgeRelayChainInfo,
+    SPCI: S2SParaBridgeSoloChainInfo,
+    TRCI: S2SParaBridgeRelayChainInfo,
+    TPCI: S2SParaBridgeSoloChainInfo,
+    SI: SubqueryInfo,
+> {
+    _greet: Lifeline,
+    _source_relay_chain_info: PhantomData<SRCI>,
+    _source_para_chain_info: PhantomData<SPCI>,
+    _target_relay_chain_info: PhantomData<TRCI>,
+    _target_para_chain_info: PhantomData<TPCI>,
+    _subquery_info: PhantomData<SI>,
+}
+
+impl<
+        SRCI: S2SParaBridgeRelayChainInfo,
+        SPCI: S2SParaBridgeSoloChainInfo,
+        TRCI: S2SParaBridgeRelayChainInfo,
+        TPCI: S2SParaBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > BridgeService for RuntimeMonitorService<SRCI, SPCI, TRCI, TPCI, SI>
+{
+}
+
+impl<
+        SRCI: S2SParaBridgeRelayChainInfo,
+        SPCI: S2SParaBridgeSoloChainInfo,
+        TRCI: S2SParaBridgeRelayChainInfo,
+        TPCI: S2SParaBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > Service for RuntimeMonitorService<SRCI, SPCI, TRCI, TPCI, SI>
+{
+    type Bus = BridgeBus;
+    type Lifeline = color_eyre::Result<Self>;
+
+    fn spawn(bus: &Self::Bus) -> Self::Lifeline {
+        let bridge_config: BridgeConfig<SRCI, SPCI, TRCI, TPCI, SI> =
+            bus.storage().clone_resource()?;
+        let config_chain = bridge_config.chain;
+        let task_name = format!(
+            "{}-{}-runtime-monitor-service",
+            config_chain.source_para.chain().name(),
+            config_chain.target_para.chain().name(),
+        );
+        let _greet = Self::try_task(&task_name, async move {
+            let mut source_para = RuntimeMonitor::default();
+            let mut source_relay = RuntimeMonitor::default();
+            let mut target_para = RuntimeMonitor::default();
+            let mut target_relay = RuntimeMonitor::default();
+            loop {
+                source_para.check(config_chain.source_para.client()).await;
+                source_relay.check(config_chain.source_relay.client()).await;
+                target_para.check(config_chain.target_para.client()).await;
+                target_relay.check(config_chain.target_relay.client()).await;
+                tokio::time::sleep(std::time::Duration::from_secs(RUNTIME_CHECK_INTERVAL)).await;
+            }
+        });
+        Ok(Self {
+            _greet,
+            _source_relay_chain_info: Default::default(),
+            _source_para_chain_info: Default::default(),
+            _target_relay_chain_info: Default::default(),
+            _target_para_chain_info: Default::default(),
+            _subquery_info: Default::default(),
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use std::future::Future;
+
+use bridge_s2s_traits::client::S2SClientRuntime;
+use client_common_traits::ClientCommon;
+
+use crate::error::{BinS2SError, BinS2SResult};
+
+/// Interval (seconds) between two runtime version checks
+pub const RUNTIME_CHECK_INTERVAL: u64 = 30;
+
+/// Runtime monitor of a chain. The client is built at the first check and reused to poll the
+/// runtime version, it's dropped after a failed check so the next check connects again.
+pub struct RuntimeMonitor<C> {
+    client: Option<C>,
+    spec_version: Option<u32>,
+}
+
+impl<C> Default for RuntimeMonitor<C> {
+    fn default() -> Self {
+        Self {
+            client: None,
+            spec_version: None,
+        }
+    }
+}
+
+impl<C: S2SClientRuntime> RuntimeMonitor<C> {
+    /// Check the runtime compatibility of chain, the client is built by `client` only if there
+    /// isn't one. A failure is logged, so the checks of other chains go on.
+    pub async fn check(&mut self, client: impl Future<Output = BinS2SResult<C>>) {
+        if let Err(e) = self.try_check(client).await {
+            self.client = None;
+            tracing::error!(
+                target: "bin-s2s",
+                "[runtime] [{}] failed to check runtime compatibility: {:?}",
+                <C as ClientCommon>::CHAIN,
+                e,
+            );
+        }
+    }
+
+    async fn try_check(
+        &mut self,
+        client: impl Future<Output = BinS2SResult<C>>,
+    ) -> BinS2SResult<()> {
+        if self.client.is_none() {
+            self.client = Some(client.await?);
+        }
+        let client = self.client.as_ref().expect("The client is built");
+        check_runtime_compatibility(client, &mut self.spec_version).await
+    }
+}
+
+/// Compare the runtime metadata of chain with the client codegen when it's first checked or
+/// the spec version changed. Relays reading or writing this chain are paused while any storage
+/// or call used by the client is incompatible, and resumed once a later runtime fixes it.
+pub async fn check_runtime_compatibility<C: S2SClientRuntime>(
+    client: &C,
+    checked_spec_version: &mut Option<u32>,
+) -> BinS2SResult<()> {
+    let chain = <C as ClientCommon>::CHAIN;
+    let spec_version = client
+        .runtime_spec_version()
+        .await
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+    let previous = *checked_spec_version;
+    if previous == Some(spec_version) {
+        return Ok(());
+    }
+    if let Some(previous) = previous {
+        tracing::info!(
+            target: "bin-s2s",
+            "[runtime] [{}] runtime upgraded from spec version {} to {}",
+            chain,
+            previous,
+            spec_version,
+        );
+    }
+
+    let items = client
+        .incompatible_runtime_items()
+        .await
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+    if !items.is_empty() {
+        tracing::error!(
+            target: "bin-s2s",
+            "[runtime] [{}] runtime spec version {} is incompatible with the client, relays of this chain are paused until the client is upgraded: {}",
+            chain,
+            spec_version,
+            items.join("; "),
+        );
+    } else if relay_s2s::keepstate::is_runtime_incompatible(chain) {
+        tracing::info!(
+            target: "bin-s2s",
+            "[runtime] [{}] runtime spec version {} is compatible with the client, resume relays",
+            chain,
+            spec_version,
+        );
+    } else {
+        tracing::debug!(
+            target: "bin-s2s",
+            "[runtime] [{}] runtime spec version {} is compatible with the client",
+            chain,
+            spec_version,
+        );
+    }
+    relay_s2s::keepstate::set_runtime_incompatible(chain, items);
+    *checked_spec_version = Some(spec_version);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use std::marker::PhantomData;
+
+use lifeline::dyn_bus::DynBus;
+use lifeline::{Lifeline, Service, Task};
+
+use support_lifeline::service::BridgeService;
+
+use crate::bridge::config::solo_with_para::BridgeConfig;
+use crate::bridge::BridgeBus;
+use crate::service::balance::{BalanceMonitor, BALANCE_CHECK_INTERVAL};
+use crate::traits::{
+    S2SParaBridgeRelayChainInfo, S2SParaBridgeSoloChainInfo, S2SSoloBridgeSoloChainInfo,
+    SubqueryInfo,
+};
+
+#[derive(Debug)]
+pub struct BalanceMonitorService<
+    SCI: S2SParaBridgeSoloChainInfo,
+    RCI: S2SParaBridgeRelayChainInfo,
+    PCI: S2SSoloBridgeSoloChainInfo,
+    SI: SubqueryInfo,
+> {
+    _greet: Lifeline,
+    _relaychain_info: PhantomData<RCI>,
+    _solochain_info: PhantomData<SCI>,
+    _parachain_info: PhantomData<PCI>,
+    _subquery_info: PhantomData<SI>,
+}
+
+impl<
+        SCI: S2SParaBridgeSoloChainInfo,
+        RCI: S2SParaBridgeRelayChainInfo,
+        PCI: S2SSoloBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > BridgeService for BalanceMonitorService<SCI, RCI, PCI, SI>
+{
+}
+
+impl<
+        SCI: S2SParaBridgeSoloChainInfo,
+        RCI: S2SParaBridgeRelayChainInfo,
+        PCI: S2SSoloBridgeSoloChainInfo,
+        SI: SubqueryInfo,
+    > Service for BalanceMonitorService<SCI, RCI, PCI, SI>
+{
+    type Bus = BridgeBus;
+    type Lifeline = color_eyre::Result<Self>;
+
+    fn spawn(bus: &Self::Bus) -> Self::Lifeline {
+        let bridge_config: BridgeConfig<SCI, RCI, PCI, SI> = bus.storage().clone_resource()?;
+        let config_chain = bridge_config.chain;
+        let task_name = format!(
+            "{}-{}-balance-monitor-service",
+            config_chain.solo.chain().name(),
+            config_chain.para.chain().name(),
+        );
+        let _greet = Self::try_task(&task_name, async move {
+            let mut solo = BalanceMonitor::default();
+            let mut para = BalanceMonitor::default();
+            loop {
+                solo.check(
+                    config_chain.solo.client(),
+                    config_chain.solo.account(),
+                    config_chain.solo.signer_accounts(),
+                    config_chain.solo.reserve_balance(),
+                )
+                .await;
+                para.check(
+                    config_chain.para.client(),
+                    config_chain.para.account(),
+                    config_chain.para.signer_accounts(),
+                    config_chain.para.reserve_balance(),
+                )
+                .await;
+                tokio::time::sleep(std::time::Duration::from_secs(BALANCE_CHECK_INTERVAL)).await;
+            }
+        });
+        Ok(Self {
+            _greet,
+            _relaychain_info: Default::default(),
+            _solochain_info: Default::default(),
+            _parachain_info: Default::default(),
+            _subquery_info: Default::default(),
+        })
+    }
+}
@@ -161,11 +161,13 @@ impl<
 
         let lanes = relay_config.raw_lanes();
 
+        let client_source = config_chain.para.client().await?;
         let input = MessageReceivingInput {
             lanes,
             relayer_account: config_chain.para.account()?,
-            client_source: config_chain.para.client().await?,
+            client_source: client_source.clone(),
             client_target: config_chain.solo.client().await?,
+            submitters_source: config_chain.para.submitters(client_source).await?,
             subquery_source: config_index.para.subquery()?,
             subquery_target: config_index.solo.subquery()?,
         };
@@ -189,7 +191,8 @@ impl<
             nonces_limit: 11,
             relayer_account: input.relayer_account,
             client_source: input.client_source,
-            client_target: input.client_target,
+            client_target: input.client_target.clone(),
+            submitters_target: config_chain.solo.submitters(input.client_target).await?,
             subquery_source: input.subquery_source,
             subquery_target: input.subquery_target,
             relay_block_origin: config_chain.solo.origin_type(),
@@ -143,11 +143,13 @@ impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: Subqu
 
         let lanes = relay_config.raw_lanes();
 
+        let client_source = config_chain.source.client().await?;
         let input = MessageReceivingInput {
             lanes,
             relayer_account: config_chain.source.account()?,
-            client_source: config_chain.source.client().await?,
+            client_source: client_source.clone(),
             client_target: config_chain.target.client().await?,
+            submitters_source: config_chain.source.submitters(client_source).await?,
             subquery_source: config_index.source.subquery()?,
             subquery_target: config_index.target.subquery()?,
         };
@@ -170,7 +172,8 @@ impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: Subqu
             nonces_limit: 11,
             relayer_account: input.relayer_account,
             client_source: input.client_source,
-            client_target: input.client_target,
+            client_target: input.client_target.clone(),
+            submitters_target: config_chain.target.submitters(input.client_target).await?,
             subquery_source: input.subquery_source,
             subquery_target: input.subquery_target,
             relay_block_origin: config_chain.target.origin_type(),
@@ -142,11 +142,13 @@ impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: Subqu
 
         let lanes = relay_config.raw_lanes();
 
+        let client_source = config_chain.target.client().await?;
         let input = MessageReceivingInput {
             lanes,
             relayer_account: config_chain.target.account()?,
-            client_source: config_chain.target.client().await?,
+            client_source: client_source.clone(),
             client_target: config_chain.source.client().await?,
+            submitters_source: config_chain.target.submitters(client_source).await?,
             subquery_source: config_index.target.subquery()?,
             subquery_target: config_index.source.subquery()?,
         };
@@ -169,7 +171,8 @@ impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: Subqu
             nonces_limit: 11,
             relayer_account: input.relayer_account,
             client_source: input.client_source,
-            client_target: input.client_target,
+            client_target: input.client_target.clone(),
+            submitters_target: config_chain.source.submitters(input.client_target).await?,
             subquery_source: input.subquery_source,
             subquery_target: input.subquery_target,
             relay_block_origin: config_chain.source.origin_type(),
@@ -0,0 +1,60 @@
+use std::marker::PhantomData;
+
+use lifeline::dyn_bus::DynBus;
+use lifeline::{Lifeline, Service, Task};
+
+use support_lifeline::service::BridgeService;
+
+use crate::bridge::config::solo_with_solo::BridgeConfig;
+use crate::bridge::BridgeBus;
+use crate::service::runtime::{RuntimeMonitor, RUNTIME_CHECK_INTERVAL};
+use crate::traits::{S2SSoloBridgeSoloChainInfo, SubqueryInfo};
+
+#[derive(Debug)]
+pub struct RuntimeMonitorService<
+    SCI: S2SSoloBridgeSoloChainInfo,
+    TCI: S2SSoloBridgeSoloChainInfo,
+    SI: SubqueryInfo,
+> {
+    _greet: Lifeline,
+    _source_chain_info: PhantomData<SCI>,
+    _target_chain_info: PhantomData<TCI>,
+    _subquery_info: PhantomData<SI>,
+}
+
+impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: SubqueryInfo>
+    BridgeService for RuntimeMonitorService<SCI, TCI, SI>
+{
+}
+
+impl<SCI: S2SSoloBridgeSoloChainInfo, TCI: S2SSoloBridgeSoloChainInfo, SI: SubqueryInfo> Service
+    for RuntimeMonitorService<SCI, TCI, SI>
+{
+    type Bus = BridgeBus;
+    type Lifeline = color_eyre::Result<Self>;
+
+    fn spawn(bus: &Self::Bus) -> Self::Lifeline {
+        let bridge_config: BridgeConfig<SCI, TCI, SI> = bus.storage().clone_resource()?;
+        let config_chain = bridge_config.chain;
+        let task_name = format!(
+            "{}-{}-runtime-monitor-service",
+            config_chain.source.chain().name(),
+            config_chain.target.chain().name(),
+        );
+        let _greet = Self::try_task(&task_name, async move {
+            let mut source = RuntimeMonitor::default();
+            let mut target = RuntimeMonitor::default();
+            loop {
+                source.check(config_chain.source.client()).await;
+                target.check(config_chain.target.client()).await;
+                tokio::time::sleep(std::time::Duration::from_secs(RUNTIME_CHECK_INTERVAL)).await;
+            }
+        });
+        Ok(Self {
+            _greet,
+            _source_chain_info: Default::default(),
+            _target_chain_info: Default::default(),
+            _subquery_info: Default::default(),
+        })
+    }
+}
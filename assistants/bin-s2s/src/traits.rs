@@ -5,7 +5,8 @@ use bridge_s2s_traits::client::S2SClientRelay;
 use bridge_s2s_traits::client::{S2SParaBridgeClientRelaychain, S2SParaBridgeClientSolochain};
 use bridge_s2s_traits::types::bp_runtime;
 use client_common_traits::ClientCommon;
-use feemarket_s2s_traits::api::FeemarketApiRelay;
+use feemarket_s2s_traits::api::{FeemarketApiBalance, FeemarketApiRelay};
+use relay_s2s::submitter::Submitters;
 use subquery::types::OriginType;
 use subquery::Subquery;
 
@@ -24,7 +25,7 @@ pub trait S2SBasicChainInfo: 'static + Sync + Send + Sized + Clone + Debug {
 /// solo bridge solo chain info
 #[async_trait::async_trait]
 pub trait S2SSoloBridgeSoloChainInfo: S2SBasicChainInfo {
-    type Client: S2SClientRelay + FeemarketApiRelay;
+    type Client: S2SClientRelay + FeemarketApiRelay + FeemarketApiBalance;
 
     fn origin_type(&self) -> OriginType;
 
@@ -32,14 +33,51 @@ pub trait S2SSoloBridgeSoloChainInfo: S2SBasicChainInfo {
         &self,
     ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId>;
 
+    /// Account which signs extrinsics and pays the fee, differs from `account` in proxy mode
+    fn signer_account(
+        &self,
+    ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
+        self.account()
+    }
+
+    /// Accounts of signer and extra signers, in the order of submitters
+    fn signer_accounts(
+        &self,
+    ) -> BinS2SResult<Vec<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId>>
+    {
+        Ok(vec![self.signer_account()?])
+    }
+
+    /// Minimum free balance of relayer account, submitting is paused when below it
+    fn reserve_balance(
+        &self,
+    ) -> Option<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::Balance> {
+        None
+    }
+
     async fn client(&self) -> BinS2SResult<Self::Client>;
+
+    /// Clients of the extra relayer accounts, they submit messages relay in turns with signer
+    async fn extra_clients(&self) -> BinS2SResult<Vec<Self::Client>> {
+        Ok(vec![])
+    }
+
+    /// Submitters of messages relay, the client of signer is the first one
+    async fn submitters(&self, client: Self::Client) -> BinS2SResult<Submitters<Self::Client>> {
+        let mut clients = vec![client];
+        clients.extend(self.extra_clients().await?);
+        Ok(Submitters::new(
+            <Self::Client as ClientCommon>::CHAIN,
+            clients,
+        ))
+    }
 }
 
 /// para bridge solo chain info
 #[async_trait::async_trait]
 #[cfg(any(feature = "solo-with-para", feature = "para-with-para"))]
 pub trait S2SParaBridgeSoloChainInfo: S2SBasicChainInfo {
-    type Client: S2SParaBridgeClientSolochain + FeemarketApiRelay;
+    type Client: S2SParaBridgeClientSolochain + FeemarketApiRelay + FeemarketApiBalance;
 
     fn origin_type(&self) -> OriginType;
 
@@ -47,7 +85,44 @@ pub trait S2SParaBridgeSoloChainInfo: S2SBasicChainInfo {
         &self,
     ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId>;
 
+    /// Account which signs extrinsics and pays the fee, differs from `account` in proxy mode
+    fn signer_account(
+        &self,
+    ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
+        self.account()
+    }
+
+    /// Accounts of signer and extra signers, in the order of submitters
+    fn signer_accounts(
+        &self,
+    ) -> BinS2SResult<Vec<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId>>
+    {
+        Ok(vec![self.signer_account()?])
+    }
+
+    /// Minimum free balance of relayer account, submitting is paused when below it
+    fn reserve_balance(
+        &self,
+    ) -> Option<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::Balance> {
+        None
+    }
+
     async fn client(&self) -> BinS2SResult<Self::Client>;
+
+    /// Clients of the extra relayer accounts, they submit messages relay in turns with signer
+    async fn extra_clients(&self) -> BinS2SResult<Vec<Self::Client>> {
+        Ok(vec![])
+    }
+
+    /// Submitters of messages relay, the client of signer is the first one
+    async fn submitters(&self, client: Self::Client) -> BinS2SResult<Submitters<Self::Client>> {
+        let mut clients = vec![client];
+        clients.extend(self.extra_clients().await?);
+        Ok(Submitters::new(
+            <Self::Client as ClientCommon>::CHAIN,
+            clients,
+        ))
+    }
 }
 
 /// para bridge relay chain info
@@ -6,7 +6,11 @@ use crate::{
     error::{BeaconApiError, BeaconApiResult},
     types::{BeaconBlockRoot, BeaconBlockWrapper, ErrorResponse},
 };
-use reqwest::{header::CONTENT_TYPE, RequestBuilder, Response};
+use futures::stream::{self, BoxStream, StreamExt};
+use reqwest::{
+    header::{ACCEPT, CONTENT_TYPE},
+    RequestBuilder, Response,
+};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use types::{BeaconBlock, MainnetEthSpec};
 
@@ -286,6 +290,51 @@ impl BeaconApiClient {
         };
         Ok(result)
     }
+
+    /// Subscribe server-sent events of the given topics, yields the topic of each event
+    pub async fn subscribe_events(
+        &self,
+        topics: &[&str],
+    ) -> BeaconApiResult<BoxStream<'static, BeaconApiResult<String>>> {
+        let url = format!(
+            "{}/eth/v1/events?topics={}",
+            self.api_base_url,
+            topics.join(","),
+        );
+        let response = self
+            .get(&url)
+            .header(ACCEPT, "text/event-stream")
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(BeaconApiError::Custom(format!(
+                "Failed to subscribe beacon events. url: {:?}, status code: {:?}",
+                url,
+                response.status()
+            )));
+        }
+        let bytes = response.bytes_stream().boxed();
+        let events = stream::unfold(
+            (bytes, String::new()),
+            |(mut bytes, mut buffer)| async move {
+                loop {
+                    if let Some(pos) = buffer.find('\n') {
+                        let line: String = buffer.drain(..=pos).collect();
+                        if let Some(topic) = line.trim().strip_prefix("event:") {
+                            return Some((Ok(topic.trim().to_string()), (bytes, buffer)));
+                        }
+                        continue;
+                    }
+                    match bytes.next().await {
+                        Some(Ok(chunk)) => buffer.push_str(&String::from_utf8_lossy(&chunk)),
+                        Some(Err(e)) => return Some((Err(e.into()), (bytes, buffer))),
+                        None => return None,
+                    }
+                }
+            },
+        );
+        Ok(events.boxed())
+    }
 }
 
 #[cfg(test)]
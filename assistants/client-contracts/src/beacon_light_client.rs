@@ -1,29 +1,38 @@
-use secp256k1::SecretKey;
 pub use types::*;
 use web3::{
     contract::{tokens::Tokenize, Contract, Options},
     signing::Key,
-    transports::Http,
     types::{Address, H256, BlockId, U256},
     Web3,
 };
 
 use crate::error::BridgeContractResult;
+use crate::transport::{CriticalContract, FailoverHttp};
 
 #[derive(Debug, Clone)]
 pub struct BeaconLightClient {
-    pub contract: Contract<Http>,
+    pub contract: Contract<FailoverHttp>,
+    /// Contract for critical reads, which require a quorum of endpoints
+    pub critical_contract: CriticalContract,
 }
 
 impl BeaconLightClient {
-    pub fn new(client: &Web3<Http>, address: Address) -> BridgeContractResult<Self> {
+    pub fn new(client: &Web3<FailoverHttp>, address: Address) -> BridgeContractResult<Self> {
         let contract = Contract::from_json(
             client.eth(),
             address,
             include_bytes!("abis/BeaconLightClient.json"),
         )?;
+        let critical_contract = CriticalContract::from_json(
+            client,
+            address,
+            include_bytes!("abis/BeaconLightClient.json"),
+        )?;
 
-        Ok(Self { contract })
+        Ok(Self {
+            contract,
+            critical_contract,
+        })
     }
 
     pub async fn finalized_header(&self) -> BridgeContractResult<HeaderMessage> {
@@ -63,15 +72,15 @@ impl BeaconLightClient {
 
     pub async fn block_number(&self) -> BridgeContractResult<U256> {
         Ok(self
-            .contract
-            .query("block_number", (), None, Options::default(), None)
+            .critical_contract
+            .query("block_number", (), None)
             .await?)
     }
 
     pub async fn import_finalized_header(
         &self,
         finalized_header_update: FinalizedHeaderUpdate,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
         mut options: Options,
     ) -> BridgeContractResult<H256> {
         let call = "import_finalized_header";
@@ -97,7 +106,7 @@ impl BeaconLightClient {
         &self,
         finalized_header_update: FinalizedHeaderUpdate,
         sync_committee_update: SyncCommitteePeriodUpdate,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
         mut options: Options,
     ) -> BridgeContractResult<H256> {
         let call = "import_next_sync_committee";
@@ -1,20 +1,20 @@
 use web3::{
     contract::{Contract, Options},
-    transports::Http,
     types::{Address, BlockId, H256, U256},
     Web3,
 };
 
 use crate::error::BridgeContractResult;
+use crate::transport::FailoverHttp;
 
 use self::types::{MessageProof, MessageSingleProof};
 
 pub struct ChainMessageCommitter {
-    pub contract: Contract<Http>,
+    pub contract: Contract<FailoverHttp>,
 }
 
 impl ChainMessageCommitter {
-    pub fn new(client: &Web3<Http>, address: Address) -> BridgeContractResult<Self> {
+    pub fn new(client: &Web3<FailoverHttp>, address: Address) -> BridgeContractResult<Self> {
         let contract = Contract::from_json(
             client.eth(),
             address,
@@ -142,7 +142,7 @@ mod tests {
     #[ignore]
     #[tokio::test]
     async fn test_query_commitment() {
-        let transport = Http::new("https://pangoro-rpc.darwinia.network").unwrap();
+        let transport = FailoverHttp::new(&["https://pangoro-rpc.darwinia.network"]).unwrap();
         let client = web3::Web3::new(transport);
         let c = ChainMessageCommitter::new(
             &client,
@@ -1,5 +1,5 @@
 use crate::error::{BridgeContractError, BridgeContractResult};
-use secp256k1::SecretKey;
+use crate::transport::FailoverHttp;
 use web3::{
     contract::{
         tokens::{Tokenizable, Tokenize},
@@ -7,7 +7,6 @@ use web3::{
     },
     ethabi::Token,
     signing::Key,
-    transports::Http,
     types::{Address, H256, U256},
     Web3,
 };
@@ -17,12 +16,12 @@ use self::types::{Order, OrderExt, RelayerInfo};
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct FeeMarket {
-    pub contract: Contract<Http>,
+    pub contract: Contract<FailoverHttp>,
 }
 
 impl FeeMarket {
     #[allow(dead_code)]
-    pub fn new(client: &Web3<Http>, address: Address) -> BridgeContractResult<Self> {
+    pub fn new(client: &Web3<FailoverHttp>, address: Address) -> BridgeContractResult<Self> {
         let contract =
             Contract::from_json(client.eth(), address, include_bytes!("abis/FeeMarket.json"))?;
         Ok(Self { contract })
@@ -33,7 +32,7 @@ impl FeeMarket {
         &self,
         prev: Address,
         fee: U256,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
     ) -> BridgeContractResult<H256> {
         let call = "enroll";
         let params = (prev, fee).into_tokens();
@@ -62,7 +61,7 @@ impl FeeMarket {
     }
 
     #[allow(dead_code)]
-    pub async fn deposit(&self, fee: U256, private_key: &SecretKey) -> BridgeContractResult<H256> {
+    pub async fn deposit(&self, fee: U256, private_key: impl Key + Copy) -> BridgeContractResult<H256> {
         let call = "deposit";
         let option = Options {
             gas: None,
@@ -115,7 +114,7 @@ impl FeeMarket {
         old_prev: Address,
         new_prev: Address,
         new_fee: U256,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
     ) -> BridgeContractResult<H256> {
         let call = "move";
         let option = Options {
@@ -288,10 +287,11 @@ mod tests {
     use web3::types::{BlockNumber, FilterBuilder, Log};
 
     use super::*;
+    use secp256k1::SecretKey;
 
-    fn test_fee_market() -> (Web3<Http>, FeeMarket) {
-        // let transport = Http::new("http://127.0.0.1:8545").unwrap();
-        let transport = Http::new("https://pangoro-rpc.darwinia.network").unwrap();
+    fn test_fee_market() -> (Web3<FailoverHttp>, FeeMarket) {
+        // let transport = FailoverHttp::new(&["http://127.0.0.1:8545"]).unwrap();
+        let transport = FailoverHttp::new(&["https://pangoro-rpc.darwinia.network"]).unwrap();
         let client = web3::Web3::new(transport);
         let address = Address::from_str("0x6eDcF984eF28C29aa48242B92685244bcD6D7203").unwrap();
         let fee_market = FeeMarket::new(&client, address).unwrap();
@@ -1,27 +1,33 @@
 use std::ops::Div;
 
-use secp256k1::SecretKey;
 pub use types::*;
 use web3::{
     contract::{tokens::Tokenize, Contract, Options},
     signing::Key,
-    transports::Http,
     types::{Address, BlockId, H256, U256},
     Web3,
 };
 
 use crate::error::BridgeContractResult;
+use crate::transport::{CriticalContract, FailoverHttp};
 
 pub struct Inbound {
-    pub contract: Contract<Http>,
+    pub contract: Contract<FailoverHttp>,
+    /// Contract for critical reads, which require a quorum of endpoints
+    pub critical_contract: CriticalContract,
 }
 
 impl Inbound {
-    pub fn new(client: &Web3<Http>, address: Address) -> BridgeContractResult<Self> {
+    pub fn new(client: &Web3<FailoverHttp>, address: Address) -> BridgeContractResult<Self> {
         let contract =
             Contract::from_json(client.eth(), address, include_bytes!("abis/Inbound.json"))?;
+        let critical_contract =
+            CriticalContract::from_json(client, address, include_bytes!("abis/Inbound.json"))?;
 
-        Ok(Self { contract })
+        Ok(Self {
+            contract,
+            critical_contract,
+        })
     }
 
     pub async fn inbound_lane_nonce(
@@ -29,8 +35,8 @@ impl Inbound {
         at_block: Option<BlockId>,
     ) -> BridgeContractResult<InboundLaneNonce> {
         Ok(self
-            .contract
-            .query("inboundLaneNonce", (), None, Options::default(), at_block)
+            .critical_contract
+            .query("inboundLaneNonce", (), at_block)
             .await?)
     }
 
@@ -46,7 +52,7 @@ impl Inbound {
         &self,
         messages_proof: ReceiveMessagesProof,
         delivery_size: U256,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
         mut options: Options,
     ) -> BridgeContractResult<H256> {
         let call = "receive_messages_proof";
@@ -391,9 +397,9 @@ mod tests {
 
     use super::*;
 
-    fn test_client() -> (Web3<Http>, Inbound) {
-        let transport = Http::new("https://pangoro-rpc.darwinia.network").unwrap();
-        // let transport = Http::new("http://127.0.0.1:8545").unwrap();
+    fn test_client() -> (Web3<FailoverHttp>, Inbound) {
+        let transport = FailoverHttp::new(&["https://pangoro-rpc.darwinia.network"]).unwrap();
+        // let transport = FailoverHttp::new(&["http://127.0.0.1:8545"]).unwrap();
         let client = web3::Web3::new(transport);
         (
             client.clone(),
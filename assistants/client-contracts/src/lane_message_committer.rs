@@ -1,18 +1,18 @@
 use web3::{
     contract::{Contract, Options},
-    transports::Http,
     types::{Address, U256},
     Web3,
 };
 
 use crate::error::BridgeContractResult;
+use crate::transport::FailoverHttp;
 
 pub struct LaneMessageCommitter {
-    pub contract: Contract<Http>,
+    pub contract: Contract<FailoverHttp>,
 }
 
 impl LaneMessageCommitter {
-    pub fn new(client: &Web3<Http>, address: Address) -> BridgeContractResult<Self> {
+    pub fn new(client: &Web3<FailoverHttp>, address: Address) -> BridgeContractResult<Self> {
         let contract = Contract::from_json(
             client.eth(),
             address,
@@ -41,7 +41,7 @@ mod tests {
     #[ignore]
     #[tokio::test]
     async fn test_bridged_chain_position() {
-        let transport = Http::new("https://pangoro-rpc.darwinia.network").unwrap();
+        let transport = FailoverHttp::new(&["https://pangoro-rpc.darwinia.network"]).unwrap();
         let client = web3::Web3::new(transport);
         let c = LaneMessageCommitter::new(
             &client,
@@ -6,7 +6,9 @@ pub mod inbound;
 pub mod lane_message_committer;
 pub mod outbound;
 pub mod posa_light_client;
+pub mod signer;
 pub mod simple_fee_market;
+pub mod transport;
 
 pub use beacon_light_client::{types as beacon_light_client_types, BeaconLightClient};
 pub use chain_message_committer::ChainMessageCommitter;
@@ -15,4 +17,6 @@ pub use inbound::{types as inbound_types, Inbound};
 pub use lane_message_committer::LaneMessageCommitter;
 pub use outbound::{types as outbound_types, Outbound};
 pub use posa_light_client::{types as posa_light_client_types, PosaLightClient};
+pub use signer::EvmSigner;
 pub use simple_fee_market::{types as simple_fee_market_types, SimpleFeeMarket};
+pub use transport::{CriticalContract, FailoverHttp};
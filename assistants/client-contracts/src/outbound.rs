@@ -1,23 +1,29 @@
 pub use crate::error::BridgeContractResult;
-use secp256k1::SecretKey;
+use crate::transport::{CriticalContract, FailoverHttp};
 pub use types::*;
 use web3::{
     contract::{tokens::Tokenize, Contract, Options},
     signing::Key,
-    transports::Http,
     types::{Address, BlockId, H256, U256},
     Web3,
 };
 
 pub struct Outbound {
-    pub contract: Contract<Http>,
+    pub contract: Contract<FailoverHttp>,
+    /// Contract for critical reads, which require a quorum of endpoints
+    pub critical_contract: CriticalContract,
 }
 
 impl Outbound {
-    pub fn new(client: &Web3<Http>, address: Address) -> BridgeContractResult<Self> {
+    pub fn new(client: &Web3<FailoverHttp>, address: Address) -> BridgeContractResult<Self> {
         let contract =
             Contract::from_json(client.eth(), address, include_bytes!("abis/Outbound.json"))?;
-        Ok(Self { contract })
+        let critical_contract =
+            CriticalContract::from_json(client, address, include_bytes!("abis/Outbound.json"))?;
+        Ok(Self {
+            contract,
+            critical_contract,
+        })
     }
 
     pub async fn outbound_lane_nonce(
@@ -25,8 +31,8 @@ impl Outbound {
         at_block: Option<BlockId>,
     ) -> BridgeContractResult<OutboundLaneNonce> {
         Ok(self
-            .contract
-            .query("outboundLaneNonce", (), None, Options::default(), at_block)
+            .critical_contract
+            .query("outboundLaneNonce", (), at_block)
             .await?)
     }
 
@@ -34,7 +40,7 @@ impl Outbound {
     pub async fn send_message(
         &self,
         message: SendMessage,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
         fee: U256,
         mut options: Options,
     ) -> BridgeContractResult<H256> {
@@ -89,7 +95,7 @@ impl Outbound {
     pub async fn receive_messages_delivery_proof(
         &self,
         proof: ReceiveMessagesDeliveryProof,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
         mut options: Options,
     ) -> BridgeContractResult<H256> {
         let call = "receive_messages_delivery_proof";
@@ -289,14 +295,15 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
+    use secp256k1::SecretKey;
     use web3::contract::Options;
     use web3::ethabi::{RawLog, Token};
     use web3::signing::{Key, SecretKeyRef};
     use web3::types::{BlockNumber, FilterBuilder};
 
-    fn test_client() -> (Web3<Http>, Outbound) {
-        // let transport = Http::new("http://127.0.0.1:8545").unwrap();
-        let transport = Http::new("https://pangoro-rpc.darwinia.network").unwrap();
+    fn test_client() -> (Web3<FailoverHttp>, Outbound) {
+        // let transport = FailoverHttp::new(&["http://127.0.0.1:8545"]).unwrap();
+        let transport = FailoverHttp::new(&["https://pangoro-rpc.darwinia.network"]).unwrap();
         let client = web3::Web3::new(transport);
         let address = Address::from_str("0x654FE7E51eCA910800Df4E1fA8F2CD8Fb1aFEc4A").unwrap();
         (client.clone(), Outbound::new(&client, address).unwrap())
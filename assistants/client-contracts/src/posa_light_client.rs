@@ -1,29 +1,39 @@
-use secp256k1::SecretKey;
 pub use types::*;
 use web3::{
     contract::{Contract, Options},
     ethabi::Bytes,
-    transports::Http,
+    signing::Key,
     types::{Address, H256, U256},
     Web3,
 };
 
 use crate::error::BridgeContractResult;
+use crate::transport::{CriticalContract, FailoverHttp};
 
 #[derive(Debug, Clone)]
 pub struct PosaLightClient {
-    pub contract: Contract<Http>,
+    pub contract: Contract<FailoverHttp>,
+    /// Contract for critical reads, which require a quorum of endpoints
+    pub critical_contract: CriticalContract,
 }
 
 impl PosaLightClient {
-    pub fn new(client: &Web3<Http>, address: Address) -> BridgeContractResult<Self> {
+    pub fn new(client: &Web3<FailoverHttp>, address: Address) -> BridgeContractResult<Self> {
         let contract = Contract::from_json(
             client.eth(),
             address,
             include_bytes!("abis/POSALightClient.json"),
         )?;
+        let critical_contract = CriticalContract::from_json(
+            client,
+            address,
+            include_bytes!("abis/POSALightClient.json"),
+        )?;
 
-        Ok(Self { contract })
+        Ok(Self {
+            contract,
+            critical_contract,
+        })
     }
 
     pub async fn add_relayer(
@@ -31,7 +41,7 @@ impl PosaLightClient {
         relayer: Address,
         threshold: U256,
         signatures: Vec<Bytes>,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
     ) -> BridgeContractResult<H256> {
         Ok(self
             .contract
@@ -50,7 +60,7 @@ impl PosaLightClient {
         relayer: Address,
         threshold: U256,
         signatures: Vec<Bytes>,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
     ) -> BridgeContractResult<H256> {
         Ok(self
             .contract
@@ -69,7 +79,7 @@ impl PosaLightClient {
         old_relayer: Address,
         new_relayer: Address,
         signatures: Vec<Bytes>,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
     ) -> BridgeContractResult<H256> {
         Ok(self
             .contract
@@ -86,7 +96,7 @@ impl PosaLightClient {
         &self,
         commitment: Commitment,
         signature: Vec<Bytes>,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
     ) -> BridgeContractResult<H256> {
         Ok(self
             .contract
@@ -101,8 +111,8 @@ impl PosaLightClient {
 
     pub async fn block_number(&self) -> BridgeContractResult<U256> {
         Ok(self
-            .contract
-            .query("block_number", (), None, Options::default(), None)
+            .critical_contract
+            .query("block_number", (), None)
             .await?)
     }
 
@@ -113,6 +123,13 @@ impl PosaLightClient {
             .await?)
     }
 
+    pub async fn get_threshold(&self) -> BridgeContractResult<U256> {
+        Ok(self
+            .contract
+            .query("get_threshold", (), None, Options::default(), None)
+            .await?)
+    }
+
     pub async fn nonce(&self) -> BridgeContractResult<U256> {
         Ok(self
             .contract
@@ -177,12 +194,13 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
-    use web3::transports::Http;
+    use crate::transport::FailoverHttp;
 
     fn test_client() -> PosaLightClient {
-        let transport =
-            Http::new("https://eth-goerli.g.alchemy.com/v2/WerPq7On62-wy_ARssv291ZPg1TGR5vi")
-                .unwrap();
+        let transport = FailoverHttp::new(&[
+            "https://eth-goerli.g.alchemy.com/v2/WerPq7On62-wy_ARssv291ZPg1TGR5vi",
+        ])
+        .unwrap();
         let client = web3::Web3::new(transport);
         PosaLightClient::new(
             &client,
@@ -202,9 +220,10 @@ mod tests {
     #[ignore]
     #[tokio::test]
     async fn test_get_relayers() {
-        let transport =
-            Http::new("https://eth-goerli.g.alchemy.com/v2/WerPq7On62-wy_ARssv291ZPg1TGR5vi")
-                .unwrap();
+        let transport = FailoverHttp::new(&[
+            "https://eth-goerli.g.alchemy.com/v2/WerPq7On62-wy_ARssv291ZPg1TGR5vi",
+        ])
+        .unwrap();
         let client = web3::Web3::new(transport);
         let lclient = PosaLightClient::new(
             &client,
@@ -0,0 +1,86 @@
+use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use ecdsa_pair::signer::{Signer, SignerError};
+use web3::signing::{Key, Signature, SigningError};
+use web3::types::{Address, H256};
+
+/// Signer of EVM transactions, the key is in process or in a remote signer.
+/// Pass `&EvmSigner` where a web3 `Key` is required.
+#[derive(Clone)]
+pub struct EvmSigner {
+    inner: Arc<dyn Signer>,
+    address: Address,
+}
+
+impl Debug for EvmSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("EvmSigner {{ address: {:?} }}", self.address))
+    }
+}
+
+impl FromStr for EvmSigner {
+    type Err = SignerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(ecdsa_pair::signer::signer_from_str(s)?))
+    }
+}
+
+impl EvmSigner {
+    pub fn new(inner: Arc<dyn Signer>) -> Self {
+        let address = Address::from(inner.address());
+        Self { inner, address }
+    }
+
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    pub fn inner(&self) -> &Arc<dyn Signer> {
+        &self.inner
+    }
+
+    fn sign_digest(
+        &self,
+        message: &[u8],
+        sign: impl FnOnce(&[u8; 32]) -> Result<ecdsa_pair::crypto::ethereum::Signature, SignerError>,
+    ) -> Result<(H256, H256, u64), SigningError> {
+        let digest: [u8; 32] = message
+            .try_into()
+            .map_err(|_| SigningError::InvalidMessage)?;
+        let signature = sign(&digest).map_err(|e| {
+            tracing::error!(target: "client-contracts", "Failed to sign by {:?}: {:?}", self, e);
+            SigningError::InvalidMessage
+        })?;
+        let bytes = signature.0;
+        Ok((
+            H256::from_slice(&bytes[..32]),
+            H256::from_slice(&bytes[32..64]),
+            bytes[64] as u64,
+        ))
+    }
+}
+
+impl Key for &EvmSigner {
+    fn sign(&self, message: &[u8], chain_id: Option<u64>) -> Result<Signature, SigningError> {
+        let (r, s, standard_v) =
+            self.sign_digest(message, |digest| self.inner.sign_transaction(digest))?;
+        let v = match chain_id {
+            // replay protection of EIP-155
+            Some(chain_id) => standard_v + 35 + chain_id * 2,
+            None => standard_v + 27,
+        };
+        Ok(Signature { r, s, v })
+    }
+
+    fn sign_message(&self, message: &[u8]) -> Result<Signature, SigningError> {
+        let (r, s, v) = self.sign_digest(message, |digest| self.inner.sign_message(digest))?;
+        Ok(Signature { r, s, v })
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+}
@@ -1,11 +1,10 @@
 use crate::{
     error::BridgeContractResult, fee_market_types::RelayerInfo, simple_fee_market::types::Order,
+    transport::FailoverHttp,
 };
-use secp256k1::SecretKey;
 use web3::{
     contract::{tokens::Tokenize, Contract, Options},
     signing::Key,
-    transports::Http,
     types::{Address, H256, U256},
     Web3,
 };
@@ -13,12 +12,12 @@ use web3::{
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct SimpleFeeMarket {
-    pub contract: Contract<Http>,
+    pub contract: Contract<FailoverHttp>,
 }
 
 impl SimpleFeeMarket {
     #[allow(dead_code)]
-    pub fn new(client: &Web3<Http>, address: Address) -> BridgeContractResult<Self> {
+    pub fn new(client: &Web3<FailoverHttp>, address: Address) -> BridgeContractResult<Self> {
         let contract = Contract::from_json(
             client.eth(),
             address,
@@ -32,7 +31,7 @@ impl SimpleFeeMarket {
         &self,
         prev: Address,
         fee: U256,
-        private_key: &SecretKey,
+        private_key: impl Key + Copy,
     ) -> BridgeContractResult<H256> {
         let call = "enroll";
         let params = (prev, fee).into_tokens();
@@ -72,7 +71,7 @@ impl SimpleFeeMarket {
     }
 
     #[allow(dead_code)]
-    pub async fn deposit(&self, fee: U256, private_key: &SecretKey) -> BridgeContractResult<H256> {
+    pub async fn deposit(&self, fee: U256, private_key: impl Key + Copy) -> BridgeContractResult<H256> {
         let call = "deposit";
         let gas = self
             .contract
@@ -195,11 +194,13 @@ mod tests {
     use web3::types::{BlockNumber, FilterBuilder};
 
     use super::*;
+    use secp256k1::SecretKey;
 
-    fn test_fee_market() -> (Web3<Http>, SimpleFeeMarket) {
-        let transport =
-            Http::new("https://eth-goerli.g.alchemy.com/v2/WerPq7On62-wy_ARssv291ZPg1TGR5vi")
-                .unwrap();
+    fn test_fee_market() -> (Web3<FailoverHttp>, SimpleFeeMarket) {
+        let transport = FailoverHttp::new(&[
+            "https://eth-goerli.g.alchemy.com/v2/WerPq7On62-wy_ARssv291ZPg1TGR5vi",
+        ])
+        .unwrap();
         let client = web3::Web3::new(transport);
         let address = Address::from_str("0x380244554a9C51f0CCaFec90A2766B0C8b698a4a").unwrap();
         let fee_market = SimpleFeeMarket::new(&client, address).unwrap();
@@ -0,0 +1,364 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures::future::{self, BoxFuture};
+use web3::{
+    contract::{
+        tokens::{Detokenize, Tokenize},
+        Contract, Options,
+    },
+    error::{Error as Web3Error, Result as Web3Result, TransportError},
+    ethabi, helpers,
+    rpc::{Call, Value},
+    transports::Http,
+    types::{BlockId, BlockNumber, U64},
+    RequestId, Transport, Web3,
+};
+
+/// Http transport over several endpoints.
+///
+/// Requests are sent round-robin, and fail over to the next endpoint when one is
+/// unreachable. The transport returned by [`FailoverHttp::critical`] sends the
+/// request to every endpoint and only accepts a response that `read_quorum`
+/// endpoints agree on. Endpoints are often a block apart at `latest`, so critical
+/// reads are pinned to [`FailoverHttp::critical_block`] by [`CriticalContract`].
+#[derive(Debug, Clone)]
+pub struct FailoverHttp {
+    endpoints: Arc<Vec<(String, Http)>>,
+    next: Arc<AtomicUsize>,
+    id: Arc<AtomicUsize>,
+    read_quorum: usize,
+    required: usize,
+}
+
+impl FailoverHttp {
+    pub fn new<S: AsRef<str>>(endpoints: &[S]) -> Web3Result<Self> {
+        if endpoints.is_empty() {
+            return Err(Web3Error::Transport(TransportError::Message(
+                "No endpoint configured".to_string(),
+            )));
+        }
+        let endpoints = endpoints
+            .iter()
+            .map(|endpoint| Ok((endpoint.as_ref().to_string(), Http::new(endpoint.as_ref())?)))
+            .collect::<Web3Result<Vec<_>>>()?;
+        Ok(Self {
+            endpoints: Arc::new(endpoints),
+            next: Arc::new(AtomicUsize::new(0)),
+            id: Arc::new(AtomicUsize::new(1)),
+            read_quorum: 1,
+            required: 1,
+        })
+    }
+
+    /// Number of endpoints which must agree on critical reads, it can't be zero or more than
+    /// the endpoints
+    pub fn with_read_quorum(mut self, read_quorum: usize) -> Web3Result<Self> {
+        if read_quorum == 0 || read_quorum > self.endpoints.len() {
+            return Err(Web3Error::Transport(TransportError::Message(format!(
+                "Read quorum {} should be between 1 and the number of endpoints {}",
+                read_quorum,
+                self.endpoints.len()
+            ))));
+        }
+        self.read_quorum = read_quorum;
+        Ok(self)
+    }
+
+    /// Transport for critical reads, e.g. light client block number and lane nonces
+    pub fn critical(&self) -> Self {
+        Self {
+            required: self.read_quorum,
+            ..self.clone()
+        }
+    }
+
+    /// Block which critical reads without a block are pinned to, it's the highest block
+    /// reached by `read_quorum` endpoints. It's none if a single endpoint is required, the
+    /// read is sent at `latest` then.
+    pub async fn critical_block(&self) -> Web3Result<Option<BlockId>> {
+        if self.required <= 1 {
+            return Ok(None);
+        }
+        let (id, request) = self.prepare("eth_blockNumber", vec![]);
+        let responses = future::join_all(
+            self.endpoints
+                .iter()
+                .map(|(_, http)| http.send(id, request.clone())),
+        )
+        .await;
+        let mut block_numbers = Vec::new();
+        for ((endpoint, _), response) in self.endpoints.iter().zip(responses) {
+            match response.and_then(helpers::decode::<U64>) {
+                Ok(block_number) => block_numbers.push(block_number),
+                Err(e) => {
+                    tracing::warn!(
+                        target: "client-contracts",
+                        "Request block number of {} failed: {:?}",
+                        endpoint,
+                        e,
+                    );
+                }
+            }
+        }
+        let block_number = quorum_block_number(block_numbers, self.required).ok_or_else(|| {
+            Web3Error::InvalidResponse(format!(
+                "Less than {} endpoints answered the block number",
+                self.required
+            ))
+        })?;
+        Ok(Some(BlockId::Number(BlockNumber::Number(block_number))))
+    }
+
+    fn ordered(&self) -> Vec<(String, Http)> {
+        let mut endpoints = self.endpoints.to_vec();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % endpoints.len();
+        endpoints.rotate_left(start);
+        endpoints
+    }
+}
+
+impl Transport for FailoverHttp {
+    type Out = BoxFuture<'static, Web3Result<Value>>;
+
+    fn prepare(&self, method: &str, params: Vec<Value>) -> (RequestId, Call) {
+        let id = self.id.fetch_add(1, Ordering::AcqRel);
+        (id, helpers::build_request(id, method, params))
+    }
+
+    fn send(&self, id: RequestId, request: Call) -> Self::Out {
+        let endpoints = self.ordered();
+        let required = self.required;
+        Box::pin(async move {
+            if required > 1 {
+                send_quorum(endpoints, required, id, request).await
+            } else {
+                send_failover(endpoints, id, request).await
+            }
+        })
+    }
+}
+
+/// Contract for critical reads, which require a quorum of endpoints. A read without a block
+/// is pinned to [`FailoverHttp::critical_block`], so the endpoints answer at the same block.
+#[derive(Debug, Clone)]
+pub struct CriticalContract {
+    contract: Contract<FailoverHttp>,
+    transport: FailoverHttp,
+}
+
+impl CriticalContract {
+    pub fn from_json(
+        client: &Web3<FailoverHttp>,
+        address: web3::types::Address,
+        json: &[u8],
+    ) -> ethabi::Result<Self> {
+        let transport = client.transport().critical();
+        let contract = Contract::from_json(Web3::new(transport.clone()).eth(), address, json)?;
+        Ok(Self {
+            contract,
+            transport,
+        })
+    }
+
+    pub async fn query<R, P>(
+        &self,
+        func: &str,
+        params: P,
+        at_block: Option<BlockId>,
+    ) -> web3::contract::Result<R>
+    where
+        R: Detokenize,
+        P: Tokenize,
+    {
+        let at_block = match at_block {
+            Some(at_block) => Some(at_block),
+            None => self.transport.critical_block().await?,
+        };
+        self.contract
+            .query(func, params, None, Options::default(), at_block)
+            .await
+    }
+}
+
+/// Highest block number reached by `required` endpoints
+fn quorum_block_number(mut block_numbers: Vec<U64>, required: usize) -> Option<U64> {
+    block_numbers.sort_unstable_by(|a, b| b.cmp(a));
+    block_numbers.get(required.checked_sub(1)?).copied()
+}
+
+async fn send_failover<T: Transport>(
+    endpoints: Vec<(String, T)>,
+    id: RequestId,
+    request: Call,
+) -> Web3Result<Value> {
+    let mut last_error = None;
+    for (endpoint, http) in endpoints {
+        match http.send(id, request.clone()).await {
+            Ok(v) => return Ok(v),
+            // rpc errors are answers of a healthy node, don't ask others
+            Err(Web3Error::Rpc(e)) => return Err(Web3Error::Rpc(e)),
+            Err(e) => {
+                tracing::warn!(
+                    target: "client-contracts",
+                    "Request to {} failed, try next endpoint: {:?}",
+                    endpoint,
+                    e,
+                );
+                last_error = Some(e);
+            }
+        }
+    }
+    Err(last_error.unwrap_or(Web3Error::Unreachable))
+}
+
+async fn send_quorum<T: Transport>(
+    endpoints: Vec<(String, T)>,
+    required: usize,
+    id: RequestId,
+    request: Call,
+) -> Web3Result<Value> {
+    let responses = future::join_all(
+        endpoints
+            .iter()
+            .map(|(_, http)| http.send(id, request.clone())),
+    )
+    .await;
+    let mut answers: Vec<(Value, usize)> = Vec::new();
+    for ((endpoint, _), response) in endpoints.iter().zip(responses) {
+        match response {
+            Ok(value) => match answers.iter_mut().find(|(v, _)| v == &value) {
+                Some((_, count)) => *count += 1,
+                None => answers.push((value, 1)),
+            },
+            Err(e) => {
+                tracing::warn!(target: "client-contracts", "Request to {} failed: {:?}", endpoint, e);
+            }
+        }
+    }
+    answers
+        .into_iter()
+        .find(|(_, count)| *count >= required)
+        .map(|(value, _)| value)
+        .ok_or_else(|| {
+            Web3Error::InvalidResponse(format!(
+                "Less than {} endpoints agreed on the response",
+                required
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use web3::rpc::error::Error as RpcError;
+
+    use super::*;
+
+    /// Transport answers every request with the same result
+    #[derive(Debug, Clone)]
+    struct MockTransport(Result<u64, Option<RpcError>>);
+
+    impl Transport for MockTransport {
+        type Out = BoxFuture<'static, Web3Result<Value>>;
+
+        fn prepare(&self, method: &str, params: Vec<Value>) -> (RequestId, Call) {
+            (1, helpers::build_request(1, method, params))
+        }
+
+        fn send(&self, _id: RequestId, _request: Call) -> Self::Out {
+            let result = match self.0.clone() {
+                Ok(v) => Ok(Value::from(v)),
+                Err(Some(e)) => Err(Web3Error::Rpc(e)),
+                Err(None) => Err(Web3Error::Unreachable),
+            };
+            Box::pin(future::ready(result))
+        }
+    }
+
+    fn endpoints(results: Vec<Result<u64, Option<RpcError>>>) -> Vec<(String, MockTransport)> {
+        results
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (format!("http://{}", i), MockTransport(v)))
+            .collect()
+    }
+
+    fn request() -> Call {
+        helpers::build_request(1, "eth_blockNumber", vec![])
+    }
+
+    #[test]
+    fn test_with_read_quorum() {
+        let transport = FailoverHttp::new(&["http://127.0.0.1:1", "http://127.0.0.1:2"]).unwrap();
+        assert!(transport.clone().with_read_quorum(0).is_err());
+        assert!(transport.clone().with_read_quorum(3).is_err());
+        let transport = transport.with_read_quorum(2).unwrap();
+        assert_eq!(transport.required, 1);
+        assert_eq!(transport.critical().required, 2);
+    }
+
+    #[test]
+    fn test_ordered_round_robin() {
+        let transport = FailoverHttp::new(&["http://127.0.0.1:1", "http://127.0.0.1:2"]).unwrap();
+        let first = transport.ordered();
+        let second = transport.ordered();
+        assert_eq!(first[0].0, "http://127.0.0.1:1");
+        assert_eq!(second[0].0, "http://127.0.0.1:2");
+    }
+
+    #[tokio::test]
+    async fn test_send_failover() {
+        let value = send_failover(endpoints(vec![Err(None), Ok(7)]), 1, request()).await;
+        assert_eq!(value.unwrap(), Value::from(7));
+
+        // a rpc error is an answer, the next endpoint isn't asked
+        let value = send_failover(
+            endpoints(vec![Err(Some(RpcError::internal_error())), Ok(7)]),
+            1,
+            request(),
+        )
+        .await;
+        assert!(matches!(value, Err(Web3Error::Rpc(_))));
+
+        let value = send_failover(endpoints(vec![Err(None), Err(None)]), 1, request()).await;
+        assert!(value.is_err());
+    }
+
+    #[test]
+    fn test_quorum_block_number() {
+        let block_numbers = vec![U64::from(10), U64::from(11), U64::from(9)];
+        assert_eq!(
+            quorum_block_number(block_numbers.clone(), 1),
+            Some(U64::from(11))
+        );
+        assert_eq!(
+            quorum_block_number(block_numbers.clone(), 2),
+            Some(U64::from(10))
+        );
+        assert_eq!(
+            quorum_block_number(block_numbers.clone(), 3),
+            Some(U64::from(9))
+        );
+        assert_eq!(quorum_block_number(block_numbers, 4), None);
+    }
+
+    #[tokio::test]
+    async fn test_critical_block_single_endpoint() {
+        let transport = FailoverHttp::new(&["http://127.0.0.1:1"]).unwrap();
+        // a single endpoint reads at latest, no block number is requested
+        assert_eq!(transport.critical().critical_block().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_send_quorum() {
+        let value = send_quorum(endpoints(vec![Ok(1), Ok(2), Ok(1)]), 2, 1, request()).await;
+        assert_eq!(value.unwrap(), Value::from(1));
+
+        let value = send_quorum(endpoints(vec![Ok(1), Ok(2), Ok(1)]), 3, 1, request()).await;
+        assert!(value.is_err());
+
+        // unreachable endpoints don't count
+        let value = send_quorum(endpoints(vec![Ok(1), Err(None), Ok(2)]), 2, 1, request()).await;
+        assert!(value.is_err());
+    }
+}
@@ -4,6 +4,8 @@ use crate::client::CrabClient;
 
 impl ClientCommon for CrabClient {
     const CHAIN: &'static str = "crab";
+    const SPEC_NAME: &'static str = "Crab2";
+    const GENESIS_HASH: Option<&'static str> = Some("0x86e49c195aeae7c5c4a86ced251f1a28c67b3c35d8289c387ede1776cdd88b24");
 
     type Chain = bp_darwinia_core::DarwiniaLike;
 }
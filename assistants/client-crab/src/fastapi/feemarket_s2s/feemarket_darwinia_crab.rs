@@ -1,6 +1,6 @@
-use feemarket_s2s_traits::api::FeemarketApiRelay;
+use feemarket_s2s_traits::api::{FeemarketApiBalance, FeemarketApiRelay};
 use feemarket_s2s_traits::error::AbstractFeemarketResult;
-use feemarket_s2s_traits::types::{Chain, Order};
+use feemarket_s2s_traits::types::{Chain, Order, Relayer};
 use support_toolkit::convert::SmartCodecMapper;
 
 use crate::client::CrabClient;
@@ -30,3 +30,41 @@ impl FeemarketApiRelay for CrabClient {
         }
     }
 }
+
+#[async_trait::async_trait]
+impl FeemarketApiBalance for CrabClient {
+    async fn free_balance(
+        &self,
+        account: <Self::Chain as Chain>::AccountId,
+    ) -> AbstractFeemarketResult<<Self::Chain as Chain>::Balance> {
+        let expected_account: crate::types::runtime_types::account::AccountId20 =
+            SmartCodecMapper::map_to(&account)?;
+        let address = crate::subxt_runtime::api::storage()
+            .system()
+            .account(&expected_account);
+        let account_info = self
+            .subxt()
+            .storage()
+            .fetch_or_default(&address, None)
+            .await?;
+        Ok(account_info.data.free)
+    }
+
+    async fn relayer_info(
+        &self,
+        account: <Self::Chain as Chain>::AccountId,
+    ) -> AbstractFeemarketResult<
+        Option<Relayer<<Self::Chain as Chain>::AccountId, <Self::Chain as Chain>::Balance>>,
+    > {
+        let expected_account: crate::types::runtime_types::account::AccountId20 =
+            SmartCodecMapper::map_to(&account)?;
+        let address = crate::subxt_runtime::api::storage()
+            .darwinia_fee_market()
+            .relayers_map(&expected_account);
+
+        match self.subxt().storage().fetch(&address, None).await? {
+            Some(v) => Ok(Some(SmartCodecMapper::map_to(&v)?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -5,7 +5,7 @@ use client_crab::error::ClientResult;
 
 pub async fn client() -> ClientResult<CrabClient> {
     let config = ClientConfig {
-        endpoint: "wss://crab-rpc.darwinia.network".to_string(),
+        endpoints: vec!["wss://crab-rpc.darwinia.network".to_string()],
         relayer_private_key: "//Alice".to_string(),
         relayer_real_account: None,
     };
@@ -1,6 +1,6 @@
+use client_common_traits::submit::{self, ExtrinsicSigner};
 use client_crab::types::DarwiniaAccount;
 use sp_core::Pair;
-use subxt::tx::Signer;
 use support_toolkit::convert::SmartCodecMapper;
 
 mod common;
@@ -76,10 +76,7 @@ async fn test_transfer_call() {
     let tx = client.subxt().tx().call_data(&call).unwrap();
     println!("{:?}", source.signer().address());
     println!("{:?}", array_bytes::bytes2hex("0x", tx));
-    let track = client
-        .subxt()
-        .tx()
-        .sign_and_submit_then_watch_default(&call, source.signer())
+    let track = submit::sign_and_submit_then_watch(client.subxt(), &call, source.signer())
         .await
         .unwrap();
     let events = track.wait_for_finalized_success().await.unwrap();
@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ClientConfig {
-    pub endpoint: String,
+    /// endpoints, the healthiest one is connected
+    pub endpoints: Vec<String>,
 
     /// relayer's private key
     pub relayer_private_key: String,
@@ -4,6 +4,8 @@ use crate::client::DarwiniaClient;
 
 impl ClientCommon for DarwiniaClient {
     const CHAIN: &'static str = "darwinia";
+    const SPEC_NAME: &'static str = "Darwinia2";
+    const GENESIS_HASH: Option<&'static str> = Some("0xe71578b37a7c799b0ab4ee87ffa6f059a6b98f71f06fb8c84a8d88013a548ad6");
 
     type Chain = bp_darwinia_core::DarwiniaLike;
 }
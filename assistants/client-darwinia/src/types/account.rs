@@ -1,6 +1,6 @@
-use subxt::tx::PairSigner;
+use std::sync::Arc;
 
-use ecdsa_pair::crypto::ethereum::Pair;
+use client_common_traits::submit::ExtrinsicSigner;
 
 use crate::config::DarwiniaSubxtConfig;
 
@@ -8,19 +8,57 @@ pub use self::darwinia::*;
 
 /// AccountId
 pub type AccountId = <DarwiniaSubxtConfig as subxt::Config>::AccountId;
-/// Signer
-pub type Signer = PairSigner<DarwiniaSubxtConfig, Pair>;
+/// Signature
+pub type Signature = <DarwiniaSubxtConfig as subxt::Config>::Signature;
+
+/// Signer of extrinsics, the key is in process or in a remote signer
+#[derive(Clone)]
+pub struct Signer {
+    account_id: AccountId,
+    inner: Arc<dyn ecdsa_pair::signer::Signer>,
+}
+
+impl Signer {
+    /// Create a new Signer
+    pub fn new(inner: Arc<dyn ecdsa_pair::signer::Signer>) -> Self {
+        let account_id = AccountId::from(inner.address());
+        Self { account_id, inner }
+    }
+
+    /// get account id
+    pub fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+}
+
+impl ExtrinsicSigner<DarwiniaSubxtConfig> for Signer {
+    fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    fn address(&self) -> <DarwiniaSubxtConfig as subxt::Config>::Address {
+        self.account_id.into()
+    }
+
+    fn sign(&self, signer_payload: &[u8]) -> Result<Signature, subxt::Error> {
+        let signature = self.inner.sign_payload(signer_payload).map_err(|e| {
+            subxt::Error::Other(format!(
+                "Failed to sign extrinsic by {}: {:?}",
+                self.account_id, e
+            ))
+        })?;
+        Ok(signature.into())
+    }
+}
 
 mod darwinia {
     use std::fmt::{Debug, Formatter};
 
-    use sp_core::Pair as TraitPair;
-    use subxt::tx::PairSigner;
+    use ecdsa_pair::signer::signer_from_str;
 
     use crate::error::{ClientError, ClientResult};
 
     use super::AccountId;
-    use super::Pair;
     use super::Signer;
 
     /// Account
@@ -28,40 +66,36 @@ mod darwinia {
     pub struct DarwiniaAccount {
         /// signer of the account
         signer: Signer,
-        /// proxy real
-        real: Option<Signer>,
+        /// proxy real, calls are submitted as `proxy.proxy` of it when set
+        real: Option<AccountId>,
     }
 
     impl Debug for DarwiniaAccount {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
             f.write_str(&format!("account: {},", self.signer.account_id()))?;
             f.write_str(" signer: <..>,")?;
-            f.write_str(&format!(
-                " real: {:?}",
-                self.real.clone().map(|v| *v.account_id())
-            ))?;
+            f.write_str(&format!(" real: {:?}", self.real))?;
             Ok(())
         }
     }
 
     impl DarwiniaAccount {
-        /// Create a new Account
+        /// Create a new Account, the seed can be a remote signer `remote:<url>#<public key>`,
+        /// the real is the address of the proxied account
         pub fn new(seed: String, real: Option<String>) -> ClientResult<Self> {
             // signer to sign darwinia extrinsic
-            let pair =
-                Pair::from_string(&seed, None).map_err(|e| ClientError::Seed(format!("{e:?}")))?; // if not a valid seed
-            let signer = PairSigner::new(pair);
-
-            let mut real_signer = None;
-            if let Some(real_seed) = real {
-                let pair = Pair::from_string(&real_seed, None)
-                    .map_err(|e| ClientError::Seed(format!("{e:?}")))?;
-                real_signer = Some(PairSigner::new(pair))
+            let signer = Signer::new(
+                signer_from_str(&seed).map_err(|e| ClientError::Seed(format!("{e:?}")))?,
+            ); // if not a valid seed
+
+            let real = match real {
+                Some(address) => {
+                    let address: [u8; 20] = array_bytes::hex2array(address.trim())?;
+                    Some(AccountId::from(address))
+                }
+                None => None,
             };
-            Ok(Self {
-                signer,
-                real: real_signer,
-            })
+            Ok(Self { signer, real })
         }
     }
 
@@ -76,13 +110,16 @@ mod darwinia {
             &self.signer
         }
 
+        /// get proxy real account
+        pub fn real(&self) -> Option<&AccountId> {
+            self.real.as_ref()
+        }
+
         /// get raw real account
         pub fn real_account(&self) -> &AccountId {
-            if let Some(real_signer) = &self.real {
-                real_signer.account_id()
-            } else {
-                self.signer.account_id()
-            }
+            self.real
+                .as_ref()
+                .unwrap_or_else(|| self.signer.account_id())
         }
     }
 }
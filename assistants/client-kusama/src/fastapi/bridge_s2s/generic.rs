@@ -4,6 +4,8 @@ use bridge_s2s_traits::{
     types::bp_header_chain,
     types::bp_runtime::Chain,
 };
+use client_common_traits::endpoint;
+use client_common_traits::ChainIdentity;
 use finality_grandpa::voter_set::VoterSet;
 use sp_finality_grandpa::{AuthorityList, ConsensusLog, ScheduledChange};
 use sp_runtime::generic::{Block, SignedBlock};
@@ -13,6 +15,7 @@ use subxt::rpc::Subscription;
 use support_toolkit::convert::SmartCodecMapper;
 
 use crate::client::KusamaClient;
+use crate::config::KusamaSubxtConfig;
 use crate::error::{ClientError, ClientResult};
 
 const GRANDPA_ENGINE_ID: ConsensusEngineId = *b"FRNK";
@@ -102,6 +105,17 @@ impl S2SClientGeneric for KusamaClient {
         }
     }
 
+    async fn block_hash(
+        &self,
+        number: <Self::Chain as Chain>::BlockNumber,
+    ) -> S2SClientResult<Option<<Self::Chain as Chain>::Hash>> {
+        let number: u32 = SmartCodecMapper::map_to(&number)?;
+        match self.subxt().rpc().block_hash(Some(number.into())).await? {
+            Some(v) => Ok(Some(SmartCodecMapper::map_to(&v)?)),
+            None => Ok(None),
+        }
+    }
+
     async fn block(
         &self,
         hash: Option<<Self::Chain as Chain>::Hash>,
@@ -253,4 +267,24 @@ impl S2SClientGeneric for KusamaClient {
             S2SClientError::Custom(format!("Failed to decode initialization data: {:?}", e))
         })?)
     }
+
+    async fn chain_identities(&self) -> Vec<(String, S2SClientResult<ChainIdentity>)> {
+        endpoint::identities::<KusamaSubxtConfig>(self.endpoints())
+            .await
+            .into_iter()
+            .map(|(endpoint, identity)| (endpoint, identity.map_err(S2SClientError::from)))
+            .collect()
+    }
+
+    async fn runtime_spec_version(&self) -> S2SClientResult<u32> {
+        Ok(self.subxt().rpc().runtime_version(None).await?.spec_version)
+    }
+
+    async fn runtime_metadata(&self) -> S2SClientResult<subxt::Metadata> {
+        Ok(self.subxt().rpc().metadata(None).await?)
+    }
+
+    fn is_endpoint_degraded(&self) -> bool {
+        endpoint::is_degraded(&self.endpoint)
+    }
 }
@@ -4,6 +4,8 @@ use crate::client::KusamaClient;
 
 impl ClientCommon for KusamaClient {
     const CHAIN: &'static str = "kusama";
+    const SPEC_NAME: &'static str = "kusama";
+    const GENESIS_HASH: Option<&'static str> = Some("0xb0a8d493285c2df73290dfb7e61f870f17b41801197a149ca93654499ea3dafe");
 
     type Chain = bp_polkadot_core::PolkadotLike;
 }
@@ -4,6 +4,9 @@ use crate::client::MoonbaseClient;
 
 impl ClientCommon for MoonbaseClient {
     const CHAIN: &'static str = "moonbase";
+    const SPEC_NAME: &'static str = "westend";
+    // testnet is reset from time to time
+    const GENESIS_HASH: Option<&'static str> = None;
 
     type Chain = bp_polkadot_core::PolkadotLike;
 }
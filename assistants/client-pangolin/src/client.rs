@@ -1,51 +1,82 @@
+use client_common_traits::{endpoint, submit};
 use subxt::client::OnlineClient;
+use subxt::tx::{TxEvents, TxPayload};
 
 use crate::config::PangolinSubxtConfig;
-use crate::error::ClientResult;
+use crate::error::{ClientError, ClientResult};
 use crate::types::DarwiniaAccount;
 
 /// Pangolin client
 #[derive(Debug, Clone)]
 pub struct PangolinClient {
-    /// Endpoint
+    /// All configured endpoints
+    endpoints: Vec<String>,
+    /// Connected endpoint
     pub endpoint: String,
     /// Runtime api
     client: OnlineClient<PangolinSubxtConfig>,
-    /// Pangolin Account
-    account: DarwiniaAccount,
+    /// Pangolin Account, none if the client is read-only
+    account: Option<DarwiniaAccount>,
 }
 
 impl PangolinClient {
     /// Create a new darwinia client
     pub fn new(
-        endpoint: &str,
+        endpoints: Vec<String>,
+        endpoint: String,
         client: OnlineClient<PangolinSubxtConfig>,
-        account: DarwiniaAccount,
+        account: Option<DarwiniaAccount>,
     ) -> Self {
         Self {
-            endpoint: endpoint.into(),
+            endpoints,
+            endpoint,
             client,
             account,
         }
     }
 
+    /// Reconnect to the healthiest endpoint, the current one is reported as failed
     pub async fn reconnect_client(&mut self) -> ClientResult<()> {
-        self.client = OnlineClient::from_url(&self.endpoint).await?;
+        endpoint::report_failure(&self.endpoint);
+        let (endpoint, client) = endpoint::connect(&self.endpoints).await?;
+        self.endpoint = endpoint;
+        self.client = client;
         Ok(())
     }
 }
 
 impl PangolinClient {
-    /// Get darwinia account
-    pub fn account(&self) -> &DarwiniaAccount {
-        &self.account
+    /// Get darwinia account, fails if the client is read-only
+    pub fn account(&self) -> ClientResult<&DarwiniaAccount> {
+        self.account.as_ref().ok_or(ClientError::ReadOnly)
     }
 }
 
 /// patch rpc api
 impl PangolinClient {
+    /// Get all configured endpoints
+    pub fn endpoints(&self) -> &[String] {
+        &self.endpoints
+    }
+
     /// Get original subxt client
     pub fn subxt(&self) -> &OnlineClient<PangolinSubxtConfig> {
         &self.client
     }
 }
+
+impl PangolinClient {
+    /// Sign and submit the call by the relayer, then wait until it's finalized successfully.
+    /// It's wrapped in `proxy.proxy` when a real account is configured, so the real account
+    /// is the origin of the call.
+    pub async fn sign_and_submit_finalized(
+        &self,
+        call: &impl TxPayload,
+    ) -> ClientResult<TxEvents<PangolinSubxtConfig>> {
+        let account = self.account()?;
+        Ok(
+            submit::sign_and_submit_finalized(&self.client, call, account.signer(), account.real())
+                .await?,
+        )
+    }
+}
@@ -26,6 +26,9 @@ pub enum ClientError {
     #[error("Other error: {0}")]
     Custom(String),
 
+    #[error("The client is read-only, no account to sign extrinsics")]
+    ReadOnly,
+
     #[error("Io error: {0}")]
     Io(#[from] std::io::Error),
 
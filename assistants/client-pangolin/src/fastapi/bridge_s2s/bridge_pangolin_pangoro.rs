@@ -1,11 +1,14 @@
 use std::ops::RangeInclusive;
 
-use bridge_s2s_traits::client::{S2SClientGeneric, S2SClientRelay, S2SParaBridgeClientSolochain};
+use bridge_s2s_traits::client::{
+    S2SClientGeneric, S2SClientRelay, S2SClientRuntime, S2SParaBridgeClientSolochain,
+};
 use bridge_s2s_traits::error::{S2SClientError, S2SClientResult};
 use bridge_s2s_traits::types::bp_messages::Weight;
 use bridge_s2s_traits::types::{
-    bp_header_chain, bp_messages, bp_runtime::Chain, bridge_runtime_common,
+    bp_header_chain, bp_messages, bp_runtime::Chain, bridge_runtime_common, MessagePayloadInfo,
 };
+use client_common_traits::compat::{self, RuntimeItem};
 use client_common_traits::ClientCommon;
 
 use support_toolkit::convert::SmartCodecMapper;
@@ -21,6 +24,46 @@ type FromThisChainMessagePayload = crate::types::runtime_types::bp_message_dispa
     Vec<u8>,
 >;
 
+#[async_trait::async_trait]
+impl S2SClientRuntime for PangolinClient {
+    fn runtime_items(&self) -> S2SClientResult<Vec<RuntimeItem>> {
+        let storage = crate::subxt_runtime::api::storage();
+        let tx = crate::subxt_runtime::api::tx();
+        let mut items = vec![
+            RuntimeItem::storage(&storage.bridge_moonbase_grandpa().best_finalized()),
+            RuntimeItem::storage(&storage.bridge_pangoro_messages().outbound_lanes_root()),
+            RuntimeItem::storage(&storage.bridge_pangoro_messages().inbound_lanes_root()),
+            RuntimeItem::storage(&storage.bridge_pangoro_messages().outbound_messages_root()),
+            RuntimeItem::storage(&storage.bridge_moonbase_parachain().paras_info_root()),
+        ];
+        // the arguments are placeholders, only the hashes of calls are read
+        let calls = [
+            RuntimeItem::call(&tx.bridge_moonbase_grandpa().initialize(compat::zeroed()?)),
+            RuntimeItem::call(
+                &tx.bridge_moonbase_grandpa()
+                    .submit_finality_proof(compat::zeroed()?, compat::zeroed()?),
+            ),
+            RuntimeItem::call(&tx.bridge_pangoro_messages().receive_messages_proof(
+                compat::zeroed()?,
+                compat::zeroed()?,
+                compat::zeroed()?,
+                compat::zeroed()?,
+            )),
+            RuntimeItem::call(
+                &tx.bridge_pangoro_messages()
+                    .receive_messages_delivery_proof(compat::zeroed()?, compat::zeroed()?),
+            ),
+            RuntimeItem::call(&tx.bridge_moonbase_parachain().submit_parachain_heads(
+                compat::zeroed()?,
+                compat::zeroed()?,
+                compat::zeroed()?,
+            )),
+        ];
+        items.extend(calls.into_iter().flatten());
+        Ok(items)
+    }
+}
+
 #[async_trait::async_trait]
 impl S2SClientRelay for PangolinClient {
     fn gen_outbound_messages_storage_key(&self, lane: [u8; 4], message_nonce: u64) -> Vec<u8> {
@@ -71,13 +114,28 @@ impl S2SClientRelay for PangolinClient {
                         message_nonce
                     ))
                 })?;
-            let decoded_payload: FromThisChainMessagePayload =
-                codec::Decode::decode(&mut &message_data.payload[..])?;
-            total_weight += decoded_payload.weight.ref_time;
+            total_weight += self.decode_message_payload(&message_data.payload)?.weight;
         }
         Ok(total_weight)
     }
 
+    fn decode_message_payload(&self, payload: &[u8]) -> S2SClientResult<MessagePayloadInfo> {
+        let decoded_payload: FromThisChainMessagePayload =
+            codec::Decode::decode(&mut &payload[..])?;
+        Ok(MessagePayloadInfo {
+            spec_version: decoded_payload.spec_version,
+            weight: decoded_payload.weight.ref_time,
+            origin: format!("{:?}", decoded_payload.origin),
+            dispatch_fee_payment: format!("{:?}", decoded_payload.dispatch_fee_payment),
+            call: decoded_payload.call,
+        })
+    }
+
+    fn decode_dispatch_call(&self, call: &[u8]) -> S2SClientResult<String> {
+        let metadata = self.subxt().metadata();
+        Ok(client_common_traits::call::decode_call(&metadata, call).map_err(ClientError::from)?)
+    }
+
     async fn best_target_finalized(
         &self,
         at_block: Option<<Self::Chain as Chain>::Hash>,
@@ -103,12 +161,7 @@ impl S2SClientRelay for PangolinClient {
         let call = crate::subxt_runtime::api::tx()
             .bridge_moonbase_grandpa()
             .initialize(initialization_data);
-        let track = self
-            .subxt()
-            .tx()
-            .sign_and_submit_then_watch_default(&call, self.account().signer())
-            .await?;
-        let events = track.wait_for_finalized_success().await.map_err(|e| {
+        let events = self.sign_and_submit_finalized(&call).await.map_err(|e| {
             S2SClientError::RPC(format!(
                 "send transaction failed {}: {:?}",
                 <Self as ClientCommon>::CHAIN,
@@ -131,13 +184,7 @@ impl S2SClientRelay for PangolinClient {
         let call = crate::subxt_runtime::api::tx()
             .bridge_moonbase_grandpa()
             .submit_finality_proof(expected_target, expected_justification);
-        let track = self
-            .subxt()
-            .tx()
-            .sign_and_submit_then_watch_default(&call, self.account().signer())
-            .await?;
-
-        let events = track.wait_for_finalized_success().await.map_err(|e| {
+        let events = self.sign_and_submit_finalized(&call).await.map_err(|e| {
             S2SClientError::RPC(format!(
                 "send transaction failed {}: {:?}",
                 <Self as ClientCommon>::CHAIN,
@@ -216,12 +263,7 @@ impl S2SClientRelay for PangolinClient {
                 messages_count,
                 expected_dispatch_weight,
             );
-        let track = self
-            .subxt()
-            .tx()
-            .sign_and_submit_then_watch_default(&call, self.account().signer())
-            .await?;
-        let events = track.wait_for_finalized_success().await.map_err(|e| {
+        let events = self.sign_and_submit_finalized(&call).await.map_err(|e| {
             S2SClientError::RPC(format!(
                 "send transaction failed {}: {:?}",
                 <Self as ClientCommon>::CHAIN,
@@ -243,12 +285,7 @@ impl S2SClientRelay for PangolinClient {
         let call = crate::subxt_runtime::api::tx()
             .bridge_pangoro_messages()
             .receive_messages_delivery_proof(expected_proof, expected_relayers_state);
-        let track = self
-            .subxt()
-            .tx()
-            .sign_and_submit_then_watch_default(&call, self.account().signer())
-            .await?;
-        let events = track.wait_for_finalized_success().await.map_err(|e| {
+        let events = self.sign_and_submit_finalized(&call).await.map_err(|e| {
             S2SClientError::RPC(format!(
                 "send transaction failed {}: {:?}",
                 <Self as ClientCommon>::CHAIN,
@@ -300,12 +337,7 @@ impl S2SParaBridgeClientSolochain for PangolinClient {
                 parachain_heads_proof,
             ),
         );
-        let track = self
-            .subxt()
-            .tx()
-            .sign_and_submit_then_watch_default(&call, self.account().signer())
-            .await?;
-        let events = track.wait_for_finalized_success().await.map_err(|e| {
+        let events = self.sign_and_submit_finalized(&call).await.map_err(|e| {
             S2SClientError::RPC(format!(
                 "send transaction failed {}: {:?}",
                 <Self as ClientCommon>::CHAIN,
@@ -4,6 +4,9 @@ use crate::client::PangolinClient;
 
 impl ClientCommon for PangolinClient {
     const CHAIN: &'static str = "pangolin";
+    const SPEC_NAME: &'static str = "Pangolin2";
+    // testnet is reset from time to time
+    const GENESIS_HASH: Option<&'static str> = None;
 
     type Chain = bp_darwinia_core::DarwiniaLike;
 }
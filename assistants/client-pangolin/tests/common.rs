@@ -5,7 +5,7 @@ use client_pangolin::error::ClientResult;
 
 pub async fn client() -> ClientResult<PangolinClient> {
     let config = ClientConfig {
-        endpoint: "wss://pangolin-rpc.darwinia.network".to_string(),
+        endpoints: vec!["wss://pangolin-rpc.darwinia.network".to_string()],
         relayer_private_key: "//Alice".to_string(),
         relayer_real_account: None,
     };
@@ -1,39 +1,33 @@
-use std::{thread, time};
-
-use subxt::OnlineClient;
+use client_common_traits::endpoint;
 
 use crate::client::PangoroClient;
-use crate::config::ClientConfig;
-use crate::config::PangoroSubxtConfig;
+use crate::config::{ClientConfig, PangoroSubxtConfig};
 use crate::error::ClientResult;
 use crate::types::DarwiniaAccount;
 
-const MAX_ATTEMPTS: u32 = 6;
-
 /// Subxt component
 pub struct PangoroClientComponent;
 
 impl PangoroClientComponent {
     /// Get subxt client instance
     pub async fn component(config: ClientConfig) -> ClientResult<PangoroClient> {
-        let mut attempts = 1;
-        let mut wait_secs = 1;
-        let endpoint = support_toolkit::url::correct_endpoint(&config.endpoint)?;
-        let account =
-            DarwiniaAccount::new(config.relayer_private_key, config.relayer_real_account)?;
-        loop {
-            thread::sleep(time::Duration::from_secs(wait_secs));
-            return match OnlineClient::<PangoroSubxtConfig>::from_url(&endpoint).await {
-                Ok(client) => Ok(PangoroClient::new(&endpoint, client, account.clone())),
-                Err(err) => {
-                    if attempts < MAX_ATTEMPTS {
-                        attempts += 1;
-                        wait_secs *= 2; // backoff
-                        continue;
-                    }
-                    Err(err.into())
-                }
-            };
-        }
+        let endpoints = config
+            .endpoints
+            .iter()
+            .map(|v| support_toolkit::url::correct_endpoint(v))
+            .collect::<Result<Vec<String>, _>>()?;
+        let account = DarwiniaAccount::new(config.relayer_private_key, config.relayer_real_account)?;
+        let (endpoint, client) = endpoint::connect::<PangoroSubxtConfig>(&endpoints).await?;
+        Ok(PangoroClient::new(endpoints, endpoint, client, Some(account)))
+    }
+
+    /// Get read-only subxt client instance, it queries chain and never signs
+    pub async fn readonly(endpoints: &[String]) -> ClientResult<PangoroClient> {
+        let endpoints = endpoints
+            .iter()
+            .map(|v| support_toolkit::url::correct_endpoint(v))
+            .collect::<Result<Vec<String>, _>>()?;
+        let (endpoint, client) = endpoint::connect::<PangoroSubxtConfig>(&endpoints).await?;
+        Ok(PangoroClient::new(endpoints, endpoint, client, None))
     }
 }
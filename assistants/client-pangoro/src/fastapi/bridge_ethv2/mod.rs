@@ -1,10 +1,13 @@
 use crate::client::PangoroClient;
 use crate::config::PangoroSubxtConfig;
+use crate::subxt_runtime::api::ecdsa_authority::events as ecdsa_events;
 use crate::types::runtime_types;
 use bridge_e2e_traits::{
-    client::EcdsaClient,
+    client::{CollectingSignatureState, EcdsaClient},
     error::{E2EClientError, E2EClientResult},
+    types::{EcdsaBlockEvents, EcdsaCommitment, EcdsaEvent, EcdsaOperation},
 };
+use client_common_traits::submit;
 
 #[async_trait::async_trait]
 impl EcdsaClient for PangoroClient {
@@ -62,11 +65,8 @@ impl EcdsaClient for PangoroClient {
             .submit_authorities_change_signature(runtime_types::sp_core::ecdsa::Signature(
                 fixed_signatures,
             ));
-        let track = self
-            .subxt()
-            .tx()
-            .sign_and_submit_then_watch(&tx, self.account().signer(), Default::default())
-            .await?;
+        let account = self.account()?;
+        let track = submit::sign_and_submit_then_watch(self.subxt(), &tx, account.signer()).await?;
         let events = track.wait_for_finalized_success().await.map_err(|e| {
             E2EClientError::Custom(format!("send transaction failed pangoro: {:?}", e))
         })?;
@@ -89,11 +89,8 @@ impl EcdsaClient for PangoroClient {
                 fixed_signatures,
             ));
 
-        let track = self
-            .subxt()
-            .tx()
-            .sign_and_submit_then_watch(&tx, self.account().signer(), Default::default())
-            .await?;
+        let account = self.account()?;
+        let track = submit::sign_and_submit_then_watch(self.subxt(), &tx, account.signer()).await?;
         let hash = track.extrinsic_hash();
         tracing::trace!(target: "client-pangoro", "[submit_new_message_root_signature] tx hash: {:?}", hash);
         let events = track.wait_for_finalized_success().await.map_err(|e| {
@@ -101,4 +98,154 @@ impl EcdsaClient for PangoroClient {
         })?;
         Ok(events.extrinsic_hash())
     }
+
+    async fn new_message_root_signature_state(
+        &self,
+        message: &[u8],
+        your_address: &[u8; 20],
+    ) -> E2EClientResult<CollectingSignatureState> {
+        let address = crate::subxt_runtime::api::storage()
+            .ecdsa_authority()
+            .message_root_to_sign();
+        let to_sign = self.subxt().storage().fetch(&address, None).await?;
+        match to_sign {
+            Some(to_sign) if to_sign.message.as_bytes() == message => {
+                let signers = to_sign.signatures.0.iter().map(|(signer, _)| &signer.0);
+                Ok(CollectingSignatureState::of(signers, your_address))
+            }
+            _ => Ok(CollectingSignatureState::NotCollecting),
+        }
+    }
+
+    async fn authorities_change_signature_state(
+        &self,
+        message: &[u8],
+        your_address: &[u8; 20],
+    ) -> E2EClientResult<CollectingSignatureState> {
+        let address = crate::subxt_runtime::api::storage()
+            .ecdsa_authority()
+            .authorities_change_to_sign();
+        let to_sign = self.subxt().storage().fetch(&address, None).await?;
+        match to_sign {
+            Some(to_sign) if to_sign.message.as_bytes() == message => {
+                let signers = to_sign.signatures.0.iter().map(|(signer, _)| &signer.0);
+                Ok(CollectingSignatureState::of(signers, your_address))
+            }
+            _ => Ok(CollectingSignatureState::NotCollecting),
+        }
+    }
+
+    async fn finalized_block_number(&self) -> E2EClientResult<u32> {
+        let hash = self.subxt().rpc().finalized_head().await?;
+        let header = self
+            .subxt()
+            .rpc()
+            .header(Some(hash))
+            .await?
+            .ok_or_else(|| {
+                E2EClientError::Custom("Can not query best finalized header".to_string())
+            })?;
+        Ok(header.number)
+    }
+
+    async fn ecdsa_events(&self, block_number: u32) -> E2EClientResult<Option<EcdsaBlockEvents>> {
+        let hash = match self
+            .subxt()
+            .rpc()
+            .block_hash(Some(block_number.into()))
+            .await?
+        {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        let events = self.subxt().events().at(Some(hash)).await?;
+        let mut ecdsa_events = vec![];
+        for event in events.iter() {
+            let event = event?;
+            let index = event.index();
+            if let Some(ev) =
+                event.as_event::<ecdsa_events::CollectingNewMessageRootSignatures>()?
+            {
+                ecdsa_events.push((
+                    index,
+                    EcdsaEvent::CollectingNewMessageRootSignatures {
+                        message: ev.message.0,
+                    },
+                ));
+            } else if let Some(ev) =
+                event.as_event::<ecdsa_events::CollectedEnoughNewMessageRootSignatures>()?
+            {
+                ecdsa_events.push((
+                    index,
+                    EcdsaEvent::CollectedEnoughNewMessageRootSignatures {
+                        commitment: EcdsaCommitment {
+                            block_number: ev.commitment.block_number,
+                            message_root: ev.commitment.message_root.0,
+                            nonce: ev.commitment.nonce,
+                        },
+                        message: ev.message.0,
+                        signatures: to_ecdsa_signatures(ev.signatures),
+                    },
+                ));
+            } else if let Some(ev) =
+                event.as_event::<ecdsa_events::CollectingAuthoritiesChangeSignatures>()?
+            {
+                ecdsa_events.push((
+                    index,
+                    EcdsaEvent::CollectingAuthoritiesChangeSignatures {
+                        message: ev.message.0,
+                    },
+                ));
+            } else if let Some(ev) =
+                event.as_event::<ecdsa_events::CollectedEnoughAuthoritiesChangeSignatures>()?
+            {
+                ecdsa_events.push((
+                    index,
+                    EcdsaEvent::CollectedEnoughAuthoritiesChangeSignatures {
+                        operation: to_ecdsa_operation(ev.operation),
+                        threshold: ev.threshold,
+                        message: ev.message.0,
+                        signatures: to_ecdsa_signatures(ev.signatures),
+                    },
+                ));
+            }
+        }
+        Ok(Some(EcdsaBlockEvents {
+            block_number,
+            block_hash: hash.as_ref().to_vec(),
+            events: ecdsa_events,
+        }))
+    }
+}
+
+fn to_ecdsa_signatures(
+    signatures: Vec<(
+        runtime_types::account::AccountId20,
+        runtime_types::sp_core::ecdsa::Signature,
+    )>,
+) -> Vec<([u8; 20], [u8; 65])> {
+    signatures
+        .into_iter()
+        .map(|(address, signature)| (address.0, signature.0))
+        .collect()
+}
+
+fn to_ecdsa_operation(
+    operation: runtime_types::darwinia_ecdsa_authority::primitives::Operation<
+        runtime_types::account::AccountId20,
+    >,
+) -> EcdsaOperation {
+    use runtime_types::darwinia_ecdsa_authority::primitives::Operation;
+    match operation {
+        Operation::AddMember { new } => EcdsaOperation::AddMember { new: new.0 },
+        Operation::RemoveMember { pre, old } => EcdsaOperation::RemoveMember {
+            pre: pre.0,
+            old: old.0,
+        },
+        Operation::SwapMembers { pre, old, new } => EcdsaOperation::SwapMembers {
+            pre: pre.0,
+            old: old.0,
+            new: new.0,
+        },
+    }
 }
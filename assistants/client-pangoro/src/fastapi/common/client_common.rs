@@ -4,6 +4,9 @@ use crate::client::PangoroClient;
 
 impl ClientCommon for PangoroClient {
     const CHAIN: &'static str = "pangoro";
+    const SPEC_NAME: &'static str = "Pangoro2";
+    // testnet is reset from time to time
+    const GENESIS_HASH: Option<&'static str> = None;
 
     type Chain = bp_darwinia_core::DarwiniaLike;
 }
@@ -6,7 +6,7 @@ use client_pangoro::error::ClientResult;
 
 pub async fn client() -> ClientResult<PangoroClient> {
     let config = ClientConfig {
-        endpoint: "wss://pangoro-rpc.darwinia.network".to_string(),
+        endpoints: vec!["wss://pangoro-rpc.darwinia.network".to_string()],
         relayer_private_key: "//Alice".to_string(),
         relayer_real_account: None,
     };
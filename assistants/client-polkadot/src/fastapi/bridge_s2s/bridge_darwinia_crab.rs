@@ -1,10 +1,20 @@
-use bridge_s2s_traits::client::S2SParaBridgeClientRelaychain;
+use bridge_s2s_traits::client::{S2SClientRuntime, S2SParaBridgeClientRelaychain};
 use bridge_s2s_traits::error::S2SClientResult;
 use bridge_s2s_traits::types::bp_runtime::Chain;
 use bridge_s2s_traits::types::{HeadData, ParaId};
+use client_common_traits::compat::RuntimeItem;
 
 use crate::client::PolkadotClient;
 
+#[async_trait::async_trait]
+impl S2SClientRuntime for PolkadotClient {
+    fn runtime_items(&self) -> S2SClientResult<Vec<RuntimeItem>> {
+        Ok(vec![RuntimeItem::storage(
+            &crate::subxt_runtime::api::storage().paras().heads_root(),
+        )])
+    }
+}
+
 #[async_trait::async_trait]
 impl S2SParaBridgeClientRelaychain for PolkadotClient {
     fn gen_parachain_head_storage_key(&self, para_id: u32) -> Vec<u8> {
@@ -4,6 +4,8 @@ use crate::client::PolkadotClient;
 
 impl ClientCommon for PolkadotClient {
     const CHAIN: &'static str = "polkadot";
+    const SPEC_NAME: &'static str = "polkadot";
+    const GENESIS_HASH: Option<&'static str> = Some("0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3");
 
     type Chain = bp_polkadot_core::PolkadotLike;
 }
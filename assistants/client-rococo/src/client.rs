@@ -1,11 +1,17 @@
+use client_common_traits::endpoint;
 use subxt::OnlineClient;
 
 use crate::config::RococoSubxtConfig;
+use crate::error::ClientResult;
 use crate::types::RococoAccount;
 
 /// Rococo client
 #[derive(Clone)]
 pub struct RococoClient {
+    /// All configured endpoints
+    endpoints: Vec<String>,
+    /// Connected endpoint
+    pub endpoint: String,
     /// Runtime api
     client: OnlineClient<RococoSubxtConfig>,
     /// Darwinia Account
@@ -14,8 +20,27 @@ pub struct RococoClient {
 
 impl RococoClient {
     /// Create a new Rococo client
-    pub fn new(client: OnlineClient<RococoSubxtConfig>, account: RococoAccount) -> Self {
-        Self { client, account }
+    pub fn new(
+        endpoints: Vec<String>,
+        endpoint: String,
+        client: OnlineClient<RococoSubxtConfig>,
+        account: RococoAccount,
+    ) -> Self {
+        Self {
+            endpoints,
+            endpoint,
+            client,
+            account,
+        }
+    }
+
+    /// Reconnect to the healthiest endpoint, the current one is reported as failed
+    pub async fn reconnect_client(&mut self) -> ClientResult<()> {
+        endpoint::report_failure(&self.endpoint);
+        let (endpoint, client) = endpoint::connect(&self.endpoints).await?;
+        self.endpoint = endpoint;
+        self.client = client;
+        Ok(())
     }
 }
 
@@ -28,6 +53,11 @@ impl RococoClient {
 
 /// patch rpc api
 impl RococoClient {
+    /// Get all configured endpoints
+    pub fn endpoints(&self) -> &[String] {
+        &self.endpoints
+    }
+
     /// Get original subxt client
     pub fn subxt(&self) -> &OnlineClient<RococoSubxtConfig> {
         &self.client
@@ -4,6 +4,9 @@ use crate::client::RococoClient;
 
 impl ClientCommon for RococoClient {
     const CHAIN: &'static str = "rococo";
+    const SPEC_NAME: &'static str = "rococo";
+    // testnet is reset from time to time
+    const GENESIS_HASH: Option<&'static str> = None;
 
     type Chain = bp_polkadot_core::PolkadotLike;
 }
@@ -1,3 +1,5 @@
 pub mod crypto;
+#[cfg(feature = "std")]
+pub mod signer;
 
 mod account;
@@ -0,0 +1,26 @@
+use sp_core::Pair as TraitPair;
+
+use crate::crypto::ethereum::{Pair, Public, Signature};
+use crate::signer::{Signer, SignerResult};
+
+/// Signer with the key in process
+#[derive(Clone)]
+pub struct LocalSigner {
+    pair: Pair,
+}
+
+impl LocalSigner {
+    pub fn new(pair: Pair) -> Self {
+        Self { pair }
+    }
+}
+
+impl Signer for LocalSigner {
+    fn public(&self) -> Public {
+        self.pair.public()
+    }
+
+    fn sign_prehashed(&self, digest: &[u8; 32]) -> SignerResult<Signature> {
+        Ok(self.pair.sign_prehashed(digest))
+    }
+}
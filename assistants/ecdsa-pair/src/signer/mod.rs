@@ -0,0 +1,91 @@
+//! Signers of relayer keys.
+//!
+//! A signer either holds the key in process, or delegates signing to a
+//! web3signer-compatible HTTP signer, so the key never has to live in bridger.
+
+use std::sync::Arc;
+
+use sp_core::hashing::keccak_256;
+use sp_core::Pair as TraitPair;
+
+use crate::crypto::ethereum::{Pair, Public, Signature};
+
+pub use self::local::*;
+pub use self::remote::*;
+
+mod local;
+mod remote;
+
+/// Key values with this prefix use a remote signer, e.g. `remote:http://127.0.0.1:9000#0x04..`
+pub const REMOTE_SIGNER_PREFIX: &str = "remote:";
+
+pub type SignerResult<T> = Result<T, SignerError>;
+
+/// Signer error
+#[derive(thiserror::Error, Debug)]
+pub enum SignerError {
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+    #[error("Remote signer error: {0}")]
+    Remote(String),
+    #[error("Invalid signature from remote signer: {0}")]
+    InvalidSignature(String),
+}
+
+/// Signer of secp256k1 keys, all signatures are 65 bytes `r, s, v` with `v` as the recovery id
+pub trait Signer: Send + Sync {
+    /// Compressed public key
+    fn public(&self) -> Public;
+
+    /// Sign a 32 bytes digest as is
+    fn sign_prehashed(&self, digest: &[u8; 32]) -> SignerResult<Signature>;
+
+    /// Ethereum address
+    fn address(&self) -> [u8; 20] {
+        let public = secp256k1::PublicKey::from_slice(self.public().as_ref())
+            .expect("Public key of signer is always valid; qed");
+        let hash = keccak_256(&public.serialize_uncompressed()[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        address
+    }
+
+    /// Sign the hash of an EVM transaction
+    fn sign_transaction(&self, tx_hash: &[u8; 32]) -> SignerResult<Signature> {
+        self.sign_prehashed(tx_hash)
+    }
+
+    /// personal_sign of a message which is already hashed, as the ECDSA authority
+    /// messages from darwinia
+    fn sign_message(&self, message: &[u8; 32]) -> SignerResult<Signature> {
+        self.sign_prehashed(message)
+    }
+
+    /// Sign substrate extrinsic payload, the same as `Pair::sign`
+    fn sign_payload(&self, payload: &[u8]) -> SignerResult<Signature> {
+        self.sign_prehashed(&keccak_256(payload))
+    }
+}
+
+/// Create signer from key value of config. `remote:<url>#<public key>` uses a remote signer,
+/// others are parsed as secret key, secret uri or mnemonic.
+pub fn signer_from_str(value: &str) -> SignerResult<Arc<dyn Signer>> {
+    if let Some(remote) = value.strip_prefix(REMOTE_SIGNER_PREFIX) {
+        let (url, identifier) = remote.rsplit_once('#').ok_or_else(|| {
+            SignerError::InvalidKey(format!(
+                "Remote signer should be {}<url>#<public key>",
+                REMOTE_SIGNER_PREFIX
+            ))
+        })?;
+        return Ok(Arc::new(RemoteSigner::new(url, identifier)?));
+    }
+    // hex secret key of EVM config usually without 0x
+    let value = if value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit()) {
+        format!("0x{}", value)
+    } else {
+        value.to_string()
+    };
+    let pair =
+        Pair::from_string(&value, None).map_err(|e| SignerError::InvalidKey(format!("{:?}", e)))?;
+    Ok(Arc::new(LocalSigner::new(pair)))
+}
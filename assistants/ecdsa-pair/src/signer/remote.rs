@@ -0,0 +1,243 @@
+use std::future::Future;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sp_core::hashing::keccak_256;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+use crate::crypto::ethereum::{Public, Signature};
+use crate::signer::{Signer, SignerError, SignerResult};
+
+const REMOTE_SIGNER_TIMEOUT: u64 = 30;
+
+#[derive(Serialize)]
+struct SignRequest {
+    data: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SignResponse {
+    Raw(String),
+    Json { signature: String },
+}
+
+/// Signer delegates to a web3signer-compatible HTTP signer.
+///
+/// The identifier of key is the public key. Extrinsic payloads are sent to
+/// `POST <url>/api/v1/eth1/sign/<identifier>` as `{"data": "0x.."}`, the signer signs the
+/// keccak256 hash of them. Transactions and authority messages are only known as 32 bytes
+/// digests, they are sent to `POST <url>/api/v1/eth1/sign-hash/<identifier>` in the same
+/// format and signed as is.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    url: String,
+    identifier: String,
+    public: Public,
+    client: reqwest::Client,
+}
+
+impl RemoteSigner {
+    pub fn new(url: impl AsRef<str>, identifier: impl AsRef<str>) -> SignerResult<Self> {
+        let identifier = identifier.as_ref().trim();
+        let full = array_bytes::hex2bytes(identifier)
+            .map_err(|e| SignerError::InvalidKey(format!("{:?}", e)))?;
+        let public = Public::from_full(&full).map_err(|_| {
+            SignerError::InvalidKey(format!("Invalid public key of remote signer: {identifier}"))
+        })?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(REMOTE_SIGNER_TIMEOUT))
+            // requests may run on different runtimes, don't keep connections across them
+            .pool_max_idle_per_host(0)
+            .build()
+            .map_err(|e| SignerError::Remote(format!("{:?}", e)))?;
+        Ok(Self {
+            url: url.as_ref().trim_end_matches('/').to_string(),
+            identifier: identifier.to_string(),
+            public,
+            client,
+        })
+    }
+
+    /// Check the remote signer is up
+    pub async fn upcheck(&self) -> SignerResult<()> {
+        let response = self
+            .client
+            .get(format!("{}/upcheck", self.url))
+            .send()
+            .await
+            .map_err(|e| SignerError::Remote(format!("{:?}", e)))?;
+        if !response.status().is_success() {
+            return Err(SignerError::Remote(format!(
+                "Upcheck failed: {}",
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Post the data to the endpoint, check the signature is signed by the key for digest
+    async fn request_signature(
+        &self,
+        endpoint: &str,
+        data: &[u8],
+        digest: &[u8; 32],
+    ) -> SignerResult<Signature> {
+        let url = format!("{}/api/v1/eth1/{}/{}", self.url, endpoint, self.identifier);
+        let request = SignRequest {
+            data: array_bytes::bytes2hex("0x", data),
+        };
+        let response = self
+            .client
+            .post(url)
+            .json(&request)
+            .send()
+            .await
+            .and_then(|v| v.error_for_status())
+            .map_err(|e| SignerError::Remote(format!("{:?}", e)))?
+            .text()
+            .await
+            .map_err(|e| SignerError::Remote(format!("{:?}", e)))?;
+        let signature = match serde_json::from_str::<SignResponse>(&response) {
+            Ok(SignResponse::Raw(v)) | Ok(SignResponse::Json { signature: v }) => v,
+            Err(_) => response.trim().to_string(),
+        };
+        let bytes = array_bytes::hex2bytes(&signature)
+            .map_err(|e| SignerError::InvalidSignature(format!("{:?}", e)))?;
+        let mut signature = Signature::from_slice(&bytes)
+            .ok_or_else(|| SignerError::InvalidSignature(format!("length {}", bytes.len())))?;
+        // web3signer returns v in electrum notation
+        if signature.0[64] >= 27 {
+            signature.0[64] -= 27;
+        }
+        match signature.recover_prehashed(digest) {
+            Some(public) if public == self.public => Ok(signature),
+            _ => Err(SignerError::InvalidSignature(
+                "Signature not signed by the expected key".to_string(),
+            )),
+        }
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn public(&self) -> Public {
+        self.public
+    }
+
+    fn sign_prehashed(&self, digest: &[u8; 32]) -> SignerResult<Signature> {
+        block_on(self.request_signature("sign-hash", digest, digest))?
+    }
+
+    fn sign_payload(&self, payload: &[u8]) -> SignerResult<Signature> {
+        block_on(self.request_signature("sign", payload, &keccak_256(payload)))?
+    }
+}
+
+/// Signing is called by sync traits of web3 and subxt. Wait for the request in place on the
+/// multi-thread runtime, or on a new runtime outside of tokio.
+fn block_on<F: Future>(future: F) -> SignerResult<F::Output> {
+    match Handle::try_current() {
+        Ok(handle) => match handle.runtime_flavor() {
+            RuntimeFlavor::MultiThread => {
+                Ok(tokio::task::block_in_place(|| handle.block_on(future)))
+            }
+            _ => Err(SignerError::Remote(
+                "Remote signer requires the multi-thread runtime".to_string(),
+            )),
+        },
+        Err(_) => Ok(tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| SignerError::Remote(format!("{:?}", e)))?
+            .block_on(future)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    use sp_core::Pair as TraitPair;
+
+    use super::*;
+    use crate::crypto::ethereum::Pair;
+
+    /// Mocked web3signer, `sign` signs keccak256 of data and `sign-hash` signs data as is.
+    /// With `double_hash` the digest is hashed again, as web3signer does to a digest.
+    fn mock_signer(pair: Pair, double_hash: bool) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                // read until the json body is complete
+                while !String::from_utf8_lossy(&request).trim_end().ends_with('}') {
+                    let n = stream.read(&mut buf).unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8(request).unwrap();
+                let (head, body) = request.split_once("\r\n\r\n").unwrap();
+                let body: serde_json::Value = serde_json::from_str(body).unwrap();
+                let data = array_bytes::hex2bytes(body["data"].as_str().unwrap()).unwrap();
+                let digest = if head.contains("/sign-hash/") && !double_hash {
+                    data.try_into().unwrap()
+                } else {
+                    keccak_256(&data)
+                };
+                let mut signature = pair.sign_prehashed(&digest).0;
+                signature[64] += 27;
+                let body = array_bytes::bytes2hex("0x", signature);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        url
+    }
+
+    fn remote_signer(pair: &Pair, double_hash: bool) -> RemoteSigner {
+        let url = mock_signer(pair.clone(), double_hash);
+        let public = secp256k1::PublicKey::from_slice(pair.public().as_ref()).unwrap();
+        let identifier = array_bytes::bytes2hex("0x", public.serialize_uncompressed());
+        RemoteSigner::new(url, identifier).unwrap()
+    }
+
+    #[test]
+    fn test_sign_by_mocked_web3signer() {
+        let pair = Pair::from_seed(&[1u8; 32]);
+        let signer = remote_signer(&pair, false);
+        let payload = b"extrinsic payload";
+        let signature = signer.sign_payload(payload).unwrap();
+        assert_eq!(signature.recover(payload), Some(pair.public()));
+
+        let digest = keccak_256(b"transaction");
+        let signature = signer.sign_prehashed(&digest).unwrap();
+        assert_eq!(signature.recover_prehashed(&digest), Some(pair.public()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_sign_in_runtime() {
+        let pair = Pair::from_seed(&[2u8; 32]);
+        let signer = remote_signer(&pair, false);
+        let digest = keccak_256(b"message root");
+        let signature = signer.sign_message(&digest).unwrap();
+        assert_eq!(signature.recover_prehashed(&digest), Some(pair.public()));
+    }
+
+    #[test]
+    fn test_reject_signature_of_other_digest() {
+        let pair = Pair::from_seed(&[3u8; 32]);
+        let signer = remote_signer(&pair, true);
+        let digest = keccak_256(b"transaction");
+        assert!(matches!(
+            signer.sign_prehashed(&digest),
+            Err(SignerError::InvalidSignature(_))
+        ));
+    }
+}
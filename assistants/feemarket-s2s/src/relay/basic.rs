@@ -5,7 +5,6 @@ use bridge_s2s_traits::strategy::{RelayReference, RelayStrategy};
 
 use feemarket_s2s_traits::api::FeemarketApiRelay;
 use feemarket_s2s_traits::types::Chain;
-use support_toolkit::logk;
 
 /// Basic relay strategy
 /// 1. if you are assigned relayer you will relay all order whether or not it times out
@@ -39,8 +38,8 @@ impl<A: FeemarketApiRelay> RelayStrategy for BasicRelayStrategy<A> {
         let nonce = reference.nonce;
         tracing::trace!(
             target: "feemarket",
-            "{} determine whether to relay for nonce: {}",
-            logk::prefix_with_relation("feemarket", "relay", A::CHAIN, "::"),
+            chain = A::CHAIN,
+            "determine whether to relay for nonce: {}",
             nonce,
         );
         let order = self
@@ -57,8 +56,8 @@ impl<A: FeemarketApiRelay> RelayStrategy for BasicRelayStrategy<A> {
         if order.is_none() {
             tracing::info!(
                 target: "feemarket",
-                "{} not found order by nonce: {}, so decide don't relay this nonce",
-                logk::prefix_with_relation("feemarket", "relay", A::CHAIN, "::"),
+                chain = A::CHAIN,
+                "not found order by nonce: {}, so decide don't relay this nonce",
                 nonce,
             );
             return Ok(false);
@@ -72,8 +71,8 @@ impl<A: FeemarketApiRelay> RelayStrategy for BasicRelayStrategy<A> {
         if relayers.is_empty() {
             tracing::info!(
                 target: "feemarket",
-                "{} not found any assigned relayers so relay this nonce({}) anyway",
-                logk::prefix_with_relation("feemarket", "relay", A::CHAIN, "::"),
+                chain = A::CHAIN,
+                "not found any assigned relayers so relay this nonce({}) anyway",
                 nonce,
             );
             return Ok(true);
@@ -104,8 +103,8 @@ impl<A: FeemarketApiRelay> RelayStrategy for BasicRelayStrategy<A> {
         if finalized_block_number > maximum_timeout {
             tracing::info!(
                 target: "feemarket",
-                "{} this nonce is timeout. so the decide is relay this nonce: {}",
-                logk::prefix_with_relation("feemarket", "relay", A::CHAIN, "::"),
+                chain = A::CHAIN,
+                "this nonce is timeout. so the decide is relay this nonce: {}",
                 nonce,
             );
             return Ok(true);
@@ -121,8 +120,8 @@ impl<A: FeemarketApiRelay> RelayStrategy for BasicRelayStrategy<A> {
             if valid_range.contains(&finalized_block_number) {
                 tracing::info!(
                     target: "feemarket",
-                    "{} you are assigned relayer and this order is in your slot, you must be relay this nonce({})",
-                    logk::prefix_with_relation("feemarket", "relay", A::CHAIN, "::"),
+                    chain = A::CHAIN,
+                    "you are assigned relayer and this order is in your slot, you must be relay this nonce({})",
                     nonce,
                 );
                 return Ok(true);
@@ -135,16 +134,16 @@ impl<A: FeemarketApiRelay> RelayStrategy for BasicRelayStrategy<A> {
             if finalized_block_number.gt(&range_max_number) {
                 tracing::info!(
                     target: "feemarket",
-                    "{} your are assigned relayer and this nonce is timeout decide to relay this nonce: {}",
-                    logk::prefix_with_relation("feemarket", "relay", A::CHAIN, "::"),
+                    chain = A::CHAIN,
+                    "your are assigned relayer and this nonce is timeout decide to relay this nonce: {}",
                     nonce,
                 );
                 return Ok(true);
             }
             tracing::info!(
                 target: "feemarket",
-                "{} you are assigned relayer but this order isn't in your slot, skip this nonce({})",
-                logk::prefix_with_relation("feemarket", "relay", A::CHAIN, "::"),
+                chain = A::CHAIN,
+                "you are assigned relayer but this order isn't in your slot, skip this nonce({})",
                 nonce,
             );
             return Ok(false);
@@ -152,8 +151,8 @@ impl<A: FeemarketApiRelay> RelayStrategy for BasicRelayStrategy<A> {
 
         tracing::info!(
             target: "feemarket",
-            "{} you aren't assigned relay. and this nonce({}) is on-time. so don't relay this",
-            logk::prefix_with_relation("feemarket", "relay", A::CHAIN, "::"),
+            chain = A::CHAIN,
+            "you aren't assigned relay. and this nonce({}) is on-time. so don't relay this",
             nonce,
         );
         Ok(false)
@@ -1,9 +1,11 @@
 use crate::error::{RelayError, RelayResult};
+use crate::span;
 use bridge_e2e_traits::client::EcdsaClient;
 use std::time::Duration;
 use subquery::types::AOperationType;
 use web3::types::H160;
 
+use super::signatures::verify_collected_signatures;
 use super::types::EcdsaSource;
 
 pub struct CollectedEnoughAuthoritiesChangeSignaturesRunner<'a, T: EcdsaClient> {
@@ -19,50 +21,43 @@ impl<'a, T: EcdsaClient> CollectedEnoughAuthoritiesChangeSignaturesRunner<'a, T>
 impl<T: EcdsaClient> CollectedEnoughAuthoritiesChangeSignaturesRunner<'_, T> {
     pub async fn start(&self) -> RelayResult<Option<u32>> {
         let client_posa = &self.source.client_posa;
-        let _client_darwinia_substrate = &self.source.client_darwinia_substrate;
-        let subquery = &self.source.subquery;
+        let events = &self.source.events;
         let from_block = self.source.block.unwrap_or_default();
         let ethereum_account = &self.source.ethereum_account;
 
-        let cacse = subquery
+        let next = events
             .next_collected_enough_authorities_change_signatures_event(from_block)
             .await?;
-        if cacse.is_none() {
-            tracing::debug!(
-                target: "relay-e2e",
-                "[Darwinia][ECDSA][collectedAuthorities] no more events after {}",
-                from_block,
-            );
-            return Ok(None);
-        }
-        let event = cacse.expect("Unreachable");
+        let event = match next.event {
+            Some(v) => v,
+            None => {
+                tracing::debug!(
+                    target: "relay-e2e",
+                    "no more events after {}",
+                    from_block,
+                );
+                return Ok(next.scanned);
+            }
+        };
 
         let latest_relayed_block_number = self.source.client_posa.block_number().await?;
         if latest_relayed_block_number.as_u32() >= event.block_number {
             tracing::info!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA][collectedAuthorities] Latest relayed block number is: {:?}",
+                "Latest relayed block number is: {:?}",
                 event.block_number
             );
             return Ok(Some(event.block_number));
         }
 
-        let mut signature_nodes = event.signatures.nodes;
-        signature_nodes.sort_by(|a, b| a.address.cmp(&b.address));
-        let signatures = signature_nodes
+        let signatures = event
+            .signatures
+            .nodes
             .iter()
-            .map(|item| {
-                let mut new = item.signature.clone();
-                let index = new.len() - 2;
-                let num: u16 = u16::from_be_bytes(
-                    new[index..]
-                        .try_into()
-                        .map_err(|e| RelayError::Custom(format!("{}", e)))?,
-                ) + 27;
-                new.splice((new.len() - 2).., num.to_be_bytes());
-                Ok(new)
-            })
-            .collect::<RelayResult<Vec<Vec<u8>>>>()?;
+            .map(|item| item.signature.clone())
+            .collect::<Vec<Vec<u8>>>();
+        let signatures =
+            verify_collected_signatures(client_posa, &event.message, &signatures).await?;
 
         let threshold = event.threshold;
 
@@ -82,7 +77,7 @@ impl<T: EcdsaClient> CollectedEnoughAuthoritiesChangeSignaturesRunner<'_, T> {
                             })?
                             .into(),
                         signatures,
-                        &ethereum_account.secret_key()?,
+                        &ethereum_account.signer()?,
                     )
                     .await?
             }
@@ -101,7 +96,7 @@ impl<T: EcdsaClient> CollectedEnoughAuthoritiesChangeSignaturesRunner<'_, T> {
                             })?
                             .into(),
                         signatures,
-                        &ethereum_account.secret_key()?,
+                        &ethereum_account.signer()?,
                     )
                     .await?
             }
@@ -118,15 +113,16 @@ impl<T: EcdsaClient> CollectedEnoughAuthoritiesChangeSignaturesRunner<'_, T> {
                             RelayError::Custom("not found new authority account".to_string())
                         })?,
                         signatures,
-                        &ethereum_account.secret_key()?,
+                        &ethereum_account.signer()?,
                     )
                     .await?
             }
         };
 
+        span::record_tx(hash);
         tracing::info!(
             target: "relay-e2e",
-            "[Darwinia][ECDSA][collectedAuthorities] authorities change submitted: {}",
+            "authorities change submitted: {}",
             array_bytes::bytes2hex("0x", hash.0),
         );
         support_etherscan::wait_for_transaction_confirmation_with_timeout(
@@ -4,7 +4,9 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use web3::types::H256;
 
 use crate::error::{RelayError, RelayResult};
+use crate::span;
 
+use super::signatures::verify_collected_signatures;
 use super::types::EcdsaSource;
 
 pub struct CollectedEnoughNewMessageRootSignaturesRunner<'a, T: EcdsaClient> {
@@ -32,7 +34,7 @@ impl<T: EcdsaClient> CollectedEnoughNewMessageRootSignaturesRunner<'_, T> {
         if now - self.last_relay_time <= self.interval {
             tracing::info!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA][collectedMessages] Last relaying time is {:?}, wait for {} seconds to scan new message root",
+                "Last relaying time is {:?}, wait for {} seconds to scan new message root",
                 self.last_relay_time,
                 self.interval - (now - self.last_relay_time)
             );
@@ -40,50 +42,42 @@ impl<T: EcdsaClient> CollectedEnoughNewMessageRootSignaturesRunner<'_, T> {
         }
 
         let client_posa = &self.source.client_posa;
-        let subquery = &self.source.subquery;
+        let events = &self.source.events;
         let from_block = self.source.block.unwrap_or_default();
         let ethereum_account = &self.source.ethereum_account;
 
-        let cacse = subquery
+        let next = events
             .next_collected_enough_new_message_root_signatures_event(from_block)
             .await?;
-
-        if cacse.is_none() {
-            tracing::debug!(
-                target: "relay-e2e",
-                "[Darwinia][ECDSA][collectedMessages] no more events after {}",
-                from_block,
-            );
-            return Ok(None);
-        }
-        let event = cacse.expect("Unreachable");
+        let event = match next.event {
+            Some(v) => v,
+            None => {
+                tracing::debug!(
+                    target: "relay-e2e",
+                    "no more events after {}",
+                    from_block,
+                );
+                return Ok(next.scanned);
+            }
+        };
         let latest_relayed_block_number = self.source.client_posa.block_number().await?;
         if latest_relayed_block_number.as_u32() >= event.commitment_block_number {
             tracing::info!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA][collectedMessages] Latest relayed block number is: {:?}",
+                "Latest relayed block number is: {:?}",
                 event.block_number
             );
             return Ok(Some(event.block_number));
         }
 
-        let mut signature_nodes = event.signatures.nodes;
-        signature_nodes.sort_by(|a, b| a.address.cmp(&b.address));
-        let signatures = signature_nodes
+        let signatures = event
+            .signatures
+            .nodes
             .iter()
-            .map(|item| {
-                let mut new = item.signature.clone();
-                let index = new.len() - 2;
-                let num: u16 = u16::from_be_bytes(
-                    new[index..]
-                        .try_into()
-                        .map_err(|e| RelayError::Custom(format!("{}", e)))?,
-                ) + 27;
-
-                new.splice((new.len() - 2).., num.to_be_bytes());
-                Ok(new)
-            })
-            .collect::<RelayResult<Vec<Vec<u8>>>>()?;
+            .map(|item| item.signature.clone())
+            .collect::<Vec<Vec<u8>>>();
+        let signatures =
+            verify_collected_signatures(client_posa, &event.message, &signatures).await?;
 
         let mr_slice: [u8; 32] = event
             .commitment_message_root
@@ -95,11 +89,12 @@ impl<T: EcdsaClient> CollectedEnoughNewMessageRootSignaturesRunner<'_, T> {
             nonce: event.commitment_nonce.into(),
         };
         let hash = client_posa
-            .import_message_commitment(commitment, signatures, &ethereum_account.secret_key()?)
+            .import_message_commitment(commitment, signatures, &ethereum_account.signer()?)
             .await?;
+        span::record_tx(hash);
         tracing::info!(
             target: "relay-e2e",
-            "[Darwinia][ECDSA][collectedMessages] submitted collected enouth new message root signature: {}",
+            "submitted collected enouth new message root signature: {}",
             array_bytes::bytes2hex("0x", hash.0),
         );
         support_etherscan::wait_for_transaction_confirmation_with_timeout(
@@ -2,6 +2,7 @@ use bridge_e2e_traits::client::EcdsaClient;
 
 use crate::error::RelayResult;
 
+use super::journal::SignatureRound;
 use super::types::EcdsaSource;
 
 pub struct CollectingAuthoritiesChangeSignaturesRunner<'a, T: EcdsaClient> {
@@ -16,30 +17,42 @@ impl<'a, T: EcdsaClient> CollectingAuthoritiesChangeSignaturesRunner<'a, T> {
 
 impl<T: EcdsaClient> CollectingAuthoritiesChangeSignaturesRunner<'_, T> {
     pub async fn start(&self) -> RelayResult<Option<u32>> {
-        let client_darwinia_substrate = &self.source.client_darwinia_substrate;
-        let subquery = &self.source.subquery;
+        let authority = match &self.source.authority {
+            Some(v) => v,
+            None => {
+                tracing::debug!(
+                    target: "relay-e2e",
+                    "no authority key configured, nothing to do.",
+                );
+                return Ok(None);
+            }
+        };
+        let client_darwinia_substrate = &authority.client;
+        let events = &self.source.events;
         let from_block = self.source.block.unwrap_or_default();
-        let darwinia_evm_account = &self.source.darwinia_evm_account;
+        let darwinia_evm_account = &authority.account;
 
-        let cacse = subquery
+        let next = events
             .next_collecting_authorities_change_signatures_event(from_block)
             .await?;
-        if cacse.is_none() {
-            tracing::debug!(
-                target: "relay-e2e",
-                "[Darwinia][ECDSA][collectingAuthorities] no more events after {}",
-                from_block,
-            );
-            return Ok(None);
-        }
-        let event = cacse.expect("Unreachable");
-        let collected = subquery
+        let event = match next.event {
+            Some(v) => v,
+            None => {
+                tracing::debug!(
+                    target: "relay-e2e",
+                    "no more events after {}",
+                    from_block,
+                );
+                return Ok(next.scanned);
+            }
+        };
+        let collected = events
             .next_collected_enough_authorities_change_signatures_event(event.block_number)
             .await?;
-        if collected.is_some() {
+        if collected.event.is_some() {
             tracing::debug!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA][collectingAuthorities] Collected. Skip this event at {}",
+                "Collected. Skip this event at {}",
                 event.block_number,
             );
             return Ok(Some(event.block_number));
@@ -50,20 +63,18 @@ impl<T: EcdsaClient> CollectingAuthoritiesChangeSignaturesRunner<'_, T> {
         {
             tracing::warn!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA][collectingAuthorities] you are not authority account. nothing to do."
+                "you are not authority account. nothing to do."
             );
             return Ok(Some(event.block_number));
         }
 
-        let signature = darwinia_evm_account.sign(event.message.as_slice())?;
-        let hash = client_darwinia_substrate
-            .submit_authorities_change_signature(signature)
+        authority
+            .sign_and_submit(
+                SignatureRound::AuthoritiesChange,
+                event.block_number,
+                event.message.as_slice(),
+            )
             .await?;
-        tracing::info!(
-            target: "relay-e2e",
-            "[Darwinia][ECDSA][collectingAuthorities] submitted signature: {}",
-            array_bytes::bytes2hex("0x", hash.as_ref()),
-        );
         Ok(Some(event.block_number))
     }
 }
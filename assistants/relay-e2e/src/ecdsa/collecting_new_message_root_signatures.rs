@@ -2,6 +2,7 @@ use bridge_e2e_traits::client::EcdsaClient;
 
 use crate::error::RelayResult;
 
+use super::journal::SignatureRound;
 use super::types::EcdsaSource;
 
 pub struct CollectingNewMessageRootSignaturesRunner<'a, T: EcdsaClient> {
@@ -16,28 +17,40 @@ impl<'a, T: EcdsaClient> CollectingNewMessageRootSignaturesRunner<'a, T> {
 
 impl<T: EcdsaClient> CollectingNewMessageRootSignaturesRunner<'_, T> {
     pub async fn start(&self) -> RelayResult<Option<u32>> {
-        let client_darwinia_substrate = &self.source.client_darwinia_substrate;
-        let subquery = &self.source.subquery;
+        let authority = match &self.source.authority {
+            Some(v) => v,
+            None => {
+                tracing::debug!(
+                    target: "relay-e2e",
+                    "no authority key configured, nothing to do.",
+                );
+                return Ok(None);
+            }
+        };
+        let client_darwinia_substrate = &authority.client;
+        let events = &self.source.events;
         let from_block = self.source.block.unwrap_or_default();
-        let darwinia_evm_account = &self.source.darwinia_evm_account;
+        let darwinia_evm_account = &authority.account;
 
-        let cacse = subquery
+        let next = events
             .next_collecting_new_message_root_signatures_event(from_block)
             .await?;
-        if cacse.is_none() {
-            tracing::debug!(
-                target: "relay-e2e",
-                "[Darwinia][ECDSA][collectingMessages] no more events after {}",
-                from_block,
-            );
-            return Ok(None);
-        }
-        let event = cacse.expect("Unreachable");
+        let event = match next.event {
+            Some(v) => v,
+            None => {
+                tracing::debug!(
+                    target: "relay-e2e",
+                    "no more events after {}",
+                    from_block,
+                );
+                return Ok(next.scanned);
+            }
+        };
         let latest_relayed_block_number = self.source.client_posa.block_number().await?;
         if latest_relayed_block_number.as_u32() >= event.block_number {
             tracing::info!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA][collectingMessages] Latest relayed block number is: {:?}",
+                "Latest relayed block number is: {:?}",
                 event.block_number
             );
             return Ok(Some(event.block_number));
@@ -45,7 +58,7 @@ impl<T: EcdsaClient> CollectingNewMessageRootSignaturesRunner<'_, T> {
 
         tracing::info!(
             target: "relay-e2e",
-            "[Darwinia][ECDSA][collectingMessages] found new event from block {}",
+            "found new event from block {}",
             event.block_number,
         );
         if !client_darwinia_substrate
@@ -54,25 +67,18 @@ impl<T: EcdsaClient> CollectingNewMessageRootSignaturesRunner<'_, T> {
         {
             tracing::warn!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA][collectingMessages] you are not authority account. nothing to do.",
+                "you are not authority account. nothing to do.",
             );
             return Ok(Some(event.block_number));
         }
 
-        let signature = darwinia_evm_account.sign(event.message.as_slice())?;
-        tracing::trace!(
-            target: "relay-e2e",
-            "[Darwinia][ECDSA][collectingMessages] Signature signed"
-        );
-        let hash = client_darwinia_substrate
-            .submit_new_message_root_signature(signature)
+        authority
+            .sign_and_submit(
+                SignatureRound::NewMessageRoot,
+                event.block_number,
+                event.message.as_slice(),
+            )
             .await?;
-
-        tracing::info!(
-            target: "relay-e2e",
-            "[Darwinia][ECDSA][collectingMessages] submitted new message root signature: {}",
-            array_bytes::bytes2hex("0x", hash.as_ref()),
-        );
         Ok(Some(event.block_number))
     }
 }
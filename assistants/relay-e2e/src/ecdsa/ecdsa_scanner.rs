@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use bridge_e2e_traits::client::EcdsaClient;
 use support_tracker::Tracker;
+use tracing::Instrument;
 
 use crate::{
     ecdsa::{
@@ -11,8 +12,10 @@ use crate::{
         collecting_new_message_root_signatures::CollectingNewMessageRootSignaturesRunner,
     },
     error::{RelayError, RelayResult},
+    span,
 };
 
+use super::journal::SignatureRound;
 use super::types::EcdsaSource;
 
 #[derive(Clone, Copy, Debug)]
@@ -40,23 +43,27 @@ pub trait EcdsaScanner<T: EcdsaClient> {
     fn get_ecdsa_source(&mut self) -> &mut EcdsaSource<T>;
 
     async fn start(&mut self, tracker: Tracker, scan_type: EcdsaScanType) {
+        let span = span::module_span(&format!("ecdsa-{scan_type}"));
+        self.scan(tracker, scan_type).instrument(span).await
+    }
+
+    async fn scan(&mut self, tracker: Tracker, scan_type: EcdsaScanType) {
         while let Err(err) = self.run(tracker.clone(), scan_type).await {
             tracing::error!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA] An error occurred while processing the extrinsics: {:?}",
+                "An error occurred while processing the extrinsics: {:?}",
                 err
             );
             // Prevent too fast refresh errors
             tokio::time::sleep(std::time::Duration::from_secs(30)).await;
-            while let Err(why) = self
-                .get_ecdsa_source()
-                .client_darwinia_substrate
-                .reconnect()
-                .await
-            {
+            let authority = match &mut self.get_ecdsa_source().authority {
+                Some(v) => v,
+                None => continue,
+            };
+            while let Err(why) = authority.client.reconnect().await {
                 tracing::error!(
                     target: "relay-e2e",
-                    "[Darwinia][ECDSA] An error occurred while reconnecting {:?}, try later...",
+                    "An error occurred while reconnecting {:?}, try later...",
                     why
                 );
 
@@ -75,6 +82,15 @@ pub trait EcdsaScanner<T: EcdsaClient> {
         }
 
         let source = self.get_ecdsa_source();
+        // check the signature submitted before stopping, then it's not signed again
+        let round = match scan_type {
+            EcdsaScanType::CollectingMessage => Some(SignatureRound::NewMessageRoot),
+            EcdsaScanType::CollectingAuthority => Some(SignatureRound::AuthoritiesChange),
+            _ => None,
+        };
+        if let (Some(round), Some(authority)) = (round, &source.authority) {
+            authority.reconcile(round).await?;
+        }
         loop {
             let from = tracker
                 .current()
@@ -82,8 +98,7 @@ pub trait EcdsaScanner<T: EcdsaClient> {
                 .map_err(|e| RelayError::Custom(format!("{}", e)))?;
             tracing::info!(
                 target: "relay-e2e",
-                "[Darwinia][ECDSA][{}] Track scan block: {} ",
-                scan_type,
+                "Track scan block: {} ",
                 from,
             );
             source.block = Some(from as u32);
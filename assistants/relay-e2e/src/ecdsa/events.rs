@@ -0,0 +1,476 @@
+use std::ops::RangeInclusive;
+
+use bridge_e2e_traits::client::EcdsaClient;
+use bridge_e2e_traits::types::{EcdsaBlockEvents, EcdsaEvent, EcdsaOperation};
+use serde::{Deserialize, Serialize};
+use subquery::types::{
+    AOperationType, CollectedEnoughAuthoritiesChangeSignaturesEvent,
+    CollectedEnoughNewMessageRootSignaturesEvent, CollectingAuthoritiesChangeSignaturesEvent,
+    CollectingNewMessageRootSignaturesEvent, DataWrapper, EcdsaSignature,
+};
+use subquery::Subquery;
+
+use crate::error::RelayResult;
+
+/// Number of blocks fetched concurrently when scanning events from chain
+const SCAN_BATCH_SIZE: u32 = 32;
+
+/// Source of ECDSA authority events
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EcdsaEventsSourceType {
+    /// Query events indexed by subquery
+    #[default]
+    Subquery,
+    /// Scan events from darwinia blocks
+    Chain,
+}
+
+/// Next event after a block
+pub struct NextEvent<E> {
+    pub event: Option<E>,
+    /// Last block scanned when there isn't any event, the tracker can move to it.
+    /// Subquery doesn't tell it.
+    pub scanned: Option<u32>,
+}
+
+impl<E> NextEvent<E> {
+    fn indexed(event: Option<E>) -> Self {
+        Self {
+            event,
+            scanned: None,
+        }
+    }
+}
+
+/// Lookup of ECDSA authority events
+#[derive(Clone, Debug)]
+pub enum EcdsaEvents<T: EcdsaClient> {
+    Subquery(Subquery),
+    Chain(ChainEcdsaEvents<T>),
+}
+
+/// Scan ECDSA authority events from darwinia blocks, it doesn't lag behind like an index
+#[derive(Clone, Debug)]
+pub struct ChainEcdsaEvents<T: EcdsaClient> {
+    client: T,
+    /// Max number of blocks scanned by one lookup
+    scan_range: u32,
+}
+
+impl<T: EcdsaClient> ChainEcdsaEvents<T> {
+    pub fn new(client: T, scan_range: u32) -> Self {
+        Self {
+            client,
+            scan_range: scan_range.max(1),
+        }
+    }
+
+    /// Find the earliest matched event after the block
+    async fn next_event<E>(
+        &self,
+        from_block: u32,
+        matcher: impl Fn(&EcdsaBlockEvents, u32, &EcdsaEvent) -> Option<E> + Send + Sync,
+    ) -> RelayResult<NextEvent<E>> {
+        let finalized = self.client.finalized_block_number().await?;
+        let to_block = finalized.min(from_block.saturating_add(self.scan_range));
+        for batch in scan_batches(from_block + 1, to_block, SCAN_BATCH_SIZE) {
+            let blocks = self.fetch_batch(batch).await?;
+            if let Some(event) = blocks.iter().find_map(|block| find_event(block, &matcher)) {
+                return Ok(NextEvent {
+                    event: Some(event),
+                    scanned: None,
+                });
+            }
+        }
+        Ok(NextEvent {
+            event: None,
+            scanned: Some(to_block.max(from_block)),
+        })
+    }
+
+    /// Find the latest matched event after the block
+    async fn latest_event<E>(
+        &self,
+        from_block: u32,
+        matcher: impl Fn(&EcdsaBlockEvents, u32, &EcdsaEvent) -> Option<E> + Send + Sync,
+    ) -> RelayResult<NextEvent<E>> {
+        let finalized = self.client.finalized_block_number().await?;
+        let lowest_block = from_block.max(finalized.saturating_sub(self.scan_range));
+        for batch in scan_batches(lowest_block + 1, finalized, SCAN_BATCH_SIZE)
+            .into_iter()
+            .rev()
+        {
+            let blocks = self.fetch_batch(batch).await?;
+            if let Some(event) = blocks
+                .iter()
+                .rev()
+                .find_map(|block| find_event(block, &matcher))
+            {
+                return Ok(NextEvent {
+                    event: Some(event),
+                    scanned: None,
+                });
+            }
+        }
+        Ok(NextEvent {
+            event: None,
+            scanned: Some(finalized.max(from_block)),
+        })
+    }
+
+    /// Fetch events of the blocks concurrently, in the order of blocks
+    async fn fetch_batch(&self, blocks: RangeInclusive<u32>) -> RelayResult<Vec<EcdsaBlockEvents>> {
+        let fetches = blocks.map(|block_number| self.client.ecdsa_events(block_number));
+        let events = futures::future::try_join_all(fetches).await?;
+        Ok(events.into_iter().flatten().collect())
+    }
+}
+
+/// Split the blocks from `lowest` to `highest` into ascending batches of `size` blocks
+fn scan_batches(lowest: u32, highest: u32, size: u32) -> Vec<RangeInclusive<u32>> {
+    let mut batches = vec![];
+    let mut begin = lowest;
+    while begin <= highest {
+        let end = highest.min(begin.saturating_add(size - 1));
+        batches.push(begin..=end);
+        if end == u32::MAX {
+            break;
+        }
+        begin = end + 1;
+    }
+    batches
+}
+
+fn find_event<E>(
+    block_events: &EcdsaBlockEvents,
+    matcher: &(impl Fn(&EcdsaBlockEvents, u32, &EcdsaEvent) -> Option<E> + Send + Sync),
+) -> Option<E> {
+    block_events
+        .events
+        .iter()
+        .find_map(|(index, event)| matcher(block_events, *index, event))
+}
+
+impl<T: EcdsaClient> EcdsaEvents<T> {
+    pub async fn next_collecting_new_message_root_signatures_event(
+        &self,
+        from_block: u32,
+    ) -> RelayResult<NextEvent<CollectingNewMessageRootSignaturesEvent>> {
+        match self {
+            EcdsaEvents::Subquery(subquery) => Ok(NextEvent::indexed(
+                subquery
+                    .next_collecting_new_message_root_signatures_event(from_block)
+                    .await?,
+            )),
+            EcdsaEvents::Chain(chain) => {
+                chain
+                    .latest_event(from_block, collecting_new_message_root_signatures)
+                    .await
+            }
+        }
+    }
+
+    pub async fn next_collected_enough_new_message_root_signatures_event(
+        &self,
+        from_block: u32,
+    ) -> RelayResult<NextEvent<CollectedEnoughNewMessageRootSignaturesEvent>> {
+        match self {
+            EcdsaEvents::Subquery(subquery) => Ok(NextEvent::indexed(
+                subquery
+                    .next_collected_enough_new_message_root_signatures_event(from_block)
+                    .await?,
+            )),
+            EcdsaEvents::Chain(chain) => {
+                chain
+                    .latest_event(from_block, collected_enough_new_message_root_signatures)
+                    .await
+            }
+        }
+    }
+
+    pub async fn next_collecting_authorities_change_signatures_event(
+        &self,
+        from_block: u32,
+    ) -> RelayResult<NextEvent<CollectingAuthoritiesChangeSignaturesEvent>> {
+        match self {
+            EcdsaEvents::Subquery(subquery) => Ok(NextEvent::indexed(
+                subquery
+                    .next_collecting_authorities_change_signatures_event(from_block)
+                    .await?,
+            )),
+            EcdsaEvents::Chain(chain) => {
+                chain
+                    .next_event(from_block, collecting_authorities_change_signatures)
+                    .await
+            }
+        }
+    }
+
+    pub async fn next_collected_enough_authorities_change_signatures_event(
+        &self,
+        from_block: u32,
+    ) -> RelayResult<NextEvent<CollectedEnoughAuthoritiesChangeSignaturesEvent>> {
+        match self {
+            EcdsaEvents::Subquery(subquery) => Ok(NextEvent::indexed(
+                subquery
+                    .next_collected_enough_authorities_change_signatures_event(from_block)
+                    .await?,
+            )),
+            EcdsaEvents::Chain(chain) => {
+                chain
+                    .next_event(from_block, collected_enough_authorities_change_signatures)
+                    .await
+            }
+        }
+    }
+}
+
+fn collecting_new_message_root_signatures(
+    block: &EcdsaBlockEvents,
+    index: u32,
+    event: &EcdsaEvent,
+) -> Option<CollectingNewMessageRootSignaturesEvent> {
+    match event {
+        EcdsaEvent::CollectingNewMessageRootSignatures { message } => {
+            Some(CollectingNewMessageRootSignaturesEvent {
+                id: event_id(block, index),
+                block_number: block.block_number,
+                block_hash: block.block_hash.clone(),
+                message: message.to_vec(),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn collected_enough_new_message_root_signatures(
+    block: &EcdsaBlockEvents,
+    index: u32,
+    event: &EcdsaEvent,
+) -> Option<CollectedEnoughNewMessageRootSignaturesEvent> {
+    match event {
+        EcdsaEvent::CollectedEnoughNewMessageRootSignatures {
+            commitment,
+            message,
+            signatures,
+        } => Some(CollectedEnoughNewMessageRootSignaturesEvent {
+            id: event_id(block, index),
+            block_number: block.block_number,
+            block_hash: block.block_hash.clone(),
+            message: message.to_vec(),
+            signatures: to_signatures(block, index, signatures),
+            commitment_block_number: commitment.block_number,
+            commitment_message_root: commitment.message_root.to_vec(),
+            commitment_nonce: commitment.nonce,
+        }),
+        _ => None,
+    }
+}
+
+fn collecting_authorities_change_signatures(
+    block: &EcdsaBlockEvents,
+    index: u32,
+    event: &EcdsaEvent,
+) -> Option<CollectingAuthoritiesChangeSignaturesEvent> {
+    match event {
+        EcdsaEvent::CollectingAuthoritiesChangeSignatures { message } => {
+            Some(CollectingAuthoritiesChangeSignaturesEvent {
+                id: event_id(block, index),
+                block_number: block.block_number,
+                block_hash: block.block_hash.clone(),
+                message: message.to_vec(),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn collected_enough_authorities_change_signatures(
+    block: &EcdsaBlockEvents,
+    index: u32,
+    event: &EcdsaEvent,
+) -> Option<CollectedEnoughAuthoritiesChangeSignaturesEvent> {
+    match event {
+        EcdsaEvent::CollectedEnoughAuthoritiesChangeSignatures {
+            operation,
+            threshold,
+            message,
+            signatures,
+        } => {
+            let (operation_type, operation_pre, operation_old, operation_new) = match operation {
+                EcdsaOperation::AddMember { new } => (AOperationType::Add, None, None, Some(*new)),
+                EcdsaOperation::RemoveMember { pre, old } => {
+                    (AOperationType::Remove, Some(*pre), Some(*old), None)
+                }
+                EcdsaOperation::SwapMembers { pre, old, new } => {
+                    (AOperationType::Swap, Some(*pre), Some(*old), Some(*new))
+                }
+            };
+            Some(CollectedEnoughAuthoritiesChangeSignaturesEvent {
+                id: event_id(block, index),
+                block_number: block.block_number,
+                block_hash: block.block_hash.clone(),
+                message: message.to_vec(),
+                signatures: to_signatures(block, index, signatures),
+                operation_type,
+                operation_new,
+                operation_old,
+                operation_pre,
+                threshold: *threshold,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn event_id(block: &EcdsaBlockEvents, index: u32) -> String {
+    format!("{}-{}", block.block_number, index)
+}
+
+fn to_signatures(
+    block: &EcdsaBlockEvents,
+    index: u32,
+    signatures: &[([u8; 20], [u8; 65])],
+) -> DataWrapper<EcdsaSignature> {
+    let nodes = signatures
+        .iter()
+        .enumerate()
+        .map(|(i, (address, signature))| EcdsaSignature {
+            id: format!("{}-{}", event_id(block, index), i),
+            address: address.to_vec(),
+            signature: signature.to_vec(),
+        })
+        .collect();
+    DataWrapper { nodes }
+}
+
+#[cfg(test)]
+mod tests {
+    use bridge_e2e_traits::types::EcdsaCommitment;
+
+    use super::*;
+
+    fn signatures() -> Vec<([u8; 20], [u8; 65])> {
+        vec![([1u8; 20], [11u8; 65]), ([2u8; 20], [12u8; 65])]
+    }
+
+    fn block() -> EcdsaBlockEvents {
+        EcdsaBlockEvents {
+            block_number: 100,
+            block_hash: vec![9u8; 32],
+            events: vec![
+                (
+                    3,
+                    EcdsaEvent::CollectingNewMessageRootSignatures { message: [3u8; 32] },
+                ),
+                (
+                    5,
+                    EcdsaEvent::CollectedEnoughNewMessageRootSignatures {
+                        commitment: EcdsaCommitment {
+                            block_number: 90,
+                            message_root: [4u8; 32],
+                            nonce: 7,
+                        },
+                        message: [5u8; 32],
+                        signatures: signatures(),
+                    },
+                ),
+                (
+                    6,
+                    EcdsaEvent::CollectingAuthoritiesChangeSignatures { message: [6u8; 32] },
+                ),
+                (
+                    8,
+                    EcdsaEvent::CollectedEnoughAuthoritiesChangeSignatures {
+                        operation: EcdsaOperation::SwapMembers {
+                            pre: [1u8; 20],
+                            old: [2u8; 20],
+                            new: [3u8; 20],
+                        },
+                        threshold: Some(2),
+                        message: [8u8; 32],
+                        signatures: signatures(),
+                    },
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_event_id() {
+        assert_eq!(event_id(&block(), 5), "100-5");
+    }
+
+    #[test]
+    fn test_to_signatures() {
+        let signatures = to_signatures(&block(), 5, &signatures());
+        assert_eq!(signatures.nodes.len(), 2);
+        assert_eq!(signatures.nodes[0].id, "100-5-0");
+        assert_eq!(signatures.nodes[1].id, "100-5-1");
+        assert_eq!(signatures.nodes[1].address, vec![2u8; 20]);
+        assert_eq!(signatures.nodes[1].signature, vec![12u8; 65]);
+    }
+
+    #[test]
+    fn test_match_new_message_root_events() {
+        let block = block();
+        let collecting = find_event(&block, &collecting_new_message_root_signatures).unwrap();
+        assert_eq!(collecting.id, "100-3");
+        assert_eq!(collecting.block_number, 100);
+        assert_eq!(collecting.block_hash, vec![9u8; 32]);
+        assert_eq!(collecting.message, vec![3u8; 32]);
+
+        let collected = find_event(&block, &collected_enough_new_message_root_signatures).unwrap();
+        assert_eq!(collected.id, "100-5");
+        assert_eq!(collected.message, vec![5u8; 32]);
+        assert_eq!(collected.commitment_block_number, 90);
+        assert_eq!(collected.commitment_message_root, vec![4u8; 32]);
+        assert_eq!(collected.commitment_nonce, 7);
+        assert_eq!(collected.signatures.nodes.len(), 2);
+    }
+
+    #[test]
+    fn test_match_authorities_change_events() {
+        let block = block();
+        let collecting = find_event(&block, &collecting_authorities_change_signatures).unwrap();
+        assert_eq!(collecting.id, "100-6");
+        assert_eq!(collecting.message, vec![6u8; 32]);
+
+        let collected =
+            find_event(&block, &collected_enough_authorities_change_signatures).unwrap();
+        assert_eq!(collected.id, "100-8");
+        assert!(matches!(collected.operation_type, AOperationType::Swap));
+        assert_eq!(collected.operation_pre, Some([1u8; 20]));
+        assert_eq!(collected.operation_old, Some([2u8; 20]));
+        assert_eq!(collected.operation_new, Some([3u8; 20]));
+        assert_eq!(collected.threshold, Some(2));
+        assert_eq!(collected.signatures.nodes[0].id, "100-8-0");
+    }
+
+    #[test]
+    fn test_match_nothing() {
+        let block = EcdsaBlockEvents {
+            block_number: 1,
+            block_hash: vec![],
+            events: vec![(
+                0,
+                EcdsaEvent::CollectingNewMessageRootSignatures { message: [0u8; 32] },
+            )],
+        };
+        assert!(find_event(&block, &collecting_authorities_change_signatures).is_none());
+        assert!(find_event(&block, &collected_enough_new_message_root_signatures).is_none());
+        assert!(find_event(&block, &collected_enough_authorities_change_signatures).is_none());
+    }
+
+    #[test]
+    fn test_scan_batches() {
+        assert_eq!(scan_batches(1, 10, 4), vec![1..=4, 5..=8, 9..=10]);
+        assert_eq!(scan_batches(5, 5, 4), vec![5..=5]);
+        assert!(scan_batches(6, 5, 4).is_empty());
+        assert_eq!(
+            scan_batches(u32::MAX - 1, u32::MAX, 4),
+            vec![u32::MAX - 1..=u32::MAX]
+        );
+    }
+}
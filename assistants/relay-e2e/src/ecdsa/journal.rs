@@ -0,0 +1,251 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use bridge_e2e_traits::client::CollectingSignatureState;
+use component_state::state::NamespaceState;
+
+use crate::error::{RelayError, RelayResult};
+
+/// Collecting round signed by ECDSA authority
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SignatureRound {
+    NewMessageRoot,
+    AuthoritiesChange,
+}
+
+impl SignatureRound {
+    fn key(&self) -> &'static str {
+        match self {
+            SignatureRound::NewMessageRoot => "journal.darwinia.collecting-message",
+            SignatureRound::AuthoritiesChange => "journal.darwinia.collecting-authorities",
+        }
+    }
+}
+
+impl Display for SignatureRound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SignatureRound::NewMessageRoot => "collectingMessages",
+            SignatureRound::AuthoritiesChange => "collectingAuthorities",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SignatureStatus {
+    /// Signed, the submission is in progress
+    Signed,
+    /// The signature is accepted on chain
+    Finalized,
+    /// Submission failed, the round will be signed again
+    Failed,
+    /// The round collected enough signatures without this one
+    Expired,
+}
+
+impl SignatureStatus {
+    /// The process stopped or failed before the signature got accepted
+    pub fn is_pending(&self) -> bool {
+        matches!(self, SignatureStatus::Signed | SignatureStatus::Failed)
+    }
+}
+
+/// Signature submission of a collecting round
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SignatureRecord {
+    /// Block number of the collecting event
+    pub block_number: u32,
+    /// Signed message of the round, message root or authorities change, in hex
+    pub message: String,
+    /// Signature in hex
+    pub signature: String,
+    /// Hash of the extrinsic which submitted the signature
+    pub extrinsic_hash: Option<String>,
+    pub status: SignatureStatus,
+}
+
+/// Journal of signature submissions, keeps the last record of each round in state, so that
+/// a restarted relayer can tell whether the last signature was submitted.
+#[derive(Clone)]
+pub struct SignatureJournal {
+    state: NamespaceState,
+}
+
+impl SignatureJournal {
+    pub fn new(state: NamespaceState) -> Self {
+        Self { state }
+    }
+
+    /// Read the last record of the round
+    pub fn last(&self, round: SignatureRound) -> RelayResult<Option<SignatureRecord>> {
+        let value = self
+            .state
+            .get(round.key())
+            .map_err(|e| RelayError::Custom(format!("{e:?}")))?;
+        match value {
+            Some(v) => serde_json::from_value(v)
+                .map(Some)
+                .map_err(|e| RelayError::Custom(format!("Wrong journal record: {e:?}"))),
+            None => Ok(None),
+        }
+    }
+
+    /// The last record of the round if the relayer stopped or failed before it got accepted
+    pub fn pending(&self, round: SignatureRound) -> RelayResult<Option<SignatureRecord>> {
+        Ok(self
+            .last(round)?
+            .filter(|record| record.status.is_pending()))
+    }
+
+    /// Reconcile the pending record with the state of its signature on chain. Returns the
+    /// record if the signature was lost, the round needs to be signed again.
+    pub fn reconcile(
+        &self,
+        round: SignatureRound,
+        record: &SignatureRecord,
+        state: CollectingSignatureState,
+    ) -> RelayResult<Option<SignatureRecord>> {
+        let status = match state {
+            CollectingSignatureState::Signed => SignatureStatus::Finalized,
+            CollectingSignatureState::Unsigned => SignatureStatus::Failed,
+            CollectingSignatureState::NotCollecting => SignatureStatus::Expired,
+        };
+        self.update(round, &record.message, status, None)?;
+        if status == SignatureStatus::Failed {
+            return Ok(Some(record.clone()));
+        }
+        Ok(None)
+    }
+
+    /// Record a signature submission of the round, replaces the last one
+    pub fn record(&self, round: SignatureRound, record: &SignatureRecord) -> RelayResult<()> {
+        self.state
+            .put(round.key(), record)
+            .map_err(|e| RelayError::Custom(format!("{e:?}")))?;
+        Ok(())
+    }
+
+    /// Update status of the last record of the round if it signed the message
+    pub fn update(
+        &self,
+        round: SignatureRound,
+        message: &str,
+        status: SignatureStatus,
+        extrinsic_hash: Option<String>,
+    ) -> RelayResult<()> {
+        if let Some(mut record) = self.last(round)? {
+            if record.message != message {
+                return Ok(());
+            }
+            record.status = status;
+            if extrinsic_hash.is_some() {
+                record.extrinsic_hash = extrinsic_hash;
+            }
+            self.record(round, &record)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use component_state::backend::{MemoryBackend, StateBackendKind};
+    use component_state::state::BridgeState;
+
+    use super::*;
+
+    const ROUND: SignatureRound = SignatureRound::NewMessageRoot;
+
+    fn journal() -> SignatureJournal {
+        let state = BridgeState::with_backend(StateBackendKind::Memory, MemoryBackend::default());
+        SignatureJournal::new(state.namespace("darwinia-ethereum"))
+    }
+
+    fn record(status: SignatureStatus) -> SignatureRecord {
+        SignatureRecord {
+            block_number: 100,
+            message: "0x0101".to_string(),
+            signature: "0x0202".to_string(),
+            extrinsic_hash: None,
+            status,
+        }
+    }
+
+    #[test]
+    fn test_reconcile_submitted() {
+        let journal = journal();
+        let mut submitted = record(SignatureStatus::Finalized);
+        submitted.extrinsic_hash = Some("0x0303".to_string());
+        journal.record(ROUND, &submitted).unwrap();
+        assert!(journal.pending(ROUND).unwrap().is_none());
+        assert!(journal
+            .pending(SignatureRound::AuthoritiesChange)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_reconcile_already_on_chain() {
+        let journal = journal();
+        journal
+            .record(ROUND, &record(SignatureStatus::Signed))
+            .unwrap();
+        let pending = journal.pending(ROUND).unwrap().unwrap();
+        let lost = journal
+            .reconcile(ROUND, &pending, CollectingSignatureState::Signed)
+            .unwrap();
+        assert!(lost.is_none());
+        let last = journal.last(ROUND).unwrap().unwrap();
+        assert_eq!(last.status, SignatureStatus::Finalized);
+        assert!(journal.pending(ROUND).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_reconcile_lost() {
+        let journal = journal();
+        journal
+            .record(ROUND, &record(SignatureStatus::Signed))
+            .unwrap();
+        let pending = journal.pending(ROUND).unwrap().unwrap();
+        let lost = journal
+            .reconcile(ROUND, &pending, CollectingSignatureState::Unsigned)
+            .unwrap()
+            .unwrap();
+        assert_eq!(lost.block_number, 100);
+        assert_eq!(lost.message, "0x0101");
+        // still pending until the signature is submitted again
+        let last = journal.pending(ROUND).unwrap().unwrap();
+        assert_eq!(last.status, SignatureStatus::Failed);
+    }
+
+    #[test]
+    fn test_reconcile_not_collecting() {
+        let journal = journal();
+        journal
+            .record(ROUND, &record(SignatureStatus::Failed))
+            .unwrap();
+        let pending = journal.pending(ROUND).unwrap().unwrap();
+        let lost = journal
+            .reconcile(ROUND, &pending, CollectingSignatureState::NotCollecting)
+            .unwrap();
+        assert!(lost.is_none());
+        let last = journal.last(ROUND).unwrap().unwrap();
+        assert_eq!(last.status, SignatureStatus::Expired);
+    }
+
+    #[test]
+    fn test_update_other_message() {
+        let journal = journal();
+        journal
+            .record(ROUND, &record(SignatureStatus::Signed))
+            .unwrap();
+        journal
+            .update(ROUND, "0x0909", SignatureStatus::Finalized, None)
+            .unwrap();
+        let last = journal.last(ROUND).unwrap().unwrap();
+        assert_eq!(last.status, SignatureStatus::Signed);
+    }
+}
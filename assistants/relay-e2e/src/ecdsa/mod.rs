@@ -3,4 +3,7 @@ pub mod collected_enough_new_message_root_signatures;
 pub mod collecting_authorities_change_signatures;
 pub mod collecting_new_message_root_signatures;
 pub mod ecdsa_scanner;
+pub mod events;
+pub mod journal;
+pub mod signatures;
 pub mod types;
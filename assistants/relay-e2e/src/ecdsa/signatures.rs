@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+
+use client_contracts::PosaLightClient;
+use web3::types::Address;
+
+use crate::error::{RelayError, RelayResult};
+
+/// Verify the collected signatures against relayers and threshold of the PoSA light client,
+/// returns the signatures which the light client accepts.
+pub async fn verify_collected_signatures(
+    client_posa: &PosaLightClient,
+    message: &[u8],
+    signatures: &[Vec<u8>],
+) -> RelayResult<Vec<Vec<u8>>> {
+    let relayers = client_posa.get_relayers().await?;
+    let threshold = client_posa.get_threshold().await?;
+    verify_signatures(message, signatures, &relayers, threshold.as_usize())
+}
+
+/// Recover the signer of every signature from the signed message, drops the signatures which
+/// are invalid, duplicated or not signed by relayers. The rest are sorted by signer address
+/// in ascending order with v in 27/28, as the light client requires.
+pub fn verify_signatures(
+    message: &[u8],
+    signatures: &[Vec<u8>],
+    relayers: &[Address],
+    threshold: usize,
+) -> RelayResult<Vec<Vec<u8>>> {
+    if message.len() != 32 {
+        return Err(RelayError::Custom(format!(
+            "Wrong signed message: {}",
+            array_bytes::bytes2hex("0x", message)
+        )));
+    }
+    let mut verified = BTreeMap::new();
+    for signature in signatures {
+        let signer = match recover_signer(message, signature) {
+            Some(v) => v,
+            None => {
+                tracing::warn!(
+                    target: "relay-e2e",
+                    "drop invalid signature {}",
+                    array_bytes::bytes2hex("0x", signature),
+                );
+                continue;
+            }
+        };
+        if !relayers.contains(&signer) {
+            tracing::warn!(
+                target: "relay-e2e",
+                "drop signature of {:?} which isn't a relayer of light client",
+                signer,
+            );
+            continue;
+        }
+        if verified.contains_key(&signer) {
+            tracing::warn!(
+                target: "relay-e2e",
+                "drop duplicated signature of {:?}",
+                signer,
+            );
+            continue;
+        }
+        let mut normalized = signature.clone();
+        normalized[64] = recovery_id(signature[64]) as u8 + 27;
+        verified.insert(signer, normalized);
+    }
+    if verified.len() < threshold {
+        return Err(RelayError::Custom(format!(
+            "Not enough valid signatures, {} valid of {} collected, threshold is {}",
+            verified.len(),
+            signatures.len(),
+            threshold,
+        )));
+    }
+    Ok(verified.into_values().collect())
+}
+
+fn recovery_id(v: u8) -> i32 {
+    if v >= 27 {
+        (v - 27) as i32
+    } else {
+        v as i32
+    }
+}
+
+fn recover_signer(message: &[u8], signature: &[u8]) -> Option<Address> {
+    if signature.len() != 65 {
+        return None;
+    }
+    web3::signing::recover(message, &signature[..64], recovery_id(signature[64])).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ethereum::FastEthereumAccount;
+
+    const MESSAGE: [u8; 32] = [7u8; 32];
+
+    fn account(seed: u8) -> FastEthereumAccount {
+        FastEthereumAccount::new(array_bytes::bytes2hex("", [seed; 32]))
+    }
+
+    #[test]
+    fn test_verify_signatures() {
+        let (alice, bob, charlie) = (account(1), account(2), account(3));
+        let relayers = vec![
+            alice.address().unwrap(),
+            bob.address().unwrap(),
+            charlie.address().unwrap(),
+        ];
+        let alice_signature = alice.sign(&MESSAGE).unwrap();
+        let signatures = vec![
+            bob.sign(&MESSAGE).unwrap(),
+            alice_signature.clone(),
+            alice_signature,
+            account(4).sign(&MESSAGE).unwrap(),
+            vec![0u8; 65],
+        ];
+
+        let verified = verify_signatures(&MESSAGE, &signatures, &relayers, 2).unwrap();
+        assert_eq!(verified.len(), 2);
+        let signers = verified
+            .iter()
+            .map(|v| {
+                assert!(v[64] == 27 || v[64] == 28);
+                recover_signer(&MESSAGE, v).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let mut sorted = signers.clone();
+        sorted.sort();
+        assert_eq!(signers, sorted);
+
+        assert!(verify_signatures(&MESSAGE, &signatures, &relayers, 3).is_err());
+    }
+}
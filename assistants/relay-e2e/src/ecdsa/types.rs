@@ -1,20 +1,165 @@
-use client_contracts::PosaLightClient;
-use web3::transports::Http;
+use client_contracts::{FailoverHttp, PosaLightClient};
 use web3::Web3;
 
+use crate::error::RelayResult;
+use crate::span;
 use crate::types::ethereum::FastEthereumAccount;
-use bridge_e2e_traits::client::EcdsaClient;
-use subquery::Subquery;
+use bridge_e2e_traits::client::{CollectingSignatureState, EcdsaClient};
+
+use super::events::EcdsaEvents;
+use super::journal::{SignatureJournal, SignatureRecord, SignatureRound, SignatureStatus};
 
 #[derive(Clone)]
 pub struct EcdsaSource<T: EcdsaClient> {
     pub block: Option<u32>,
-    pub subquery: Subquery,
-    pub client_darwinia_web3: Web3<Http>,
-    pub client_eth_web3: Web3<Http>,
-    pub client_darwinia_substrate: T,
+    /// lookup of ECDSA authority events
+    pub events: EcdsaEvents<T>,
+    pub client_darwinia_web3: Web3<FailoverHttp>,
+    pub client_eth_web3: Web3<FailoverHttp>,
     pub client_posa: PosaLightClient,
-    pub darwinia_evm_account: FastEthereumAccount,
+    /// ECDSA authority, none if the relayer isn't an authority
+    pub authority: Option<EcdsaAuthority<T>>,
     pub ethereum_account: FastEthereumAccount,
     pub minimal_interval: u64,
 }
+
+/// ECDSA authority, signs the collecting rounds with its own key, which is never used to relay
+#[derive(Clone)]
+pub struct EcdsaAuthority<T: EcdsaClient> {
+    /// darwinia client, extrinsics are signed by the authority key
+    pub client: T,
+    pub account: FastEthereumAccount,
+    /// journal of submitted signatures
+    pub journal: SignatureJournal,
+}
+
+impl<T: EcdsaClient> EcdsaAuthority<T> {
+    /// State of the authority signature of the message in the collecting round on chain
+    pub async fn signature_state(
+        &self,
+        round: SignatureRound,
+        message: &[u8],
+    ) -> RelayResult<CollectingSignatureState> {
+        let address = self.account.address()?.0;
+        let state = match round {
+            SignatureRound::NewMessageRoot => {
+                self.client
+                    .new_message_root_signature_state(message, &address)
+                    .await?
+            }
+            SignatureRound::AuthoritiesChange => {
+                self.client
+                    .authorities_change_signature_state(message, &address)
+                    .await?
+            }
+        };
+        Ok(state)
+    }
+
+    /// Reconcile the pending journal record of the round with signatures on chain, the record
+    /// is pending when the relayer stopped or failed while submitting. The round is signed
+    /// again if the signature was lost.
+    pub async fn reconcile(&self, round: SignatureRound) -> RelayResult<()> {
+        let record = match self.journal.pending(round)? {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+        let message = array_bytes::hex2bytes(&record.message)?;
+        let state = self.signature_state(round, &message).await?;
+        tracing::info!(
+            target: "relay-e2e",
+            "reconciled signature of block {} with chain: {:?}",
+            record.block_number,
+            state,
+        );
+        if let Some(lost) = self.journal.reconcile(round, &record, state)? {
+            tracing::info!(
+                target: "relay-e2e",
+                "signature of block {} was lost, sign it again",
+                lost.block_number,
+            );
+            self.sign_and_submit(round, lost.block_number, &message).await?;
+        }
+        Ok(())
+    }
+
+    /// Sign the message of the collecting round and submit the signature. Nothing to do if the
+    /// chain has accepted the signature, or the round isn't collecting anymore.
+    pub async fn sign_and_submit(
+        &self,
+        round: SignatureRound,
+        block_number: u32,
+        message: &[u8],
+    ) -> RelayResult<()> {
+        let message_hex = array_bytes::bytes2hex("0x", message);
+        match self.signature_state(round, message).await? {
+            CollectingSignatureState::Signed => {
+                tracing::info!(
+                    target: "relay-e2e",
+                    "signature of block {} was submitted already",
+                    block_number,
+                );
+                return self
+                    .journal
+                    .update(round, &message_hex, SignatureStatus::Finalized, None);
+            }
+            CollectingSignatureState::NotCollecting => {
+                tracing::info!(
+                    target: "relay-e2e",
+                    "round of block {} isn't collecting signatures",
+                    block_number,
+                );
+                return self
+                    .journal
+                    .update(round, &message_hex, SignatureStatus::Expired, None);
+            }
+            CollectingSignatureState::Unsigned => {}
+        }
+
+        let signature = self.account.sign(message)?;
+        tracing::trace!(
+            target: "relay-e2e",
+            "Signature signed",
+        );
+        self.journal.record(
+            round,
+            &SignatureRecord {
+                block_number,
+                message: message_hex.clone(),
+                signature: array_bytes::bytes2hex("0x", &signature),
+                extrinsic_hash: None,
+                status: SignatureStatus::Signed,
+            },
+        )?;
+        let submitted = match round {
+            SignatureRound::NewMessageRoot => {
+                self.client
+                    .submit_new_message_root_signature(signature)
+                    .await
+            }
+            SignatureRound::AuthoritiesChange => {
+                self.client
+                    .submit_authorities_change_signature(signature)
+                    .await
+            }
+        };
+        match submitted {
+            Ok(hash) => {
+                let hash = array_bytes::bytes2hex("0x", hash.as_ref());
+                span::record_tx(&hash);
+                tracing::info!(
+                    target: "relay-e2e",
+                    "submitted signature: {}",
+                    hash,
+                );
+                self.journal
+                    .update(round, &message_hex, SignatureStatus::Finalized, Some(hash))
+            }
+            Err(e) => {
+                self.journal
+                    .update(round, &message_hex, SignatureStatus::Failed, None)?;
+                Err(e.into())
+            }
+        }
+    }
+}
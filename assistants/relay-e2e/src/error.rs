@@ -1,6 +1,7 @@
 use bridge_e2e_traits::error::E2EClientError;
 use client_beacon::error::BeaconApiError;
 use client_contracts::error::BridgeContractError;
+use ecdsa_pair::signer::SignerError;
 use subquery::SubqueryComponentError;
 use support_etherscan::Error as SupportEtherscanError;
 use thiserror::Error as ThisError;
@@ -43,6 +44,12 @@ impl From<secp256k1::Error> for RelayError {
     }
 }
 
+impl From<SignerError> for RelayError {
+    fn from(e: SignerError) -> Self {
+        Self::Seed(format!("Wrong signer: {:?}", e))
+    }
+}
+
 impl From<()> for RelayError {
     fn from(_: ()) -> Self {
         Self::Custom("Incorrect beacon api version.".into())
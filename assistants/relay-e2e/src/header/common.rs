@@ -1,9 +1,8 @@
 use bridge_e2e_traits::client::{EthTruthLayerLightClient, GasPriceOracle, Web3Client};
 use client_contracts::beacon_light_client::BeaconLightClient;
-use secp256k1::SecretKey;
+use client_contracts::{EvmSigner, FailoverHttp};
 use std::str::FromStr;
 use web3::{
-    transports::Http,
     types::{Address, U256},
     Web3,
 };
@@ -12,34 +11,33 @@ use crate::error::RelayResult;
 
 #[derive(Debug, Clone)]
 pub struct EthLightClient {
-    pub client: Web3<Http>,
+    pub client: Web3<FailoverHttp>,
     pub beacon_light_client: BeaconLightClient,
-    pub private_key: SecretKey,
+    pub signer: EvmSigner,
     pub max_gas_price: U256,
 }
 
 impl EthLightClient {
     pub fn new(
-        endpoint: &str,
+        transport: FailoverHttp,
         contract_address: Address,
         private_key: &str,
         max_gas_price: U256,
     ) -> RelayResult<Self> {
-        let transport = Http::new(endpoint)?;
         let client = web3::Web3::new(transport);
         let beacon_light_client = BeaconLightClient::new(&client, contract_address)?;
-        let private_key = SecretKey::from_str(private_key)?;
+        let signer = EvmSigner::from_str(private_key)?;
         Ok(Self {
             client,
             beacon_light_client,
-            private_key,
+            signer,
             max_gas_price,
         })
     }
 }
 
 impl Web3Client for EthLightClient {
-    fn get_web3(&self) -> &Web3<Http> {
+    fn get_web3(&self) -> &Web3<FailoverHttp> {
         &self.client
     }
 }
@@ -49,8 +47,8 @@ impl EthTruthLayerLightClient for EthLightClient {
         &self.beacon_light_client
     }
 
-    fn private_key(&self) -> &SecretKey {
-        &self.private_key
+    fn signer(&self) -> &EvmSigner {
+        &self.signer
     }
 }
 
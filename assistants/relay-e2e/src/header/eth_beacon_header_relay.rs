@@ -9,12 +9,16 @@ use client_beacon::{client::BeaconApiClient, types::FinalityUpdate};
 use client_contracts::beacon_light_client_types::FinalizedHeaderUpdate;
 use support_etherscan::wait_for_transaction_confirmation_with_timeout;
 use tokio::sync::broadcast::Receiver;
+use tracing::Instrument;
 use web3::{
     contract::Options,
     types::{Bytes, H256},
 };
 
 use crate::error::{RelayError, RelayResult};
+use crate::keepstate;
+use crate::span;
+use crate::trigger::RelayTrigger;
 
 pub struct BeaconHeaderRelayRunner<C, O>
 where
@@ -26,6 +30,7 @@ where
     pub receiver: Option<Receiver<O>>,
     pub minimal_interval: u64,
     pub last_relay_time: u64,
+    pub trigger: RelayTrigger,
 }
 
 #[derive(Debug)]
@@ -48,11 +53,25 @@ where
     pub async fn start(&mut self) -> RelayResult<()> {
         loop {
             self.run().await?;
-            tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+            self.trigger.wait().await;
         }
     }
 
     pub async fn run(&mut self) -> RelayResult<()> {
+        let span = span::module_span("header");
+        self.relay().instrument(span).await
+    }
+
+    async fn relay(&mut self) -> RelayResult<()> {
+        let relayer = self.eth_light_client.signer().address();
+        if keepstate::is_submit_paused(keepstate::DARWINIA_CHAIN, &relayer) {
+            tracing::warn!(
+                target: "relay-e2e",
+                "The balance of relayer {:?} is below reserve, submitting paused.",
+                relayer,
+            );
+            return Ok(());
+        }
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map_err(|e| RelayError::Custom(format!("{}", e)))?
@@ -60,7 +79,7 @@ where
         if now - self.last_relay_time <= self.minimal_interval {
             tracing::info!(
                 target: "relay-e2e",
-                "[Header] Last relaying time is {:?}, wait for {} seconds to start again.",
+                "Last relaying time is {:?}, wait for {} seconds to start again.",
                 self.last_relay_time,
                 self.minimal_interval - (now - self.last_relay_time)
             );
@@ -75,7 +94,7 @@ where
             .await?;
         tracing::info!(
             target: "relay-e2e",
-            "[Header] State: {:?}",
+            "State: {:?}",
             state
         );
 
@@ -88,7 +107,7 @@ where
         }
 
         if state.current_period == state.relayed_period {
-            tracing::trace!(target: "relay-e2e", "[Header] Same period");
+            tracing::trace!(target: "relay-e2e", "Same period");
             if self.is_on_demand_header(update_finality_slot).await? {
                 self.relay_latest(state, finality_update).await?;
             }
@@ -99,14 +118,14 @@ where
             return Ok(());
         }
         if state.current_period == state.relayed_period + 1 {
-            tracing::trace!(target: "relay-e2e", "[Header] One period behind");
+            tracing::trace!(target: "relay-e2e", "One period behind");
             if self.is_on_demand_header(update_finality_slot).await? {
                 self.relay_latest(state, finality_update).await
             } else {
                 Ok(())
             }
         } else {
-            tracing::trace!(target: "relay-e2e", "[Header] One more period behind");
+            tracing::trace!(target: "relay-e2e", "One more period behind");
             self.relay_next_period(state).await
         }
     }
@@ -125,7 +144,7 @@ where
                 if required_header.is_none() {
                     tracing::trace!(
                         target: "relay-e2e",
-                        "[Header] No signal yet",
+                        "No signal yet",
                     );
                     return Ok(false);
                 }
@@ -142,7 +161,7 @@ where
                     .block_number;
                 tracing::info!(
                     target: "relay-e2e",
-                    "[Header] Latest finalized slot: {:?}, finalized block number: {:?}, Required block number: {:?},",
+                    "Latest finalized slot: {:?}, finalized block number: {:?}, Required block number: {:?},",
                     update_finality_slot,
                     latest_block_number,
                     required_header.block_number(),
@@ -150,7 +169,7 @@ where
                 if latest_block_number < required_header.block_number() {
                     tracing::info!(
                         target: "relay-e2e",
-                        "[Header] Wait for finalization of blocks",
+                        "Wait for finalization of blocks",
                     );
                     return Ok(false);
                 }
@@ -188,7 +207,7 @@ where
 
         tracing::info!(
             target: "relay-e2e",
-            "[Header] Latest finality slot: {:?}",
+            "Latest finality slot: {:?}",
             &update_finality_slot
         );
         // The latest finality header has been relayed
@@ -297,16 +316,17 @@ where
             .beacon_light_client()
             .import_finalized_header(
                 finalized_header_update,
-                self.eth_light_client.private_key(),
+                self.eth_light_client.signer(),
                 Options {
                     gas_price: Some(gas_price),
                     ..Default::default()
                 },
             )
             .await?;
+        span::record_tx(tx);
         tracing::info!(
             target: "relay-e2e",
-            "[Header] Sending tx: {:?}",
+            "Sending tx: {:?}",
             &tx
         );
         wait_for_transaction_confirmation_with_timeout(
@@ -5,27 +5,46 @@ use client_beacon::client::BeaconApiClient;
 use client_contracts::beacon_light_client_types::{
     FinalizedHeaderUpdate, SyncCommitteePeriodUpdate,
 };
+use tracing::Instrument;
 use web3::{
     contract::Options,
     types::{Bytes, H256},
 };
 
 use crate::error::{RelayError, RelayResult};
+use crate::keepstate;
+use crate::span;
+use crate::trigger::RelayTrigger;
 
 pub struct SyncCommitteeRelayRunner<C: EthTruthLayerLightClient> {
     pub eth_light_client: C,
     pub beacon_api_client: BeaconApiClient,
+    pub trigger: RelayTrigger,
 }
 
 impl<C: EthTruthLayerLightClient> SyncCommitteeRelayRunner<C> {
     pub async fn start(&mut self) -> RelayResult<()> {
         loop {
             self.run().await?;
-            tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+            self.trigger.wait().await;
         }
     }
 
     pub async fn run(&mut self) -> RelayResult<()> {
+        let span = span::module_span("sync-committee");
+        self.relay().instrument(span).await
+    }
+
+    async fn relay(&mut self) -> RelayResult<()> {
+        let relayer = self.eth_light_client.signer().address();
+        if keepstate::is_submit_paused(keepstate::DARWINIA_CHAIN, &relayer) {
+            tracing::warn!(
+                target: "relay-e2e",
+                "The balance of relayer {:?} is below reserve, submitting paused.",
+                relayer,
+            );
+            return Ok(());
+        }
         let last_relayed_header = self
             .eth_light_client
             .beacon_light_client()
@@ -46,7 +65,7 @@ impl<C: EthTruthLayerLightClient> SyncCommitteeRelayRunner<C> {
         if !next_sync_committee.is_zero() {
             tracing::info!(
                 target: "relay-e2e",
-                "[SyncCommittee] Next sync committee is {:?}",
+                "Next sync committee is {:?}",
                 next_sync_committee
             );
             return Ok(());
@@ -54,7 +73,7 @@ impl<C: EthTruthLayerLightClient> SyncCommitteeRelayRunner<C> {
 
         tracing::info!(
             target: "relay-e2e",
-            "[SyncCommittee] Try to relay SyncCommittee at period {:?}",
+            "Try to relay SyncCommittee at period {:?}",
             period + 1,
         );
 
@@ -68,7 +87,7 @@ impl<C: EthTruthLayerLightClient> SyncCommitteeRelayRunner<C> {
             .import_next_sync_committee(
                 finalized_header_update,
                 sync_committee_update,
-                self.eth_light_client.private_key(),
+                self.eth_light_client.signer(),
                 Options {
                     gas_price: Some(gas_price),
                     ..Default::default()
@@ -76,9 +95,10 @@ impl<C: EthTruthLayerLightClient> SyncCommitteeRelayRunner<C> {
             )
             .await?;
 
+        span::record_tx(tx);
         tracing::info!(
             target: "relay-e2e",
-            "[SyncCommittee] Sending tx: {:?}",
+            "Sending tx: {:?}",
             &tx
         );
         support_etherscan::wait_for_transaction_confirmation_with_timeout(
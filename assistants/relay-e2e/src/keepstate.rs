@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use web3::types::Address;
+
+/// Chain name of message clients at darwinia, transactions of header relays are submitted to it
+pub const DARWINIA_CHAIN: &str = "Substrate";
+/// Chain name of message clients at ethereum
+pub const ETHEREUM_CHAIN: &str = "Eth";
+
+static SUBMIT_PAUSED: Lazy<Mutex<HashSet<(String, Address)>>> = Lazy::new(|| {
+    let set = HashSet::new();
+    Mutex::new(set)
+});
+
+/// Pause or resume transactions submitted by this account to chain,
+/// set by balance monitor when the account balance at chain below reserve
+pub fn set_submit_paused(chain: &str, account: Address, paused: bool) {
+    let mut data = SUBMIT_PAUSED.lock().unwrap();
+    if paused {
+        data.insert((chain.to_string(), account));
+    } else {
+        data.remove(&(chain.to_string(), account));
+    }
+}
+
+pub fn is_submit_paused(chain: &str, account: &Address) -> bool {
+    let data = SUBMIT_PAUSED.lock().unwrap();
+    data.contains(&(chain.to_string(), *account))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submit_paused_by_chain() {
+        let account = Address::repeat_byte(1);
+        set_submit_paused(DARWINIA_CHAIN, account, true);
+        set_submit_paused(ETHEREUM_CHAIN, account, false);
+        assert!(is_submit_paused(DARWINIA_CHAIN, &account));
+        assert!(!is_submit_paused(ETHEREUM_CHAIN, &account));
+
+        set_submit_paused(DARWINIA_CHAIN, account, false);
+        assert!(!is_submit_paused(DARWINIA_CHAIN, &account));
+    }
+}
@@ -1,6 +1,10 @@
 pub mod ecdsa;
 pub mod error;
 pub mod header;
+pub mod keepstate;
 pub mod message;
+pub mod trigger;
 pub mod types;
 pub mod utils;
+
+mod span;
@@ -11,15 +11,13 @@ use client_beacon::client::{ApiSupplier, BeaconApiClient};
 use client_contracts::{
     inbound_types::{Message, MessageDispatched, OutboundLaneData, Payload, ReceiveMessagesProof},
     outbound_types::{MessageAccepted, ReceiveMessagesDeliveryProof},
-    ChainMessageCommitter, FeeMarket, Inbound, LaneMessageCommitter, Outbound,
+    ChainMessageCommitter, EvmSigner, FailoverHttp, FeeMarket, Inbound, LaneMessageCommitter,
+    Outbound,
 };
-use secp256k1::SecretKey;
 use thegraph::Thegraph;
 use web3::{
     contract::tokens::Tokenizable,
     ethabi::encode,
-    signing::Key,
-    transports::Http,
     types::{Address, BlockId, BlockNumber, Bytes, U256},
     Web3,
 };
@@ -29,7 +27,7 @@ use crate::header::common::EthLightClient;
 
 pub struct DarwiniaMessageClient<T: RelayStrategy = FeeMarketRelayStrategy> {
     pub chain: String,
-    pub client: Web3<Http>,
+    pub client: Web3<FailoverHttp>,
     pub inbound: Inbound,
     pub outbound: Outbound,
     pub chain_message_committer: ChainMessageCommitter,
@@ -44,7 +42,7 @@ pub struct DarwiniaMessageClient<T: RelayStrategy = FeeMarketRelayStrategy> {
 impl DarwiniaMessageClient {
     pub fn new_with_fee_market(
         chain: &str,
-        endpoint: &str,
+        transport: FailoverHttp,
         beacon_api_endpoint: &str,
         beacon_api_supplier: ApiSupplier,
         inbound_address: Address,
@@ -57,8 +55,7 @@ impl DarwiniaMessageClient {
         private_key: &str,
         indexer: Thegraph,
     ) -> E2EClientResult<DarwiniaMessageClient> {
-        let transport = Http::new(endpoint)?;
-        let client = Web3::new(transport);
+        let client = Web3::new(transport.clone());
 
         let beacon_rpc_client = BeaconApiClient::new(beacon_api_endpoint, beacon_api_supplier)
             .map_err(|_| E2EClientError::Custom("Failed to build beacon api client".into()))?;
@@ -72,11 +69,11 @@ impl DarwiniaMessageClient {
             LaneMessageCommitter::new(&client, lane_message_committer_address)?;
 
         let eth_light_client =
-            EthLightClient::new(endpoint, light_client_address, private_key, max_gas_price)
+            EthLightClient::new(transport, light_client_address, private_key, max_gas_price)
                 .map_err(|e| {
                     E2EClientError::Custom(format!("Failed to build EthLightClient: {}", e))
                 })?;
-        let account = eth_light_client.private_key().address();
+        let account = eth_light_client.signer().address();
         let strategy = FeeMarketRelayStrategy::new(fee_market, account);
 
         Ok(DarwiniaMessageClient {
@@ -95,7 +92,7 @@ impl DarwiniaMessageClient {
 }
 
 impl<T: RelayStrategy> Web3Client for DarwiniaMessageClient<T> {
-    fn get_web3(&self) -> &Web3<Http> {
+    fn get_web3(&self) -> &Web3<FailoverHttp> {
         &self.client
     }
 }
@@ -124,8 +121,8 @@ impl<T: RelayStrategy> MessageClient for DarwiniaMessageClient<T> {
         &self.outbound
     }
 
-    fn private_key(&self) -> &SecretKey {
-        &self.eth_light_client.private_key
+    fn signer(&self) -> &EvmSigner {
+        &self.eth_light_client.signer
     }
 
     async fn decide(&mut self, encoded_key: U256) -> E2EClientResult<bool> {
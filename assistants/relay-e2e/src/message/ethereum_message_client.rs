@@ -10,14 +10,12 @@ use client_contracts::{
     error::BridgeContractError,
     inbound_types::{Message, MessageDispatched, OutboundLaneData, Payload, ReceiveMessagesProof},
     outbound_types::{MessageAccepted, ReceiveMessagesDeliveryProof},
-    Inbound, Outbound, PosaLightClient, SimpleFeeMarket,
+    EvmSigner, FailoverHttp, Inbound, Outbound, PosaLightClient, SimpleFeeMarket,
 };
-use secp256k1::SecretKey;
 use support_etherscan::EtherscanClient;
 use web3::{
     ethabi::{encode, RawLog},
-    signing::{keccak256, Key},
-    transports::Http,
+    signing::keccak256,
     types::{Address, BlockId, BlockNumber, Bytes, FilterBuilder, Proof as Web3Proof, H256, U256},
     Web3,
 };
@@ -30,12 +28,12 @@ pub const LANE_MESSAGE_SLOT: u64 = 2u64;
 
 pub struct EthMessageClient<T: RelayStrategy = SimpleFeeMarketRelayStrategy> {
     pub chain: String,
-    pub client: Web3<Http>,
+    pub client: Web3<FailoverHttp>,
     pub inbound: Inbound,
     pub outbound: Outbound,
     pub darwinia_light_client: PosaLightClient,
     pub strategy: T,
-    pub private_key: SecretKey,
+    pub signer: EvmSigner,
     pub max_gas_price: U256,
     pub etherscan_client: Option<EtherscanClient>,
 }
@@ -44,7 +42,7 @@ pub struct EthMessageClient<T: RelayStrategy = SimpleFeeMarketRelayStrategy> {
 impl EthMessageClient {
     pub fn new_with_simple_fee_market(
         chain: &str,
-        endpoint: &str,
+        transport: FailoverHttp,
         inbound_address: Address,
         outbound_address: Address,
         fee_market_address: Address,
@@ -53,14 +51,13 @@ impl EthMessageClient {
         max_gas_price: U256,
         etherscan_api_key: &str,
     ) -> E2EClientResult<EthMessageClient> {
-        let transport = Http::new(endpoint)?;
         let client = Web3::new(transport);
         let inbound = Inbound::new(&client, inbound_address)?;
         let outbound = Outbound::new(&client, outbound_address)?;
         let fee_market = SimpleFeeMarket::new(&client, fee_market_address)?;
-        let private_key = SecretKey::from_str(private_key)
+        let signer = EvmSigner::from_str(private_key)
             .map_err(|e| E2EClientError::Custom(format!("Failed to decode private key: {}", e)))?;
-        let account = (&private_key).address();
+        let account = signer.address();
         let darwinia_light_client = PosaLightClient::new(&client, darwinia_light_client_address)?;
         let strategy = SimpleFeeMarketRelayStrategy::new(fee_market, account);
         let etherscan_client =
@@ -77,7 +74,7 @@ impl EthMessageClient {
             outbound,
             darwinia_light_client,
             strategy,
-            private_key,
+            signer,
             max_gas_price,
             etherscan_client,
         })
@@ -85,7 +82,7 @@ impl EthMessageClient {
 }
 
 impl<T: RelayStrategy> Web3Client for EthMessageClient<T> {
-    fn get_web3(&self) -> &Web3<Http> {
+    fn get_web3(&self) -> &Web3<FailoverHttp> {
         &self.client
     }
 }
@@ -114,8 +111,8 @@ impl<T: RelayStrategy> MessageClient for EthMessageClient<T> {
         &self.outbound
     }
 
-    fn private_key(&self) -> &SecretKey {
-        &self.private_key
+    fn signer(&self) -> &EvmSigner {
+        &self.signer
     }
 
     async fn decide(&mut self, encoded_key: U256) -> E2EClientResult<bool> {
@@ -4,12 +4,15 @@ use bridge_e2e_traits::client::{MessageClient, MessageEventsQuery, OnDemandHeade
 use client_contracts::{inbound_types::InboundLaneNonce, outbound_types::OutboundLaneNonce};
 use support_etherscan::wait_for_transaction_confirmation_with_timeout;
 use tokio::sync::broadcast::Sender;
+use tracing::Instrument;
 use web3::{
     contract::Options,
     types::{BlockId, BlockNumber, U256},
 };
 
 use crate::error::{RelayError, RelayResult};
+use crate::keepstate;
+use crate::span;
 
 #[derive(Debug)]
 pub struct MessageRelayRunner<S0, S1, O1, O2>
@@ -85,15 +88,27 @@ where
     }
 
     pub async fn message_relay(&mut self) -> RelayResult<()> {
+        let span = span::relay_span("delivery", self.source.chain(), self.target.chain());
+        self.relay_messages().instrument(span).await
+    }
+
+    async fn relay_messages(&mut self) -> RelayResult<()> {
+        let relayer = self.target.signer().address();
+        if keepstate::is_submit_paused(self.target.chain(), &relayer) {
+            tracing::warn!(
+                target: "relay-e2e",
+                "The balance of relayer {:?} is below reserve, submitting paused.",
+                relayer,
+            );
+            return Ok(());
+        }
         self.update_channel_state().await?;
         if self.state.target_inbound.last_delivered_nonce
             == self.state.source_outbound.latest_generated_nonce
         {
             tracing::info!(
                 target: "relay-e2e",
-                "[MessageDelivery][{}=>{}] Last delivered nonce is {:?}, equal to lastest generated. Do nothing.",
-                self.source.chain(),
-                self.target.chain(),
+                "Last delivered nonce is {:?}, equal to lastest generated. Do nothing.",
                 self.state.target_inbound.last_delivered_nonce,
             );
             return Ok(());
@@ -110,7 +125,7 @@ where
             None => {
                 tracing::info!(
                     target: "relay-e2e",
-                    "[MessageDelivery] Wait for header relay",
+                    "Wait for header relay",
                 );
                 return Ok(());
             }
@@ -123,9 +138,7 @@ where
         {
             tracing::info!(
                 target: "relay-e2e",
-                "[MessageDelivery][{}=>{}] Messages: [{:?}, {:?}] need to be relayed, wait for header relay",
-                self.source.chain(),
-                self.target.chain(),
+                "Messages: [{:?}, {:?}] need to be relayed, wait for header relay",
                 begin,
                 end
             );
@@ -138,9 +151,7 @@ where
         );
         tracing::info!(
             target: "relay-e2e",
-            "[MessageDelivery][{}=>{}] Try to relay messages: [{:?}, {:?}]",
-            self.source.chain(),
-            self.target.chain(),
+            "Try to relay messages: [{:?}, {:?}]",
             self.state.target_inbound.last_delivered_nonce + 1,
             end
         );
@@ -193,18 +204,15 @@ where
         if count == delivered {
             tracing::info!(
                 target: "relay-e2e",
-                "[MessageDelivery][{}=>{}] No need to relay",
-                self.source.chain(),
-                self.target.chain(),
+                "No need to relay",
             );
             return Ok(());
         }
 
+        span::record_nonces(begin + delivered, begin + count - 1);
         tracing::info!(
             target: "relay-e2e",
-            "[MessageDelivery][{}=>{}] Relaying messages: [{:?}, {:?}]",
-            self.source.chain(),
-            self.target.chain(),
+            "Relaying messages: [{:?}, {:?}]",
             begin + delivered,
             begin + count - 1,
         );
@@ -216,7 +224,7 @@ where
             .receive_messages_proof(
                 proof,
                 U256::from(count),
-                self.target.private_key(),
+                self.target.signer(),
                 Options {
                     gas_price: Some(gas_price),
                     ..Default::default()
@@ -224,11 +232,10 @@ where
             )
             .await?;
 
+        span::record_tx(tx);
         tracing::info!(
             target: "relay-e2e",
-            "[MessageDelivery][{}=>{}] Sending tx: {:?}",
-            self.source.chain(),
-            self.target.chain(),
+            "Sending tx: {:?}",
             tx
         );
 
@@ -245,15 +252,27 @@ where
     }
 
     pub async fn message_confirm(&mut self) -> RelayResult<()> {
+        let span = span::relay_span("confirmation", self.source.chain(), self.target.chain());
+        self.confirm_messages().instrument(span).await
+    }
+
+    async fn confirm_messages(&mut self) -> RelayResult<()> {
+        let relayer = self.source.signer().address();
+        if keepstate::is_submit_paused(self.source.chain(), &relayer) {
+            tracing::warn!(
+                target: "relay-e2e",
+                "The balance of relayer {:?} is below reserve, submitting paused.",
+                relayer,
+            );
+            return Ok(());
+        }
         self.update_channel_state().await?;
         if self.state.source_outbound.latest_received_nonce
             == self.state.source_outbound.latest_generated_nonce
         {
             tracing::info!(
                 target: "relay-e2e",
-                "[MessageConfirmation][{}=>{}] All confirmed({:?}), nothing to do.",
-                self.source.chain(),
-                self.target.chain(),
+                "All confirmed({:?}), nothing to do.",
                 self.state.source_outbound
             );
             return Ok(());
@@ -275,9 +294,7 @@ where
             } else {
                 tracing::info!(
                     target: "relay-e2e",
-                    "[MessageConfirmation][{}=>{}] Nonce {:?} was confirmed, wait for delivery from {:?} to {:?}. ",
-                    self.source.chain(),
-                    self.target.chain(),
+                    "Nonce {:?} was confirmed, wait for delivery from {:?} to {:?}. ",
                     self.state.source_outbound.latest_received_nonce,
                     self.state.target_inbound_relayed.last_delivered_nonce + 1,
                     self.state.source_outbound.latest_generated_nonce
@@ -289,20 +306,17 @@ where
         if self.state.target_block_at_source.is_none() {
             tracing::info!(
                 target: "relay-e2e",
-                "[MessageConfirmation][{}=>{}] Nonce [{:?}:{:?}] was delivered, wait for header relay",
-                self.source.chain(),
-                self.target.chain(),
+                "Nonce [{:?}:{:?}] was delivered, wait for header relay",
                 self.state.source_outbound.latest_received_nonce + 1,
                 self.state.target_inbound_relayed.last_delivered_nonce,
             );
             return Ok(());
         }
 
+        span::record_nonces(begin, end);
         tracing::info!(
             target: "relay-e2e",
-            "[MessageConfirmation][{}=>{}] Try to confirm nonces [{:?}:{:?}]",
-            self.source.chain(),
-            self.target.chain(),
+            "Try to confirm nonces [{:?}:{:?}]",
             begin,
             end,
         );
@@ -319,7 +333,7 @@ where
             .outbound()
             .receive_messages_delivery_proof(
                 proof,
-                self.source.private_key(),
+                self.source.signer(),
                 Options {
                     gas_price: Some(gas_price),
                     ..Default::default()
@@ -327,11 +341,10 @@ where
             )
             .await?;
 
+        span::record_tx(hash);
         tracing::info!(
             target: "relay-e2e",
-            "[MessageConfirmation][{}=>{}] Messages confirmation tx: {:?}",
-            self.source.chain(),
-            self.target.chain(),
+            "Messages confirmation tx: {:?}",
             hash
         );
         wait_for_transaction_confirmation_with_timeout(
@@ -365,9 +378,7 @@ where
             if !s.is_empty() {
                 tracing::trace!(
                     target: "relay-e2e",
-                    "[MessageConfirmation][{}=>{}] Tunnel length: {:?}, required header: {:?}",
-                    self.source.chain(),
-                    self.target.chain(),
+                    "Tunnel length: {:?}, required header: {:?}",
                     s.len(),
                     event.block_number,
                 );
@@ -378,9 +389,7 @@ where
                 .map_err(|_| RelayError::Custom("Tunnel error".into()))?;
             tracing::info!(
                 target: "relay-e2e",
-                "[MessageConfirmation][{}=>{}] Signal: required header({:?})",
-                self.source.chain(),
-                self.target.chain(),
+                "Signal: required header({:?})",
                 event.block_number,
             );
         }
@@ -398,9 +407,7 @@ where
             if !s.is_empty() {
                 tracing::trace!(
                     target: "relay-e2e",
-                    "[MessageDelivery][{}=>{}] Tunnel length: {:?}, required header: {:?}",
-                    self.source.chain(),
-                    self.target.chain(),
+                    "Tunnel length: {:?}, required header: {:?}",
                     s.len(),
                     event.block_number,
                 );
@@ -410,9 +417,7 @@ where
                 .map_err(|_| RelayError::Custom("Tunnel error".into()))?;
             tracing::info!(
                 target: "relay-e2e",
-                "[MessageDelivery][{}=>{}] Signal: required header({:?})",
-                self.source.chain(),
-                self.target.chain(),
+                "Signal: required header({:?})",
                 event.block_number,
             );
         }
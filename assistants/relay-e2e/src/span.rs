@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+
+use tracing::field::{debug, Empty};
+use tracing::Span;
+
+/// Span of one relay round, the fields of it are attached to all events logged inside,
+/// nonces and tx are recorded while relaying
+pub(crate) fn relay_span(module: &str, source: &str, target: &str) -> Span {
+    tracing::info_span!(
+        target: "relay-e2e",
+        "relay",
+        module = module,
+        source = source,
+        target = target,
+        nonce_start = Empty,
+        nonce_end = Empty,
+        tx = Empty,
+    )
+}
+
+/// Span of a relay without the source and target chain, e.g. header relay of beacon chain or
+/// the scanners of ECDSA rounds
+pub(crate) fn module_span(module: &str) -> Span {
+    tracing::info_span!(
+        target: "relay-e2e",
+        "relay",
+        module = module,
+        source = Empty,
+        target = Empty,
+        nonce_start = Empty,
+        nonce_end = Empty,
+        tx = Empty,
+    )
+}
+
+pub(crate) fn record_nonces(start: u64, end: u64) {
+    let span = Span::current();
+    span.record("nonce_start", start);
+    span.record("nonce_end", end);
+}
+
+pub(crate) fn record_tx(hash: impl Debug) {
+    Span::current().record("tx", &debug(hash));
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use client_beacon::client::{ApiSupplier, BeaconApiClient};
+use client_contracts::FailoverHttp;
+use futures::StreamExt;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use web3::{
+    contract::Contract,
+    transports::WebSocket,
+    types::{Address, FilterBuilder},
+    Web3,
+};
+
+use crate::error::{RelayError, RelayResult};
+
+/// Wait before subscribing again after a subscription dropped
+const RESUBSCRIBE_INTERVAL: u64 = 30;
+
+/// Wakes a relay runner when a subscribed event arrives, or when the polling
+/// interval elapses. Polling keeps the runner going while subscriptions are down.
+pub struct RelayTrigger {
+    interval: Duration,
+    notify: Arc<Notify>,
+    subscriptions: Vec<JoinHandle<()>>,
+}
+
+impl RelayTrigger {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            notify: Arc::new(Notify::new()),
+            subscriptions: Vec::new(),
+        }
+    }
+
+    /// Wait for the next subscribed event or the polling interval
+    pub async fn wait(&self) {
+        let _ = tokio::time::timeout(self.interval, self.notify.notified()).await;
+    }
+
+    /// Wake on every new block of the chain
+    pub fn subscribe_new_heads(&mut self, ws_endpoint: &str) {
+        let notify = self.notify.clone();
+        let ws_endpoint = ws_endpoint.to_string();
+        self.spawn(format!("newHeads of {}", ws_endpoint), move || {
+            let notify = notify.clone();
+            let ws_endpoint = ws_endpoint.clone();
+            async move {
+                let client = Web3::new(WebSocket::new(&ws_endpoint).await?);
+                let mut heads = client.eth_subscribe().subscribe_new_heads().await?;
+                while let Some(head) = heads.next().await {
+                    head?;
+                    notify.notify_one();
+                }
+                Ok(())
+            }
+        });
+    }
+
+    /// Wake on every log of `event` emitted by the contract
+    pub fn subscribe_contract_event(
+        &mut self,
+        ws_endpoint: &str,
+        contract: &Contract<FailoverHttp>,
+        event: &str,
+    ) -> RelayResult<()> {
+        let signature = contract
+            .abi()
+            .event(event)
+            .map_err(|e| RelayError::Custom(format!("Unknown event {}: {:?}", event, e)))?
+            .signature();
+        let address: Address = contract.address();
+        let notify = self.notify.clone();
+        let ws_endpoint = ws_endpoint.to_string();
+        self.spawn(format!("{} logs of {:?}", event, address), move || {
+            let notify = notify.clone();
+            let ws_endpoint = ws_endpoint.clone();
+            let filter = FilterBuilder::default()
+                .address(vec![address])
+                .topics(Some(vec![signature]), None, None, None)
+                .build();
+            async move {
+                let client = Web3::new(WebSocket::new(&ws_endpoint).await?);
+                let mut logs = client.eth_subscribe().subscribe_logs(filter).await?;
+                while let Some(log) = logs.next().await {
+                    log?;
+                    notify.notify_one();
+                }
+                Ok(())
+            }
+        });
+        Ok(())
+    }
+
+    /// Wake on beacon node server-sent events of the given topics
+    pub fn subscribe_beacon_events(
+        &mut self,
+        api_endpoint: &str,
+        api_supplier: ApiSupplier,
+        topics: &'static [&'static str],
+    ) {
+        let notify = self.notify.clone();
+        let api_endpoint = api_endpoint.to_string();
+        self.spawn(format!("beacon events {:?}", topics), move || {
+            let notify = notify.clone();
+            let api_endpoint = api_endpoint.clone();
+            async move {
+                let client = BeaconApiClient::new(&api_endpoint, api_supplier)?;
+                let mut events = client.subscribe_events(topics).await?;
+                while let Some(topic) = events.next().await {
+                    tracing::trace!(target: "relay-e2e", "Received beacon event {}", topic?);
+                    notify.notify_one();
+                }
+                Ok(())
+            }
+        });
+    }
+
+    fn spawn<F, Fut>(&mut self, name: String, subscribe: F)
+    where
+        F: Fn() -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = RelayResult<()>> + Send + 'static,
+    {
+        let handle = tokio::spawn(async move {
+            loop {
+                tracing::info!(target: "relay-e2e", "Subscribing {}", name);
+                match subscribe().await {
+                    Ok(_) => tracing::warn!(
+                        target: "relay-e2e",
+                        "Subscription of {} closed, fall back to polling",
+                        name,
+                    ),
+                    Err(e) => tracing::warn!(
+                        target: "relay-e2e",
+                        "Subscription of {} dropped, fall back to polling: {:?}",
+                        name,
+                        e,
+                    ),
+                }
+                tokio::time::sleep(Duration::from_secs(RESUBSCRIBE_INTERVAL)).await;
+            }
+        });
+        self.subscriptions.push(handle);
+    }
+}
+
+impl Drop for RelayTrigger {
+    fn drop(&mut self) {
+        self.subscriptions.iter().for_each(|v| v.abort());
+    }
+}
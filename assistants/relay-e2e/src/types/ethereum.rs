@@ -1,10 +1,11 @@
-use secp256k1::SecretKey;
-use web3::signing::{Key, SecretKeyRef};
+use std::str::FromStr;
+
+use client_contracts::EvmSigner;
 use web3::types::Address;
 
 use crate::error::{RelayError, RelayResult};
 
-/// fast ethereum account, provide a text seed
+/// fast ethereum account, provide a text seed or a remote signer
 #[derive(Clone)]
 pub struct FastEthereumAccount {
     seed: String,
@@ -13,46 +14,32 @@ pub struct FastEthereumAccount {
 impl FastEthereumAccount {
     pub fn new(seed: impl AsRef<str>) -> Self {
         let seed = seed.as_ref();
-        let mut seed = seed.to_string();
-        if seed.contains("0x") {
-            seed = seed.replace("0x", "");
-        }
+        let seed = seed.strip_prefix("0x").unwrap_or(seed).to_string();
         Self { seed }
     }
 }
 
 impl FastEthereumAccount {
-    /// get secret key
-    pub fn secret_key(&self) -> RelayResult<SecretKey> {
-        let private_key = array_bytes::hex2bytes(&self.seed)?;
-        let secret_key = SecretKey::from_slice(&private_key)?;
-        Ok(secret_key)
+    /// get signer
+    pub fn signer(&self) -> RelayResult<EvmSigner> {
+        Ok(EvmSigner::from_str(&self.seed)?)
     }
 
     /// ethereum address
     pub fn address(&self) -> RelayResult<Address> {
-        let secret_key = self.secret_key()?;
-        let secret_key_ref = SecretKeyRef::new(&secret_key);
-        let address = secret_key_ref.address();
-        Ok(address)
+        Ok(self.signer()?.address())
     }
 
     /// sign message
     pub fn sign(&self, message: &[u8]) -> RelayResult<Vec<u8>> {
-        let secret_key = self.secret_key()?;
-        let secret_key_ref = SecretKeyRef::new(&secret_key);
-        let signature = secret_key_ref
-            .sign_message(message)
-            .map_err(|e| RelayError::Custom(format!("Sign message error: {:?}", e)))?;
-        let v = signature
-            .v
+        let message: [u8; 32] = message
             .try_into()
-            .expect("signature recovery in electrum notation always fits in a u8");
-
-        let mut bytes = Vec::with_capacity(65);
-        bytes.extend_from_slice(signature.r.as_bytes());
-        bytes.extend_from_slice(signature.s.as_bytes());
-        bytes.push(v);
-        Ok(bytes)
+            .map_err(|_| RelayError::Custom("Sign message error: not a 32 bytes hash".into()))?;
+        let signature = self
+            .signer()?
+            .inner()
+            .sign_message(&message)
+            .map_err(|e| RelayError::Custom(format!("Sign message error: {:?}", e)))?;
+        Ok(signature.0.to_vec())
     }
 }
@@ -0,0 +1,27 @@
+use bridge_s2s_traits::client::S2SClientGeneric;
+
+use crate::error::{RelayError, RelayResult};
+use crate::submitter::Submitters;
+
+/// Leave the runner once the endpoint connected by the client is degraded, the service
+/// restarts it with clients connected to the healthiest endpoints
+pub(crate) fn ensure_endpoint_healthy<C: S2SClientGeneric>(client: &C) -> RelayResult<()> {
+    if client.is_endpoint_degraded() {
+        return Err(RelayError::Custom(format!(
+            "the connected endpoint of {} is degraded, reconnect",
+            C::CHAIN
+        )));
+    }
+    Ok(())
+}
+
+/// Check the endpoints connected by clients of all relayer accounts, the extra accounts may
+/// connect to other endpoints than the client of signer
+pub(crate) fn ensure_submitters_healthy<C: S2SClientGeneric + Clone>(
+    submitters: &Submitters<C>,
+) -> RelayResult<()> {
+    for client in submitters.clients() {
+        ensure_endpoint_healthy(client)?;
+    }
+    Ok(())
+}
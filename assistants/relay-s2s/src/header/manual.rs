@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use bridge_s2s_traits::client::S2SClientGeneric;
+use bridge_s2s_traits::types::bp_runtime::Chain;
+use sp_runtime::ConsensusEngineId;
+use subquery::Subquery;
+
+use support_toolkit::convert::SmartCodecMapper;
+
+use crate::error::{RelayError, RelayResult};
+use crate::types::HeaderSelector;
+
+const GRANDPA_ENGINE_ID: ConsensusEngineId = *b"FRNK";
+
+/// Find hash and grandpa justification of the header, the justification is read from the
+/// block, or from the indexer when the chain doesn't keep it
+pub(crate) async fn find_grandpa_justification<C: S2SClientGeneric>(
+    client: &C,
+    subquery: &Subquery,
+    block: HeaderSelector,
+) -> RelayResult<(String, Vec<u8>)> {
+    let block_hash: <C::Chain as Chain>::Hash = match block {
+        HeaderSelector::Number(number) => {
+            let expected_number = SmartCodecMapper::map_to(&number)?;
+            client.block_hash(expected_number).await?.ok_or_else(|| {
+                RelayError::Custom(format!("Not found block {} in {}", number, C::CHAIN))
+            })?
+        }
+        HeaderSelector::Hash(hash) => {
+            let hash = sp_core::H256::from_str(&hash)
+                .map_err(|e| RelayError::Custom(format!("Wrong block hash [{hash}] {e:?}")))?;
+            SmartCodecMapper::map_to(&hash)?
+        }
+    };
+    let block_hex = array_bytes::bytes2hex("0x", block_hash);
+    let block = client.block(Some(block_hash)).await?.ok_or_else(|| {
+        RelayError::Custom(format!("Not found block {} in {}", block_hex, C::CHAIN))
+    })?;
+    if let Some(justification) = block
+        .justifications
+        .and_then(|v| v.into_justification(GRANDPA_ENGINE_ID))
+    {
+        return Ok((block_hex, justification));
+    }
+    let justification = subquery
+        .find_justification(&block_hex, true)
+        .await?
+        .ok_or_else(|| {
+            RelayError::Custom(format!(
+                "Not found justification of block {} in {}",
+                block_hex,
+                C::CHAIN,
+            ))
+        })?;
+    Ok((block_hex, justification.justification))
+}
@@ -1,10 +1,14 @@
 use bridge_s2s_traits::client::{S2SParaBridgeClientRelaychain, S2SParaBridgeClientSolochain};
 use bridge_s2s_traits::types::ParaId;
 use sp_runtime::traits::Header;
+use tracing::Instrument;
 
-use support_toolkit::{convert::SmartCodecMapper, logk};
+use support_toolkit::convert::SmartCodecMapper;
 
+use crate::endpoint;
 use crate::error::{RelayError, RelayResult};
+use crate::keepstate;
+use crate::span;
 use crate::types::{ParaHeaderInput, M_PARA_HEAD};
 
 /// para head to solo chain header relay runner
@@ -21,12 +25,35 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
 impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHeaderRunner<SC, TC> {
     pub async fn start(&self) -> RelayResult<()> {
         loop {
-            self.run().await?;
+            self.run()
+                .instrument(span::relay_span(M_PARA_HEAD, SC::CHAIN, TC::CHAIN))
+                .await?;
+            endpoint::ensure_endpoint_healthy(&self.input.client_relaychain)?;
+            endpoint::ensure_endpoint_healthy(&self.input.client_solochain)?;
             tokio::time::sleep(std::time::Duration::from_secs(2)).await;
         }
     }
 
     async fn run(&self) -> RelayResult<()> {
+        for chain in [SC::CHAIN, TC::CHAIN] {
+            if keepstate::is_runtime_incompatible(chain) {
+                tracing::warn!(
+                    target: "relay-s2s",
+                    "the runtime of {} is incompatible with the client, relay paused.",
+                    chain,
+                );
+                return Ok(());
+            }
+        }
+        if keepstate::is_submit_paused(TC::CHAIN) {
+            tracing::warn!(
+                target: "relay-s2s",
+                "the relayer balance at {} is below reserve, submitting paused.",
+                TC::CHAIN,
+            );
+            return Ok(());
+        }
+
         let client_solochain = &self.input.client_solochain;
         let client_relaychain = &self.input.client_relaychain;
 
@@ -36,8 +63,7 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
             .ok_or_else(|| RelayError::Custom(format!("Failed to get {} header", SC::CHAIN)))?;
         tracing::trace!(
             target: "relay-s2s",
-            "{} current {} block: {:?}",
-            logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+            "current {} block: {:?}",
             SC::CHAIN,
             best_target_header.number(),
         );
@@ -46,8 +72,7 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
             .await?;
         tracing::trace!(
             target: "relay-s2s",
-            "{} the last para-head on {}: {}",
-            logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+            "the last para-head on {}: {}",
             SC::CHAIN,
             if let Some(phat) = &para_head_at_target {
                 format!("{}@{}", phat.best_head_hash.at_relay_block_number, phat.best_head_hash.head_hash)
@@ -64,8 +89,7 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
             None => {
                 tracing::warn!(
                     target: "relay-s2s",
-                    "{} no best target finalized block queried from {} at block {}@{}",
-                    logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+                    "no best target finalized block queried from {} at block {}@{}",
                     TC::CHAIN,
                     best_target_header.number(),
                     best_target_header.hash(),
@@ -84,8 +108,7 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
             SmartCodecMapper::map_to(best_finalized_source_block_at_target.block.header.number())?;
         tracing::trace!(
             target: "relay-s2s",
-            "{} the last relaychain block on solochain: {:?}",
-            logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+            "the last relaychain block on solochain: {:?}",
             best_finalized_source_block_at_target_number,
         );
         let para_head_at_source = client_relaychain
@@ -93,8 +116,7 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
             .await?;
         tracing::trace!(
             target: "relay-s2s",
-            "{} the last para-head on relaychain {:?}",
-            logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+            "the last para-head on relaychain {:?}",
             best_finalized_source_block_at_target_number,
         );
 
@@ -119,16 +141,14 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
             (None, None) => {
                 tracing::info!(
                     target: "relay-s2s",
-                    "{} parachain is unknown to both clients",
-                    logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+                    "parachain is unknown to both clients",
                 );
                 false
             }
             (Some(_), Some(_)) => {
                 tracing::info!(
                     target: "relay-s2s",
-                    "{} not need to relay",
-                    logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+                    "not need to relay",
                 );
                 false
             }
@@ -146,8 +166,7 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
             .await?;
         tracing::info!(
             target: "relay-s2s",
-            "{} submitting parachain heads update transaction to {}",
-            logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+            "submitting parachain heads update transaction to {}",
             SC::CHAIN,
         );
 
@@ -161,10 +180,10 @@ impl<SC: S2SParaBridgeClientRelaychain, TC: S2SParaBridgeClientSolochain> ParaHe
                 heads_proofs,
             )
             .await?;
+        span::record_tx(hash);
         tracing::info!(
             target: "relay-s2s",
-            "{} the tx hash {} emitted",
-            logk::prefix_with_bridge(M_PARA_HEAD, SC::CHAIN, TC::CHAIN),
+            "the tx hash {} emitted",
             array_bytes::bytes2hex("0x", hash),
         );
         Ok(())
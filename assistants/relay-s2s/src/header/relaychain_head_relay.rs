@@ -6,11 +6,16 @@ use bridge_s2s_traits::types::bp_runtime::Chain;
 use sp_runtime::codec;
 use sp_runtime::traits::Header;
 use subquery::types::NeedRelayBlock;
+use tracing::Instrument;
 
-use support_toolkit::{convert::SmartCodecMapper, logk};
+use support_toolkit::convert::SmartCodecMapper;
 
+use crate::endpoint;
 use crate::error::{RelayError, RelayResult};
-use crate::types::{RelaychainHeaderInput, M_HEADER};
+use crate::header::manual::find_grandpa_justification;
+use crate::keepstate;
+use crate::span;
+use crate::types::{HeaderSelector, ManualRelayReport, RelaychainHeaderInput, M_HEADER};
 
 /// relay chain to solo chain header relay runner
 pub struct RelaychainHeaderRunner<SC: S2SClientGeneric, TC: S2SClientRelay> {
@@ -26,12 +31,35 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
 impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
     pub async fn start(&self) -> RelayResult<()> {
         loop {
-            self.run().await?;
+            self.run()
+                .instrument(span::relay_span(M_HEADER, SC::CHAIN, TC::CHAIN))
+                .await?;
+            endpoint::ensure_endpoint_healthy(&self.input.client_relaychain)?;
+            endpoint::ensure_endpoint_healthy(&self.input.client_solochain)?;
             tokio::time::sleep(std::time::Duration::from_secs(2)).await;
         }
     }
 
     async fn run(&self) -> RelayResult<()> {
+        for chain in [SC::CHAIN, TC::CHAIN] {
+            if keepstate::is_runtime_incompatible(chain) {
+                tracing::warn!(
+                    target: "relay-s2s",
+                    "the runtime of {} is incompatible with the client, relay paused.",
+                    chain,
+                );
+                return Ok(());
+            }
+        }
+        if keepstate::is_submit_paused(TC::CHAIN) {
+            tracing::warn!(
+                target: "relay-s2s",
+                "the relayer balance at {} is below reserve, submitting paused.",
+                TC::CHAIN,
+            );
+            return Ok(());
+        }
+
         let client_relaychain = &self.input.client_relaychain;
         let client_solochain = &self.input.client_solochain;
         let last_relayed_relaychain_block_in_solochain =
@@ -40,8 +68,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
                 None => {
                     tracing::warn!(
                         target: "relay-s2s",
-                        "{} the bridge not initialized, please init first.",
-                        logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                        "the bridge not initialized, please init first.",
                     );
                     return Ok(());
                 }
@@ -50,8 +77,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
             SmartCodecMapper::map_to(&last_relayed_relaychain_block_in_solochain.1)?;
         // tracing::debug!(
         //     target: "relay-s2s",
-        //     "{} get last relayed relaychain block hash: {:?}",
-        //     logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+        //     "get last relayed relaychain block hash: {:?}",
         //     array_bytes::bytes2hex("0x", expected_relaychain_hash.as_ref()),
         // );
         let last_relayed_relaychain_block_in_solochain = client_relaychain
@@ -71,8 +97,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
         let block_number: u32 = SmartCodecMapper::map_to(block_number)?;
         tracing::info!(
             target: "relay-s2s",
-            "{} get last relayed relaychain block number: {:?}",
-            logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+            "get last relayed relaychain block number: {:?}",
             block_number,
         );
 
@@ -88,8 +113,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
                 } else {
                     tracing::warn!(
                         target: "relay-s2s",
-                        "{} found mandatory header ({}) but you disabled relay it.",
-                        logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                        "found mandatory header ({}) but you disabled relay it.",
                         block_to_relay.block_number,
                     );
                 }
@@ -102,11 +126,29 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
         Ok(())
     }
 
+    /// Relay the header with its justification once, regardless of whether the header is
+    /// needed, used to push a stuck bridge by hand
+    pub async fn relay_header(
+        &self,
+        block: HeaderSelector,
+        dry_run: bool,
+    ) -> RelayResult<ManualRelayReport> {
+        let (block_hash, justification) = find_grandpa_justification(
+            &self.input.client_relaychain,
+            &self.input.subquery_relaychain,
+            block,
+        )
+        .await?;
+        self.submit_finality(block_hash, justification, dry_run)
+            .await
+    }
+
     async fn submit_finality(
         &self,
         block_hash: impl AsRef<str>,
         justification: Vec<u8>,
-    ) -> RelayResult<()> {
+        dry_run: bool,
+    ) -> RelayResult<ManualRelayReport> {
         let client_relaychain = &self.input.client_relaychain;
         let client_solochain = &self.input.client_solochain;
         let block_hash = block_hash.as_ref();
@@ -119,25 +161,33 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
             .ok_or_else(|| RelayError::Custom(format!("Not found header by hash: {block_hash}")))?;
         let grandpa_justification =
             sp_runtime::codec::Decode::decode(&mut justification.as_slice())?;
+        span::record_block(header.number());
+        let mut report = ManualRelayReport {
+            proof_size: codec::Encode::encoded_size(&header) + justification.len(),
+            ..Default::default()
+        };
+        if dry_run {
+            return Ok(report);
+        }
         let expected_header = SmartCodecMapper::map_to(&header)?;
         let hash = client_solochain
             .submit_finality_proof(expected_header, grandpa_justification)
             .await?;
+        span::record_tx(hash);
         tracing::info!(
             target: "relay-s2s",
-            "{} header relayed: {:?}",
-            logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+            "header relayed: {:?}",
             array_bytes::bytes2hex("0x", hash),
         );
-        Ok(())
+        report.extrinsic_hash = Some(array_bytes::bytes2hex("0x", hash));
+        Ok(report)
     }
 
     /// Try to relay mandatory headers, return Ok(Some(block_number)) if success, else Ok(None)
     async fn try_to_relay_mandatory(&self, block_to_relay: NeedRelayBlock) -> RelayResult<()> {
         tracing::info!(
             target: "relay-s2s",
-            "{} the next mandatory block: {:?}",
-            logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+            "the next mandatory block: {:?}",
             &block_to_relay.block_number,
         );
         let subquery_relaychain = &self.input.subquery_relaychain;
@@ -150,8 +200,12 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
                     &block_to_relay.block_hash
                 ))
             })?;
-        self.submit_finality(block_to_relay.block_hash, justification.justification)
-            .await?;
+        self.submit_finality(
+            block_to_relay.block_hash,
+            justification.justification,
+            false,
+        )
+        .await?;
 
         Ok(())
     }
@@ -167,8 +221,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
         let next_para_header = next_para_header.expect("Unreachable");
         tracing::trace!(
             target: "relay-s2s",
-            "{} queryied last need relay parachain header is {}",
-            logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+            "queryied last need relay parachain header is {}",
             next_para_header.block_number,
         );
 
@@ -179,8 +232,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
             .filter(|header| {
                 tracing::debug!(
                     target: "relay-s2s",
-                    "{} get related realy chain header: {}, last relayed header at solochain is {}",
-                    logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                    "get related realy chain header: {}, last relayed header at solochain is {}",
                     header.included_relay_block,
                     last_block_number,
                 );
@@ -190,8 +242,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
         if next_header.is_none() {
             tracing::debug!(
                 target: "relay-s2s",
-                "{} para head has not been finalized",
-                logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                "para head has not been finalized",
             );
             return Ok(());
         }
@@ -209,8 +260,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
                 })?;
                 tracing::debug!(
                     target: "relay-s2s",
-                    "{} found justification at block {} and last block number is {}",
-                    logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                    "found justification at block {} and last block number is {}",
                     grandpa_justification.commit.target_number,
                     last_block_number,
                 );
@@ -224,6 +274,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
                             grandpa_justification.commit.target_hash.as_ref(),
                         ),
                         justification.to_vec(),
+                        false,
                     )
                     .await?;
                 }
@@ -231,8 +282,7 @@ impl<SC: S2SClientGeneric, TC: S2SClientRelay> RelaychainHeaderRunner<SC, TC> {
             None => {
                 tracing::warn!(
                     target: "relay-s2s",
-                    "{} found on-demand block {}, but not have justification to relay.",
-                    logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                    "found on-demand block {}, but not have justification to relay.",
                     next_header.para_head,
                 );
             }
@@ -6,10 +6,15 @@ use bridge_s2s_traits::types::bp_runtime::Chain;
 use sp_runtime::codec;
 use sp_runtime::traits::Header;
 use subquery::types::NeedRelayBlock;
-use support_toolkit::{convert::SmartCodecMapper, logk};
+use support_toolkit::convert::SmartCodecMapper;
+use tracing::Instrument;
 
+use crate::endpoint;
 use crate::error::{RelayError, RelayResult};
-use crate::types::{SolochainHeaderInput, M_HEADER};
+use crate::header::manual::find_grandpa_justification;
+use crate::keepstate;
+use crate::span;
+use crate::types::{HeaderSelector, ManualRelayReport, SolochainHeaderInput, M_HEADER};
 
 /// solo chain to solo chain header relay runner
 pub struct SolochainHeaderRunner<SC: S2SClientRelay, TC: S2SClientRelay> {
@@ -26,12 +31,35 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
     /// start header relay
     pub async fn start(&self) -> RelayResult<()> {
         loop {
-            self.run().await?;
+            self.run()
+                .instrument(span::relay_span(M_HEADER, SC::CHAIN, TC::CHAIN))
+                .await?;
+            endpoint::ensure_endpoint_healthy(&self.input.client_source)?;
+            endpoint::ensure_endpoint_healthy(&self.input.client_target)?;
             tokio::time::sleep(std::time::Duration::from_secs(2)).await;
         }
     }
 
     async fn run(&self) -> RelayResult<()> {
+        for chain in [SC::CHAIN, TC::CHAIN] {
+            if keepstate::is_runtime_incompatible(chain) {
+                tracing::warn!(
+                    target: "relay-s2s",
+                    "the runtime of {} is incompatible with the client, relay paused.",
+                    chain,
+                );
+                return Ok(());
+            }
+        }
+        if keepstate::is_submit_paused(TC::CHAIN) {
+            tracing::warn!(
+                target: "relay-s2s",
+                "the relayer balance at {} is below reserve, submitting paused.",
+                TC::CHAIN,
+            );
+            return Ok(());
+        }
+
         let client_source = &self.input.client_source;
         let client_target = &self.input.client_target;
 
@@ -41,8 +69,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
                 None => {
                     tracing::warn!(
                         target: "relay-s2s",
-                        "{} the bridge not initialized, please init first.",
-                        logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                        "the bridge not initialized, please init first.",
                     );
                     return Ok(());
                 }
@@ -64,8 +91,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
         let block_number: u32 = SmartCodecMapper::map_to(block_number)?;
         tracing::trace!(
             target: "relay-s2s",
-            "{} the last relayed {} block is: {:?}",
-            logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+            "the last relayed {} block is: {:?}",
             SC::CHAIN,
             block_number,
         );
@@ -80,8 +106,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
                 } else {
                     tracing::warn!(
                         target: "relay-s2s",
-                        "{} found mandatory header ({}) but you disabled relay it.",
-                        logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                        "found mandatory header ({}) but you disabled relay it.",
                         block_to_relay.block_number,
                     );
                 }
@@ -94,11 +119,29 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
         Ok(())
     }
 
+    /// Relay the header with its justification once, regardless of whether the header is
+    /// needed, used to push a stuck bridge by hand
+    pub async fn relay_header(
+        &self,
+        block: HeaderSelector,
+        dry_run: bool,
+    ) -> RelayResult<ManualRelayReport> {
+        let (block_hash, justification) = find_grandpa_justification(
+            &self.input.client_source,
+            &self.input.subquery_source,
+            block,
+        )
+        .await?;
+        self.submit_finality(block_hash, justification, dry_run)
+            .await
+    }
+
     async fn submit_finality(
         &self,
         block_hash: impl AsRef<str>,
         justification: Vec<u8>,
-    ) -> RelayResult<()> {
+        dry_run: bool,
+    ) -> RelayResult<ManualRelayReport> {
         let client_source = &self.input.client_source;
         let client_target = &self.input.client_target;
         let block_hash = block_hash.as_ref();
@@ -111,24 +154,32 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
             .ok_or_else(|| RelayError::Custom(format!("Not found header by hash: {block_hash}")))?;
         let grandpa_justification =
             sp_runtime::codec::Decode::decode(&mut justification.as_slice())?;
+        span::record_block(header.number());
+        let mut report = ManualRelayReport {
+            proof_size: codec::Encode::encoded_size(&header) + justification.len(),
+            ..Default::default()
+        };
+        if dry_run {
+            return Ok(report);
+        }
         let expected_header = SmartCodecMapper::map_to(&header)?;
         let hash = client_target
             .submit_finality_proof(expected_header, grandpa_justification)
             .await?;
+        span::record_tx(hash);
         tracing::info!(
             target: "relay-s2s",
-            "{} header relayed: {:?}",
-            logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+            "header relayed: {:?}",
             array_bytes::bytes2hex("0x", hash),
         );
-        Ok(())
+        report.extrinsic_hash = Some(array_bytes::bytes2hex("0x", hash));
+        Ok(report)
     }
 
     async fn try_to_relay_mandatory(&self, block_to_relay: NeedRelayBlock) -> RelayResult<()> {
         tracing::info!(
             target: "relay-s2s",
-            "{} the next mandatory block: {:?} ",
-            logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+            "the next mandatory block: {:?} ",
             &block_to_relay.block_number
         );
         let subquery_source = &self.input.subquery_source;
@@ -141,8 +192,12 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
                     &block_to_relay.block_hash
                 ))
             })?;
-        self.submit_finality(block_to_relay.block_hash, justification.justification)
-            .await?;
+        self.submit_finality(
+            block_to_relay.block_hash,
+            justification.justification,
+            false,
+        )
+        .await?;
 
         Ok(())
     }
@@ -157,8 +212,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
                 if v.block_number <= last_block_number {
                     tracing::debug!(
                         target: "relay-s2s",
-                        "{} the last storage block ({}) is less or equal last relayed block ({}). nothing to do.",
-                        logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                        "the last storage block ({}) is less or equal last relayed block ({}). nothing to do.",
                         v.block_number,
                         last_block_number,
                     );
@@ -169,16 +223,14 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
             None => {
                 tracing::debug!(
                     target: "relay-s2s",
-                    "{} try relay header on-demand, but not found any on-demand block",
-                    logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                    "try relay header on-demand, but not found any on-demand block",
                 );
                 return Ok(());
             }
         };
         tracing::debug!(
             target: "relay-s2s",
-            "{} try relay header on-demand, the on-demand block is {}",
-            logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+            "try relay header on-demand, the on-demand block is {}",
             next_header.block_number,
         );
 
@@ -186,8 +238,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
             Some(justification) => {
                 tracing::trace!(
                     target: "relay-s2s",
-                    "{} found on-demand block {}, and found new justification, ready to relay header",
-                    logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                    "found on-demand block {}, and found new justification, ready to relay header",
                     next_header.block_number,
                 );
                 let grandpa_justification: bp_header_chain::justification::GrandpaJustification<
@@ -205,6 +256,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
                     self.submit_finality(
                         array_bytes::bytes2hex("", grandpa_justification.commit.target_hash),
                         justification.to_vec(),
+                        false,
                     )
                     .await?;
                 }
@@ -212,8 +264,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay> SolochainHeaderRunner<SC, TC> {
             None => {
                 tracing::warn!(
                     target: "relay-s2s",
-                    "{} found on-demand block {}, but not have justification to relay.",
-                    logk::prefix_with_bridge(M_HEADER, SC::CHAIN, TC::CHAIN),
+                    "found on-demand block {}, but not have justification to relay.",
                     next_header.block_number,
                 );
             }
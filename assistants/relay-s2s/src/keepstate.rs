@@ -4,35 +4,112 @@ use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
 
+use crate::types::LaneId;
+
 static LAST_RELAYED_NONCE: Lazy<Mutex<HashMap<String, u64>>> = Lazy::new(|| {
     let map = HashMap::new();
     Mutex::new(map)
 });
 
+static SUBMIT_PAUSED: Lazy<Mutex<HashMap<String, bool>>> = Lazy::new(|| {
+    let map = HashMap::new();
+    Mutex::new(map)
+});
+
+static SIGNER_PAUSED: Lazy<Mutex<HashMap<(String, usize), bool>>> = Lazy::new(|| {
+    let map = HashMap::new();
+    Mutex::new(map)
+});
+
+static RUNTIME_INCOMPATIBLE: Lazy<Mutex<HashMap<String, Vec<String>>>> = Lazy::new(|| {
+    let map = HashMap::new();
+    Mutex::new(map)
+});
+
 static RECENTLY_JUSTIFICATIONS: Lazy<Mutex<HashMap<&str, VecDeque<sp_core::Bytes>>>> =
     Lazy::new(|| {
         let map = HashMap::new();
         Mutex::new(map)
     });
 
-pub fn get_last_delivery_relayed_nonce(chain: &str) -> Option<u64> {
+fn relayed_nonce_key(chain: &str, lane: LaneId, direction: &str) -> String {
+    format!("{chain}-{}-{direction}", array_bytes::bytes2hex("", lane))
+}
+
+pub fn get_last_delivery_relayed_nonce(chain: &str, lane: LaneId) -> Option<u64> {
     let data = LAST_RELAYED_NONCE.lock().unwrap();
-    data.get(&format!("{chain}-delivery")).cloned()
+    data.get(&relayed_nonce_key(chain, lane, "delivery"))
+        .cloned()
+}
+
+pub fn set_last_delivery_relayed_nonce(chain: &str, lane: LaneId, nonce: u64) {
+    let mut data = LAST_RELAYED_NONCE.lock().unwrap();
+    data.insert(relayed_nonce_key(chain, lane, "delivery"), nonce);
 }
 
-pub fn set_last_delivery_relayed_nonce(chain: &str, nonce: u64) {
+/// forget the delivery relayed nonce of lane, the next round reads it from chain again
+pub fn clear_last_delivery_relayed_nonce(chain: &str, lane: LaneId) {
     let mut data = LAST_RELAYED_NONCE.lock().unwrap();
-    data.insert(format!("{chain}-delivery"), nonce);
+    data.remove(&relayed_nonce_key(chain, lane, "delivery"));
 }
 
-pub fn get_last_receiving_relayed_nonce(chain: &str) -> Option<u64> {
+pub fn get_last_receiving_relayed_nonce(chain: &str, lane: LaneId) -> Option<u64> {
     let data = LAST_RELAYED_NONCE.lock().unwrap();
-    data.get(&format!("{chain}-receiving")).cloned()
+    data.get(&relayed_nonce_key(chain, lane, "receiving"))
+        .cloned()
 }
 
-pub fn set_last_receiving_relayed_nonce(chain: &str, nonce: u64) {
+pub fn set_last_receiving_relayed_nonce(chain: &str, lane: LaneId, nonce: u64) {
     let mut data = LAST_RELAYED_NONCE.lock().unwrap();
-    data.insert(format!("{chain}-receiving"), nonce);
+    data.insert(relayed_nonce_key(chain, lane, "receiving"), nonce);
+}
+
+/// forget the receiving relayed nonce of lane, the next round reads it from chain again
+pub fn clear_last_receiving_relayed_nonce(chain: &str, lane: LaneId) {
+    let mut data = LAST_RELAYED_NONCE.lock().unwrap();
+    data.remove(&relayed_nonce_key(chain, lane, "receiving"));
+}
+
+/// pause or resume extrinsic submission to chain, used when relayer balance is below reserve
+pub fn set_submit_paused(chain: &str, paused: bool) {
+    let mut data = SUBMIT_PAUSED.lock().unwrap();
+    data.insert(chain.to_string(), paused);
+}
+
+pub fn is_submit_paused(chain: &str) -> bool {
+    let data = SUBMIT_PAUSED.lock().unwrap();
+    data.get(chain).cloned().unwrap_or_default()
+}
+
+/// pause or resume submission of an extra signer, the index is of submitters and the signer
+/// is skipped when picking an idle submitter
+pub fn set_signer_paused(chain: &str, index: usize, paused: bool) {
+    let mut data = SIGNER_PAUSED.lock().unwrap();
+    data.insert((chain.to_string(), index), paused);
+}
+
+pub fn is_signer_paused(chain: &str, index: usize) -> bool {
+    let data = SIGNER_PAUSED.lock().unwrap();
+    data.get(&(chain.to_string(), index))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// record the runtime items of chain which are incompatible with the client codegen, relays
+/// reading or writing this chain are paused until no item is incompatible
+pub fn set_runtime_incompatible(chain: &str, items: Vec<String>) {
+    let mut data = RUNTIME_INCOMPATIBLE.lock().unwrap();
+    data.insert(chain.to_string(), items);
+}
+
+pub fn get_runtime_incompatible(chain: &str) -> Vec<String> {
+    let data = RUNTIME_INCOMPATIBLE.lock().unwrap();
+    data.get(chain).cloned().unwrap_or_default()
+}
+
+pub fn is_runtime_incompatible(chain: &str) -> bool {
+    let data = RUNTIME_INCOMPATIBLE.lock().unwrap();
+    data.get(chain).map(|v| !v.is_empty()).unwrap_or_default()
 }
 
 pub fn set_recently_justification(chain: &'static str, justification: sp_core::Bytes) {
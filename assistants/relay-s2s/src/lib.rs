@@ -1,9 +1,12 @@
 pub mod error;
 pub mod header;
+pub mod keepstate;
 pub mod message;
+pub mod submitter;
 pub mod subscribe;
 pub mod types;
 
-mod keepstate;
+mod endpoint;
+mod span;
 mod special;
 mod strategy;
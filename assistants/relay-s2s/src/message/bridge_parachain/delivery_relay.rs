@@ -1,10 +1,12 @@
+use std::ops::RangeInclusive;
+
 use bridge_s2s_traits::client::{S2SClientRelay, S2SParaBridgeClientSolochain};
 use bridge_s2s_traits::strategy::RelayStrategy;
 
 use crate::error::RelayResult;
 use crate::message::common::CommonDeliveryRunner;
 use crate::special::ParachainSpecialClientApi;
-use crate::types::MessageDeliveryInput;
+use crate::types::{LaneId, ManualRelayOptions, ManualRelayReport, MessageDeliveryInput};
 
 pub struct BridgeParachainDeliveryRunner<SC, TC, Strategy>
 where
@@ -33,4 +35,13 @@ where
     pub async fn start(&self) -> RelayResult<()> {
         self.common.start().await
     }
+
+    pub async fn deliver(
+        &self,
+        lane: LaneId,
+        nonces: RangeInclusive<u64>,
+        options: &ManualRelayOptions,
+    ) -> RelayResult<ManualRelayReport> {
+        self.common.deliver(lane, nonces, options).await
+    }
 }
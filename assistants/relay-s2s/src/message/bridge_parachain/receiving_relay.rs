@@ -3,7 +3,7 @@ use bridge_s2s_traits::client::{S2SClientRelay, S2SParaBridgeClientSolochain};
 use crate::error::RelayResult;
 use crate::message::common::CommonReceivingRunner;
 use crate::special::ParachainSpecialClientApi;
-use crate::types::MessageReceivingInput;
+use crate::types::{LaneId, ManualRelayOptions, ManualRelayReport, MessageReceivingInput};
 
 pub struct BridgeParachainReceivingRunner<SC, TC>
 where
@@ -30,4 +30,12 @@ where
     pub async fn start(&self) -> RelayResult<()> {
         self.common.start().await
     }
+
+    pub async fn confirm(
+        &self,
+        lane: LaneId,
+        options: &ManualRelayOptions,
+    ) -> RelayResult<ManualRelayReport> {
+        self.common.confirm(lane, options).await
+    }
 }
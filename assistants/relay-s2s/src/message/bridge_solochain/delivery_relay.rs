@@ -1,10 +1,12 @@
+use std::ops::RangeInclusive;
+
 use bridge_s2s_traits::client::S2SClientRelay;
 use bridge_s2s_traits::strategy::RelayStrategy;
 
 use crate::error::RelayResult;
 use crate::message::common::CommonDeliveryRunner;
 use crate::special::SolochainSpecialClientApi;
-use crate::types::MessageDeliveryInput;
+use crate::types::{LaneId, ManualRelayOptions, ManualRelayReport, MessageDeliveryInput};
 
 pub struct BridgeSolochainDeliveryRunner<SC, TC, Strategy>
 where
@@ -32,4 +34,13 @@ where
     pub async fn start(&self) -> RelayResult<()> {
         self.common.start().await
     }
+
+    pub async fn deliver(
+        &self,
+        lane: LaneId,
+        nonces: RangeInclusive<u64>,
+        options: &ManualRelayOptions,
+    ) -> RelayResult<ManualRelayReport> {
+        self.common.deliver(lane, nonces, options).await
+    }
 }
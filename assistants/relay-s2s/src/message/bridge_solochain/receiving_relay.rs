@@ -3,7 +3,7 @@ use bridge_s2s_traits::client::S2SClientRelay;
 use crate::error::RelayResult;
 use crate::message::common::CommonReceivingRunner;
 use crate::special::SolochainSpecialClientApi;
-use crate::types::MessageReceivingInput;
+use crate::types::{LaneId, ManualRelayOptions, ManualRelayReport, MessageReceivingInput};
 
 pub struct BridgeSolochainReceivingRunner<SC, TC>
 where
@@ -29,4 +29,12 @@ where
     pub async fn start(&self) -> RelayResult<()> {
         self.common.start().await
     }
+
+    pub async fn confirm(
+        &self,
+        lane: LaneId,
+        options: &ManualRelayOptions,
+    ) -> RelayResult<ManualRelayReport> {
+        self.common.confirm(lane, options).await
+    }
 }
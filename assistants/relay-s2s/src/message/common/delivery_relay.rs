@@ -3,16 +3,23 @@ use std::ops::RangeInclusive;
 use bridge_s2s_traits::client::S2SClientRelay;
 use bridge_s2s_traits::strategy::RelayStrategy;
 use bridge_s2s_traits::types::bp_messages::{OutboundLaneData, Weight};
+use bridge_s2s_traits::types::bp_runtime::Chain;
 use bridge_s2s_traits::types::bridge_runtime_common::messages::target::FromBridgedChainMessagesProof;
 use sp_runtime::traits::Header;
+use tracing::Instrument;
 
-use support_toolkit::{convert::SmartCodecMapper, logk};
+use support_toolkit::convert::SmartCodecMapper;
 
+use crate::endpoint;
 use crate::error::{RelayError, RelayResult};
 use crate::keepstate;
+use crate::span;
 use crate::special::DifferentClientApi;
 use crate::strategy::{EnforcementDecideReference, EnforcementRelayStrategy};
-use crate::types::{LaneId, MessageDeliveryInput, M_DELIVERY};
+use crate::submitter::Submissions;
+use crate::types::{
+    LaneId, ManualRelayOptions, ManualRelayReport, MessageDeliveryInput, M_DELIVERY,
+};
 
 pub struct CommonDeliveryRunner<SC, TC, DC, Strategy>
 where
@@ -23,6 +30,7 @@ where
 {
     different: DC,
     input: MessageDeliveryInput<SC, TC, Strategy>,
+    submissions: Submissions,
 }
 
 impl<SC, TC, DC, Strategy> CommonDeliveryRunner<SC, TC, DC, Strategy>
@@ -33,7 +41,11 @@ where
     Strategy: RelayStrategy,
 {
     pub fn new(input: MessageDeliveryInput<SC, TC, Strategy>, different: DC) -> Self {
-        Self { input, different }
+        Self {
+            input,
+            different,
+            submissions: Submissions::default(),
+        }
     }
 }
 
@@ -51,7 +63,7 @@ where
     }
 
     async fn last_delivered_nonce(&self, lane: LaneId) -> RelayResult<u64> {
-        if let Some(nonce) = keepstate::get_last_delivery_relayed_nonce(SC::CHAIN) {
+        if let Some(nonce) = keepstate::get_last_delivery_relayed_nonce(SC::CHAIN, lane) {
             return Ok(nonce);
         }
         let client_target = &self.input.client_target;
@@ -72,13 +84,7 @@ where
         );
         tracing::info!(
             target: "relay-s2s",
-            "{} sync status: [{},{}]",
-            logk::prefix_with_bridge_and_others(
-                M_DELIVERY,
-                SC::CHAIN,
-                TC::CHAIN,
-                vec![array_bytes::bytes2hex("0x", lane),],
-            ),
+            "sync status: [{},{}]",
             latest_confirmed_nonce,
             latest_generated_nonce,
         );
@@ -86,21 +92,14 @@ where
             return Ok(None);
         }
 
-        // assemble nonce range
-        let start: u64 = latest_confirmed_nonce + 1;
+        // assemble nonce range, continue after the nonces delivered or in-flight of the lane
         let last_relayed_nonce = self.last_delivered_nonce(lane).await?;
-        if last_relayed_nonce >= start {
-            tracing::warn!(
+        let start: u64 = latest_confirmed_nonce.max(last_relayed_nonce) + 1;
+        if start > latest_generated_nonce {
+            tracing::debug!(
                 target: "relay-s2s",
-                "{} last relayed nonce is {} but start nonce is {}, please wait receiving.",
-                logk::prefix_with_bridge_and_others(
-                    M_DELIVERY,
-                    SC::CHAIN,
-                    TC::CHAIN,
-                    vec![array_bytes::bytes2hex("0x", lane),],
-                ),
+                "the nonces to {} are delivered or in-flight, wait next round.",
                 last_relayed_nonce,
-                start,
             );
             return Ok(None);
         }
@@ -108,13 +107,7 @@ where
         let inclusive_limit = limit - 1;
         tracing::trace!(
             target: "relay-s2s",
-            "{} assemble nonces, start from {} and last generated is {}",
-            logk::prefix_with_bridge_and_others(
-                M_DELIVERY,
-                SC::CHAIN,
-                TC::CHAIN,
-                vec![array_bytes::bytes2hex("0x", lane),],
-            ),
+            "assemble nonces, start from {} and last generated is {}",
             start,
             latest_generated_nonce,
         );
@@ -126,6 +119,103 @@ where
         let nonces = start..=end;
         Ok(Some(nonces))
     }
+
+    /// number and hash of the last source block relayed to target chain, none if the bridge
+    /// isn't initialized
+    async fn last_relayed_source_block(
+        &self,
+    ) -> RelayResult<Option<(u32, <SC::Chain as Chain>::Hash)>> {
+        let last_relayed_source_block_in_target =
+            match self.different.best_target_finalized(None).await? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+        let expected_source_hash: <SC::Chain as Chain>::Hash =
+            SmartCodecMapper::map_to(&last_relayed_source_block_in_target.1)?;
+        let last_relayed_source_block_in_target = self
+            .input
+            .client_source
+            .block(Some(expected_source_hash))
+            .await?
+            .ok_or_else(|| {
+                RelayError::Custom(format!(
+                    "Failed to query block by [{}] in {}",
+                    array_bytes::bytes2hex("0x", expected_source_hash),
+                    SC::CHAIN,
+                ))
+            })?;
+        let relayed_block_number = last_relayed_source_block_in_target.block.header.number();
+        let relayed_block_number: u32 = SmartCodecMapper::map_to(relayed_block_number)?;
+        Ok(Some((relayed_block_number, expected_source_hash)))
+    }
+
+    /// read proof of the messages at the source block, returns it with the dispatch weight
+    async fn messages_proof(
+        &self,
+        lane: LaneId,
+        nonces: &RangeInclusive<u64>,
+        source_outbound_lane_data: &OutboundLaneData,
+        expected_source_hash: <SC::Chain as Chain>::Hash,
+    ) -> RelayResult<(
+        FromBridgedChainMessagesProof<<SC::Chain as Chain>::Hash>,
+        u64,
+    )> {
+        // alias
+        let client_source = &self.input.client_source;
+        let client_target = &self.input.client_target;
+
+        // read proof
+        let mut storage_keys = Vec::with_capacity((nonces.end() - nonces.start()) as usize + 1);
+        let mut message_nonce = *nonces.start();
+        while message_nonce <= *nonces.end() {
+            let message_key = client_source.gen_outbound_messages_storage_key(lane, message_nonce);
+            storage_keys.push(message_key);
+            message_nonce += 1;
+        }
+
+        //- query inbound land data
+        let target_inbound_lane_data = client_target.inbound_lanes(lane, None).await?;
+        let outbound_state_proof_required = target_inbound_lane_data.last_confirmed_nonce
+            < source_outbound_lane_data.latest_received_nonce;
+        if outbound_state_proof_required {
+            storage_keys.push(client_source.gen_outbound_lanes_storage_key(lane));
+        }
+
+        // fill delivery data
+        let total_weight = client_source
+            .calculate_dispatch_weight(lane, nonces.clone())
+            .await?;
+
+        // query last relayed  header
+        let proof = client_source
+            .read_proof(storage_keys, Some(expected_source_hash))
+            .await?;
+        let proof = FromBridgedChainMessagesProof {
+            bridged_header_hash: expected_source_hash,
+            storage_proof: proof,
+            lane,
+            nonces_start: *nonces.start(),
+            nonces_end: *nonces.end(),
+        };
+        Ok((proof, total_weight))
+    }
+
+    async fn decide(
+        &self,
+        lane: LaneId,
+        nonces: &RangeInclusive<u64>,
+        message_size: usize,
+        total_weight: u64,
+    ) -> RelayResult<bool> {
+        let reference = EnforcementDecideReference {
+            lane,
+            nonces: nonces.clone(),
+            message_size,
+            total_weight,
+        };
+        let mut relay_strategy = EnforcementRelayStrategy::new(self.input.relay_strategy.clone());
+        Ok(relay_strategy.decide(reference).await?)
+    }
 }
 
 impl<SC, TC, DC, Strategy> CommonDeliveryRunner<SC, TC, DC, Strategy>
@@ -136,31 +226,68 @@ where
     Strategy: RelayStrategy,
 {
     pub async fn start(&self) -> RelayResult<()> {
-        tracing::info!(
-            target: "relay-s2s",
-            "{} SERVICE RESTARTING...",
-            logk::prefix_with_bridge(M_DELIVERY, SC::CHAIN, TC::CHAIN),
-        );
+        span::relay_span(M_DELIVERY, SC::CHAIN, TC::CHAIN).in_scope(|| {
+            tracing::info!(
+                target: "relay-s2s",
+                "SERVICE RESTARTING...",
+            );
+        });
         loop {
             for lane in &self.input.lanes {
-                let last_relayed_nonce = self.run(*lane, self.input.nonces_limit).await?;
-                if last_relayed_nonce.is_some() {
-                    keepstate::set_last_delivery_relayed_nonce(
-                        SC::CHAIN,
-                        last_relayed_nonce.expect("Unreachable"),
-                    );
-                }
+                self.run(*lane, self.input.nonces_limit)
+                    .instrument(span::relay_span(M_DELIVERY, SC::CHAIN, TC::CHAIN))
+                    .await?;
+                self.submissions.check().await?;
+                endpoint::ensure_endpoint_healthy(&self.input.client_source)?;
+                endpoint::ensure_endpoint_healthy(&self.input.client_target)?;
+                endpoint::ensure_submitters_healthy(&self.input.submitters_target)?;
                 tokio::time::sleep(std::time::Duration::from_secs(20)).await;
             }
         }
     }
 
-    async fn run(&self, lane: LaneId, limit: u64) -> RelayResult<Option<u64>> {
+    async fn run(&self, lane: LaneId, limit: u64) -> RelayResult<()> {
+        span::record_lane(lane);
+        for chain in [SC::CHAIN, TC::CHAIN] {
+            if keepstate::is_runtime_incompatible(chain) {
+                tracing::warn!(
+                    target: "relay-s2s",
+                    "the runtime of {} is incompatible with the client, relay paused.",
+                    chain,
+                );
+                return Ok(());
+            }
+        }
+        if keepstate::is_submit_paused(TC::CHAIN) {
+            tracing::warn!(
+                target: "relay-s2s",
+                "the relayer balance at {} is below reserve, submitting paused.",
+                TC::CHAIN,
+            );
+            return Ok(());
+        }
+        if self.submissions.is_lane_pending(lane) {
+            tracing::debug!(
+                target: "relay-s2s",
+                "the last submission of this lane isn't finalized, wait next round.",
+            );
+            return Ok(());
+        }
+        let submitter = match self.input.submitters_target.acquire() {
+            Some(v) => v,
+            None => {
+                tracing::debug!(
+                    target: "relay-s2s",
+                    "all relayer accounts at {} have in-flight extrinsics, wait next round.",
+                    TC::CHAIN,
+                );
+                return Ok(());
+            }
+        };
+
         let source_outbound_lane_data = self.source_outbound_lane_data(lane).await?;
 
         // alias
-        let client_source = &self.input.client_source;
-        let client_target = &self.input.client_target;
         let subquery_source = &self.input.subquery_source;
 
         let nonces = match self
@@ -171,26 +298,15 @@ where
             None => {
                 tracing::debug!(
                     target: "relay-s2s",
-                    "{} all nonces delivered, nothing to do.",
-                    logk::prefix_with_bridge_and_others(
-                        M_DELIVERY,
-                        SC::CHAIN,
-                        TC::CHAIN,
-                        vec![array_bytes::bytes2hex("0x", lane),],
-                    ),
+                    "all nonces delivered, nothing to do.",
                 );
-                return Ok(None);
+                return Ok(());
             }
         };
+        span::record_nonces(&nonces);
         tracing::debug!(
             target: "relay-s2s",
-            "{} assembled nonces {:?}",
-            logk::prefix_with_bridge_and_others(
-                M_DELIVERY,
-                SC::CHAIN,
-                TC::CHAIN,
-                vec![array_bytes::bytes2hex("0x", lane),],
-            ),
+            "assembled nonces {:?}",
             nonces,
         );
 
@@ -203,149 +319,213 @@ where
             None => {
                 tracing::warn!(
                     target: "relay-s2s",
-                    "{} the last nonce({}) isn't storage by indexer for {} chain",
-                    logk::prefix_with_bridge_and_others(
-                        M_DELIVERY,
-                        SC::CHAIN,
-                        TC::CHAIN,
-                        vec![array_bytes::bytes2hex("0x", lane),],
-                    ),
+                    "the last nonce({}) isn't storage by indexer for {} chain",
                     nonces.end(),
                     SC::CHAIN,
                 );
-                return Ok(None);
+                return Ok(());
             }
         };
 
+        span::record_block(last_relay.block_number);
+
         // query last relayed header
-        let last_relayed_source_block_in_target =
-            match self.different.best_target_finalized(None).await? {
+        let (relayed_block_number, expected_source_hash) =
+            match self.last_relayed_source_block().await? {
                 Some(v) => v,
                 None => {
                     tracing::warn!(
                         target: "relay-s2s",
-                        "{} the bridge not initialized, please init first.",
-                        logk::prefix_with_bridge(M_DELIVERY, SC::CHAIN, TC::CHAIN),
+                        "the bridge not initialized, please init first.",
                     );
-                    return Ok(None);
+                    return Ok(());
                 }
             };
-        let expected_source_hash =
-            SmartCodecMapper::map_to(&last_relayed_source_block_in_target.1)?;
-        let last_relayed_source_block_in_target = client_source
-            .block(Some(expected_source_hash))
-            .await?
-            .ok_or_else(|| {
-                RelayError::Custom(format!(
-                    "Failed to query block by [{}] in {}",
-                    array_bytes::bytes2hex("0x", expected_source_hash),
-                    SC::CHAIN,
-                ))
-            })?;
 
         // compare last nonce block with last relayed header
-        let relayed_block_number = last_relayed_source_block_in_target.block.header.number();
-        let relayed_block_number: u32 = SmartCodecMapper::map_to(relayed_block_number)?;
         if relayed_block_number < last_relay.block_number {
             tracing::warn!(
                 target: "relay-s2s",
-                "{} the last nonce({}) at block {} is large than last relayed header {}, please wait header relay.",
-                logk::prefix_with_bridge_and_others(
-                    M_DELIVERY,
-                    SC::CHAIN,
-                    TC::CHAIN,
-                    vec![array_bytes::bytes2hex("0x", lane),],
-                ),
+                "the last nonce({}) at block {} is large than last relayed header {}, please wait header relay.",
                 nonces.end(),
                 last_relay.block_number,
                 relayed_block_number,
             );
-            return Ok(None);
-        }
-
-        // read proof
-        let mut storage_keys = Vec::with_capacity((nonces.end() - nonces.start()) as usize + 1);
-        let mut message_nonce = *nonces.start();
-        while message_nonce <= *nonces.end() {
-            let message_key = client_source.gen_outbound_messages_storage_key(lane, message_nonce);
-            storage_keys.push(message_key);
-            message_nonce += 1;
-        }
-
-        //- query inbound land data
-        let target_inbound_lane_data = client_target.inbound_lanes(lane, None).await?;
-        let outbound_state_proof_required = target_inbound_lane_data.last_confirmed_nonce
-            < source_outbound_lane_data.latest_received_nonce;
-        if outbound_state_proof_required {
-            storage_keys.push(client_source.gen_outbound_lanes_storage_key(lane));
+            return Ok(());
         }
 
-        // fill delivery data
-        let total_weight = client_source
-            .calculate_dispatch_weight(lane, nonces.clone())
-            .await?;
-
-        // query last relayed  header
-        let proof = client_source
-            .read_proof(storage_keys, Some(expected_source_hash))
+        let (proof, total_weight) = self
+            .messages_proof(
+                lane,
+                &nonces,
+                &source_outbound_lane_data,
+                expected_source_hash,
+            )
             .await?;
-        let message_size = proof.len();
-        let proof = FromBridgedChainMessagesProof {
-            bridged_header_hash: expected_source_hash,
-            storage_proof: proof,
-            lane,
-            nonces_start: *nonces.start(),
-            nonces_end: *nonces.end(),
-        };
 
         // relay strategy
-        let reference = EnforcementDecideReference {
-            lane,
-            nonces: nonces.clone(),
-            message_size,
-            total_weight,
-        };
-        let mut relay_strategy = EnforcementRelayStrategy::new(self.input.relay_strategy.clone());
-        if !relay_strategy.decide(reference).await? {
+        let message_size = proof.storage_proof.len();
+        if !self
+            .decide(lane, &nonces, message_size, total_weight)
+            .await?
+        {
             tracing::warn!(
                 target: "relay-s2s",
-                "{} the relay strategy decide not relay these nonces({:?})",
-                logk::prefix_with_bridge_and_others(
-                    M_DELIVERY,
-                    SC::CHAIN,
-                    TC::CHAIN,
-                    vec![array_bytes::bytes2hex("0x", lane),],
-                ),
+                "the relay strategy decide not relay these nonces({:?})",
                 nonces,
             );
-            return Ok(None);
+            return Ok(());
         }
 
         // submit messages proof to target chain
         let expected_proof = SmartCodecMapper::map_to(&proof)?;
         let relayer_account_source_chain = self.input.relayer_account.clone();
         let expected_relayer_id = SmartCodecMapper::map_to(&relayer_account_source_chain)?;
-        let hash = client_target
+        let messages_count = (nonces.end() - nonces.start() + 1) as u32;
+
+        // the nonces are marked as relayed before submitting, the next round of this lane
+        // continues with the next nonces once the submission is finalized, other lanes don't
+        // wait for it. it's reverted if the submission failed.
+        keepstate::set_last_delivery_relayed_nonce(SC::CHAIN, lane, *nonces.end());
+        let submit = async move {
+            let result = submitter
+                .receive_messages_proof(
+                    expected_relayer_id,
+                    expected_proof,
+                    messages_count,
+                    Weight::from_ref_time(total_weight),
+                )
+                .await;
+            match result {
+                Ok(hash) => {
+                    span::record_tx(hash);
+                    tracing::info!(
+                        target: "relay-s2s",
+                        "the nonces {:?} in delivered to target chain by account #{} -> {}",
+                        nonces,
+                        submitter.index(),
+                        array_bytes::bytes2hex("0x", hash),
+                    );
+                }
+                Err(e) => {
+                    keepstate::clear_last_delivery_relayed_nonce(SC::CHAIN, lane);
+                    tracing::error!(
+                        target: "relay-s2s",
+                        "failed to deliver nonces {:?} by account #{}: {:?}",
+                        nonces,
+                        submitter.index(),
+                        e,
+                    );
+                    return Err(e.into());
+                }
+            }
+            Ok(())
+        };
+        self.submissions
+            .spawn(lane, submit.instrument(tracing::Span::current()));
+        Ok(())
+    }
+}
+
+impl<SC, TC, DC, Strategy> CommonDeliveryRunner<SC, TC, DC, Strategy>
+where
+    SC: S2SClientRelay,
+    TC: S2SClientRelay,
+    DC: DifferentClientApi<TC>,
+    Strategy: RelayStrategy,
+{
+    /// Deliver the nonces once and wait for the extrinsic, used to push stuck messages by hand.
+    /// The messages must be generated at the last relayed source header.
+    pub async fn deliver(
+        &self,
+        lane: LaneId,
+        nonces: RangeInclusive<u64>,
+        options: &ManualRelayOptions,
+    ) -> RelayResult<ManualRelayReport> {
+        span::record_lane(lane);
+        span::record_nonces(&nonces);
+        if nonces.is_empty() {
+            return Err(RelayError::Custom(format!("Wrong nonces {:?}", nonces)));
+        }
+        let source_outbound_lane_data = self.source_outbound_lane_data(lane).await?;
+        if *nonces.start() <= source_outbound_lane_data.latest_received_nonce {
+            return Err(RelayError::Custom(format!(
+                "The nonce {} is confirmed already, the last confirmed nonce is {}",
+                nonces.start(),
+                source_outbound_lane_data.latest_received_nonce,
+            )));
+        }
+
+        let (relayed_block_number, expected_source_hash) =
+            self.last_relayed_source_block().await?.ok_or_else(|| {
+                RelayError::Custom("The bridge not initialized, please init first".to_string())
+            })?;
+        let relayed_outbound_lane_data = self
+            .input
+            .client_source
+            .outbound_lanes(lane, Some(expected_source_hash))
+            .await?;
+        if relayed_outbound_lane_data.latest_generated_nonce < *nonces.end() {
+            return Err(RelayError::Custom(format!(
+                "The nonce {} isn't generated at the last relayed header {}, please relay header first",
+                nonces.end(),
+                relayed_block_number,
+            )));
+        }
+
+        let (proof, total_weight) = self
+            .messages_proof(
+                lane,
+                &nonces,
+                &source_outbound_lane_data,
+                expected_source_hash,
+            )
+            .await?;
+        let mut report = ManualRelayReport {
+            proof_size: proof.storage_proof.iter().map(|item| item.len()).sum(),
+            dispatch_weight: Some(total_weight),
+            ..Default::default()
+        };
+        if !options.ignore_strategy {
+            let message_size = proof.storage_proof.len();
+            if !self
+                .decide(lane, &nonces, message_size, total_weight)
+                .await?
+            {
+                report.rejected_by_strategy = true;
+                return Ok(report);
+            }
+        }
+        if options.dry_run {
+            return Ok(report);
+        }
+
+        let submitter = self.input.submitters_target.acquire().ok_or_else(|| {
+            RelayError::Custom(format!(
+                "All relayer accounts at {} have in-flight extrinsics",
+                TC::CHAIN,
+            ))
+        })?;
+        let expected_proof = SmartCodecMapper::map_to(&proof)?;
+        let expected_relayer_id = SmartCodecMapper::map_to(&self.input.relayer_account)?;
+        let messages_count = (nonces.end() - nonces.start() + 1) as u32;
+        let hash = submitter
             .receive_messages_proof(
                 expected_relayer_id,
                 expected_proof,
-                (nonces.end() - nonces.start() + 1) as _,
+                messages_count,
                 Weight::from_ref_time(total_weight),
             )
             .await?;
-
+        span::record_tx(hash);
         tracing::info!(
             target: "relay-s2s",
-            "{} the nonces {:?} in delivered to target chain -> {}",
-            logk::prefix_with_bridge_and_others(
-                M_DELIVERY,
-                SC::CHAIN,
-                TC::CHAIN,
-                vec![array_bytes::bytes2hex("0x", lane),],
-            ),
+            "the nonces {:?} in delivered to target chain by account #{} -> {}",
             nonces,
+            submitter.index(),
             array_bytes::bytes2hex("0x", hash),
         );
-        Ok(Some(*nonces.end()))
+        report.extrinsic_hash = Some(array_bytes::bytes2hex("0x", hash));
+        Ok(report)
     }
 }
@@ -3,17 +3,24 @@ use bridge_s2s_traits::types::bp_messages::{OutboundLaneData, UnrewardedRelayers
 use bridge_s2s_traits::types::bp_runtime::Chain;
 use bridge_s2s_traits::types::bridge_runtime_common::messages::source::FromBridgedChainMessagesDeliveryProof;
 
-use support_toolkit::{convert::SmartCodecMapper, logk};
+use support_toolkit::convert::SmartCodecMapper;
+use tracing::Instrument;
 
-use crate::error::RelayResult;
+use crate::endpoint;
+use crate::error::{RelayError, RelayResult};
 use crate::keepstate;
+use crate::span;
 use crate::special::DifferentClientApi;
-use crate::types::{LaneId, MessageReceivingInput, M_RECEIVING};
+use crate::submitter::Submissions;
+use crate::types::{
+    LaneId, ManualRelayOptions, ManualRelayReport, MessageReceivingInput, M_RECEIVING,
+};
 
 pub struct CommonReceivingRunner<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
 {
     different: DC,
     input: MessageReceivingInput<SC, TC>,
+    submissions: Submissions,
 }
 
 impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
@@ -23,6 +30,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
         Self {
             different,
             input: message_relay,
+            submissions: Submissions::default(),
         }
     }
 }
@@ -57,20 +65,20 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
         if max_confirm_end_at_target == source_outbound_lane_data.latest_received_nonce {
             tracing::info!(
                 target: "relay-s2s",
-                "{} the last dispatched nonce({}) at target({}) is same with last received nonce({}) at source. nothing to do.",
-                logk::prefix_with_bridge(M_RECEIVING, SC::CHAIN, TC::CHAIN),
+                "the last dispatched nonce({}) at target({}) is same with last received nonce({}) at source. nothing to do.",
                 max_confirm_end_at_target,
                 block_hex,
                 source_outbound_lane_data.latest_received_nonce,
             );
             return Ok(None);
         }
-        if let Some(last_relayed_nonce) = keepstate::get_last_receiving_relayed_nonce(TC::CHAIN) {
+        if let Some(last_relayed_nonce) =
+            keepstate::get_last_receiving_relayed_nonce(TC::CHAIN, lane)
+        {
             if last_relayed_nonce >= max_confirm_end_at_target {
                 tracing::warn!(
                     target: "relay-s2s",
-                    "{} the nonce({}) is being processed. please waiting for the processing to finish.",
-                    logk::prefix_with_bridge(M_RECEIVING, SC::CHAIN, TC::CHAIN),
+                    "the nonce({}) is being processed. please waiting for the processing to finish.",
                     max_confirm_end_at_target,
                 );
                 return Ok(None);
@@ -91,8 +99,7 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
         if total_unrewarded_messages.is_none() {
             tracing::info!(
                 target: "relay-s2s",
-                "{} not have unrewarded message. nothing to do.",
-                logk::prefix_with_bridge(M_RECEIVING, SC::CHAIN, TC::CHAIN),
+                "not have unrewarded message. nothing to do.",
             );
             return Ok(None);
         }
@@ -109,35 +116,89 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
             },
         )))
     }
+
+    /// read proof of the inbound lane at the last relayed target block
+    async fn messages_delivery_proof(
+        &self,
+        lane: LaneId,
+        bridged_header_hash: <SC::Chain as Chain>::Hash,
+    ) -> RelayResult<FromBridgedChainMessagesDeliveryProof<<SC::Chain as Chain>::Hash>> {
+        let client_target = &self.input.client_target;
+        let expected_target_hash = SmartCodecMapper::map_to(&bridged_header_hash)?;
+        let inbound_data_key = client_target.gen_inbound_lanes_storage_key(lane);
+        let proof = client_target
+            .read_proof(vec![inbound_data_key], Some(expected_target_hash))
+            .await?;
+        Ok(FromBridgedChainMessagesDeliveryProof {
+            bridged_header_hash,
+            storage_proof: proof,
+            lane,
+        })
+    }
 }
 
 impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
     CommonReceivingRunner<SC, TC, DC>
 {
     pub async fn start(&self) -> RelayResult<()> {
-        tracing::info!(
-            target: "relay-s2s",
-            "{} SERVICE RESTARTING...",
-            logk::prefix_with_bridge(M_RECEIVING, SC::CHAIN, TC::CHAIN),
-        );
+        span::relay_span(M_RECEIVING, SC::CHAIN, TC::CHAIN).in_scope(|| {
+            tracing::info!(
+                target: "relay-s2s",
+                "SERVICE RESTARTING...",
+            );
+        });
         loop {
             for lane in &self.input.lanes {
-                let last_relayed_nonce = self.run(*lane).await?;
-                if last_relayed_nonce.is_some() {
-                    keepstate::set_last_receiving_relayed_nonce(
-                        TC::CHAIN,
-                        last_relayed_nonce.expect("Unreachable"),
-                    );
-                }
+                self.run(*lane)
+                    .instrument(span::relay_span(M_RECEIVING, SC::CHAIN, TC::CHAIN))
+                    .await?;
+                self.submissions.check().await?;
+                endpoint::ensure_endpoint_healthy(&self.input.client_source)?;
+                endpoint::ensure_endpoint_healthy(&self.input.client_target)?;
+                endpoint::ensure_submitters_healthy(&self.input.submitters_source)?;
                 tokio::time::sleep(std::time::Duration::from_secs(5)).await;
             }
         }
     }
 
-    async fn run(&self, lane: LaneId) -> RelayResult<Option<u64>> {
-        // alias
-        let client_source = &self.input.client_source;
-        let client_target = &self.input.client_target;
+    async fn run(&self, lane: LaneId) -> RelayResult<()> {
+        span::record_lane(lane);
+        for chain in [SC::CHAIN, TC::CHAIN] {
+            if keepstate::is_runtime_incompatible(chain) {
+                tracing::warn!(
+                    target: "relay-s2s",
+                    "the runtime of {} is incompatible with the client, relay paused.",
+                    chain,
+                );
+                return Ok(());
+            }
+        }
+        if keepstate::is_submit_paused(SC::CHAIN) {
+            tracing::warn!(
+                target: "relay-s2s",
+                "the relayer balance at {} is below reserve, submitting paused.",
+                SC::CHAIN,
+            );
+            return Ok(());
+        }
+        if self.submissions.is_lane_pending(lane) {
+            tracing::debug!(
+                target: "relay-s2s",
+                "the last submission of this lane isn't finalized, wait next round.",
+            );
+            return Ok(());
+        }
+        let submitter = match self.input.submitters_source.acquire() {
+            Some(v) => v,
+            None => {
+                tracing::debug!(
+                    target: "relay-s2s",
+                    "all relayer accounts at {} have in-flight extrinsics, wait next round.",
+                    SC::CHAIN,
+                );
+                return Ok(());
+            }
+        };
 
         let source_outbound_lane_data = self.source_outbound_lane_data(lane).await?;
         if source_outbound_lane_data.latest_received_nonce
@@ -145,15 +206,9 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
         {
             tracing::debug!(
                 target: "relay-s2s",
-                "{} all nonces received, nothing to do.",
-                logk::prefix_with_bridge_and_others(
-                    M_RECEIVING,
-                    SC::CHAIN,
-                    TC::CHAIN,
-                    vec![array_bytes::bytes2hex("0x", lane),],
-                ),
+                "all nonces received, nothing to do.",
             );
-            return Ok(None);
+            return Ok(());
         }
 
         // query last relayed header (from source chain)
@@ -163,14 +218,9 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
                 None => {
                     tracing::warn!(
                         target: "relay-s2s",
-                        "{} the bridge not initialized, please init first.",
-                        logk::prefix_with_bridge(
-                            M_RECEIVING,
-                            SC::CHAIN,
-                            TC::CHAIN,
-                        ),
+                        "the bridge not initialized, please init first.",
                     );
-                    return Ok(None);
+                    return Ok(());
                 }
             };
         let expected_target_hash =
@@ -189,46 +239,119 @@ impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
             None => {
                 tracing::warn!(
                     target: "relay-s2s",
-                    "{} no unrewarded relayers state found by {}",
-                    logk::prefix_with_bridge_and_others(
-                        M_RECEIVING,
-                        SC::CHAIN,
-                        TC::CHAIN,
-                        vec![array_bytes::bytes2hex("0x", lane),],
-                    ),
+                    "no unrewarded relayers state found by {}",
                     TC::CHAIN,
                 );
-                return Ok(None);
+                return Ok(());
             }
         };
 
         // read proof
-        let inbound_data_key = client_target.gen_inbound_lanes_storage_key(lane);
-        let proof = client_target
-            .read_proof(vec![inbound_data_key], Some(expected_target_hash))
+        let proof = self
+            .messages_delivery_proof(lane, last_relayed_target_block_in_source.1)
             .await?;
-        let proof = FromBridgedChainMessagesDeliveryProof {
-            bridged_header_hash: last_relayed_target_block_in_source.1,
-            storage_proof: proof,
+
+        // send proof, the nonce is marked as relayed before submitting and reverted if failed
+        keepstate::set_last_receiving_relayed_nonce(
+            TC::CHAIN,
             lane,
+            max_confirmed_nonce_at_target,
+        );
+        let submit = async move {
+            let result = submitter
+                .receive_messages_delivery_proof(proof, relayers_state)
+                .await;
+            match result {
+                Ok(hash) => {
+                    span::record_tx(hash);
+                    tracing::info!(
+                        target: "relay-s2s",
+                        "receiving extensics sent successful by account #{}: {}",
+                        submitter.index(),
+                        array_bytes::bytes2hex("0x", hash),
+                    );
+                }
+                Err(e) => {
+                    keepstate::clear_last_receiving_relayed_nonce(TC::CHAIN, lane);
+                    tracing::error!(
+                        target: "relay-s2s",
+                        "failed to send receiving extrinsic by account #{}: {:?}",
+                        submitter.index(),
+                        e,
+                    );
+                    return Err(e.into());
+                }
+            }
+            Ok(())
         };
+        self.submissions
+            .spawn(lane, submit.instrument(tracing::Span::current()));
+        Ok(())
+    }
+}
 
-        // send proof
-        let hash = client_source
-            .receive_messages_delivery_proof(proof, relayers_state)
+impl<SC: S2SClientRelay, TC: S2SClientRelay, DC: DifferentClientApi<SC>>
+    CommonReceivingRunner<SC, TC, DC>
+{
+    /// Confirm the delivered messages once and wait for the extrinsic, the delivery must be
+    /// included in the last relayed target header.
+    pub async fn confirm(
+        &self,
+        lane: LaneId,
+        options: &ManualRelayOptions,
+    ) -> RelayResult<ManualRelayReport> {
+        span::record_lane(lane);
+        let source_outbound_lane_data = self.source_outbound_lane_data(lane).await?;
+        let last_relayed_target_block_in_source = self
+            .different
+            .best_target_finalized(None)
+            .await?
+            .ok_or_else(|| {
+                RelayError::Custom("The bridge not initialized, please init first".to_string())
+            })?;
+        let expected_target_hash =
+            SmartCodecMapper::map_to(&last_relayed_target_block_in_source.1)?;
+        let (_, relayers_state) = self
+            .target_unrewarded_relayers_state(
+                lane,
+                expected_target_hash,
+                &source_outbound_lane_data,
+            )
+            .await?
+            .ok_or_else(|| {
+                RelayError::Custom(format!(
+                    "Nothing to confirm at the last relayed header of {}, please relay header first if the messages are delivered",
+                    TC::CHAIN,
+                ))
+            })?;
+        let proof = self
+            .messages_delivery_proof(lane, last_relayed_target_block_in_source.1)
             .await?;
+        let mut report = ManualRelayReport {
+            proof_size: proof.storage_proof.iter().map(|item| item.len()).sum(),
+            ..Default::default()
+        };
+        if options.dry_run {
+            return Ok(report);
+        }
 
+        let submitter = self.input.submitters_source.acquire().ok_or_else(|| {
+            RelayError::Custom(format!(
+                "All relayer accounts at {} have in-flight extrinsics",
+                SC::CHAIN,
+            ))
+        })?;
+        let hash = submitter
+            .receive_messages_delivery_proof(proof, relayers_state)
+            .await?;
+        span::record_tx(hash);
         tracing::info!(
             target: "relay-s2s",
-            "{} receiving extensics sent successful: {}",
-            logk::prefix_with_bridge_and_others(
-                M_RECEIVING,
-                SC::CHAIN,
-                TC::CHAIN,
-                vec![array_bytes::bytes2hex("0x", lane),],
-            ),
+            "receiving extensics sent successful by account #{}: {}",
+            submitter.index(),
             array_bytes::bytes2hex("0x", hash),
         );
-        Ok(Some(max_confirmed_nonce_at_target))
+        report.extrinsic_hash = Some(array_bytes::bytes2hex("0x", hash));
+        Ok(report)
     }
 }
@@ -0,0 +1,41 @@
+use std::ops::RangeInclusive;
+
+use tracing::field::{display, Empty};
+use tracing::Span;
+
+use crate::types::LaneId;
+
+/// Span of one relay round, the fields of it are attached to all events logged inside,
+/// lane, nonces, block and tx are recorded while relaying
+pub(crate) fn relay_span(module: &str, source: &str, target: &str) -> Span {
+    tracing::info_span!(
+        target: "relay-s2s",
+        "relay",
+        module = module,
+        source = source,
+        target = target,
+        lane = Empty,
+        nonce_start = Empty,
+        nonce_end = Empty,
+        block = Empty,
+        tx = Empty,
+    )
+}
+
+pub(crate) fn record_lane(lane: LaneId) {
+    Span::current().record("lane", &display(array_bytes::bytes2hex("0x", lane)));
+}
+
+pub(crate) fn record_nonces(nonces: &RangeInclusive<u64>) {
+    let span = Span::current();
+    span.record("nonce_start", nonces.start());
+    span.record("nonce_end", nonces.end());
+}
+
+pub(crate) fn record_block(block: impl std::fmt::Display) {
+    Span::current().record("block", &display(block));
+}
+
+pub(crate) fn record_tx(hash: impl AsRef<[u8]>) {
+    Span::current().record("tx", &display(array_bytes::bytes2hex("0x", hash)));
+}
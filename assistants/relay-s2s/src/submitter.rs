@@ -0,0 +1,264 @@
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::task::JoinHandle;
+
+use crate::error::{RelayError, RelayResult};
+use crate::keepstate;
+use crate::types::LaneId;
+
+/// Clients of the relayer accounts on one chain. Every submission picks an idle account in
+/// round-robin, so consecutive submissions don't wait on the nonce and balance of one account.
+#[derive(Clone)]
+pub struct Submitters<C: Clone> {
+    chain: String,
+    clients: Arc<Vec<(C, Arc<AtomicUsize>)>>,
+    next: Arc<AtomicUsize>,
+}
+
+impl<C: Clone> Submitters<C> {
+    pub fn new(chain: impl AsRef<str>, clients: Vec<C>) -> Self {
+        let clients = clients
+            .into_iter()
+            .map(|client| (client, Arc::new(AtomicUsize::new(0))))
+            .collect();
+        Self {
+            chain: chain.as_ref().to_string(),
+            clients: Arc::new(clients),
+            next: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Pick the next account without in-flight extrinsic, `None` when all accounts are busy.
+    /// Accounts paused by balance monitor are skipped.
+    pub fn acquire(&self) -> Option<Submitter<C>> {
+        let len = self.clients.len();
+        let start = self.next.fetch_add(1, Ordering::SeqCst);
+        for offset in 0..len {
+            let index = (start + offset) % len;
+            if keepstate::is_signer_paused(&self.chain, index) {
+                continue;
+            }
+            let (client, in_flight) = &self.clients[index];
+            if in_flight
+                .compare_exchange(0, 1, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                return Some(Submitter {
+                    index,
+                    client: client.clone(),
+                    in_flight: in_flight.clone(),
+                });
+            }
+        }
+        None
+    }
+
+    /// Clients of all accounts, in the order of configured signers
+    pub fn clients(&self) -> impl Iterator<Item = &C> {
+        self.clients.iter().map(|(client, _)| client)
+    }
+
+    /// Number of in-flight extrinsics of all accounts
+    pub fn in_flight(&self) -> usize {
+        self.clients
+            .iter()
+            .map(|(_, in_flight)| in_flight.load(Ordering::SeqCst))
+            .sum()
+    }
+}
+
+/// Client of an acquired account, the account becomes idle again when it's dropped
+pub struct Submitter<C> {
+    index: usize,
+    client: C,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl<C> Submitter<C> {
+    /// Index of the account in configured signers
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<C> Deref for Submitter<C> {
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+impl<C> Drop for Submitter<C> {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Extrinsics submitted in background. The runner checks them every round and leaves with the
+/// first failure, so the service restarts it with new clients.
+///
+/// Messages of a lane must be included in order, a later range included before an earlier one
+/// is rejected and the lane stalls. So a lane has at most one in-flight submission, the
+/// submissions only run in parallel across lanes.
+#[derive(Default)]
+pub struct Submissions {
+    handles: Mutex<Vec<(LaneId, JoinHandle<RelayResult<()>>)>>,
+}
+
+impl Submissions {
+    pub fn spawn<F>(&self, lane: LaneId, submission: F)
+    where
+        F: Future<Output = RelayResult<()>> + Send + 'static,
+    {
+        let handle = tokio::spawn(submission);
+        self.handles.lock().unwrap().push((lane, handle));
+    }
+
+    /// Number of submissions not finished yet
+    pub fn pending(&self) -> usize {
+        self.handles
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, handle)| !handle.is_finished())
+            .count()
+    }
+
+    /// Whether the lane has a submission not finished yet, the next range of the lane waits
+    /// until it's finalized
+    pub fn is_lane_pending(&self, lane: LaneId) -> bool {
+        self.handles
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(v, handle)| *v == lane && !handle.is_finished())
+    }
+
+    /// Take the finished submissions, returns the first failure of them
+    pub async fn check(&self) -> RelayResult<()> {
+        let finished: Vec<_> = {
+            let mut handles = self.handles.lock().unwrap();
+            let (finished, pending) = handles
+                .drain(..)
+                .partition(|(_, handle)| handle.is_finished());
+            *handles = pending;
+            finished
+        };
+        let mut result = Ok(());
+        for (_, handle) in finished {
+            let output = handle
+                .await
+                .map_err(|e| RelayError::Custom(format!("submission panicked: {e:?}")))
+                .and_then(|v| v);
+            if result.is_ok() {
+                result = output;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acquire_round_robin() {
+        let submitters = Submitters::new("test-round-robin", vec!["a", "b", "c"]);
+        let first = submitters.acquire().unwrap();
+        let second = submitters.acquire().unwrap();
+        assert_eq!(*first, "a");
+        assert_eq!(*second, "b");
+        assert_eq!(submitters.in_flight(), 2);
+
+        drop(first);
+        // c is next, then a is idle again
+        assert_eq!(*submitters.acquire().unwrap(), "c");
+        assert_eq!(*submitters.acquire().unwrap(), "a");
+    }
+
+    #[test]
+    fn test_acquire_all_busy() {
+        let submitters = Submitters::new("test-all-busy", vec!["a"]);
+        let submitter = submitters.acquire().unwrap();
+        assert!(submitters.acquire().is_none());
+        drop(submitter);
+        assert!(submitters.acquire().is_some());
+        assert_eq!(submitters.in_flight(), 0);
+    }
+
+    #[test]
+    fn test_acquire_skip_paused() {
+        let submitters = Submitters::new("test-skip-paused", vec!["a", "b"]);
+        keepstate::set_signer_paused("test-skip-paused", 1, true);
+        let first = submitters.acquire().unwrap();
+        assert_eq!(*first, "a");
+        assert!(submitters.acquire().is_none());
+        drop(first);
+        keepstate::set_signer_paused("test-skip-paused", 1, false);
+        let first = submitters.acquire().unwrap();
+        let second = submitters.acquire().unwrap();
+        assert_eq!((*first, *second), ("a", "b"));
+    }
+
+    #[tokio::test]
+    async fn test_submissions_check() {
+        let submissions = Submissions::default();
+        submissions.spawn([0; 4], async { Ok(()) });
+        submissions.spawn([0; 4], async {
+            Err(RelayError::Custom("failed".to_string()))
+        });
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        submissions.spawn([0; 4], async move {
+            let _ = rx.await;
+            Ok(())
+        });
+        while submissions.pending() > 1 {
+            tokio::task::yield_now().await;
+        }
+        assert!(submissions.check().await.is_err());
+        // the failure is taken once, the pending one is kept
+        assert!(submissions.check().await.is_ok());
+        assert_eq!(submissions.pending(), 1);
+        tx.send(()).unwrap();
+        while submissions.pending() > 0 {
+            tokio::task::yield_now().await;
+        }
+        assert!(submissions.check().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_submissions_lane_pending() {
+        let submissions = Submissions::default();
+        let (lane_a, lane_b) = ([0, 0, 0, 1], [0, 0, 0, 2]);
+        // nonces 1..=10 of lane a are in-flight
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        submissions.spawn(lane_a, async move {
+            let _ = rx.await;
+            Ok(())
+        });
+        // nonces 11..=20 of lane a wait, or they may be included before 1..=10 by another
+        // account, the other lane isn't blocked
+        assert!(submissions.is_lane_pending(lane_a));
+        assert!(!submissions.is_lane_pending(lane_b));
+
+        tx.send(()).unwrap();
+        while submissions.pending() > 0 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!submissions.is_lane_pending(lane_a));
+        assert!(submissions.check().await.is_ok());
+    }
+}
@@ -4,8 +4,6 @@ use bridge_s2s_traits::client::S2SClientGeneric;
 use bridge_s2s_traits::error::S2SClientError;
 use subxt::rpc::Subscription;
 
-use support_toolkit::logk;
-
 use crate::error::{RelayError, RelayResult};
 use crate::keepstate;
 use crate::types::JustificationInput;
@@ -41,8 +39,8 @@ where
     if let Err(err) = subscribe_justification(&client, callback).await {
         tracing::error!(
             target: "relay-s2s",
-            "{} failed to get justification from {}: {:?}",
-            logk::prefix_multi("subscribe", vec![T::CHAIN]),
+            chain = T::CHAIN,
+            "failed to get justification from {}: {:?}",
             T::CHAIN,
             err,
         );
@@ -69,8 +67,8 @@ where
                     justification.map_err(|e| S2SClientError::RPC(format!("{e:?}")))?;
                 tracing::info!(
                     target: "relay-s2s",
-                    "{} subscribed new justification for {}",
-                    logk::prefix_multi("subscribe", vec![T::CHAIN]),
+                    chain = T::CHAIN,
+                    "subscribed new justification for {}",
                     T::CHAIN,
                 );
                 callback(justification);
@@ -78,8 +76,8 @@ where
             None => {
                 tracing::error!(
                     target: "relay-s2s",
-                    "{} the subscription has been terminated",
-                    logk::prefix_multi("subscribe", vec![T::CHAIN]),
+                    chain = T::CHAIN,
+                    "the subscription has been terminated",
                 );
                 return Err(RelayError::Custom(format!(
                     "[{}] the subscription has been terminated",
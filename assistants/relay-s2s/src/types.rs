@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use bridge_s2s_traits::client::{S2SClientGeneric, S2SClientRelay};
 #[cfg(feature = "bridge-parachain")]
 use bridge_s2s_traits::client::{S2SParaBridgeClientRelaychain, S2SParaBridgeClientSolochain};
@@ -7,6 +9,9 @@ use bridge_s2s_traits::types::bp_runtime::Chain;
 use subquery::types::OriginType;
 use subquery::Subquery;
 
+use crate::error::RelayError;
+use crate::submitter::Submitters;
+
 pub(crate) static M_HEADER: &str = "header";
 #[cfg(feature = "bridge-parachain")]
 pub(crate) static M_PARA_HEAD: &str = "para-head";
@@ -47,9 +52,12 @@ pub struct JustificationInput<C: S2SClientGeneric> {
 pub struct MessageDeliveryInput<SC: S2SClientRelay, TC: S2SClientRelay, Strategy: RelayStrategy> {
     pub lanes: Vec<LaneId>,
     pub nonces_limit: u64,
+    /// account rewarded at source chain, it's same for all accounts submitting at target chain
     pub relayer_account: <SC::Chain as Chain>::AccountId,
     pub client_source: SC,
     pub client_target: TC,
+    /// clients of relayer accounts submitting the delivery at target chain
+    pub submitters_target: Submitters<TC>,
     pub subquery_source: Subquery,
     pub subquery_target: Subquery,
     pub relay_block_origin: OriginType,
@@ -61,6 +69,53 @@ pub struct MessageReceivingInput<SC: S2SClientRelay, TC: S2SClientRelay> {
     pub relayer_account: <SC::Chain as Chain>::AccountId,
     pub client_source: SC,
     pub client_target: TC,
+    /// clients of relayer accounts submitting the receiving at source chain
+    pub submitters_source: Submitters<SC>,
     pub subquery_source: Subquery,
     pub subquery_target: Subquery,
 }
+
+/// Options of manual one-shot relay
+#[derive(Clone, Debug, Default)]
+pub struct ManualRelayOptions {
+    /// relay even if the relay strategy decides not to relay
+    pub ignore_strategy: bool,
+    /// build the proof without submitting it
+    pub dry_run: bool,
+}
+
+/// Result of manual one-shot relay
+#[derive(Clone, Debug, Default)]
+pub struct ManualRelayReport {
+    /// size of the proof in bytes
+    pub proof_size: usize,
+    /// dispatch weight of delivered messages
+    pub dispatch_weight: Option<u64>,
+    /// the relay strategy decided not to relay
+    pub rejected_by_strategy: bool,
+    /// hash of the submitted extrinsic, none if it's dry run or rejected by the strategy
+    pub extrinsic_hash: Option<String>,
+}
+
+/// Header to relay, by block number or block hash
+#[derive(Clone, Debug)]
+pub enum HeaderSelector {
+    Number(u32),
+    Hash(String),
+}
+
+impl FromStr for HeaderSelector {
+    type Err = RelayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("0x") {
+            let _: [u8; 32] = array_bytes::hex2array(s)
+                .map_err(|e| RelayError::Custom(format!("Wrong block hash [{s}]: {e:?}")))?;
+            return Ok(HeaderSelector::Hash(s.to_string()));
+        }
+        let number = s
+            .parse::<u32>()
+            .map_err(|e| RelayError::Custom(format!("Wrong block number [{s}]: {e:?}")))?;
+        Ok(HeaderSelector::Number(number))
+    }
+}
@@ -9,6 +9,7 @@ use crate::types::{BridgeFlow, RawBridgeConfig};
 pub async fn handle_init(bridge: BridgeFlow) -> color_eyre::Result<()> {
     tracing::info!(target: "darwinia-crab", "init bridge {:?}", bridge);
     let bridge_config: RawBridgeConfig = Config::restore(Names::BridgeDarwiniaCrab)?;
+    let bridge_config = bridge_config.resolve_keys()?;
     init_bridge(bridge, bridge_config).await?;
     Ok(())
 }
@@ -0,0 +1,124 @@
+use std::ops::RangeInclusive;
+
+use bin_s2s::manual::{self, HeaderSelector, ManualRelayOptions};
+use bin_s2s::types::HexLaneId;
+
+use support_common::config::{Config, Names};
+use support_common::error::BridgerError;
+
+use crate::command::handler::bridge_config;
+use crate::types::{BridgeFlow, RawBridgeConfig};
+
+pub async fn handle_relay_header(
+    bridge: BridgeFlow,
+    block: HeaderSelector,
+    dry_run: bool,
+) -> color_eyre::Result<()> {
+    tracing::info!(
+        target: "darwinia-crab",
+        "relay header {:?} of bridge {:?}",
+        block,
+        bridge,
+    );
+    let raw_bridge_config: RawBridgeConfig = Config::restore(Names::BridgeDarwiniaCrab)?;
+    let bridge_config = bridge_config(raw_bridge_config)?;
+    let report = match bridge {
+        BridgeFlow::DarwiniaToCrab => {
+            bridge_config
+                .source_to_target_manual()
+                .relay_header(block, dry_run)
+                .await?
+        }
+        BridgeFlow::CrabToDarwinia => {
+            bridge_config
+                .target_to_source_manual()
+                .relay_header(block, dry_run)
+                .await?
+        }
+    };
+    let options = ManualRelayOptions {
+        ignore_strategy: false,
+        dry_run,
+    };
+    manual::output_manual_report(&report, &options);
+    Ok(())
+}
+
+pub async fn handle_deliver(
+    bridge: BridgeFlow,
+    lane: Option<HexLaneId>,
+    nonces: RangeInclusive<u64>,
+    options: ManualRelayOptions,
+) -> color_eyre::Result<()> {
+    tracing::info!(
+        target: "darwinia-crab",
+        "deliver messages {:?} of bridge {:?}",
+        nonces,
+        bridge,
+    );
+    let raw_bridge_config: RawBridgeConfig = Config::restore(Names::BridgeDarwiniaCrab)?;
+    let lane = lane_or_default(&raw_bridge_config, lane)?;
+    let bridge_config = bridge_config(raw_bridge_config)?;
+    let report = match bridge {
+        BridgeFlow::DarwiniaToCrab => {
+            bridge_config
+                .source_to_target_manual()
+                .deliver(lane.0, nonces, &options)
+                .await?
+        }
+        BridgeFlow::CrabToDarwinia => {
+            bridge_config
+                .target_to_source_manual()
+                .deliver(lane.0, nonces, &options)
+                .await?
+        }
+    };
+    manual::output_manual_report(&report, &options);
+    Ok(())
+}
+
+pub async fn handle_confirm(
+    bridge: BridgeFlow,
+    lane: Option<HexLaneId>,
+    options: ManualRelayOptions,
+) -> color_eyre::Result<()> {
+    tracing::info!(
+        target: "darwinia-crab",
+        "confirm messages of bridge {:?}",
+        bridge,
+    );
+    let raw_bridge_config: RawBridgeConfig = Config::restore(Names::BridgeDarwiniaCrab)?;
+    let lane = lane_or_default(&raw_bridge_config, lane)?;
+    let bridge_config = bridge_config(raw_bridge_config)?;
+    let report = match bridge {
+        BridgeFlow::DarwiniaToCrab => {
+            bridge_config
+                .source_to_target_manual()
+                .confirm(lane.0, &options)
+                .await?
+        }
+        BridgeFlow::CrabToDarwinia => {
+            bridge_config
+                .target_to_source_manual()
+                .confirm(lane.0, &options)
+                .await?
+        }
+    };
+    manual::output_manual_report(&report, &options);
+    Ok(())
+}
+
+fn lane_or_default(
+    raw_bridge_config: &RawBridgeConfig,
+    lane: Option<HexLaneId>,
+) -> color_eyre::Result<HexLaneId> {
+    match lane {
+        Some(v) => Ok(v),
+        None => Ok(raw_bridge_config
+            .relay
+            .lanes
+            .first()
+            .cloned()
+            .ok_or_else(|| BridgerError::Custom("Missing lanes in relay config".to_string()))?),
+    }
+}
@@ -0,0 +1,45 @@
+use std::ops::RangeInclusive;
+
+use bin_s2s::inspect;
+use bin_s2s::types::HexLaneId;
+
+use support_common::config::{Config, Names};
+use support_common::error::BridgerError;
+use support_terminal::output::OutputFormat;
+
+use crate::types::{BridgeFlow, RawBridgeConfig};
+
+pub async fn handle_message(
+    bridge: BridgeFlow,
+    lane: Option<HexLaneId>,
+    nonces: RangeInclusive<u64>,
+    output: OutputFormat,
+) -> color_eyre::Result<()> {
+    tracing::info!(
+        target: "darwinia-crab",
+        "inspect messages {:?} of bridge {:?}",
+        nonces,
+        bridge,
+    );
+    let bridge_config: RawBridgeConfig = Config::restore(Names::BridgeDarwiniaCrab)?;
+    let lane = match lane {
+        Some(v) => v,
+        None => bridge_config
+            .relay
+            .lanes
+            .first()
+            .cloned()
+            .ok_or_else(|| BridgerError::Custom("Missing lanes in relay config".to_string()))?,
+    };
+    let client_darwinia = bridge_config.darwinia.readonly_client().await?;
+    let client_crab = bridge_config.crab.readonly_client().await?;
+    let inspection = match bridge {
+        BridgeFlow::DarwiniaToCrab => {
+            inspect::inspect_lane(&client_darwinia, &client_crab, lane.0, nonces).await?
+        }
+        BridgeFlow::CrabToDarwinia => {
+            inspect::inspect_lane(&client_crab, &client_darwinia, lane.0, nonces).await?
+        }
+    };
+    inspect::output_lane_inspection(&inspection, output)
+}
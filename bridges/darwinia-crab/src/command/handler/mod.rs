@@ -1,5 +1,13 @@
+pub use self::config::*;
 pub use self::init::*;
+pub use self::manual::*;
+pub use self::message::*;
+pub use self::preflight::*;
 pub use self::relay::*;
 
+mod config;
 mod init;
+mod manual;
+mod message;
+mod preflight;
 mod relay;
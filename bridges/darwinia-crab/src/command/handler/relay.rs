@@ -6,17 +6,44 @@ use bin_s2s::types::BasicSubqueryInfo;
 use support_common::config::{Config, Names};
 use support_types::mark::BridgeName;
 
-use crate::types::RawBridgeConfig;
+use crate::types::{
+    CrabChainConfig, DarwiniaChainConfig, KusamaChainConfig, PolkadotChainConfig, RawBridgeConfig,
+};
 
-pub async fn handle_relay() -> color_eyre::Result<()> {
+pub async fn handle_relay(skip_preflight: bool) -> color_eyre::Result<()> {
     tracing::info!(target: "darwinia-crab", "start bridge darwinia-crab");
     let raw_bridge_config: RawBridgeConfig = Config::restore(Names::BridgeDarwiniaCrab)?;
+    let bridge_config = bridge_config(raw_bridge_config)?;
+    if !skip_preflight {
+        let report = bridge_config.preflight().await?;
+        report.output();
+        report.ensure()?;
+    }
+    let _task = BridgeTask::new(bridge_config)?;
+    loop {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+}
+
+/// Bridge config of darwinia-crab from the raw config, keys are resolved
+pub(crate) fn bridge_config(
+    raw_bridge_config: RawBridgeConfig,
+) -> color_eyre::Result<
+    BridgeConfig<
+        PolkadotChainConfig,
+        DarwiniaChainConfig,
+        KusamaChainConfig,
+        CrabChainConfig,
+        BasicSubqueryInfo,
+    >,
+> {
+    let raw_bridge_config = raw_bridge_config.resolve_keys()?;
     let raw_config_index = raw_bridge_config.index;
     let (source_para_id, target_para_id) = (
         raw_bridge_config.darwinia.para_id,
         raw_bridge_config.crab.para_id,
     );
-    let bridge_config = BridgeConfig {
+    Ok(BridgeConfig {
         chain: ChainConfig {
             source_para: raw_bridge_config.darwinia,
             source_relay: raw_bridge_config.polkadot,
@@ -40,9 +67,5 @@ pub async fn handle_relay() -> color_eyre::Result<()> {
             target_para: BasicSubqueryInfo::new(BridgeName::DarwiniaCrab, raw_config_index.crab),
             target_relay: BasicSubqueryInfo::new(BridgeName::DarwiniaCrab, raw_config_index.kusama),
         },
-    };
-    let _task = BridgeTask::new(bridge_config)?;
-    loop {
-        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-    }
+    })
 }
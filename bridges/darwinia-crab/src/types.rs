@@ -10,6 +10,7 @@ use client_polkadot::component::PolkadotClientComponent;
 use serde::{Deserialize, Serialize};
 use subquery::types::OriginType;
 use subquery::SubqueryConfig;
+use support_command_config::ConfigCheck;
 
 use support_types::mark::ChainName;
 
@@ -30,32 +31,141 @@ pub struct RawBridgeConfig {
     pub index: RawIndexConfig,
 }
 
+impl RawBridgeConfig {
+    /// Decrypt `keystore:<id>` signers from keystore
+    pub fn resolve_keys(mut self) -> support_keystore::KeystoreResult<Self> {
+        self.darwinia.signer = support_keystore::resolve(&self.darwinia.signer)?;
+        for signer in self.darwinia.extra_signers.iter_mut() {
+            *signer = support_keystore::resolve(signer)?;
+        }
+        self.crab.signer = support_keystore::resolve(&self.crab.signer)?;
+        for signer in self.crab.extra_signers.iter_mut() {
+            *signer = support_keystore::resolve(signer)?;
+        }
+        Ok(self)
+    }
+}
+
+impl ConfigCheck for RawBridgeConfig {
+    fn endpoints(&self) -> Vec<(String, String)> {
+        let mut endpoints = Vec::new();
+        endpoints.extend(support_command_config::section_endpoints(
+            "darwinia",
+            &self.darwinia.endpoint,
+            &self.darwinia.backup_endpoints,
+        ));
+        endpoints.extend(support_command_config::section_endpoints(
+            "crab",
+            &self.crab.endpoint,
+            &self.crab.backup_endpoints,
+        ));
+        endpoints.extend(support_command_config::section_endpoints(
+            "polkadot",
+            &self.polkadot.endpoint,
+            &self.polkadot.backup_endpoints,
+        ));
+        endpoints.extend(support_command_config::section_endpoints(
+            "kusama",
+            &self.kusama.endpoint,
+            &self.kusama.backup_endpoints,
+        ));
+        for (chain, config) in [
+            ("darwinia", &self.index.darwinia),
+            ("crab", &self.index.crab),
+            ("polkadot", &self.index.polkadot),
+            ("kusama", &self.index.kusama),
+        ] {
+            endpoints.push((format!("index.{chain}.endpoint"), config.endpoint.clone()));
+        }
+        endpoints
+    }
+
+    fn check_keys(&self) -> Vec<(String, Result<(), String>)> {
+        let mut keys = Vec::new();
+        let decode_darwinia =
+            |v: &str| client_darwinia::types::DarwiniaAccount::new(v.to_string(), None).map(|_| ());
+        keys.push((
+            "darwinia.signer".to_string(),
+            support_command_config::check_key(&self.darwinia.signer, decode_darwinia),
+        ));
+        for (ix, signer) in self.darwinia.extra_signers.iter().enumerate() {
+            keys.push((
+                format!("darwinia.extra_signers.{ix}"),
+                support_command_config::check_key(signer, decode_darwinia),
+            ));
+        }
+        let decode_crab =
+            |v: &str| client_crab::types::DarwiniaAccount::new(v.to_string(), None).map(|_| ());
+        keys.push((
+            "crab.signer".to_string(),
+            support_command_config::check_key(&self.crab.signer, decode_crab),
+        ));
+        for (ix, signer) in self.crab.extra_signers.iter().enumerate() {
+            keys.push((
+                format!("crab.extra_signers.{ix}"),
+                support_command_config::check_key(signer, decode_crab),
+            ));
+        }
+        keys
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DarwiniaChainConfig {
     /// Endpoint
     pub endpoint: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
     pub signer: String,
+    /// Extra relayer accounts, messages relay is submitted by an idle one of all signers
+    #[serde(default)]
+    pub extra_signers: Vec<String>,
+    /// Proxied real account, calls are submitted as `proxy.proxy` of it and rewards go to it
+    #[serde(default)]
+    pub real_account: Option<String>,
     pub para_id: u32,
+    /// Minimum free balance of relayer, submitting is paused when below it
+    #[serde(default, with = "support_common::serde_str::option")]
+    pub reserve_balance: Option<u128>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CrabChainConfig {
     /// Endpoint
     pub endpoint: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
     pub signer: String,
+    /// Extra relayer accounts, messages relay is submitted by an idle one of all signers
+    #[serde(default)]
+    pub extra_signers: Vec<String>,
+    /// Proxied real account, calls are submitted as `proxy.proxy` of it and rewards go to it
+    #[serde(default)]
+    pub real_account: Option<String>,
     pub para_id: u32,
+    /// Minimum free balance of relayer, submitting is paused when below it
+    #[serde(default, with = "support_common::serde_str::option")]
+    pub reserve_balance: Option<u128>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct KusamaChainConfig {
     /// Endpoint
     pub endpoint: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PolkadotChainConfig {
     /// Endpoint
     pub endpoint: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -82,22 +192,77 @@ impl S2SParaBridgeSoloChainInfo for DarwiniaChainConfig {
 
     fn account(
         &self,
+    ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
+        let account = client_darwinia::types::DarwiniaAccount::new(
+            self.signer.clone(),
+            self.real_account.clone(),
+        )
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+        Ok(*account.real_account())
+    }
+
+    fn signer_account(
+        &self,
     ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
         let account = client_darwinia::types::DarwiniaAccount::new(self.signer.clone(), None)
             .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
         Ok(*account.account_id())
     }
 
+    fn signer_accounts(
+        &self,
+    ) -> BinS2SResult<Vec<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId>>
+    {
+        let mut accounts = vec![self.signer_account()?];
+        for signer in &self.extra_signers {
+            let account = client_darwinia::types::DarwiniaAccount::new(signer.clone(), None)
+                .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+            accounts.push(*account.account_id());
+        }
+        Ok(accounts)
+    }
+
+    fn reserve_balance(
+        &self,
+    ) -> Option<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::Balance> {
+        self.reserve_balance
+    }
+
     async fn client(&self) -> BinS2SResult<Self::Client> {
-        let config = client_darwinia::config::ClientConfig {
-            endpoint: self.endpoint.clone(),
-            relayer_private_key: self.signer.clone(),
-            relayer_real_account: None,
-        };
+        let config = self.client_config(&self.signer);
         Ok(DarwiniaClientComponent::component(config)
             .await
             .map_err(|e| BinS2SError::Client(format!("{e:?}")))?)
     }
+
+    async fn extra_clients(&self) -> BinS2SResult<Vec<Self::Client>> {
+        let mut clients = Vec::with_capacity(self.extra_signers.len());
+        for signer in &self.extra_signers {
+            let client = DarwiniaClientComponent::component(self.client_config(signer))
+                .await
+                .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+            clients.push(client);
+        }
+        Ok(clients)
+    }
+}
+
+impl DarwiniaChainConfig {
+    fn client_config(&self, signer: &str) -> client_darwinia::config::ClientConfig {
+        client_darwinia::config::ClientConfig {
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
+            relayer_private_key: signer.to_string(),
+            relayer_real_account: self.real_account.clone(),
+        }
+    }
+
+    /// Read-only client, the signers aren't resolved so it never asks for keystore password
+    pub async fn readonly_client(&self) -> BinS2SResult<client_darwinia::client::DarwiniaClient> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        DarwiniaClientComponent::readonly(&endpoints)
+            .await
+            .map_err(|e| BinS2SError::Client(format!("{e:?}")))
+    }
 }
 
 impl S2SBasicChainInfo for CrabChainConfig {
@@ -114,22 +279,77 @@ impl S2SParaBridgeSoloChainInfo for CrabChainConfig {
 
     fn account(
         &self,
+    ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
+        let account = client_crab::types::DarwiniaAccount::new(
+            self.signer.clone(),
+            self.real_account.clone(),
+        )
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+        Ok(*account.real_account())
+    }
+
+    fn signer_account(
+        &self,
     ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
         let account = client_crab::types::DarwiniaAccount::new(self.signer.clone(), None)
             .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
         Ok(*account.account_id())
     }
 
+    fn signer_accounts(
+        &self,
+    ) -> BinS2SResult<Vec<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId>>
+    {
+        let mut accounts = vec![self.signer_account()?];
+        for signer in &self.extra_signers {
+            let account = client_crab::types::DarwiniaAccount::new(signer.clone(), None)
+                .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+            accounts.push(*account.account_id());
+        }
+        Ok(accounts)
+    }
+
+    fn reserve_balance(
+        &self,
+    ) -> Option<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::Balance> {
+        self.reserve_balance
+    }
+
     async fn client(&self) -> BinS2SResult<Self::Client> {
-        let config = client_crab::config::ClientConfig {
-            endpoint: self.endpoint.clone(),
-            relayer_private_key: self.signer.clone(),
-            relayer_real_account: None,
-        };
+        let config = self.client_config(&self.signer);
         Ok(CrabClientComponent::component(config)
             .await
             .map_err(|e| BinS2SError::Client(format!("{e:?}")))?)
     }
+
+    async fn extra_clients(&self) -> BinS2SResult<Vec<Self::Client>> {
+        let mut clients = Vec::with_capacity(self.extra_signers.len());
+        for signer in &self.extra_signers {
+            let client = CrabClientComponent::component(self.client_config(signer))
+                .await
+                .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+            clients.push(client);
+        }
+        Ok(clients)
+    }
+}
+
+impl CrabChainConfig {
+    fn client_config(&self, signer: &str) -> client_crab::config::ClientConfig {
+        client_crab::config::ClientConfig {
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
+            relayer_private_key: signer.to_string(),
+            relayer_real_account: self.real_account.clone(),
+        }
+    }
+
+    /// Read-only client, the signers aren't resolved so it never asks for keystore password
+    pub async fn readonly_client(&self) -> BinS2SResult<client_crab::client::CrabClient> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        CrabClientComponent::readonly(&endpoints)
+            .await
+            .map_err(|e| BinS2SError::Client(format!("{e:?}")))
+    }
 }
 
 // === relay chain
@@ -144,7 +364,7 @@ impl S2SParaBridgeRelayChainInfo for PolkadotChainConfig {
 
     async fn client(&self) -> BinS2SResult<Self::Client> {
         let config = client_polkadot::config::ClientConfig {
-            endpoint: self.endpoint.clone(),
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
             relayer_private_key: "//Alice".to_string(),
         };
         Ok(PolkadotClientComponent::component(config)
@@ -163,7 +383,7 @@ impl S2SParaBridgeRelayChainInfo for KusamaChainConfig {
 
     async fn client(&self) -> BinS2SResult<Self::Client> {
         let config = client_kusama::config::ClientConfig {
-            endpoint: self.endpoint.clone(),
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
             relayer_private_key: "//Alice".to_string(),
         };
         Ok(KusamaClientComponent::component(config)
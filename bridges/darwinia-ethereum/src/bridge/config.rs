@@ -1,9 +1,15 @@
+use client_common_traits::ChainIdentity;
 use client_darwinia::client::DarwiniaClient;
 use client_darwinia::component::DarwiniaClientComponent;
+use client_darwinia::config::DarwiniaSubxtConfig;
+use relay_e2e::ecdsa::events::EcdsaEventsSourceType;
+use relay_e2e::types::ethereum::FastEthereumAccount;
 use serde::{Deserialize, Serialize};
+use support_command_config::ConfigCheck;
 
 use bin_e2e::config::{
-    BeaconApiConfig, EVMChainConfig, ExecutionLayerInfoConfig, GeneralConfig, IndexConfig,
+    BeaconApiConfig, EVMChainConfig, EcdsaConfig, ExecutionLayerInfoConfig, GeneralConfig,
+    IndexConfig,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -11,6 +17,9 @@ pub struct BridgeConfig {
     pub general: GeneralConfig,
     pub darwinia_evm: EVMChainConfig,
     pub darwinia_substrate: DarwiniaSubstrateConfig,
+    /// ECDSA authority key, only required for authorities
+    #[serde(default)]
+    pub ecdsa: Option<EcdsaConfig>,
     pub ethereum: ExecutionLayerInfoConfig,
     pub beacon: BeaconApiConfig,
     pub index: IndexConfig,
@@ -19,22 +28,143 @@ pub struct BridgeConfig {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DarwiniaSubstrateConfig {
     pub endpoint: String,
-    pub private_key: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
+    /// Deprecated, the ECDSA authority key is configured in `ecdsa`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
 }
 
-impl From<DarwiniaSubstrateConfig> for client_darwinia::config::ClientConfig {
-    fn from(config: DarwiniaSubstrateConfig) -> Self {
-        client_darwinia::config::ClientConfig {
-            endpoint: config.endpoint,
-            relayer_private_key: config.private_key,
-            relayer_real_account: None,
+impl BridgeConfig {
+    /// ECDSA authority config, the key in substrate section is still accepted
+    pub fn ecdsa_config(&self) -> Option<EcdsaConfig> {
+        if let Some(ecdsa) = &self.ecdsa {
+            return Some(ecdsa.clone());
+        }
+        let private_key = self.darwinia_substrate.private_key.as_ref()?;
+        tracing::warn!(
+            "The private_key of darwinia_substrate is deprecated, please move it to the ecdsa section",
+        );
+        Some(EcdsaConfig::new(private_key))
+    }
+
+    /// Darwinia client scanning ECDSA authority events, only present when they're read from
+    /// chain. It's the authority client if any, otherwise a read-only client without key.
+    pub async fn to_ecdsa_events_client(
+        &self,
+        substrate_client: Option<&DarwiniaClient>,
+    ) -> color_eyre::Result<Option<DarwiniaClient>> {
+        if self.index.ecdsa_events != EcdsaEventsSourceType::Chain {
+            return Ok(None);
+        }
+        if let Some(client) = substrate_client {
+            return Ok(Some(client.clone()));
+        }
+        let client = self.darwinia_substrate.to_readonly_client().await?;
+        Ok(Some(client))
+    }
+}
+
+impl ConfigCheck for BridgeConfig {
+    fn endpoints(&self) -> Vec<(String, String)> {
+        let mut endpoints = Vec::new();
+        for (section, endpoint, ws_endpoint, backup_endpoints) in [
+            (
+                "darwinia_evm",
+                &self.darwinia_evm.endpoint,
+                &self.darwinia_evm.ws_endpoint,
+                &self.darwinia_evm.backup_endpoints,
+            ),
+            (
+                "ethereum",
+                &self.ethereum.endpoint,
+                &self.ethereum.ws_endpoint,
+                &self.ethereum.backup_endpoints,
+            ),
+        ] {
+            endpoints.extend(support_command_config::section_endpoints(
+                section,
+                endpoint,
+                backup_endpoints,
+            ));
+            if let Some(ws_endpoint) = ws_endpoint {
+                endpoints.push((format!("{section}.ws_endpoint"), ws_endpoint.clone()));
+            }
         }
+        endpoints.extend(support_command_config::section_endpoints(
+            "darwinia_substrate",
+            &self.darwinia_substrate.endpoint,
+            &self.darwinia_substrate.backup_endpoints,
+        ));
+        endpoints.push(("beacon.endpoint".to_string(), self.beacon.endpoint.clone()));
+        endpoints.push((
+            "index.evm_chain.endpoint".to_string(),
+            self.index.evm_chain.endpoint.clone(),
+        ));
+        if let Some(substrate_chain) = &self.index.substrate_chain {
+            endpoints.push((
+                "index.substrate_chain.endpoint".to_string(),
+                substrate_chain.endpoint.clone(),
+            ));
+        }
+        endpoints
+    }
+
+    fn check_keys(&self) -> Vec<(String, Result<(), String>)> {
+        let decode = |v: &str| FastEthereumAccount::new(v).signer().map(|_| ());
+        let mut keys = vec![
+            (
+                "darwinia_evm.private_key".to_string(),
+                support_command_config::check_key(&self.darwinia_evm.private_key, decode),
+            ),
+            (
+                "ethereum.private_key".to_string(),
+                support_command_config::check_key(&self.ethereum.private_key, decode),
+            ),
+        ];
+        if let Some(ecdsa) = &self.ecdsa {
+            keys.push((
+                "ecdsa.private_key".to_string(),
+                support_command_config::check_key(&ecdsa.private_key, decode),
+            ));
+        } else if let Some(private_key) = &self.darwinia_substrate.private_key {
+            keys.push((
+                "darwinia_substrate.private_key".to_string(),
+                support_command_config::check_key(private_key, decode),
+            ));
+        }
+        keys
     }
 }
 
 impl DarwiniaSubstrateConfig {
-    pub async fn to_substrate_client(&self) -> color_eyre::Result<DarwiniaClient> {
-        let config = self.clone().into();
+    /// Darwinia client signing extrinsics by the key
+    pub async fn to_substrate_client(
+        &self,
+        private_key: &str,
+    ) -> color_eyre::Result<DarwiniaClient> {
+        let config = client_darwinia::config::ClientConfig {
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
+            relayer_private_key: private_key.to_string(),
+            relayer_real_account: None,
+        };
         Ok(DarwiniaClientComponent::component(config).await?)
     }
+
+    /// Read-only client of the endpoint and backup endpoints, it never signs
+    pub async fn to_readonly_client(&self) -> color_eyre::Result<DarwiniaClient> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        Ok(DarwiniaClientComponent::readonly(&endpoints).await?)
+    }
+
+    /// Identities of the chains behind the endpoint and backup endpoints
+    pub async fn identities(&self) -> Vec<(String, Result<ChainIdentity, String>)> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        client_common_traits::endpoint::identities::<DarwiniaSubxtConfig>(&endpoints)
+            .await
+            .into_iter()
+            .map(|(endpoint, identity)| (endpoint, identity.map_err(|e| format!("{e:?}"))))
+            .collect()
+    }
 }
@@ -1,25 +1,66 @@
 use bin_e2e::config::BridgeConfig;
+use bin_e2e::preflight::{PreflightChains, PreflightReport};
 use bin_e2e::task::BridgeTask;
+use client_common_traits::ClientCommon;
+use client_darwinia::client::DarwiniaClient;
 use subquery::types::BridgeName;
+use support_command_config::ConfigOpts;
 use support_common::config::Config;
 use support_common::config::Names;
 use thegraph::types::LikethChain;
 
 use crate::bridge::BridgeConfig as RawBridgeConfig;
 
-pub async fn handle_start() -> color_eyre::Result<()> {
+/// Chains of the bridge, the endpoints are checked against them before starting
+const PREFLIGHT_CHAINS: PreflightChains = PreflightChains {
+    darwinia: "darwinia",
+    ethereum: "ethereum",
+    spec_name: <DarwiniaClient as ClientCommon>::SPEC_NAME,
+    genesis_hash: <DarwiniaClient as ClientCommon>::GENESIS_HASH,
+    darwinia_chain_id: 46,
+    ethereum_chain_id: 1,
+};
+
+/// Commented config template of darwinia-ethereum
+const CONFIG_TEMPLATE: &str =
+    include_str!("../../../../.maintain/config/bridge-darwinia-ethereum.toml");
+
+pub async fn handle_start(skip_preflight: bool) -> color_eyre::Result<()> {
     tracing::info!("Start bridge darwinia-ethereum");
     let raw_config: RawBridgeConfig = Config::restore(Names::BridgeDarwiniaEthereum)?;
+    if !skip_preflight {
+        let report = preflight(&raw_config).await?;
+        report.output();
+        report.ensure()?;
+    }
+    let ecdsa = match raw_config.ecdsa_config() {
+        Some(v) => Some(v.resolve_keys()?),
+        None => None,
+    };
+    let substrate_client = match &ecdsa {
+        Some(v) => Some(
+            raw_config
+                .darwinia_substrate
+                .to_substrate_client(&v.private_key)
+                .await?,
+        ),
+        None => None,
+    };
+    let darwinia_evm = raw_config.darwinia_evm.clone().resolve_keys()?;
+    let ecdsa_events_client = raw_config
+        .to_ecdsa_events_client(substrate_client.as_ref())
+        .await?;
     let bridge_config = BridgeConfig {
         name: BridgeName::DarwiniaEthereum.name().into(),
         general: raw_config.general,
-        darwinia_evm: raw_config.darwinia_evm,
-        substrate_client: raw_config.darwinia_substrate.to_substrate_client().await?,
-        ethereum: raw_config.ethereum,
+        darwinia_evm,
+        substrate_client,
+        ecdsa,
+        ethereum: raw_config.ethereum.resolve_keys()?,
         beacon: raw_config.beacon,
-        substrate_index: raw_config
+        ecdsa_events: raw_config
             .index
-            .to_substrate_subquery(BridgeName::DarwiniaEthereum),
+            .to_ecdsa_events(BridgeName::DarwiniaEthereum, ecdsa_events_client)?,
         evm_index: raw_config.index.to_evm_thegraph(LikethChain::Darwinia)?,
     };
     let _manager = BridgeTask::new(bridge_config)?;
@@ -27,3 +68,30 @@ pub async fn handle_start() -> color_eyre::Result<()> {
         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
     }
 }
+
+pub async fn handle_preflight() -> color_eyre::Result<()> {
+    let raw_config: RawBridgeConfig = Config::restore(Names::BridgeDarwiniaEthereum)?;
+    let report = preflight(&raw_config).await?;
+    report.output();
+    report.ensure()?;
+    Ok(())
+}
+
+async fn preflight(raw_config: &RawBridgeConfig) -> color_eyre::Result<PreflightReport> {
+    let substrate_identities = raw_config.darwinia_substrate.identities().await;
+    bin_e2e::preflight::preflight(
+        &raw_config.darwinia_evm,
+        &raw_config.ethereum,
+        &PREFLIGHT_CHAINS,
+        substrate_identities,
+    )
+    .await
+}
+
+pub fn handle_config(command: ConfigOpts) -> color_eyre::Result<()> {
+    support_command_config::handle_config::<RawBridgeConfig>(
+        Names::BridgeDarwiniaEthereum,
+        CONFIG_TEMPLATE,
+        command,
+    )
+}
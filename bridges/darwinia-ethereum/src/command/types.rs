@@ -1,16 +1,41 @@
+use std::path::PathBuf;
+
 use structopt::StructOpt;
+use support_command_config::ConfigOpts;
 use support_command_kv::NamespaceKvOpts;
 
-/// Bridge darwinia-ethereum options
+/// Bridge darwinia-ethereum
 #[derive(Debug, StructOpt)]
 #[structopt(name = "darwinia-ethereum", about = "Bridge darwinia-ethereum")]
+pub struct BridgeOpts {
+    /// Config file merged over the bridge config in `BRIDGER_HOME`
+    #[structopt(long, global = true, parse(from_os_str))]
+    pub config: Option<PathBuf>,
+    #[structopt(subcommand)]
+    pub opts: Opts,
+}
+
+/// Bridge darwinia-ethereum options
+#[derive(Debug, StructOpt)]
 pub enum Opts {
     /// Start bridge darwinia-ethereum
-    Start,
+    Start {
+        /// Don't check endpoints, light clients and lanes before starting
+        #[structopt(long)]
+        skip_preflight: bool,
+    },
+    /// Check endpoints, light clients and lanes, it's done before starting too
+    Preflight,
     /// Kv command
     Kv {
         /// Commands of kv
         #[structopt(flatten)]
         command: NamespaceKvOpts,
     },
+    /// Generate, validate and show config
+    Config {
+        /// Commands of config
+        #[structopt(flatten)]
+        command: ConfigOpts,
+    },
 }
@@ -1,11 +1,15 @@
 use bridge_darwinia_ethereum::cli;
-use bridge_darwinia_ethereum::command::types::Opts;
+use bridge_darwinia_ethereum::command::types::BridgeOpts;
 use structopt::StructOpt;
+use support_common::config::Config;
 
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
     support_common::initialize::init()?;
-    let opt = Opts::from_args();
-    cli::execute(opt).await?;
+    let opts = BridgeOpts::from_args();
+    if let Some(path) = opts.config {
+        Config::set_overlay_file(path);
+    }
+    cli::execute(opts.opts).await?;
     Ok(())
 }
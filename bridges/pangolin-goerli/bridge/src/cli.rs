@@ -6,14 +6,19 @@ use subquery::types::BridgeName;
 /// Execute command
 pub async fn execute(opts: Opts) -> color_eyre::Result<()> {
     match opts {
-        Opts::Start => handler::handle_start().await,
+        Opts::Start { skip_preflight } => handler::handle_start(skip_preflight).await,
+        Opts::Preflight => handler::handle_preflight().await,
         Opts::Kv { command } => {
             let task_name = BridgeName::PangolinGoerli.name();
             let namespace = command.namespace.unwrap_or_else(|| task_name.to_string());
             let state_options = StateOptions {
                 db_name: task_name.to_string(),
+                backend: None,
             };
-            support_command_kv::handle_kv(state_options, Some(namespace), command.command)
+            // the schema documents the keys of the bridge namespace only
+            let schema = (namespace == task_name).then_some(&bin_e2e::kv::KV_SCHEMA);
+            support_command_kv::handle_kv(state_options, Some(namespace), schema, command.command)
         }
+        Opts::Config { command } => handler::handle_config(command),
     }
 }
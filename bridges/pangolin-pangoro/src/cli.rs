@@ -1,3 +1,5 @@
+use bin_s2s::manual::ManualRelayOptions;
+
 use crate::command::handler;
 use crate::command::types::Opts;
 
@@ -5,6 +7,45 @@ use crate::command::types::Opts;
 pub async fn execute(opts: Opts) -> color_eyre::Result<()> {
     match opts {
         Opts::Init { bridge } => handler::handle_init(bridge).await,
-        Opts::Start => handler::handle_relay().await,
+        Opts::Start { skip_preflight } => handler::handle_relay(skip_preflight).await,
+        Opts::Preflight => handler::handle_preflight().await,
+        Opts::Message {
+            bridge,
+            lane,
+            begin,
+            end,
+            output,
+        } => handler::handle_message(bridge, lane, begin..=end.unwrap_or(begin), output).await,
+        Opts::RelayHeader {
+            bridge,
+            block,
+            dry_run,
+        } => handler::handle_relay_header(bridge, block, dry_run).await,
+        Opts::Deliver {
+            bridge,
+            lane,
+            begin,
+            end,
+            ignore_strategy,
+            dry_run,
+        } => {
+            let options = ManualRelayOptions {
+                ignore_strategy,
+                dry_run,
+            };
+            handler::handle_deliver(bridge, lane, begin..=end.unwrap_or(begin), options).await
+        }
+        Opts::Confirm {
+            bridge,
+            lane,
+            dry_run,
+        } => {
+            let options = ManualRelayOptions {
+                ignore_strategy: false,
+                dry_run,
+            };
+            handler::handle_confirm(bridge, lane, options).await
+        }
+        Opts::Config { command } => handler::handle_config(command),
     }
 }
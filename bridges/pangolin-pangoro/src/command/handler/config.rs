@@ -0,0 +1,16 @@
+use support_command_config::ConfigOpts;
+use support_common::config::Names;
+
+use crate::types::RawBridgeConfig;
+
+/// Commented config template of pangolin-pangoro
+const CONFIG_TEMPLATE: &str =
+    include_str!("../../../../../.maintain/config/bridge-pangolin-pangoro.toml");
+
+pub fn handle_config(command: ConfigOpts) -> color_eyre::Result<()> {
+    support_command_config::handle_config::<RawBridgeConfig>(
+        Names::BridgePangolinPangoro,
+        CONFIG_TEMPLATE,
+        command,
+    )
+}
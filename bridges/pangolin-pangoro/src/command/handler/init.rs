@@ -9,6 +9,7 @@ use crate::types::{BridgeFlow, RawBridgeConfig};
 pub async fn handle_init(bridge: BridgeFlow) -> color_eyre::Result<()> {
     tracing::info!(target: "pangolin-pangoro", "init bridge {:?}", bridge);
     let bridge_config: RawBridgeConfig = Config::restore(Names::BridgePangolinPangoro)?;
+    let bridge_config = bridge_config.resolve_keys()?;
     init_bridge(bridge, bridge_config).await?;
     Ok(())
 }
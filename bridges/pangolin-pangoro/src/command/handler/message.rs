@@ -0,0 +1,45 @@
+use std::ops::RangeInclusive;
+
+use bin_s2s::inspect;
+use bin_s2s::types::HexLaneId;
+
+use support_common::config::{Config, Names};
+use support_common::error::BridgerError;
+use support_terminal::output::OutputFormat;
+
+use crate::types::{BridgeFlow, RawBridgeConfig};
+
+pub async fn handle_message(
+    bridge: BridgeFlow,
+    lane: Option<HexLaneId>,
+    nonces: RangeInclusive<u64>,
+    output: OutputFormat,
+) -> color_eyre::Result<()> {
+    tracing::info!(
+        target: "pangolin-pangoro",
+        "inspect messages {:?} of bridge {:?}",
+        nonces,
+        bridge,
+    );
+    let bridge_config: RawBridgeConfig = Config::restore(Names::BridgePangolinPangoro)?;
+    let lane = match lane {
+        Some(v) => v,
+        None => bridge_config
+            .relay
+            .lanes
+            .first()
+            .cloned()
+            .ok_or_else(|| BridgerError::Custom("Missing lanes in relay config".to_string()))?,
+    };
+    let client_pangolin = bridge_config.pangolin.readonly_client().await?;
+    let client_pangoro = bridge_config.pangoro.readonly_client().await?;
+    let inspection = match bridge {
+        BridgeFlow::PangolinToPangoro => {
+            inspect::inspect_lane(&client_pangolin, &client_pangoro, lane.0, nonces).await?
+        }
+        BridgeFlow::PangoroToPangolin => {
+            inspect::inspect_lane(&client_pangoro, &client_pangolin, lane.0, nonces).await?
+        }
+    };
+    inspect::output_lane_inspection(&inspection, output)
+}
@@ -0,0 +1,13 @@
+use support_common::config::{Config, Names};
+
+use crate::command::handler::bridge_config;
+use crate::types::RawBridgeConfig;
+
+pub async fn handle_preflight() -> color_eyre::Result<()> {
+    let raw_bridge_config: RawBridgeConfig = Config::restore(Names::BridgePangolinPangoro)?;
+    let bridge_config = bridge_config(raw_bridge_config)?;
+    let report = bridge_config.preflight().await?;
+    report.output();
+    report.ensure()?;
+    Ok(())
+}
@@ -6,17 +6,45 @@ use bin_s2s::types::BasicSubqueryInfo;
 use support_common::config::{Config, Names};
 use support_types::mark::BridgeName;
 
-use crate::types::RawBridgeConfig;
+use crate::types::{
+    MoonbaseChainConfig, PangolinChainConfig, PangoroChainConfig, RawBridgeConfig,
+    RococoChainConfig,
+};
 
-pub async fn handle_relay() -> color_eyre::Result<()> {
+pub async fn handle_relay(skip_preflight: bool) -> color_eyre::Result<()> {
     tracing::info!(target: "pangolin-pangoro", "start bridge pangolin-pangoro");
     let raw_bridge_config: RawBridgeConfig = Config::restore(Names::BridgePangolinPangoro)?;
+    let bridge_config = bridge_config(raw_bridge_config)?;
+    if !skip_preflight {
+        let report = bridge_config.preflight().await?;
+        report.output();
+        report.ensure()?;
+    }
+    let _task = BridgeTask::new(bridge_config)?;
+    loop {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+}
+
+/// Bridge config of pangolin-pangoro from the raw config, keys are resolved
+pub(crate) fn bridge_config(
+    raw_bridge_config: RawBridgeConfig,
+) -> color_eyre::Result<
+    BridgeConfig<
+        RococoChainConfig,
+        PangolinChainConfig,
+        MoonbaseChainConfig,
+        PangoroChainConfig,
+        BasicSubqueryInfo,
+    >,
+> {
+    let raw_bridge_config = raw_bridge_config.resolve_keys()?;
     let raw_config_index = raw_bridge_config.index;
     let (source_para_id, target_para_id) = (
         raw_bridge_config.pangolin.para_id,
         raw_bridge_config.pangoro.para_id,
     );
-    let bridge_config = BridgeConfig {
+    Ok(BridgeConfig {
         chain: ChainConfig {
             source_para: raw_bridge_config.pangolin,
             source_relay: raw_bridge_config.rococo,
@@ -40,9 +68,5 @@ pub async fn handle_relay() -> color_eyre::Result<()> {
             target_para: BasicSubqueryInfo::new(BridgeName::PangolinPangoro, raw_config_index.pangoro),
             target_relay: BasicSubqueryInfo::new(BridgeName::PangolinPangoro, raw_config_index.moonbase),
         },
-    };
-    let _task = BridgeTask::new(bridge_config)?;
-    loop {
-        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-    }
+    })
 }
@@ -1,10 +1,27 @@
+use std::path::PathBuf;
+
+use bin_s2s::manual::HeaderSelector;
+use bin_s2s::types::HexLaneId;
 use structopt::StructOpt;
+use support_command_config::ConfigOpts;
+
+use support_terminal::output::OutputFormat;
 
 use crate::types::BridgeFlow;
 
-/// Bridge pangolin-pangoro operations
+/// Bridge pangolin-pangoro
 #[derive(Debug, StructOpt)]
 #[structopt(name = "pangolin-pangoro", about = "Bridge pangolin-pangoro")]
+pub struct BridgeOpts {
+    /// Config file merged over the bridge config in `BRIDGER_HOME`
+    #[structopt(long, global = true, parse(from_os_str))]
+    pub config: Option<PathBuf>,
+    #[structopt(subcommand)]
+    pub opts: Opts,
+}
+
+/// Bridge pangolin-pangoro operations
+#[derive(Debug, StructOpt)]
 pub enum Opts {
     /// Init bridge
     Init {
@@ -13,5 +30,80 @@ pub enum Opts {
         bridge: BridgeFlow,
     },
     /// Start bridge
-    Start,
+    Start {
+        /// Don't check endpoints, bridge initialization and lanes before starting
+        #[structopt(long)]
+        skip_preflight: bool,
+    },
+    /// Check endpoints, bridge initialization and lanes, it's done before starting too
+    Preflight,
+    /// Inspect messages of a lane, show lane data, decoded payload and fee market order
+    Message {
+        /// Bridge flow direction, [pangolin-to-pangoro | pangoro-to-pangolin]
+        #[structopt()]
+        bridge: BridgeFlow,
+        /// Lane id, default is the first lane of relay config
+        #[structopt(short, long)]
+        lane: Option<HexLaneId>,
+        /// Begin nonce
+        #[structopt(short, long)]
+        begin: u64,
+        /// End nonce, default is the begin nonce
+        #[structopt(short, long)]
+        end: Option<u64>,
+        /// Output mode, support raw|table|json
+        #[structopt(short, long, default_value = "table")]
+        output: OutputFormat,
+    },
+    /// Relay a header with its justification once, the header of source relay chain
+    RelayHeader {
+        /// Bridge flow direction, [pangolin-to-pangoro | pangoro-to-pangolin]
+        #[structopt()]
+        bridge: BridgeFlow,
+        /// Block number or block hash (0x prefixed)
+        #[structopt(long)]
+        block: HeaderSelector,
+        /// Only print the proof size, don't submit
+        #[structopt(long)]
+        dry_run: bool,
+    },
+    /// Deliver messages of the nonce range once
+    Deliver {
+        /// Bridge flow direction, [pangolin-to-pangoro | pangoro-to-pangolin]
+        #[structopt()]
+        bridge: BridgeFlow,
+        /// Lane id, default is the first lane of relay config
+        #[structopt(short, long)]
+        lane: Option<HexLaneId>,
+        /// Begin nonce
+        #[structopt(short, long)]
+        begin: u64,
+        /// End nonce, default is the begin nonce
+        #[structopt(short, long)]
+        end: Option<u64>,
+        /// Deliver even if the relay strategy decides not to relay
+        #[structopt(long)]
+        ignore_strategy: bool,
+        /// Only print the proof size and dispatch weight, don't submit
+        #[structopt(long)]
+        dry_run: bool,
+    },
+    /// Confirm delivered messages once
+    Confirm {
+        /// Bridge flow direction, [pangolin-to-pangoro | pangoro-to-pangolin]
+        #[structopt()]
+        bridge: BridgeFlow,
+        /// Lane id, default is the first lane of relay config
+        #[structopt(short, long)]
+        lane: Option<HexLaneId>,
+        /// Only print the proof size, don't submit
+        #[structopt(long)]
+        dry_run: bool,
+    },
+    /// Generate, validate and show config
+    Config {
+        /// Commands of config
+        #[structopt(flatten)]
+        command: ConfigOpts,
+    },
 }
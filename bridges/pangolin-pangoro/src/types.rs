@@ -10,6 +10,7 @@ use client_rococo::component::RococoClientComponent;
 use serde::{Deserialize, Serialize};
 use subquery::types::OriginType;
 use subquery::SubqueryConfig;
+use support_command_config::ConfigCheck;
 
 use support_types::mark::ChainName;
 
@@ -30,32 +31,141 @@ pub struct RawBridgeConfig {
     pub index: RawIndexConfig,
 }
 
+impl RawBridgeConfig {
+    /// Decrypt `keystore:<id>` signers from keystore
+    pub fn resolve_keys(mut self) -> support_keystore::KeystoreResult<Self> {
+        self.pangolin.signer = support_keystore::resolve(&self.pangolin.signer)?;
+        for signer in self.pangolin.extra_signers.iter_mut() {
+            *signer = support_keystore::resolve(signer)?;
+        }
+        self.pangoro.signer = support_keystore::resolve(&self.pangoro.signer)?;
+        for signer in self.pangoro.extra_signers.iter_mut() {
+            *signer = support_keystore::resolve(signer)?;
+        }
+        Ok(self)
+    }
+}
+
+impl ConfigCheck for RawBridgeConfig {
+    fn endpoints(&self) -> Vec<(String, String)> {
+        let mut endpoints = Vec::new();
+        endpoints.extend(support_command_config::section_endpoints(
+            "pangolin",
+            &self.pangolin.endpoint,
+            &self.pangolin.backup_endpoints,
+        ));
+        endpoints.extend(support_command_config::section_endpoints(
+            "pangoro",
+            &self.pangoro.endpoint,
+            &self.pangoro.backup_endpoints,
+        ));
+        endpoints.extend(support_command_config::section_endpoints(
+            "rococo",
+            &self.rococo.endpoint,
+            &self.rococo.backup_endpoints,
+        ));
+        endpoints.extend(support_command_config::section_endpoints(
+            "moonbase",
+            &self.moonbase.endpoint,
+            &self.moonbase.backup_endpoints,
+        ));
+        for (chain, config) in [
+            ("pangolin", &self.index.pangolin),
+            ("pangoro", &self.index.pangoro),
+            ("rococo", &self.index.rococo),
+            ("moonbase", &self.index.moonbase),
+        ] {
+            endpoints.push((format!("index.{chain}.endpoint"), config.endpoint.clone()));
+        }
+        endpoints
+    }
+
+    fn check_keys(&self) -> Vec<(String, Result<(), String>)> {
+        let mut keys = Vec::new();
+        let decode_pangolin =
+            |v: &str| client_pangolin::types::DarwiniaAccount::new(v.to_string(), None).map(|_| ());
+        keys.push((
+            "pangolin.signer".to_string(),
+            support_command_config::check_key(&self.pangolin.signer, decode_pangolin),
+        ));
+        for (ix, signer) in self.pangolin.extra_signers.iter().enumerate() {
+            keys.push((
+                format!("pangolin.extra_signers.{ix}"),
+                support_command_config::check_key(signer, decode_pangolin),
+            ));
+        }
+        let decode_pangoro =
+            |v: &str| client_pangoro::types::DarwiniaAccount::new(v.to_string(), None).map(|_| ());
+        keys.push((
+            "pangoro.signer".to_string(),
+            support_command_config::check_key(&self.pangoro.signer, decode_pangoro),
+        ));
+        for (ix, signer) in self.pangoro.extra_signers.iter().enumerate() {
+            keys.push((
+                format!("pangoro.extra_signers.{ix}"),
+                support_command_config::check_key(signer, decode_pangoro),
+            ));
+        }
+        keys
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PangolinChainConfig {
     /// Endpoint
     pub endpoint: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
     pub signer: String,
+    /// Extra relayer accounts, messages relay is submitted by an idle one of all signers
+    #[serde(default)]
+    pub extra_signers: Vec<String>,
+    /// Proxied real account, calls are submitted as `proxy.proxy` of it and rewards go to it
+    #[serde(default)]
+    pub real_account: Option<String>,
     pub para_id: u32,
+    /// Minimum free balance of relayer, submitting is paused when below it
+    #[serde(default, with = "support_common::serde_str::option")]
+    pub reserve_balance: Option<u128>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PangoroChainConfig {
     /// Endpoint
     pub endpoint: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
     pub signer: String,
+    /// Extra relayer accounts, messages relay is submitted by an idle one of all signers
+    #[serde(default)]
+    pub extra_signers: Vec<String>,
+    /// Proxied real account, calls are submitted as `proxy.proxy` of it and rewards go to it
+    #[serde(default)]
+    pub real_account: Option<String>,
     pub para_id: u32,
+    /// Minimum free balance of relayer, submitting is paused when below it
+    #[serde(default, with = "support_common::serde_str::option")]
+    pub reserve_balance: Option<u128>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MoonbaseChainConfig {
     /// Endpoint
     pub endpoint: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RococoChainConfig {
     /// Endpoint
     pub endpoint: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -82,22 +192,77 @@ impl S2SParaBridgeSoloChainInfo for PangolinChainConfig {
 
     fn account(
         &self,
+    ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
+        let account = client_pangolin::types::DarwiniaAccount::new(
+            self.signer.clone(),
+            self.real_account.clone(),
+        )
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+        Ok(*account.real_account())
+    }
+
+    fn signer_account(
+        &self,
     ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
         let account = client_pangolin::types::DarwiniaAccount::new(self.signer.clone(), None)
             .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
         Ok(*account.account_id())
     }
 
+    fn signer_accounts(
+        &self,
+    ) -> BinS2SResult<Vec<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId>>
+    {
+        let mut accounts = vec![self.signer_account()?];
+        for signer in &self.extra_signers {
+            let account = client_pangolin::types::DarwiniaAccount::new(signer.clone(), None)
+                .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+            accounts.push(*account.account_id());
+        }
+        Ok(accounts)
+    }
+
+    fn reserve_balance(
+        &self,
+    ) -> Option<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::Balance> {
+        self.reserve_balance
+    }
+
     async fn client(&self) -> BinS2SResult<Self::Client> {
-        let config = client_pangolin::config::ClientConfig {
-            endpoint: self.endpoint.clone(),
-            relayer_private_key: self.signer.clone(),
-            relayer_real_account: None,
-        };
+        let config = self.client_config(&self.signer);
         Ok(PangolinClientComponent::component(config)
             .await
             .map_err(|e| BinS2SError::Client(format!("{e:?}")))?)
     }
+
+    async fn extra_clients(&self) -> BinS2SResult<Vec<Self::Client>> {
+        let mut clients = Vec::with_capacity(self.extra_signers.len());
+        for signer in &self.extra_signers {
+            let client = PangolinClientComponent::component(self.client_config(signer))
+                .await
+                .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+            clients.push(client);
+        }
+        Ok(clients)
+    }
+}
+
+impl PangolinChainConfig {
+    fn client_config(&self, signer: &str) -> client_pangolin::config::ClientConfig {
+        client_pangolin::config::ClientConfig {
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
+            relayer_private_key: signer.to_string(),
+            relayer_real_account: self.real_account.clone(),
+        }
+    }
+
+    /// Read-only client, the signers aren't resolved so it never asks for keystore password
+    pub async fn readonly_client(&self) -> BinS2SResult<client_pangolin::client::PangolinClient> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        PangolinClientComponent::readonly(&endpoints)
+            .await
+            .map_err(|e| BinS2SError::Client(format!("{e:?}")))
+    }
 }
 
 impl S2SBasicChainInfo for PangoroChainConfig {
@@ -114,22 +279,77 @@ impl S2SParaBridgeSoloChainInfo for PangoroChainConfig {
 
     fn account(
         &self,
+    ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
+        let account = client_pangoro::types::DarwiniaAccount::new(
+            self.signer.clone(),
+            self.real_account.clone(),
+        )
+        .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+        Ok(*account.real_account())
+    }
+
+    fn signer_account(
+        &self,
     ) -> BinS2SResult<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId> {
         let account = client_pangoro::types::DarwiniaAccount::new(self.signer.clone(), None)
             .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
         Ok(*account.account_id())
     }
 
+    fn signer_accounts(
+        &self,
+    ) -> BinS2SResult<Vec<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::AccountId>>
+    {
+        let mut accounts = vec![self.signer_account()?];
+        for signer in &self.extra_signers {
+            let account = client_pangoro::types::DarwiniaAccount::new(signer.clone(), None)
+                .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+            accounts.push(*account.account_id());
+        }
+        Ok(accounts)
+    }
+
+    fn reserve_balance(
+        &self,
+    ) -> Option<<<Self::Client as ClientCommon>::Chain as bp_runtime::Chain>::Balance> {
+        self.reserve_balance
+    }
+
     async fn client(&self) -> BinS2SResult<Self::Client> {
-        let config = client_pangoro::config::ClientConfig {
-            endpoint: self.endpoint.clone(),
-            relayer_private_key: self.signer.clone(),
-            relayer_real_account: None,
-        };
+        let config = self.client_config(&self.signer);
         Ok(PangoroClientComponent::component(config)
             .await
             .map_err(|e| BinS2SError::Client(format!("{e:?}")))?)
     }
+
+    async fn extra_clients(&self) -> BinS2SResult<Vec<Self::Client>> {
+        let mut clients = Vec::with_capacity(self.extra_signers.len());
+        for signer in &self.extra_signers {
+            let client = PangoroClientComponent::component(self.client_config(signer))
+                .await
+                .map_err(|e| BinS2SError::Client(format!("{e:?}")))?;
+            clients.push(client);
+        }
+        Ok(clients)
+    }
+}
+
+impl PangoroChainConfig {
+    fn client_config(&self, signer: &str) -> client_pangoro::config::ClientConfig {
+        client_pangoro::config::ClientConfig {
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
+            relayer_private_key: signer.to_string(),
+            relayer_real_account: self.real_account.clone(),
+        }
+    }
+
+    /// Read-only client, the signers aren't resolved so it never asks for keystore password
+    pub async fn readonly_client(&self) -> BinS2SResult<client_pangoro::client::PangoroClient> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        PangoroClientComponent::readonly(&endpoints)
+            .await
+            .map_err(|e| BinS2SError::Client(format!("{e:?}")))
+    }
 }
 
 // === relay chain
@@ -144,7 +364,7 @@ impl S2SParaBridgeRelayChainInfo for RococoChainConfig {
 
     async fn client(&self) -> BinS2SResult<Self::Client> {
         let config = client_rococo::config::ClientConfig {
-            endpoint: self.endpoint.clone(),
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
             relayer_private_key: "//Alice".to_string(),
         };
         Ok(RococoClientComponent::component(config)
@@ -163,7 +383,7 @@ impl S2SParaBridgeRelayChainInfo for MoonbaseChainConfig {
 
     async fn client(&self) -> BinS2SResult<Self::Client> {
         let config = client_moonbase::config::ClientConfig {
-            endpoint: self.endpoint.clone(),
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
             relayer_private_key: "//Alice".to_string(),
         };
         Ok(MoonbaseClientComponent::component(config)
@@ -1,8 +1,20 @@
+use std::path::PathBuf;
+
 use structopt::StructOpt;
 
-/// Bridge pangoro-chapel options
+/// Bridge pangoro-chapel
 #[derive(Debug, StructOpt)]
 #[structopt(name = "pangoro-chapel", about = "Bridge pangoro-chapel")]
+pub struct BridgeOpts {
+    /// Config file merged over the bridge config in `BRIDGER_HOME`
+    #[structopt(long, global = true, parse(from_os_str))]
+    pub config: Option<PathBuf>,
+    #[structopt(subcommand)]
+    pub opts: Opts,
+}
+
+/// Bridge pangoro-chapel options
+#[derive(Debug, StructOpt)]
 pub enum Opts {
     /// Start bridge pangoro-chapel
     Start,
@@ -1,6 +1,7 @@
 use structopt::StructOpt;
+use support_common::config::Config;
 
-use crate::command::types::Opts;
+use crate::command::types::BridgeOpts;
 
 mod bridge;
 mod cli;
@@ -11,7 +12,10 @@ mod service;
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
     support_common::initialize::init()?;
-    let opt = Opts::from_args();
-    cli::execute(opt).await?;
+    let opts = BridgeOpts::from_args();
+    if let Some(path) = opts.config {
+        Config::set_overlay_file(path);
+    }
+    cli::execute(opts.opts).await?;
     Ok(())
 }
@@ -1,9 +1,15 @@
+use client_common_traits::ChainIdentity;
 use client_pangoro::client::PangoroClient;
 use client_pangoro::component::PangoroClientComponent;
+use client_pangoro::config::PangoroSubxtConfig;
+use relay_e2e::ecdsa::events::EcdsaEventsSourceType;
+use relay_e2e::types::ethereum::FastEthereumAccount;
 use serde::{Deserialize, Serialize};
+use support_command_config::ConfigCheck;
 
 use bin_e2e::config::{
-    BeaconApiConfig, EVMChainConfig, ExecutionLayerInfoConfig, GeneralConfig, IndexConfig,
+    BeaconApiConfig, EVMChainConfig, EcdsaConfig, ExecutionLayerInfoConfig, GeneralConfig,
+    IndexConfig,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -11,6 +17,9 @@ pub struct BridgeConfig {
     pub general: GeneralConfig,
     pub pangoro_evm: EVMChainConfig,
     pub pangoro_substrate: PangoroSubstrateConfig,
+    /// ECDSA authority key, only required for authorities
+    #[serde(default)]
+    pub ecdsa: Option<EcdsaConfig>,
     pub goerli: ExecutionLayerInfoConfig,
     pub beacon: BeaconApiConfig,
     pub index: IndexConfig,
@@ -19,22 +28,143 @@ pub struct BridgeConfig {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PangoroSubstrateConfig {
     pub endpoint: String,
-    pub private_key: String,
+    /// Backup endpoints, switched to when the endpoint is unhealthy
+    #[serde(default)]
+    pub backup_endpoints: Vec<String>,
+    /// Deprecated, the ECDSA authority key is configured in `ecdsa`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
 }
 
-impl From<PangoroSubstrateConfig> for client_pangoro::config::ClientConfig {
-    fn from(config: PangoroSubstrateConfig) -> Self {
-        client_pangoro::config::ClientConfig {
-            endpoint: config.endpoint,
-            relayer_private_key: config.private_key,
-            relayer_real_account: None,
+impl BridgeConfig {
+    /// ECDSA authority config, the key in substrate section is still accepted
+    pub fn ecdsa_config(&self) -> Option<EcdsaConfig> {
+        if let Some(ecdsa) = &self.ecdsa {
+            return Some(ecdsa.clone());
+        }
+        let private_key = self.pangoro_substrate.private_key.as_ref()?;
+        tracing::warn!(
+            "The private_key of pangoro_substrate is deprecated, please move it to the ecdsa section",
+        );
+        Some(EcdsaConfig::new(private_key))
+    }
+
+    /// Darwinia client scanning ECDSA authority events, only present when they're read from
+    /// chain. It's the authority client if any, otherwise a read-only client without key.
+    pub async fn to_ecdsa_events_client(
+        &self,
+        substrate_client: Option<&PangoroClient>,
+    ) -> color_eyre::Result<Option<PangoroClient>> {
+        if self.index.ecdsa_events != EcdsaEventsSourceType::Chain {
+            return Ok(None);
+        }
+        if let Some(client) = substrate_client {
+            return Ok(Some(client.clone()));
+        }
+        let client = self.pangoro_substrate.to_readonly_client().await?;
+        Ok(Some(client))
+    }
+}
+
+impl ConfigCheck for BridgeConfig {
+    fn endpoints(&self) -> Vec<(String, String)> {
+        let mut endpoints = Vec::new();
+        for (section, endpoint, ws_endpoint, backup_endpoints) in [
+            (
+                "pangoro_evm",
+                &self.pangoro_evm.endpoint,
+                &self.pangoro_evm.ws_endpoint,
+                &self.pangoro_evm.backup_endpoints,
+            ),
+            (
+                "goerli",
+                &self.goerli.endpoint,
+                &self.goerli.ws_endpoint,
+                &self.goerli.backup_endpoints,
+            ),
+        ] {
+            endpoints.extend(support_command_config::section_endpoints(
+                section,
+                endpoint,
+                backup_endpoints,
+            ));
+            if let Some(ws_endpoint) = ws_endpoint {
+                endpoints.push((format!("{section}.ws_endpoint"), ws_endpoint.clone()));
+            }
         }
+        endpoints.extend(support_command_config::section_endpoints(
+            "pangoro_substrate",
+            &self.pangoro_substrate.endpoint,
+            &self.pangoro_substrate.backup_endpoints,
+        ));
+        endpoints.push(("beacon.endpoint".to_string(), self.beacon.endpoint.clone()));
+        endpoints.push((
+            "index.evm_chain.endpoint".to_string(),
+            self.index.evm_chain.endpoint.clone(),
+        ));
+        if let Some(substrate_chain) = &self.index.substrate_chain {
+            endpoints.push((
+                "index.substrate_chain.endpoint".to_string(),
+                substrate_chain.endpoint.clone(),
+            ));
+        }
+        endpoints
+    }
+
+    fn check_keys(&self) -> Vec<(String, Result<(), String>)> {
+        let decode = |v: &str| FastEthereumAccount::new(v).signer().map(|_| ());
+        let mut keys = vec![
+            (
+                "pangoro_evm.private_key".to_string(),
+                support_command_config::check_key(&self.pangoro_evm.private_key, decode),
+            ),
+            (
+                "goerli.private_key".to_string(),
+                support_command_config::check_key(&self.goerli.private_key, decode),
+            ),
+        ];
+        if let Some(ecdsa) = &self.ecdsa {
+            keys.push((
+                "ecdsa.private_key".to_string(),
+                support_command_config::check_key(&ecdsa.private_key, decode),
+            ));
+        } else if let Some(private_key) = &self.pangoro_substrate.private_key {
+            keys.push((
+                "pangoro_substrate.private_key".to_string(),
+                support_command_config::check_key(private_key, decode),
+            ));
+        }
+        keys
     }
 }
 
 impl PangoroSubstrateConfig {
-    pub async fn to_substrate_client(&self) -> color_eyre::Result<PangoroClient> {
-        let config = self.clone().into();
+    /// Darwinia client signing extrinsics by the key
+    pub async fn to_substrate_client(
+        &self,
+        private_key: &str,
+    ) -> color_eyre::Result<PangoroClient> {
+        let config = client_pangoro::config::ClientConfig {
+            endpoints: [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat(),
+            relayer_private_key: private_key.to_string(),
+            relayer_real_account: None,
+        };
         Ok(PangoroClientComponent::component(config).await?)
     }
+
+    /// Read-only client of the endpoint and backup endpoints, it never signs
+    pub async fn to_readonly_client(&self) -> color_eyre::Result<PangoroClient> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        Ok(PangoroClientComponent::readonly(&endpoints).await?)
+    }
+
+    /// Identities of the chains behind the endpoint and backup endpoints
+    pub async fn identities(&self) -> Vec<(String, Result<ChainIdentity, String>)> {
+        let endpoints = [vec![self.endpoint.clone()], self.backup_endpoints.clone()].concat();
+        client_common_traits::endpoint::identities::<PangoroSubxtConfig>(&endpoints)
+            .await
+            .into_iter()
+            .map(|(endpoint, identity)| (endpoint, identity.map_err(|e| format!("{e:?}"))))
+            .collect()
+    }
 }
@@ -1,16 +1,41 @@
+use std::path::PathBuf;
+
 use structopt::StructOpt;
+use support_command_config::ConfigOpts;
 use support_command_kv::NamespaceKvOpts;
 
-/// Bridge pangoro-goerli options
+/// Bridge pangoro-goerli
 #[derive(Debug, StructOpt)]
 #[structopt(name = "pangoro-goerli", about = "Bridge pangoro-goerli")]
+pub struct BridgeOpts {
+    /// Config file merged over the bridge config in `BRIDGER_HOME`
+    #[structopt(long, global = true, parse(from_os_str))]
+    pub config: Option<PathBuf>,
+    #[structopt(subcommand)]
+    pub opts: Opts,
+}
+
+/// Bridge pangoro-goerli options
+#[derive(Debug, StructOpt)]
 pub enum Opts {
     /// Start bridge pangoro-goerli
-    Start,
+    Start {
+        /// Don't check endpoints, light clients and lanes before starting
+        #[structopt(long)]
+        skip_preflight: bool,
+    },
+    /// Check endpoints, light clients and lanes, it's done before starting too
+    Preflight,
     /// Kv command
     Kv {
         /// Commands of kv
         #[structopt(flatten)]
         command: NamespaceKvOpts,
     },
+    /// Generate, validate and show config
+    Config {
+        /// Commands of config
+        #[structopt(flatten)]
+        command: ConfigOpts,
+    },
 }
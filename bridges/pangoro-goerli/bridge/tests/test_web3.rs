@@ -42,16 +42,30 @@ fn test_signing() {
 
 async fn get_bridge_config() -> color_eyre::Result<BridgeConfig<PangoroClient>> {
     let raw_config: RawBridgeConfig = Config::restore(Names::BridgePangoroGoerli)?;
+    let ecdsa = raw_config.ecdsa_config();
+    let substrate_client = match &ecdsa {
+        Some(v) => Some(
+            raw_config
+                .pangoro_substrate
+                .to_substrate_client(&v.private_key)
+                .await?,
+        ),
+        None => None,
+    };
+    let ecdsa_events_client = raw_config
+        .to_ecdsa_events_client(substrate_client.as_ref())
+        .await?;
     let bridge_config = BridgeConfig {
         name: Names::BridgePangoroGoerli.name().into(),
         general: raw_config.general,
         darwinia_evm: raw_config.pangoro_evm,
-        substrate_client: raw_config.pangoro_substrate.to_substrate_client().await?,
+        substrate_client,
+        ecdsa,
         ethereum: raw_config.goerli,
         beacon: raw_config.beacon,
-        substrate_index: raw_config
+        ecdsa_events: raw_config
             .index
-            .to_substrate_subquery(BridgeName::PangoroGoerli),
+            .to_ecdsa_events(BridgeName::PangoroGoerli, ecdsa_events_client)?,
         evm_index: raw_config.index.to_evm_thegraph(LikethChain::Pangoro)?,
     };
     Ok(bridge_config)
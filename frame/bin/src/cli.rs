@@ -9,5 +9,8 @@ fn run(opt: Opt) -> color_eyre::Result<()> {
     match opt {
         Opt::List => handler::exec_list(),
         Opt::Registry { command } => handler::handle_registry(command),
+        Opt::Key { command } => handler::handle_key(command),
+        Opt::Run => handler::handle_run(),
+        Opt::Ps { output } => handler::handle_ps(output),
     }
 }
@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+
+use colored::Colorize;
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+
+use support_keystore::{password, KeyInfo, Keystore, KEY_REFERENCE_PREFIX};
+use support_terminal::output::{self, OutputFormat};
+
+use crate::command::types::KeyOpt;
+
+/// Handle key command
+pub fn handle_key(opt: KeyOpt) -> color_eyre::Result<()> {
+    let keystore = Keystore::default();
+    match opt {
+        KeyOpt::Import { id, file } => handle_import(keystore, id, file),
+        KeyOpt::List { output } => handle_list(keystore, output),
+        KeyOpt::ExportPublic { id } => handle_export_public(keystore, id),
+        KeyOpt::Generate { id } => handle_generate(keystore, id),
+    }
+}
+
+fn handle_import(keystore: Keystore, id: String, file: Option<String>) -> color_eyre::Result<()> {
+    let key = match file {
+        Some(file) => {
+            let password = read_password(false)?;
+            keystore.import_file(&id, &PathBuf::from(file), &password)?
+        }
+        None => {
+            let answer = requestty::prompt_one(
+                requestty::Question::password("private_key")
+                    .message("Private key (hex)")
+                    .mask('*')
+                    .build(),
+            )?;
+            let private_key = answer.as_string().unwrap_or_default().trim();
+            let secret = array_bytes::hex2bytes(private_key)
+                .map_err(|e| color_eyre::eyre::eyre!("Invalid private key: {:?}", e))?;
+            let password = read_password(true)?;
+            keystore.import_secret(&id, &secret, &password)?
+        }
+    };
+    output_imported(key);
+    Ok(())
+}
+
+fn handle_generate(keystore: Keystore, id: String) -> color_eyre::Result<()> {
+    let password = read_password(true)?;
+    let key = keystore.generate(&id, &password)?;
+    output_imported(key);
+    Ok(())
+}
+
+fn handle_export_public(keystore: Keystore, id: String) -> color_eyre::Result<()> {
+    let key = keystore.get(&id)?;
+    match key.address {
+        Some(address) => output::output_text(address),
+        None => {
+            // the address is optional in key file, fall back to decrypt it
+            let password = read_password(false)?;
+            let secret = keystore.secret(&id, &password)?;
+            output::output_text(support_keystore::address(&secret)?);
+        }
+    }
+    Ok(())
+}
+
+fn handle_list(keystore: Keystore, out: OutputFormat) -> color_eyre::Result<()> {
+    let keys = keystore.list()?;
+    match out {
+        OutputFormat::Raw => {
+            keys.iter().for_each(|key| {
+                output::output_text(format!(
+                    "{} ({:?}) {}",
+                    key.id.bold(),
+                    key.kind,
+                    key.address.clone().unwrap_or_default()
+                ))
+            });
+        }
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&keys)?;
+            output::output_text(json);
+        }
+        OutputFormat::Table => {
+            let mut table = Table::new();
+            table.max_column_width = 50;
+            table.separate_rows = false;
+            table.style = TableStyle::empty();
+            table.add_row(Row::new(vec![
+                TableCell::new("Id".bold()),
+                TableCell::new("Kind".bold()),
+                TableCell::new("Address".bold()),
+            ]));
+            for key in keys {
+                table.add_row(Row::new(vec![
+                    TableCell::new_with_alignment(key.id, 1, Alignment::Left),
+                    TableCell::new_with_alignment(format!("{:?}", key.kind), 1, Alignment::Left),
+                    TableCell::new_with_alignment(
+                        key.address.unwrap_or_default(),
+                        1,
+                        Alignment::Left,
+                    ),
+                ]));
+            }
+            output::output_text(table.render());
+        }
+    }
+    Ok(())
+}
+
+fn output_imported(key: KeyInfo) {
+    output::output_text(format!(
+        "{}: {}",
+        "ADDRESS".bold(),
+        key.address.unwrap_or_default()
+    ));
+    output::output_text(format!(
+        "{}: {}{}",
+        "REFERENCE".bold(),
+        KEY_REFERENCE_PREFIX,
+        key.id
+    ));
+}
+
+/// Keystore password from env, or prompt for it
+fn read_password(confirm: bool) -> color_eyre::Result<String> {
+    if password::is_provided() {
+        return Ok(password::password()?);
+    }
+    let password = prompt_password("password", "Keystore password")?;
+    if confirm && password != prompt_password("confirm", "Confirm password")? {
+        output::output_err_and_exit("The passwords do not match");
+    }
+    Ok(password)
+}
+
+fn prompt_password(name: &str, message: &str) -> color_eyre::Result<String> {
+    let answer = requestty::prompt_one(
+        requestty::Question::password(name)
+            .message(message)
+            .mask('*')
+            .build(),
+    )?;
+    Ok(answer.as_string().unwrap_or_default().to_string())
+}
@@ -5,5 +5,8 @@ use crate::external;
 pub fn exec_list() -> color_eyre::Result<()> {
     let (_, binaries) = external::helpers::list_externals(None)?;
     binaries.iter().for_each(output::output_text);
+    for (bridge, versions) in external::helpers::list_installed()? {
+        output::output_text(format!("{} ({})", bridge, versions.join(", ")));
+    }
     Ok(())
 }
@@ -1,5 +1,11 @@
+pub use self::key::*;
 pub use self::list::*;
+pub use self::ps::*;
 pub use self::registry::*;
+pub use self::run::*;
 
+mod key;
 mod list;
+mod ps;
 mod registry;
+mod run;
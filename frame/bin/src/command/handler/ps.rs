@@ -0,0 +1,115 @@
+use colored::Colorize;
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+
+use support_terminal::output::{self, OutputFormat};
+
+use crate::supervisor::{self, BridgeStatus, SupervisorState};
+
+pub fn handle_ps(out: OutputFormat) -> color_eyre::Result<()> {
+    let state = match SupervisorState::load()? {
+        Some(v) => v,
+        None => {
+            output::output_text("No supervisor is running, start it by `bridger run`");
+            return Ok(());
+        }
+    };
+    if !state.is_alive() {
+        output::output_warning(format!(
+            "The supervisor (pid {}) isn't running, the state is stale",
+            state.pid
+        ));
+    }
+    let now = supervisor::now();
+    match out {
+        OutputFormat::Raw => {
+            output::output_text(format!(
+                "supervisor pid {}, uptime {}",
+                state.pid,
+                format_uptime(now.saturating_sub(state.started_at))
+            ));
+            for (name, bridge) in &state.bridges {
+                output::output_text(format!(
+                    "{} pid {} uptime {} restarts {} status {:?}",
+                    name,
+                    bridge.pid.map(|v| v.to_string()).unwrap_or_default(),
+                    bridge
+                        .started_at
+                        .map(|v| format_uptime(now.saturating_sub(v)))
+                        .unwrap_or_default(),
+                    bridge.restarts,
+                    bridge.status,
+                ));
+            }
+        }
+        OutputFormat::Json => {
+            output::output_text(serde_json::to_string_pretty(&state)?);
+        }
+        OutputFormat::Table => {
+            let mut table = Table::new();
+            table.max_column_width = 40;
+            table.separate_rows = false;
+            table.style = TableStyle::empty();
+            table.add_row(Row::new(vec![
+                TableCell::new("Bridge".bold()),
+                TableCell::new("PID".bold()),
+                TableCell::new("Uptime".bold()),
+                TableCell::new("Restarts".bold()),
+                TableCell::new("Status".bold()),
+                TableCell::new("Last exit".bold()),
+            ]));
+            for (name, bridge) in &state.bridges {
+                let status = format!("{:?}", bridge.status);
+                let status = match bridge.status {
+                    BridgeStatus::Running => status.green(),
+                    BridgeStatus::Starting | BridgeStatus::Stopping => status.yellow(),
+                    BridgeStatus::Backoff | BridgeStatus::Stopped => status.red(),
+                };
+                table.add_row(Row::new(vec![
+                    TableCell::new_with_alignment(name, 1, Alignment::Left),
+                    TableCell::new_with_alignment(
+                        bridge.pid.map(|v| v.to_string()).unwrap_or_default(),
+                        1,
+                        Alignment::Left,
+                    ),
+                    TableCell::new_with_alignment(
+                        bridge
+                            .started_at
+                            .map(|v| format_uptime(now.saturating_sub(v)))
+                            .unwrap_or_default(),
+                        1,
+                        Alignment::Left,
+                    ),
+                    TableCell::new_with_alignment(bridge.restarts, 1, Alignment::Left),
+                    TableCell::new_with_alignment(status, 1, Alignment::Left),
+                    TableCell::new_with_alignment(
+                        bridge.last_exit.clone().unwrap_or_default(),
+                        1,
+                        Alignment::Left,
+                    ),
+                ]));
+            }
+            output::output_text(table.render());
+        }
+    }
+    Ok(())
+}
+
+fn format_uptime(seconds: u64) -> String {
+    let (days, hours, minutes, seconds) = (
+        seconds / 86400,
+        seconds % 86400 / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+    );
+    if days > 0 {
+        format!("{days}d {hours}h {minutes}m")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m {seconds}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
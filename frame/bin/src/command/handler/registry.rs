@@ -4,20 +4,267 @@ use term_table::table_cell::{Alignment, TableCell};
 use term_table::{Table, TableStyle};
 
 use support_common::config::{Config, Names};
+use support_common::error::BridgerError;
 use support_terminal::output::{self, OutputFormat};
 
 use crate::command::types::{RegistryOpt, RegistryType};
 use crate::config::BridgerConfig;
+use crate::external;
+use crate::external::index::{RegistryIndex, Target};
 
 /// Handle registry command
 pub fn handle_registry(opt: RegistryOpt) -> color_eyre::Result<()> {
     match opt {
         RegistryOpt::Set { type_, path } => handle_set(type_, path),
         RegistryOpt::Get { output } => handle_get(output),
-        RegistryOpt::Version { value, bundle } => handle_version(value, bundle),
+        RegistryOpt::Version {
+            value,
+            bridge,
+            bundle,
+            unpin,
+        } => match version_target(value, bridge)? {
+            VersionTarget::Bridge { bridge, version } => handle_pin(bridge, version, bundle, unpin),
+            VersionTarget::Registry(_) if unpin => Err(BridgerError::Subcommand(
+                "Please provide the bridge to unpin by `--bridge <bridge>`".to_string(),
+            )
+            .into()),
+            VersionTarget::Registry(value) => handle_version(value, bundle),
+        },
+        RegistryOpt::Verify {
+            public_key,
+            skip,
+            trust_bundled,
+        } => handle_verify(public_key, skip, trust_bundled),
+        RegistryOpt::Versions { output } => handle_versions(output),
+        RegistryOpt::List {
+            remote,
+            version,
+            output,
+        } => handle_list(remote, version, output),
     }
 }
 
+fn registry_index(config: &BridgerConfig) -> color_eyre::Result<RegistryIndex> {
+    if config.registry.type_ != RegistryType::Server {
+        return Err(BridgerError::Subcommand(
+            "Only server registry has the index, please set registry by `bridger registry set --type server --path <path>`"
+                .to_string(),
+        )
+        .into());
+    }
+    let path =
+        config.registry.path.as_ref().ok_or_else(|| {
+            BridgerError::Subcommand("Missing path of server registry".to_string())
+        })?;
+    RegistryIndex::fetch(path)
+}
+
+fn handle_versions(out: OutputFormat) -> color_eyre::Result<()> {
+    let config: BridgerConfig = Config::restore(Names::Bridger)?;
+    let registry_index = registry_index(&config)?;
+    let current_version = config
+        .registry
+        .version
+        .unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string());
+    let target = Target::current()?;
+    match out {
+        OutputFormat::Raw => {
+            for item in &registry_index.versions {
+                let mark = if item.version == current_version {
+                    " (current)"
+                } else {
+                    ""
+                };
+                output::output_text(format!("{}{}", item.version, mark));
+            }
+        }
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&registry_index.versions)?;
+            output::output_text(json);
+        }
+        OutputFormat::Table => {
+            let mut table = Table::new();
+            table.max_column_width = 40;
+            table.separate_rows = false;
+            table.style = TableStyle::empty();
+            table.add_row(Row::new(vec![
+                TableCell::new("Version".bold()),
+                TableCell::new(format!("Bridges ({target})").bold()),
+            ]));
+            for item in &registry_index.versions {
+                let version = if item.version == current_version {
+                    format!("{} (current)", item.version)
+                } else {
+                    item.version.clone()
+                };
+                table.add_row(Row::new(vec![
+                    TableCell::new_with_alignment(version, 1, Alignment::Left),
+                    TableCell::new_with_alignment(
+                        item.packages_of(&target).len(),
+                        1,
+                        Alignment::Left,
+                    ),
+                ]));
+            }
+            output::output_text(table.render());
+        }
+    }
+    Ok(())
+}
+
+fn handle_list(remote: bool, version: Option<String>, out: OutputFormat) -> color_eyre::Result<()> {
+    if !remote {
+        let installed = external::helpers::list_installed()?;
+        match out {
+            OutputFormat::Json => output::output_text(serde_json::to_string_pretty(&installed)?),
+            _ => installed.iter().for_each(|(bridge, versions)| {
+                output::output_text(format!("{}: {}", bridge, versions.join(", ")))
+            }),
+        }
+        return Ok(());
+    }
+
+    let config: BridgerConfig = Config::restore(Names::Bridger)?;
+    let registry_index = registry_index(&config)?;
+    let version = version
+        .or(config.registry.version)
+        .unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string());
+    let index_version = registry_index.version(&version).ok_or_else(|| {
+        BridgerError::Subcommand(format!("Not found version {version} in registry index"))
+    })?;
+    let target = Target::current()?;
+    let packages = index_version.packages_of(&target);
+    match out {
+        OutputFormat::Raw => {
+            packages
+                .iter()
+                .for_each(|item| output::output_text(&item.name));
+        }
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&packages)?;
+            output::output_text(json);
+        }
+        OutputFormat::Table => {
+            let mut table = Table::new();
+            table.max_column_width = 60;
+            table.separate_rows = false;
+            table.style = TableStyle::empty();
+            table.add_row(Row::new(vec![
+                TableCell::new("Name".bold()),
+                TableCell::new("Format".bold()),
+                TableCell::new("Url".bold()),
+            ]));
+            for item in packages {
+                table.add_row(Row::new(vec![
+                    TableCell::new_with_alignment(&item.name, 1, Alignment::Left),
+                    TableCell::new_with_alignment(item.format.extension(), 1, Alignment::Left),
+                    TableCell::new_with_alignment(&item.url, 1, Alignment::Left),
+                ]));
+            }
+            output::output_text(table.render());
+        }
+    }
+    Ok(())
+}
+
+fn handle_verify(
+    public_key: Option<String>,
+    skip: bool,
+    trust_bundled: bool,
+) -> color_eyre::Result<()> {
+    let mut config: BridgerConfig = Config::restore(Names::Bridger)?;
+    if skip {
+        output::output_warning("The downloaded packages will not be verified");
+    }
+    if trust_bundled {
+        output::output_warning("The bundled binaries will be run without verification");
+    }
+    config.registry.skip_verify = skip;
+    config.registry.public_key = public_key;
+    config.registry.trust_bundled = trust_bundled;
+    Config::store(Names::Bridger, config)?;
+    output::output_ok();
+    Ok(())
+}
+
+/// Target of `registry version`
+#[derive(Debug, Eq, PartialEq)]
+enum VersionTarget {
+    /// Version of registry
+    Registry(Option<String>),
+    /// Version pinned to a bridge
+    Bridge {
+        bridge: String,
+        version: Option<String>,
+    },
+}
+
+/// The bridge is given by `--bridge <bridge>` or the value `<bridge>@<version>`, otherwise the
+/// value is the version of registry
+fn version_target(
+    value: Option<String>,
+    bridge: Option<String>,
+) -> color_eyre::Result<VersionTarget> {
+    let pin = match value.as_deref().map(|v| v.split_once('@')) {
+        Some(Some((bridge, version))) => {
+            if bridge.is_empty() || version.is_empty() {
+                return Err(BridgerError::Subcommand(format!(
+                    "Wrong version {}, pin version of a bridge by `<bridge>@<version>`",
+                    value.unwrap_or_default()
+                ))
+                .into());
+            }
+            Some((bridge.to_string(), version.to_string()))
+        }
+        _ => None,
+    };
+    match (bridge, pin) {
+        (Some(_), Some(_)) => Err(BridgerError::Subcommand(
+            "Use either `--bridge <bridge> <version>` or `<bridge>@<version>`".to_string(),
+        )
+        .into()),
+        (Some(bridge), None) => Ok(VersionTarget::Bridge {
+            bridge,
+            version: value,
+        }),
+        (None, Some((bridge, version))) => Ok(VersionTarget::Bridge {
+            bridge,
+            version: Some(version),
+        }),
+        (None, None) => Ok(VersionTarget::Registry(value)),
+    }
+}
+
+fn handle_pin(
+    bridge: String,
+    version: Option<String>,
+    bundle: bool,
+    unpin: bool,
+) -> color_eyre::Result<()> {
+    let mut config: BridgerConfig = Config::restore(Names::Bridger)?;
+    if unpin {
+        config.pins.remove(&bridge);
+        Config::store(Names::Bridger, config)?;
+        output::output_ok();
+        return Ok(());
+    }
+    let version = match version {
+        _ if bundle => env!("CARGO_PKG_VERSION").to_string(),
+        Some(v) => v.trim_start_matches('v').to_string(),
+        None => {
+            let version = config
+                .bridge_version(&bridge)
+                .unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string());
+            output::output_text(version);
+            return Ok(());
+        }
+    };
+    config.pins.insert(bridge.clone(), version.clone());
+    Config::store(Names::Bridger, config)?;
+    output::output_text(format!("{bridge}: {version}"));
+    Ok(())
+}
+
 fn handle_version(value: Option<String>, bundle: bool) -> color_eyre::Result<()> {
     let bundle_version = env!("CARGO_PKG_VERSION");
     if bundle {
@@ -79,10 +326,24 @@ fn handle_get(out: OutputFormat) -> color_eyre::Result<()> {
                     "PATH".bold(),
                     config.registry.path.unwrap_or_default()
                 ));
+                let verify = if config.registry.skip_verify {
+                    "skipped"
+                } else if config.registry.public_key.is_some() {
+                    "SHA256SUMS with minisign signature"
+                } else {
+                    "SHA256SUMS"
+                };
+                output::output_text(format!("{}: {}", "VERIFY".bold(), verify));
+                if config.registry.trust_bundled {
+                    output::output_text(format!("{}: trusted", "BUNDLED".bold()));
+                }
+            }
+            for (bridge, version) in &config.pins {
+                output::output_text(format!("{}: {}@{}", "PIN".bold(), bridge, version));
             }
         }
         OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(&config.registry)?;
+            let json = serde_json::to_string_pretty(&config)?;
             output::output_text(json);
         }
         OutputFormat::Table => {
@@ -108,3 +369,53 @@ fn handle_get(out: OutputFormat) -> color_eyre::Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(value: Option<&str>, bridge: Option<&str>) -> color_eyre::Result<VersionTarget> {
+        version_target(value.map(String::from), bridge.map(String::from))
+    }
+
+    #[test]
+    fn test_version_of_registry() {
+        assert_eq!(
+            target(Some("0.8.1"), None).unwrap(),
+            VersionTarget::Registry(Some("0.8.1".to_string())),
+        );
+        // any value without `@` is a version, even it looks like a bridge name
+        assert_eq!(
+            target(Some("darwinia-crab"), None).unwrap(),
+            VersionTarget::Registry(Some("darwinia-crab".to_string())),
+        );
+        assert_eq!(target(None, None).unwrap(), VersionTarget::Registry(None));
+    }
+
+    #[test]
+    fn test_version_of_bridge() {
+        let pinned = VersionTarget::Bridge {
+            bridge: "darwinia-crab".to_string(),
+            version: Some("v0.8.1".to_string()),
+        };
+        assert_eq!(target(Some("darwinia-crab@v0.8.1"), None).unwrap(), pinned);
+        assert_eq!(
+            target(Some("v0.8.1"), Some("darwinia-crab")).unwrap(),
+            pinned
+        );
+        assert_eq!(
+            target(None, Some("darwinia-crab")).unwrap(),
+            VersionTarget::Bridge {
+                bridge: "darwinia-crab".to_string(),
+                version: None,
+            },
+        );
+    }
+
+    #[test]
+    fn test_wrong_version_of_bridge() {
+        assert!(target(Some("darwinia-crab@"), None).is_err());
+        assert!(target(Some("@0.8.1"), None).is_err());
+        assert!(target(Some("darwinia-crab@0.8.1"), Some("darwinia-crab")).is_err());
+    }
+}
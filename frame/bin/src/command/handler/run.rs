@@ -0,0 +1,17 @@
+use support_common::config::{Config, Names};
+use support_common::error::BridgerError;
+
+use crate::config::BridgerConfig;
+use crate::supervisor::Supervisor;
+
+pub fn handle_run() -> color_eyre::Result<()> {
+    let config: BridgerConfig = Config::restore(Names::Bridger)?;
+    let bridges = config.enabled_bridges();
+    if bridges.is_empty() {
+        return Err(BridgerError::Custom(
+            "No enabled bridge, add bridges to `[[bridges]]` of bridger config".to_string(),
+        )
+        .into());
+    }
+    Supervisor::new(bridges).run()
+}
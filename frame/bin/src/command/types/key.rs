@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+use support_terminal::output::OutputFormat;
+
+/// Keystore option
+#[derive(Clone, Debug, Deserialize, Serialize, StructOpt)]
+pub enum KeyOpt {
+    /// Import a private key, or an Ethereum V3/substrate key json by `--file`
+    Import {
+        /// The key id, reference it in bridge config by `keystore:<id>`
+        #[structopt()]
+        id: String,
+        /// Path of the key json file
+        #[structopt(long)]
+        file: Option<String>,
+    },
+    /// List all keys
+    List {
+        /// The output format
+        #[structopt(short, long, default_value = "raw")]
+        output: OutputFormat,
+    },
+    /// Export the public address of a key
+    ExportPublic {
+        /// The key id
+        #[structopt()]
+        id: String,
+    },
+    /// Generate a new secp256k1 key
+    Generate {
+        /// The key id
+        #[structopt()]
+        id: String,
+    },
+}
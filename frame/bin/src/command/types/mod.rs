@@ -1,5 +1,7 @@
+pub use self::key::*;
 pub use self::opt::*;
 pub use self::registry::*;
 
+mod key;
 mod opt;
 mod registry;
@@ -1,6 +1,8 @@
 use structopt::StructOpt;
 
-use crate::command::types::RegistryOpt;
+use support_terminal::output::OutputFormat;
+
+use crate::command::types::{KeyOpt, RegistryOpt};
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "bridger", about = "Darwinia bridger")]
@@ -13,4 +15,18 @@ pub enum Opt {
     },
     /// List all bridges
     List,
+    /// Encrypted keystore of relayer keys
+    Key {
+        /// Commands of keystore
+        #[structopt(flatten)]
+        command: KeyOpt,
+    },
+    /// Run the enabled bridges of bridger config, restart them when they crash
+    Run,
+    /// Show the bridges run by `bridger run`
+    Ps {
+        /// The output format
+        #[structopt(short, long, default_value = "table")]
+        output: OutputFormat,
+    },
 }
@@ -31,13 +31,51 @@ pub enum RegistryOpt {
         #[structopt(short, long, default_value = "raw")]
         output: OutputFormat,
     },
-    /// Set registry version, will be download by your set value
+    /// Set registry version, will be download by your set value. Pin version of a bridge by
+    /// `<bridge>@<version>` or `--bridge <bridge> <version>`, the other bridges keep their versions
     Version {
-        /// The version
+        /// The version, or `<bridge>@<version>` to pin version of a bridge
         #[structopt()]
         value: Option<String>,
-        /// Set version to bridger bundle, if true, not use the value parameter
+        /// The bridge to show, pin or unpin version
+        #[structopt(long)]
+        bridge: Option<String>,
+        /// Set version to bridger bundle, if true, not use the version parameter
         #[structopt(long)]
         bundle: bool,
+        /// Remove the version pinned to the bridge
+        #[structopt(long)]
+        unpin: bool,
+    },
+    /// Set verification of downloaded packages
+    Verify {
+        /// Minisign public key to verify the signature of `SHA256SUMS`
+        #[structopt(long)]
+        public_key: Option<String>,
+        /// Skip verifying downloaded packages, not recommended
+        #[structopt(long)]
+        skip: bool,
+        /// Run the unverified binaries bundled beside bridger, e.g. in docker image
+        #[structopt(long)]
+        trust_bundled: bool,
+    },
+    /// List versions of registry, only server registry supports it
+    Versions {
+        /// The output format
+        #[structopt(short, long, default_value = "raw")]
+        output: OutputFormat,
+    },
+    /// List bridges
+    List {
+        /// List bridges of registry for current os and architecture, only server registry
+        /// supports it. Otherwise list the downloaded bridges
+        #[structopt(long)]
+        remote: bool,
+        /// The version of remote bridges, default is the registry version
+        #[structopt(long)]
+        version: Option<String>,
+        /// The output format
+        #[structopt(short, long, default_value = "raw")]
+        output: OutputFormat,
     },
 }
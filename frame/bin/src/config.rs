@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use serde_with::rust::string_empty_as_none;
 
@@ -8,6 +10,48 @@ use crate::command::types::RegistryType;
 pub struct BridgerConfig {
     /// The registry of bridger
     pub registry: BridgerRegistry,
+    /// Version pinned to bridges, the key is bridge name, e.g. `darwinia-crab`
+    #[serde(default)]
+    pub pins: BTreeMap<String, String>,
+    /// Bridges run by `bridger run`
+    #[serde(default)]
+    pub bridges: Vec<BridgeProcess>,
+}
+
+impl BridgerConfig {
+    /// Version of the bridge, the pinned version first, then the registry version
+    pub fn bridge_version(&self, bridge: impl AsRef<str>) -> Option<String> {
+        self.pins
+            .get(bridge.as_ref())
+            .or(self.registry.version.as_ref())
+            .cloned()
+    }
+
+    /// Enabled bridges of `bridger run`
+    pub fn enabled_bridges(&self) -> Vec<BridgeProcess> {
+        self.bridges.iter().filter(|v| v.enable).cloned().collect()
+    }
+}
+
+/// Bridge run by supervisor
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BridgeProcess {
+    /// Bridge name, e.g. `darwinia-crab`
+    pub name: String,
+    /// Whether run this bridge, default is true
+    #[serde(default = "default_bridge_enable")]
+    pub enable: bool,
+    /// Arguments of the bridge, default is `start`
+    #[serde(default = "default_bridge_args")]
+    pub args: Vec<String>,
+}
+
+fn default_bridge_enable() -> bool {
+    true
+}
+
+fn default_bridge_args() -> Vec<String> {
+    vec!["start".to_string()]
 }
 
 /// Bridger registry
@@ -22,6 +66,16 @@ pub struct BridgerRegistry {
     /// The version of bridge
     #[serde(with = "string_empty_as_none")]
     pub version: Option<String>,
+    /// Skip verifying the downloaded packages by `SHA256SUMS`
+    #[serde(default)]
+    pub skip_verify: bool,
+    /// Minisign public key, the `SHA256SUMS` is verified by `SHA256SUMS.minisig` when it's set
+    #[serde(default, with = "string_empty_as_none")]
+    pub public_key: Option<String>,
+    /// Run the bridge binaries bundled beside bridger, e.g. in docker image. They aren't
+    /// verified by `SHA256SUMS`, so they're only run when trusted explicitly
+    #[serde(default)]
+    pub trust_bundled: bool,
 }
 
 impl Default for BridgerRegistry {
@@ -30,6 +84,9 @@ impl Default for BridgerRegistry {
             type_: RegistryType::Github,
             path: Some("https://github.com/darwinia-network/bridger".to_string()),
             version: None,
+            skip_verify: false,
+            public_key: None,
+            trust_bundled: false,
         }
     }
 }
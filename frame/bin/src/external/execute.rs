@@ -1,9 +1,12 @@
+use std::process::Child;
+
 use support_common::config::{Config, Names};
 
 use crate::command::types::RegistryType;
 use crate::config::BridgerConfig;
+use crate::external::provider::common;
 use crate::external::provider::{CompileSourceExecutor, PrecompiledBinaryExecutor};
-use crate::external::types::CompileChannel;
+use crate::external::types::{CompileChannel, PreparedBinary};
 
 /// External subcommand executor
 #[derive(Clone, Debug)]
@@ -23,6 +26,30 @@ impl ExternalExecutor {
     /// Execute external subcommand
     pub fn execute(&self) -> color_eyre::Result<()> {
         tracing::trace!(target: "bridger", "Try execute external command");
+        let (executor, path) = self.subcommand_executor()?;
+        executor.execute(path)
+    }
+
+    /// Download or compile the binary of external subcommand, but not execute it
+    pub fn prepare(&self) -> color_eyre::Result<PreparedBinary> {
+        let (executor, path) = self.subcommand_executor()?;
+        executor.prepare(path)
+    }
+
+    /// Spawn external subcommand as child process, the stdout and stderr are piped
+    pub fn spawn(&self) -> color_eyre::Result<Child> {
+        let prepared = self.prepare()?;
+        common::spawn_binary(
+            prepared.command,
+            prepared.path_binary,
+            self.args.clone(),
+            prepared.cwd,
+        )
+    }
+
+    fn subcommand_executor(
+        &self,
+    ) -> color_eyre::Result<(Box<dyn ISubcommandExecutor>, Option<String>)> {
         let config: BridgerConfig = Config::restore(Names::Bridger)?;
         let registry = config.registry;
 
@@ -34,12 +61,12 @@ impl ExternalExecutor {
                     CompileChannel::Debug,
                     None,
                 );
-                executor.execute(None)
+                Ok((Box::new(executor), None))
             }
             RegistryType::Github | RegistryType::Server => {
                 let executor =
                     PrecompiledBinaryExecutor::new(self.command.clone(), self.args.clone());
-                executor.execute(registry.path)
+                Ok((Box::new(executor), registry.path))
             }
         }
     }
@@ -49,4 +76,7 @@ impl ExternalExecutor {
 pub trait ISubcommandExecutor {
     /// Execute subcommand, The parameter path is current registry path value.
     fn execute(&self, path: Option<String>) -> color_eyre::Result<()>;
+
+    /// Prepare the binary of subcommand, The parameter path is current registry path value.
+    fn prepare(&self, path: Option<String>) -> color_eyre::Result<PreparedBinary>;
 }
@@ -1,6 +1,47 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use support_common::error::BridgerError;
 
+/// Directory of the binary of a bridge version, `BRIDGER_HOME/bin/<bridge>/<version>`
+pub fn bridge_binary_dir(bridge: impl AsRef<str>, version: impl AsRef<str>) -> PathBuf {
+    support_types::constants::bridger_home()
+        .join("bin")
+        .join(bridge.as_ref())
+        .join(version.as_ref())
+}
+
+/// Installed versions of bridges, the key is bridge name
+pub fn list_installed() -> color_eyre::Result<BTreeMap<String, Vec<String>>> {
+    let path_bin = support_types::constants::bridger_home().join("bin");
+    let mut installed = BTreeMap::new();
+    if !path_bin.exists() {
+        return Ok(installed);
+    }
+    for dir_bridge in std::fs::read_dir(&path_bin)? {
+        let path_bridge = dir_bridge?.path();
+        if !path_bridge.is_dir() {
+            continue;
+        }
+        let bridge = match path_bridge.file_name() {
+            Some(v) => v.to_string_lossy().to_string(),
+            None => continue,
+        };
+        let mut versions = Vec::new();
+        for dir_version in std::fs::read_dir(&path_bridge)? {
+            let path_version = dir_version?.path();
+            if !path_version.is_dir() {
+                continue;
+            }
+            if let Some(v) = path_version.file_name() {
+                versions.push(v.to_string_lossy().to_string());
+            }
+        }
+        versions.sort();
+        installed.insert(bridge, versions);
+    }
+    Ok(installed)
+}
+
 pub fn list_externals(
     except_base_path: Option<PathBuf>,
 ) -> color_eyre::Result<(PathBuf, Vec<String>)> {
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use support_common::error::BridgerError;
+
+use crate::external::verify;
+
+/// Index file of server registry, it's placed at `{path}/index.json`
+pub const INDEX_FILE: &str = "index.json";
+
+/// Registry index, lists all versions and packages of a server registry.
+/// The format is documented in `docs/registry-index.md`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RegistryIndex {
+    pub versions: Vec<IndexVersion>,
+}
+
+/// Packages of a version
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IndexVersion {
+    /// Version without `v` prefix, e.g. `0.8.1`
+    pub version: String,
+    /// Url of `SHA256SUMS` of the packages, relative to registry path or absolute
+    #[serde(default)]
+    pub checksums: Option<String>,
+    pub packages: Vec<IndexPackage>,
+}
+
+/// Package of a bridge for a target
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IndexPackage {
+    /// Binary name, e.g. `bridge-darwinia-crab`
+    pub name: String,
+    /// Operating system, `linux`, `darwin` or `windows`
+    pub os: String,
+    /// Architecture, `x86`, `x86_64` or `aarch64`
+    pub arch: String,
+    #[serde(default)]
+    pub format: ArchiveFormat,
+    /// Url of package, relative to registry path or absolute
+    pub url: String,
+}
+
+/// Archive format of package
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArchiveFormat {
+    #[default]
+    Zip,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Zip => "zip",
+            ArchiveFormat::TarGz => "tar.gz",
+        }
+    }
+}
+
+/// Operating system and architecture of packages
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Target {
+    pub os: String,
+    pub arch: String,
+}
+
+impl Target {
+    /// Target of current bridger
+    pub fn current() -> color_eyre::Result<Self> {
+        let os = match std::env::consts::OS {
+            "macos" => "darwin",
+            v => v,
+        };
+        let arch = match std::env::consts::ARCH {
+            v @ ("x86" | "x86_64" | "aarch64") => v,
+            v => {
+                return Err(BridgerError::Subcommand(format!(
+                    "Can not support current architecture: {v}"
+                ))
+                .into())
+            }
+        };
+        Ok(Self {
+            os: os.to_string(),
+            arch: arch.to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.os, self.arch)
+    }
+}
+
+impl RegistryIndex {
+    /// Download index from the registry path
+    pub fn fetch(path: impl AsRef<str>) -> color_eyre::Result<Self> {
+        let path = path.as_ref().trim_end_matches('/');
+        let url = format!("{path}/{INDEX_FILE}");
+        let content = verify::download(&url)?
+            .ok_or_else(|| BridgerError::Custom(format!("Not found registry index {url}")))?;
+        let index = serde_json::from_slice(&content)
+            .map_err(|e| BridgerError::Custom(format!("Wrong registry index {url}: {e:?}")))?;
+        Ok(index)
+    }
+
+    pub fn version(&self, version: impl AsRef<str>) -> Option<&IndexVersion> {
+        let version = version.as_ref().trim_start_matches('v');
+        self.versions.iter().find(|item| item.version == version)
+    }
+}
+
+impl IndexVersion {
+    /// Package of the binary for the target
+    pub fn package(&self, name: impl AsRef<str>, target: &Target) -> Option<&IndexPackage> {
+        let name = name.as_ref();
+        self.packages
+            .iter()
+            .find(|item| item.name == name && item.os == target.os && item.arch == target.arch)
+    }
+
+    /// All packages for the target
+    pub fn packages_of(&self, target: &Target) -> Vec<&IndexPackage> {
+        self.packages
+            .iter()
+            .filter(|item| item.os == target.os && item.arch == target.arch)
+            .collect()
+    }
+}
+
+impl IndexPackage {
+    /// File name of package, the last segment of url
+    pub fn file_name(&self) -> String {
+        self.url
+            .rsplit('/')
+            .next()
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| format!("{}.{}", self.name, self.format.extension()))
+    }
+}
+
+/// Join the url of index to registry path if it's relative
+pub fn absolute_url(path: impl AsRef<str>, url: impl AsRef<str>) -> String {
+    let url = url.as_ref();
+    if url.starts_with("http://") || url.starts_with("https://") {
+        return url.to_string();
+    }
+    format!(
+        "{}/{}",
+        path.as_ref().trim_end_matches('/'),
+        url.trim_start_matches('/')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(name: &str, os: &str, arch: &str, url: &str) -> IndexPackage {
+        IndexPackage {
+            name: name.to_string(),
+            os: os.to_string(),
+            arch: arch.to_string(),
+            format: ArchiveFormat::Zip,
+            url: url.to_string(),
+        }
+    }
+
+    fn target(os: &str, arch: &str) -> Target {
+        Target {
+            os: os.to_string(),
+            arch: arch.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_current_target() {
+        let target = Target::current().unwrap();
+        assert_ne!(target.os, "macos");
+        assert!(["x86", "x86_64", "aarch64"].contains(&target.arch.as_str()));
+        assert_eq!(target.to_string(), format!("{}-{}", target.os, target.arch));
+    }
+
+    #[test]
+    fn test_package() {
+        let index: RegistryIndex = serde_json::from_str(
+            r#"{"versions": [{"version": "0.8.1", "packages": [
+                {"name": "bridge-darwinia-crab", "os": "linux", "arch": "x86_64",
+                 "url": "v0.8.1/bridge-darwinia-crab-linux-x86_64.zip"},
+                {"name": "bridge-darwinia-crab", "os": "darwin", "arch": "aarch64",
+                 "format": "tar-gz", "url": "v0.8.1/bridge-darwinia-crab-darwin-aarch64.tar.gz"}
+            ]}]}"#,
+        )
+        .unwrap();
+        let version = index.version("v0.8.1").unwrap();
+        assert!(index.version("0.8.2").is_none());
+
+        let linux = version
+            .package("bridge-darwinia-crab", &target("linux", "x86_64"))
+            .unwrap();
+        assert_eq!(linux.format, ArchiveFormat::Zip);
+        let darwin = version
+            .package("bridge-darwinia-crab", &target("darwin", "aarch64"))
+            .unwrap();
+        assert_eq!(darwin.format, ArchiveFormat::TarGz);
+        assert!(version
+            .package("bridge-darwinia-crab", &target("windows", "x86_64"))
+            .is_none());
+        assert!(version
+            .package("bridge-pangolin-pangoro", &target("linux", "x86_64"))
+            .is_none());
+        assert_eq!(version.packages_of(&target("linux", "x86_64")).len(), 1);
+    }
+
+    #[test]
+    fn test_absolute_url() {
+        assert_eq!(
+            absolute_url("https://example.com/bridger/", "/v0.8.1/SHA256SUMS"),
+            "https://example.com/bridger/v0.8.1/SHA256SUMS",
+        );
+        assert_eq!(
+            absolute_url("https://example.com/bridger", "v0.8.1/SHA256SUMS"),
+            "https://example.com/bridger/v0.8.1/SHA256SUMS",
+        );
+        assert_eq!(
+            absolute_url("https://example.com/bridger", "https://mirror.com/SHA256SUMS"),
+            "https://mirror.com/SHA256SUMS",
+        );
+    }
+
+    #[test]
+    fn test_file_name() {
+        let item = package(
+            "bridge-darwinia-crab",
+            "linux",
+            "x86_64",
+            "https://example.com/v0.8.1/bridge-darwinia-crab-linux-x86_64.zip",
+        );
+        assert_eq!(item.file_name(), "bridge-darwinia-crab-linux-x86_64.zip");
+        let item = package("bridge-darwinia-crab", "linux", "x86_64", "download/");
+        assert_eq!(item.file_name(), "bridge-darwinia-crab.zip");
+    }
+}
@@ -1,5 +1,7 @@
 pub mod execute;
 pub mod helpers;
+pub mod index;
 pub mod types;
+pub mod verify;
 
 mod provider;
@@ -1,10 +1,42 @@
 use std::path::PathBuf;
+use std::process::{Child, Stdio};
 
 use cargo_util::ProcessBuilder;
 use colored::Colorize;
 
 use support_common::error::BridgerError;
 
+fn process_builder(path_binary: PathBuf, args: &[String], cwd: &PathBuf) -> ProcessBuilder {
+    let mut builder_bridge = ProcessBuilder::new(path_binary);
+    builder_bridge.args(args).cwd(cwd);
+    for (n, v) in std::env::vars() {
+        builder_bridge.env(&n, v);
+    }
+    builder_bridge
+}
+
+/// Spawn binary as child process, the stdout and stderr are piped
+pub fn spawn_binary(
+    command: String,
+    path_binary: PathBuf,
+    args: Vec<String>,
+    cwd: PathBuf,
+) -> color_eyre::Result<Child> {
+    let builder_bridge = process_builder(path_binary, args.as_slice(), &cwd);
+    let mut process = builder_bridge.build_command();
+    process.stdout(Stdio::piped()).stderr(Stdio::piped());
+    tracing::info!(
+        target: "bridger",
+        "Spawn `{} {}` in path: {}",
+        &command.green(),
+        args.join(" ").green(),
+        cwd.display()
+    );
+    process
+        .spawn()
+        .map_err(|e| BridgerError::Process(command, args.join(" "), format!("{e:?}")).into())
+}
+
 /// Execute binary
 pub fn execute_binary(
     command: String,
@@ -12,11 +44,7 @@ pub fn execute_binary(
     args: Vec<String>,
     cwd: PathBuf,
 ) -> color_eyre::Result<()> {
-    let mut builder_bridge = ProcessBuilder::new(path_binary);
-    builder_bridge.args(args.as_slice()).cwd(&cwd);
-    for (n, v) in std::env::vars() {
-        builder_bridge.env(&n, v);
-    }
+    let builder_bridge = process_builder(path_binary, args.as_slice(), &cwd);
 
     tracing::info!(
         target: "bridger",
@@ -6,7 +6,7 @@ use support_common::error::BridgerError;
 
 use crate::external;
 use crate::external::execute::ISubcommandExecutor;
-use crate::external::types::CompileChannel;
+use crate::external::types::{CompileChannel, PreparedBinary};
 
 /// Compile source code and execute binary
 #[derive(Clone, Debug)]
@@ -34,14 +34,23 @@ impl CompileSourceExecutor {
 }
 
 impl ISubcommandExecutor for CompileSourceExecutor {
-    fn execute(&self, _path: Option<String>) -> color_eyre::Result<()> {
-        self.try_compile_and_execute()?;
-        Ok(())
+    fn execute(&self, path: Option<String>) -> color_eyre::Result<()> {
+        let prepared = self.prepare(path)?;
+        external::provider::common::execute_binary(
+            prepared.command,
+            prepared.path_binary,
+            self.args.clone(),
+            prepared.cwd,
+        )
+    }
+
+    fn prepare(&self, _path: Option<String>) -> color_eyre::Result<PreparedBinary> {
+        self.try_compile()
     }
 }
 
 impl CompileSourceExecutor {
-    fn try_compile_and_execute(&self) -> color_eyre::Result<()> {
+    fn try_compile(&self) -> color_eyre::Result<PreparedBinary> {
         let path_exe = std::env::current_exe()?
             .parent()
             .ok_or_else(|| {
@@ -50,7 +59,6 @@ impl CompileSourceExecutor {
             .join("");
         tracing::trace!(target: "bridger", "The execute path is: {}", path_exe.display());
 
-        let mut exists = false;
         for prefix in support_types::constants::ALLOW_BINARY_PREFIX {
             let mut path_bridge = path_exe.join("../../../bridges").join(&self.command);
             tracing::trace!(target: "bridger", "Try detect binary for path: {}", path_bridge.display());
@@ -61,30 +69,29 @@ impl CompileSourceExecutor {
                     continue;
                 }
             }
-            if let Err(e) = self.try_compile_and_execute_with_command(path_bridge, full_command) {
-                if let Some(BridgerError::Subcommand(msg)) = e.downcast_ref() {
-                    tracing::error!(target: "bridger", "{}", msg);
-                    continue;
+            match self.try_compile_with_command(path_bridge, full_command) {
+                Ok(v) => return Ok(v),
+                Err(e) => {
+                    if let Some(BridgerError::Subcommand(msg)) = e.downcast_ref() {
+                        tracing::error!(target: "bridger", "{}", msg);
+                        continue;
+                    }
+                    return Err(e);
                 }
             }
-            exists = true;
-            break;
         }
-        if !exists {
-            return Err(BridgerError::UnsupportExternal(format!(
-                "Not support this subcommand: {}",
-                self.command
-            ))
-            .into());
-        }
-        Ok(())
+        Err(BridgerError::UnsupportExternal(format!(
+            "Not support this subcommand: {}",
+            self.command
+        ))
+        .into())
     }
 
-    fn try_compile_and_execute_with_command(
+    fn try_compile_with_command(
         &self,
         path_bridge: PathBuf,
         command: impl AsRef<str>,
-    ) -> color_eyre::Result<()> {
+    ) -> color_eyre::Result<PreparedBinary> {
         let command = command.as_ref();
         tracing::info!(
             target: "bridger",
@@ -124,7 +131,7 @@ impl CompileSourceExecutor {
             .into());
         }
 
-        // when compiled success, prepare execute this binary
+        // when compiled success, prepare this binary
 
         let base_path = path_bridge.join("target").join(self.channel.name());
         let platform_command = if cfg!(windows) {
@@ -142,11 +149,10 @@ impl CompileSourceExecutor {
             .into());
         }
 
-        external::provider::common::execute_binary(
-            command.to_string(),
+        Ok(PreparedBinary {
+            command: command.to_string(),
             path_binary,
-            self.args.clone(),
-            path_bridge,
-        )
+            cwd: path_bridge,
+        })
     }
 }
@@ -1,6 +1,6 @@
 pub use self::compile_source::*;
 pub use self::precompiled_binary::*;
 
-mod common;
+pub mod common;
 mod compile_source;
 mod precompiled_binary;
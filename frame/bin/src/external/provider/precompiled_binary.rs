@@ -1,15 +1,17 @@
-use std::io::Cursor;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use support_common::config::{Config, Names};
-use sysinfo::{ProcessExt, System, SystemExt};
 
-use crate::config::BridgerConfig;
+use crate::command::types::RegistryType;
+use crate::config::{BridgerConfig, BridgerRegistry};
 use support_common::error::BridgerError;
 use support_terminal::output;
 
 use crate::external;
 use crate::external::execute::ISubcommandExecutor;
+use crate::external::index::{self, ArchiveFormat, RegistryIndex, Target};
+use crate::external::types::PreparedBinary;
+use crate::external::verify;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -17,28 +19,46 @@ const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub struct PrecompiledBinaryExecutor {
     command: String,
     args: Vec<String>,
-    pkg_type: String,
 }
 
 impl PrecompiledBinaryExecutor {
     pub fn new(command: String, args: Vec<String>) -> Self {
-        Self {
-            command,
-            args,
-            pkg_type: "zip".to_string(),
-        }
+        Self { command, args }
     }
 }
 
+/// Package of a binary in the registry
+struct RemotePackage {
+    file_name: String,
+    url: String,
+    format: ArchiveFormat,
+    /// Url of `SHA256SUMS`
+    checksums_url: Option<String>,
+}
+
 impl ISubcommandExecutor for PrecompiledBinaryExecutor {
     fn execute(&self, path: Option<String>) -> color_eyre::Result<()> {
+        let prepared = self.prepare(path)?;
+        external::provider::common::execute_binary(
+            prepared.command,
+            prepared.path_binary,
+            self.args.clone(),
+            prepared.cwd,
+        )
+    }
+
+    fn prepare(&self, path: Option<String>) -> color_eyre::Result<PreparedBinary> {
         // get path of binary
         let (command, path_binary) = self.download_and_extract_binary(path, false)?;
 
         let cwd = path_binary.parent().map(|v| v.join("")).ok_or_else(|| {
             BridgerError::Subcommand("Can not get current binary's path".to_string())
         })?;
-        external::provider::common::execute_binary(command, path_binary, self.args.clone(), cwd)
+        Ok(PreparedBinary {
+            command,
+            path_binary,
+            cwd,
+        })
     }
 }
 
@@ -50,8 +70,7 @@ impl PrecompiledBinaryExecutor {
     ) -> color_eyre::Result<(String, PathBuf)> {
         let config: BridgerConfig = Config::restore(Names::Bridger)?;
         let version = config
-            .registry
-            .version
+            .bridge_version(&self.command)
             .unwrap_or_else(|| VERSION.to_string());
         for prefix in support_types::constants::ALLOW_BINARY_PREFIX {
             let command = format!("{}{}", prefix, self.command);
@@ -59,6 +78,7 @@ impl PrecompiledBinaryExecutor {
             output::output_text(format!("Try execute {command}@{version}"));
             match self.download_and_extract_binary_with_command(
                 path.clone(),
+                &config.registry,
                 &version,
                 &command,
                 force,
@@ -79,6 +99,7 @@ impl PrecompiledBinaryExecutor {
     fn download_and_extract_binary_with_command(
         &self,
         path: Option<String>,
+        registry: &BridgerRegistry,
         version: impl AsRef<str>,
         command: impl AsRef<str>,
         force: bool,
@@ -89,22 +110,39 @@ impl PrecompiledBinaryExecutor {
         let path = path.ok_or_else(|| {
             BridgerError::Subcommand("Missing remote base url for precompiled binary".to_string())
         })?;
-        let package_name = self.package_name(command)?;
-        let remote_url = format!("{path}/releases/download/v{version}/{package_name}");
-        let path_binary_base = std::env::current_exe()?
-            .parent()
-            .map(|v| v.join(""))
-            .ok_or_else(|| {
-                BridgerError::Subcommand("Can not get current binary path".to_string())
-            })?;
-
-        let path_binary = path_binary_base.join(if cfg!(windows) {
+        let binary_name = if cfg!(windows) {
             format!("{command}.exe")
         } else {
             command.to_string()
-        });
+        };
+
+        // the binaries bundled beside bridger, e.g. in docker image, they aren't verified so
+        // they're neither run nor asked for version unless trusted explicitly
+        if !force && registry.trust_bundled {
+            if let Some(path_bundled) = Self::bundled_binary(&binary_name, version)? {
+                tracing::warn!(
+                    target: "bridger",
+                    "Run the bundled binary {} without verification, it's trusted by `trust_bundled` of registry",
+                    path_bundled.display(),
+                );
+                return Ok(path_bundled);
+            }
+        }
+
+        // binaries of every version are kept side by side, so upgrading a bridge doesn't touch
+        // the others which still run the old version
+        let path_binary_base = external::helpers::bridge_binary_dir(&self.command, version);
+        let path_binary = path_binary_base.join(&binary_name);
+        if !force && path_binary.exists() {
+            return Ok(path_binary);
+        }
+
+        let package = self.remote_package(&path, registry, version, command)?;
+        let package_name = &package.file_name;
 
-        let path_download_package = path_binary_base.join(&package_name);
+        // verified packages are cached by version, so switching versions doesn't download again
+        let path_cache = verify::package_cache_dir(version);
+        let path_download_package = path_cache.join(package_name);
 
         tracing::trace!(target: "bridger", "Force mode: {}", force);
         if force && path_download_package.exists() {
@@ -116,104 +154,50 @@ impl PrecompiledBinaryExecutor {
             std::fs::remove_file(&path_download_package)?;
         }
 
-        if !force && path_binary.exists() {
-            let version_output = Command::new(&path_binary).args(["--version"]).output()?;
-            match version_output.status.code() {
-                Some(0) => {
-                    let stdout = String::from_utf8_lossy(&version_output.stdout).into_owned();
-                    let parts: Vec<&str> = stdout.split(' ').collect();
-                    let binary_version = parts.get(1);
-                    if let Some(&bversion) = &binary_version {
-                        if bversion.trim() == version.trim() {
-                            return Ok(path_binary);
-                        }
-                    }
-                    tracing::warn!(
-                        target: "bridger",
-                        "The expected version is [{}], but the binary's ({}) version is [{}].",
-                        version,
-                        command,
-                        binary_version.unwrap_or(&"UNKNOWN")
-                    );
-                    let mut sys = System::new_all();
-                    sys.refresh_all();
-                    for (pid, process) in sys.processes() {
-                        if !process.name().contains(command) {
-                            continue;
-                        }
-                        if cfg!(windows) {
-                            return Err(BridgerError::Custom(format!(
-                                "The {command} is running (PID: {pid}), can not update to new version"
-                            ))
-                            .into());
-                        } else {
-                            tracing::warn!(
-                                target: "bridger",
-                                "The binary ({command}) will updated to {version}, when finished, please restart your running progress.",
-                            );
-                        }
-                        break;
-                    }
-                    tracing::trace!(
-                        target: "bridger",
-                        "The version changed, remove old binary for command: {}",
-                        command
-                    );
-                    std::fs::remove_file(&path_binary)?;
-                }
-                _ => {
-                    let stderr = String::from_utf8_lossy(&version_output.stderr).into_owned();
-                    return Err(BridgerError::Subcommand(format!(
-                        "Can not get version from [{}]: {}",
-                        path_binary.display(),
-                        stderr
-                    ))
-                    .into());
-                }
-            }
-        }
-
         tracing::trace!(
             target: "bridger",
             "Download package path is: {}",
             path_download_package.display(),
         );
-        if !path_download_package.exists() {
-            #[allow(clippy::redundant_clone)]
-            let mut url_package = remote_url.clone();
-            let mut response;
-            let mut times = 0;
-            loop {
-                times += 1;
-                if times > 5 {
-                    return Err(BridgerError::Custom(format!(
-                        "Too many redirect times for download url: {}",
-                        &remote_url
+        let cached = if registry.skip_verify {
+            path_download_package.exists()
+        } else {
+            verify::verify_cached_package(&path_cache, package_name)?
+        };
+        if !cached {
+            let checksums = if registry.skip_verify {
+                tracing::warn!(
+                    target: "bridger",
+                    "The verification of downloaded package is skipped, the package {} isn't verified",
+                    package_name,
+                );
+                None
+            } else {
+                let checksums_url = package.checksums_url.as_ref().ok_or_else(|| {
+                    BridgerError::Custom(format!(
+                        "Missing checksums of version {version} in registry index, set `skip_verify = true` of registry to run the package without verification"
                     ))
-                    .into());
-                }
-                output::output_text(format!("Downloading `{url_package}`"));
-                response = reqwest::blocking::get(&url_package)?;
-                let code = response.status().as_u16();
-                tracing::trace!(target: "bridger", "Response code is: {}", code);
-                let headers = response.headers();
-                if let Some(value) = headers.get("Location") {
-                    url_package = value.to_str()?.to_string();
-                    tracing::trace!(target: "bridger", "Found redirect location: {}", &url_package);
-                    continue;
-                }
-                break;
-            }
-            let code = response.status().as_u16();
-            if code != 200 && code != 201 {
-                return Err(BridgerError::Custom(format!(
-                    "[{code}] Failed to download package. the url is: {remote_url}"
+                })?;
+                Some(verify::download_checksums(
+                    checksums_url,
+                    registry.public_key.as_ref(),
+                )?)
+            };
+            let content = verify::download(&package.url)?.ok_or_else(|| {
+                BridgerError::Custom(format!(
+                    "[404] Failed to download package. the url is: {}",
+                    package.url
                 ))
-                .into());
+            })?;
+            if let Some(checksums) = &checksums {
+                checksums.verify(package_name, &content)?;
+                output::output_text(format!("Verified checksum of {package_name}"));
+            }
+            std::fs::create_dir_all(&path_cache)?;
+            std::fs::write(&path_download_package, &content)?;
+            if let Some(checksums) = &checksums {
+                std::fs::write(path_cache.join(verify::CHECKSUM_FILE), checksums.raw())?;
             }
-            let mut content = Cursor::new(response.bytes()?);
-            let mut file = std::fs::File::create(&path_download_package)?;
-            std::io::copy(&mut content, &mut file)?;
             output::output_text("Downloaded");
         }
 
@@ -227,7 +211,103 @@ impl PrecompiledBinaryExecutor {
         }
 
         output::output_text(format!("Start extract {}", path_download_package.display()));
-        let file = std::fs::File::open(&path_download_package)?;
+        std::fs::create_dir_all(&path_binary_base)?;
+        match package.format {
+            ArchiveFormat::Zip => Self::extract_zip(&path_download_package, &path_binary_base)?,
+            ArchiveFormat::TarGz => {
+                Self::extract_tar_gz(&path_download_package, &path_binary_base)?
+            }
+        }
+        Ok(path_binary)
+    }
+
+    /// Find the binary beside bridger which version is the expected version
+    fn bundled_binary(binary_name: &str, version: &str) -> color_eyre::Result<Option<PathBuf>> {
+        let path_binary = match std::env::current_exe()?.parent() {
+            Some(v) => v.join(binary_name),
+            None => return Ok(None),
+        };
+        if !path_binary.exists() {
+            return Ok(None);
+        }
+        let version_output = Command::new(&path_binary).args(["--version"]).output()?;
+        if version_output.status.code() != Some(0) {
+            let stderr = String::from_utf8_lossy(&version_output.stderr).into_owned();
+            tracing::warn!(
+                target: "bridger",
+                "Can not get version from [{}]: {}",
+                path_binary.display(),
+                stderr
+            );
+            return Ok(None);
+        }
+        let stdout = String::from_utf8_lossy(&version_output.stdout).into_owned();
+        let parts: Vec<&str> = stdout.split(' ').collect();
+        let binary_version = parts.get(1).map(|v| v.trim());
+        if binary_version == Some(version.trim()) {
+            return Ok(Some(path_binary));
+        }
+        tracing::debug!(
+            target: "bridger",
+            "The expected version is [{}], but the bundled binary's ({}) version is [{}].",
+            version,
+            binary_name,
+            binary_version.unwrap_or("UNKNOWN")
+        );
+        Ok(None)
+    }
+
+    /// Find package of the command for current target. The github registry follows the layout
+    /// of github releases, the server registry lists packages in index
+    fn remote_package(
+        &self,
+        path: &str,
+        registry: &BridgerRegistry,
+        version: &str,
+        command: &str,
+    ) -> color_eyre::Result<RemotePackage> {
+        let target = Target::current()?;
+        if registry.type_ == RegistryType::Server {
+            let registry_index = RegistryIndex::fetch(path)?;
+            let index_version = registry_index.version(version).ok_or_else(|| {
+                BridgerError::Subcommand(format!("Not found version {version} in registry index"))
+            })?;
+            let index_package = index_version.package(command, &target).ok_or_else(|| {
+                BridgerError::Subcommand(format!(
+                    "Not found package {command}@{version} for {target} in registry index"
+                ))
+            })?;
+            return Ok(RemotePackage {
+                file_name: index_package.file_name(),
+                url: index::absolute_url(path, &index_package.url),
+                format: index_package.format,
+                checksums_url: index_version
+                    .checksums
+                    .as_ref()
+                    .map(|v| index::absolute_url(path, v)),
+            });
+        }
+
+        // https://github.com/darwinia-network/bridger/releases/download/v0.8.1/bridge-darwinia-crab-linux-x86_64.zip
+        let format = ArchiveFormat::Zip;
+        let file_name = format!(
+            "{}-{}-{}.{}",
+            command,
+            target.os,
+            target.arch,
+            format.extension()
+        );
+        let release_url = format!("{path}/releases/download/v{version}");
+        Ok(RemotePackage {
+            url: format!("{release_url}/{file_name}"),
+            file_name,
+            format,
+            checksums_url: Some(format!("{release_url}/{}", verify::CHECKSUM_FILE)),
+        })
+    }
+
+    fn extract_zip(path_package: &Path, path_binary_base: &Path) -> color_eyre::Result<()> {
+        let file = std::fs::File::open(path_package)?;
         let mut archive = zip::ZipArchive::new(file)?;
         for i in 0..archive.len() {
             let mut zip_inner_file = archive.by_index(i)?;
@@ -274,29 +354,31 @@ impl PrecompiledBinaryExecutor {
             }
         }
 
-        std::fs::remove_file(&path_download_package)?;
-        Ok(path_binary)
+        Ok(())
     }
 
-    fn package_name(&self, base_name: impl AsRef<str>) -> color_eyre::Result<String> {
-        let os = sys_info::os_type()?;
-        let arch = if cfg!(target_arch = "x86") {
-            "x86"
-        } else if cfg!(target_arch = "x86_64") {
-            "x86_64"
-        } else {
-            return Err(BridgerError::Subcommand(
-                "Can not support current architecture".to_string(),
-            )
-            .into());
-        };
-        let package_name = format!(
-            "{}-{}-{}.{}",
-            base_name.as_ref(),
-            os.to_lowercase(),
-            arch,
-            &self.pkg_type
-        );
-        Ok(package_name)
+    fn extract_tar_gz(path_package: &Path, path_binary_base: &Path) -> color_eyre::Result<()> {
+        let file = std::fs::File::open(path_package)?;
+        let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+        archive.set_preserve_permissions(true);
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            // extract flatly as the zip packages, same as `zip -j`
+            let outpath = match entry.path()?.file_name() {
+                Some(name) => path_binary_base.join(name),
+                None => continue,
+            };
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            tracing::debug!(
+                target: "bridger",
+                "File extracted to \"{}\" ({} bytes)",
+                outpath.display(),
+                entry.size()
+            );
+            entry.unpack(&outpath)?;
+        }
+        Ok(())
     }
 }
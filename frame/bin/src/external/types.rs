@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
 /// Compile channel
@@ -19,3 +21,13 @@ impl CompileChannel {
         }
     }
 }
+
+/// The binary of a bridge which is ready to execute
+#[derive(Clone, Debug)]
+pub struct PreparedBinary {
+    /// The full command, e.g. `bridge-darwinia-crab`
+    pub command: String,
+    pub path_binary: PathBuf,
+    /// Working directory to execute the binary
+    pub cwd: PathBuf,
+}
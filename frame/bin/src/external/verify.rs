@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use support_common::error::BridgerError;
+use support_terminal::output;
+
+/// Checksum file published alongside the packages
+pub const CHECKSUM_FILE: &str = "SHA256SUMS";
+/// Checksum file name of the releases before `SHA256SUMS` published
+const LEGACY_CHECKSUM_FILE: &str = "sha256sums.txt";
+
+/// Checksums of the packages of a version, the key is package name
+#[derive(Clone, Debug)]
+pub struct Checksums {
+    raw: String,
+    sums: HashMap<String, String>,
+}
+
+impl Checksums {
+    /// Parse the output of `sha256sum`, each line is `<hash>  <file name>`
+    pub fn parse(raw: impl AsRef<str>) -> color_eyre::Result<Self> {
+        let raw = raw.as_ref();
+        let mut sums = HashMap::new();
+        for line in raw.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (hash, name) = line.split_once(char::is_whitespace).ok_or_else(|| {
+                BridgerError::Custom(format!("Wrong line of {CHECKSUM_FILE}: {line}"))
+            })?;
+            // binary mode of sha256sum prefixes the file name with `*`
+            let name = name.trim().trim_start_matches('*');
+            sums.insert(name.to_string(), hash.to_lowercase());
+        }
+        Ok(Self {
+            raw: raw.to_string(),
+            sums,
+        })
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Verify the content of package, refuse the package which isn't listed
+    pub fn verify(&self, package_name: &str, content: &[u8]) -> color_eyre::Result<()> {
+        let expected = self.sums.get(package_name).ok_or_else(|| {
+            BridgerError::Custom(format!(
+                "The package {package_name} isn't listed in {CHECKSUM_FILE}"
+            ))
+        })?;
+        let actual = array_bytes::bytes2hex("", Sha256::digest(content));
+        if &actual != expected {
+            return Err(BridgerError::Custom(format!(
+                "Checksum mismatch of {package_name}, expected {expected} but got {actual}"
+            ))
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Download the checksums from the url of `SHA256SUMS`, the signature `SHA256SUMS.minisig` is
+/// verified by the minisign public key when it's set
+pub fn download_checksums(
+    url: impl AsRef<str>,
+    public_key: Option<&String>,
+) -> color_eyre::Result<Checksums> {
+    let url = url.as_ref();
+    let raw = match download(url)? {
+        Some(v) => v,
+        None => {
+            let legacy_url = url
+                .strip_suffix(CHECKSUM_FILE)
+                .map(|v| format!("{v}{LEGACY_CHECKSUM_FILE}"));
+            let legacy = match legacy_url {
+                Some(v) => download(v)?,
+                None => None,
+            };
+            legacy.ok_or_else(|| {
+                BridgerError::Custom(format!(
+                    "Not found {url}, set `skip_verify = true` of registry to run the package without verification"
+                ))
+            })?
+        }
+    };
+    if let Some(public_key) = public_key {
+        let signature_url = format!("{url}.minisig");
+        let signature = download(&signature_url)?
+            .ok_or_else(|| BridgerError::Custom(format!("Not found signature {signature_url}")))?;
+        verify_signature(public_key, &raw, &signature)?;
+        output::output_text(format!("Verified signature of {CHECKSUM_FILE}"));
+    }
+    Checksums::parse(String::from_utf8_lossy(&raw))
+}
+
+fn verify_signature(public_key: &str, content: &[u8], signature: &[u8]) -> color_eyre::Result<()> {
+    let public_key = minisign_verify::PublicKey::from_base64(public_key)
+        .map_err(|e| BridgerError::Custom(format!("Wrong minisign public key: {e:?}")))?;
+    let signature = minisign_verify::Signature::decode(&String::from_utf8_lossy(signature))
+        .map_err(|e| BridgerError::Custom(format!("Wrong signature of {CHECKSUM_FILE}: {e:?}")))?;
+    public_key.verify(content, &signature, false).map_err(|e| {
+        BridgerError::Custom(format!(
+            "Failed to verify signature of {CHECKSUM_FILE}: {e:?}"
+        ))
+    })?;
+    Ok(())
+}
+
+/// Cache dir of verified packages of a version
+pub fn package_cache_dir(version: impl AsRef<str>) -> PathBuf {
+    support_types::constants::bridger_home()
+        .join("cache")
+        .join("packages")
+        .join(format!("v{}", version.as_ref()))
+}
+
+/// Verify the cached package again by the cached checksums, returns false and removes the
+/// package if it's missing or modified
+pub fn verify_cached_package(cache_dir: &Path, package_name: &str) -> color_eyre::Result<bool> {
+    let path_package = cache_dir.join(package_name);
+    let path_checksums = cache_dir.join(CHECKSUM_FILE);
+    if !path_package.exists() || !path_checksums.exists() {
+        return Ok(false);
+    }
+    let checksums = Checksums::parse(std::fs::read_to_string(&path_checksums)?)?;
+    let content = std::fs::read(&path_package)?;
+    if let Err(e) = checksums.verify(package_name, &content) {
+        tracing::warn!(
+            target: "bridger",
+            "The cached package {} is broken, download it again: {:?}",
+            path_package.display(),
+            e,
+        );
+        std::fs::remove_file(&path_package)?;
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// Download the content of url, returns none if not found
+pub fn download(url: impl AsRef<str>) -> color_eyre::Result<Option<Vec<u8>>> {
+    let remote_url = url.as_ref();
+    let mut url = remote_url.to_string();
+    let mut response;
+    let mut times = 0;
+    loop {
+        times += 1;
+        if times > 5 {
+            return Err(BridgerError::Custom(format!(
+                "Too many redirect times for download url: {remote_url}"
+            ))
+            .into());
+        }
+        output::output_text(format!("Downloading `{url}`"));
+        response = reqwest::blocking::get(&url)?;
+        let code = response.status().as_u16();
+        tracing::trace!(target: "bridger", "Response code is: {}", code);
+        let headers = response.headers();
+        if let Some(value) = headers.get("Location") {
+            url = value.to_str()?.to_string();
+            tracing::trace!(target: "bridger", "Found redirect location: {}", &url);
+            continue;
+        }
+        break;
+    }
+    let code = response.status().as_u16();
+    if code == 404 {
+        return Ok(None);
+    }
+    if code != 200 && code != 201 {
+        return Err(BridgerError::Custom(format!(
+            "[{code}] Failed to download. the url is: {remote_url}"
+        ))
+        .into());
+    }
+    Ok(Some(response.bytes()?.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sha256_hex(content: &[u8]) -> String {
+        array_bytes::bytes2hex("", Sha256::digest(content))
+    }
+
+    #[test]
+    fn test_parse_checksums() {
+        let raw = format!(
+            "{}  darwinia-ethereum-linux-x86_64.zip\n\n{} *pangolin-pangoro-linux-x86_64.zip\n",
+            sha256_hex(b"darwinia-ethereum"),
+            sha256_hex(b"pangolin-pangoro").to_uppercase(),
+        );
+        let checksums = Checksums::parse(&raw).unwrap();
+        assert_eq!(checksums.raw(), raw);
+        assert_eq!(checksums.sums.len(), 2);
+        assert_eq!(
+            checksums.sums.get("pangolin-pangoro-linux-x86_64.zip"),
+            Some(&sha256_hex(b"pangolin-pangoro")),
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_line() {
+        let raw = format!(
+            "{}  darwinia-ethereum-linux-x86_64.zip\nmalformed",
+            sha256_hex(b"darwinia-ethereum"),
+        );
+        assert!(Checksums::parse(raw).is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let raw = format!(
+            "{}  darwinia-ethereum-linux-x86_64.zip",
+            sha256_hex(b"darwinia-ethereum")
+        );
+        let checksums = Checksums::parse(raw).unwrap();
+        assert!(checksums
+            .verify("darwinia-ethereum-linux-x86_64.zip", b"darwinia-ethereum")
+            .is_ok());
+        // mismatched content
+        assert!(checksums
+            .verify("darwinia-ethereum-linux-x86_64.zip", b"modified")
+            .is_err());
+        // not listed package
+        assert!(checksums
+            .verify("pangolin-pangoro-linux-x86_64.zip", b"darwinia-ethereum")
+            .is_err());
+    }
+}
@@ -9,6 +9,7 @@ mod command;
 mod config;
 mod external;
 mod initialize;
+mod supervisor;
 
 fn main() -> color_eyre::Result<()> {
     initialize::init()?;
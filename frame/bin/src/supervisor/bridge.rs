@@ -0,0 +1,220 @@
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use support_terminal::output;
+
+use crate::config::BridgeProcess;
+use crate::external::execute::ExternalExecutor;
+use crate::supervisor::state::{self, BridgeState, BridgeStatus, SupervisorState};
+
+/// The first delay to restart a crashed bridge, doubled on each crash
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+/// The max delay to restart a crashed bridge
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// The back-off is reset when the bridge ran longer than this
+const STABLE_UPTIME: Duration = Duration::from_secs(300);
+/// Time to wait the bridge exit after SIGTERM, then it's killed
+const STOP_TIMEOUT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Keep a bridge running until shutdown
+pub(crate) struct BridgeSupervisor {
+    bridge: BridgeProcess,
+    /// Colored `[<bridge>]`, prefixed to every line of the bridge output
+    prefix: String,
+    state: Arc<Mutex<SupervisorState>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl BridgeSupervisor {
+    pub fn new(
+        bridge: BridgeProcess,
+        prefix: String,
+        state: Arc<Mutex<SupervisorState>>,
+        shutdown: Arc<AtomicBool>,
+    ) -> Self {
+        Self {
+            bridge,
+            prefix,
+            state,
+            shutdown,
+        }
+    }
+
+    pub fn supervise(&self) {
+        let mut backoff = MIN_BACKOFF;
+        while !self.is_shutdown() {
+            self.update(|v| {
+                v.pid = None;
+                v.status = BridgeStatus::Starting;
+            });
+            let started = Instant::now();
+            match self.run_once() {
+                Ok(status) => {
+                    if self.is_shutdown() {
+                        break;
+                    }
+                    output::output_warning(format!("{} exited with {}", self.prefix, status));
+                    self.update(|v| v.last_exit = Some(status.to_string()));
+                }
+                Err(e) => {
+                    output::output_err(format!("{} failed to run: {:?}", self.prefix, e));
+                    self.update(|v| v.last_exit = Some(format!("{e}")));
+                }
+            }
+            if self.is_shutdown() {
+                break;
+            }
+            if started.elapsed() >= STABLE_UPTIME {
+                backoff = MIN_BACKOFF;
+            }
+
+            output::output_warning(format!(
+                "{} restart in {} seconds",
+                self.prefix,
+                backoff.as_secs()
+            ));
+            self.update(|v| {
+                v.pid = None;
+                v.started_at = None;
+                v.status = BridgeStatus::Backoff;
+            });
+            if !self.sleep(backoff) {
+                break;
+            }
+            backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
+            self.update(|v| v.restarts += 1);
+        }
+        self.update(|v| {
+            v.pid = None;
+            v.started_at = None;
+            v.status = BridgeStatus::Stopped;
+        });
+    }
+
+    fn run_once(&self) -> color_eyre::Result<ExitStatus> {
+        let executor = ExternalExecutor::new(self.bridge.name.clone(), self.bridge.args.clone());
+        let mut child = executor.spawn()?;
+        let pid = child.id();
+        self.update(|v| {
+            v.pid = Some(pid);
+            v.started_at = Some(state::now());
+            v.status = BridgeStatus::Running;
+        });
+
+        let mut forwarders = Vec::new();
+        if let Some(stdout) = child.stdout.take() {
+            forwarders.push(self.forward_output(stdout, false)?);
+        }
+        if let Some(stderr) = child.stderr.take() {
+            forwarders.push(self.forward_output(stderr, true)?);
+        }
+        let status = self.wait(&mut child);
+        for forwarder in forwarders {
+            let _ = forwarder.join();
+        }
+        status
+    }
+
+    /// Wait the bridge exit, terminate it when shutdown
+    fn wait(&self, child: &mut Child) -> color_eyre::Result<ExitStatus> {
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(status);
+            }
+            if self.is_shutdown() {
+                return self.terminate(child);
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Forward SIGTERM to the bridge, kill it if it doesn't exit in time
+    fn terminate(&self, child: &mut Child) -> color_eyre::Result<ExitStatus> {
+        self.update(|v| v.status = BridgeStatus::Stopping);
+        output::output_text(format!("{} stopping", self.prefix));
+        #[cfg(unix)]
+        unsafe {
+            libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+        }
+        #[cfg(not(unix))]
+        child.kill()?;
+
+        let deadline = Instant::now() + STOP_TIMEOUT;
+        while Instant::now() < deadline {
+            if let Some(status) = child.try_wait()? {
+                return Ok(status);
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+        output::output_warning(format!(
+            "{} not exited in {} seconds, kill it",
+            self.prefix,
+            STOP_TIMEOUT.as_secs()
+        ));
+        child.kill()?;
+        Ok(child.wait()?)
+    }
+
+    /// Print the output of bridge line by line with the prefix
+    fn forward_output(
+        &self,
+        reader: impl Read + Send + 'static,
+        stderr: bool,
+    ) -> color_eyre::Result<JoinHandle<()>> {
+        let prefix = self.prefix.clone();
+        let handle = std::thread::Builder::new()
+            .name(format!("{}-output", self.bridge.name))
+            .spawn(move || {
+                for line in BufReader::new(reader).split(b'\n') {
+                    let line = match line {
+                        Ok(v) => v,
+                        Err(_) => break,
+                    };
+                    let line = String::from_utf8_lossy(&line);
+                    let line = line.trim_end_matches('\r');
+                    if stderr {
+                        eprintln!("{prefix} {line}");
+                    } else {
+                        println!("{prefix} {line}");
+                    }
+                }
+            })?;
+        Ok(handle)
+    }
+
+    /// Sleep until timeout or shutdown, returns false when shutdown
+    fn sleep(&self, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+        while Instant::now() < deadline {
+            if self.is_shutdown() {
+                return false;
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+        !self.is_shutdown()
+    }
+
+    fn is_shutdown(&self) -> bool {
+        self.shutdown.load(Ordering::SeqCst)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut BridgeState)) {
+        let mut state = match self.state.lock() {
+            Ok(v) => v,
+            Err(e) => e.into_inner(),
+        };
+        f(state.bridges.entry(self.bridge.name.clone()).or_default());
+        if let Err(e) = state.store() {
+            tracing::warn!(
+                target: "bridger",
+                "Failed to write state of supervisor: {:?}",
+                e
+            );
+        }
+    }
+}
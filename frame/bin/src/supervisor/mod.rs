@@ -0,0 +1,86 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use colored::{Color, Colorize};
+
+use support_common::error::BridgerError;
+use support_terminal::output;
+
+use crate::config::BridgeProcess;
+use crate::supervisor::bridge::BridgeSupervisor;
+
+pub use self::state::*;
+
+mod bridge;
+mod state;
+
+/// Colors of the bridge prefix, picked by the bridge order
+const PREFIX_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Blue,
+    Color::Green,
+    Color::Yellow,
+    Color::BrightCyan,
+];
+
+/// Run bridges as child processes, restart them when they exit and stop them on SIGINT,
+/// SIGTERM or SIGHUP
+pub struct Supervisor {
+    bridges: Vec<BridgeProcess>,
+}
+
+impl Supervisor {
+    pub fn new(bridges: Vec<BridgeProcess>) -> Self {
+        Self { bridges }
+    }
+
+    /// Block until all bridges stopped
+    pub fn run(&self) -> color_eyre::Result<()> {
+        if let Some(state) = SupervisorState::load()? {
+            if state.pid != std::process::id() && state.is_alive() {
+                return Err(BridgerError::Custom(format!(
+                    "Another supervisor is running, the pid is {}",
+                    state.pid
+                ))
+                .into());
+            }
+        }
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let signal_shutdown = shutdown.clone();
+        ctrlc::set_handler(move || {
+            if !signal_shutdown.swap(true, Ordering::SeqCst) {
+                output::output_text("Received stop signal, stopping bridges");
+            }
+        })
+        .map_err(|e| BridgerError::Custom(format!("Failed to set signal handler: {e:?}")))?;
+
+        let state = SupervisorState::new(&self.bridges);
+        state.store()?;
+        let state = Arc::new(Mutex::new(state));
+
+        let mut handles = Vec::new();
+        for (ix, bridge) in self.bridges.iter().enumerate() {
+            let prefix = format!("[{}]", bridge.name)
+                .color(PREFIX_COLORS[ix % PREFIX_COLORS.len()])
+                .to_string();
+            output::output_text(format!("{} {}", prefix, bridge.args.join(" ")));
+            let bridge_supervisor =
+                BridgeSupervisor::new(bridge.clone(), prefix, state.clone(), shutdown.clone());
+            let handle = std::thread::Builder::new()
+                .name(bridge.name.clone())
+                .spawn(move || bridge_supervisor.supervise())?;
+            handles.push(handle);
+        }
+        for handle in handles {
+            if handle.join().is_err() {
+                tracing::error!(target: "bridger", "The supervisor thread of bridge panicked");
+            }
+        }
+
+        SupervisorState::remove()?;
+        output::output_text("All bridges stopped");
+        Ok(())
+    }
+}
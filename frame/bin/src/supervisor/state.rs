@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::BridgeProcess;
+
+/// State file of supervisor, it's placed at `$BRIDGER_HOME/supervisor.json`
+pub const STATE_FILE: &str = "supervisor.json";
+
+/// State of supervisor, written when the state of any bridge changed, read by `bridger ps`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SupervisorState {
+    /// Pid of supervisor
+    pub pid: u32,
+    /// Unix timestamp in seconds
+    pub started_at: u64,
+    /// The key is bridge name
+    pub bridges: BTreeMap<String, BridgeState>,
+}
+
+/// State of a bridge process
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BridgeState {
+    /// Pid of the bridge, none if it's not running
+    pub pid: Option<u32>,
+    /// Unix timestamp in seconds of the current run
+    pub started_at: Option<u64>,
+    pub restarts: u32,
+    pub status: BridgeStatus,
+    /// Exit status of the last run
+    pub last_exit: Option<String>,
+}
+
+/// Status of a bridge process
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BridgeStatus {
+    /// Preparing the binary or spawning
+    #[default]
+    Starting,
+    Running,
+    /// Waiting to restart after exited
+    Backoff,
+    Stopping,
+    Stopped,
+}
+
+impl SupervisorState {
+    pub fn new(bridges: &[BridgeProcess]) -> Self {
+        Self {
+            pid: std::process::id(),
+            started_at: now(),
+            bridges: bridges
+                .iter()
+                .map(|v| (v.name.clone(), BridgeState::default()))
+                .collect(),
+        }
+    }
+
+    pub fn path() -> PathBuf {
+        support_types::constants::bridger_home().join(STATE_FILE)
+    }
+
+    /// Read state file, returns none if there isn't supervisor ran
+    pub fn load() -> color_eyre::Result<Option<Self>> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read(&path)?;
+        Ok(Some(serde_json::from_slice(&content)?))
+    }
+
+    pub fn store(&self) -> color_eyre::Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // write to temp file first, so `bridger ps` never reads a partial file
+        let path_tmp = path.with_extension("json.tmp");
+        std::fs::write(&path_tmp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(&path_tmp, &path)?;
+        Ok(())
+    }
+
+    pub fn remove() -> color_eyre::Result<()> {
+        let path = Self::path();
+        if path.exists() {
+            std::fs::remove_file(&path)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the supervisor process is still alive
+    pub fn is_alive(&self) -> bool {
+        process_alive(self.pid)
+    }
+}
+
+/// Whether the process exists, the state of a process which can't be checked is stale
+fn process_alive(pid: u32) -> bool {
+    // pid 0 and negative pids are process groups to kill
+    if pid == 0 || pid > i32::MAX as u32 {
+        return false;
+    }
+    #[cfg(unix)]
+    {
+        // signal 0 only checks the existence of process
+        unsafe { libc::kill(pid as libc::pid_t, 0) == 0 }
+    }
+    #[cfg(not(unix))]
+    {
+        // the csv output of tasklist lists `"<image>","<pid>",...` if the process exists
+        std::process::Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {pid}"), "/FO", "CSV", "/NH"])
+            .output()
+            .map(|v| String::from_utf8_lossy(&v.stdout).contains(&format!("\",\"{pid}\",")))
+            .unwrap_or(false)
+    }
+}
+
+/// Current unix timestamp in seconds
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|v| v.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_alive() {
+        assert!(process_alive(std::process::id()));
+        assert!(!process_alive(0));
+        assert!(!process_alive(u32::MAX));
+    }
+
+    #[test]
+    fn test_exited_process_is_not_alive() {
+        let mut child = if cfg!(unix) {
+            std::process::Command::new("true").spawn().unwrap()
+        } else {
+            std::process::Command::new("cmd")
+                .args(["/C", "exit"])
+                .spawn()
+                .unwrap()
+        };
+        let pid = child.id();
+        child.wait().unwrap();
+        assert!(!process_alive(pid));
+    }
+}
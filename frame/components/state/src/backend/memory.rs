@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use serde_json::Value;
+
+use crate::backend::{BatchOperation, StateBackend, StateBatch};
+use crate::error::StateResult;
+
+/// In-memory state, it's lost after the process exits
+#[derive(Debug, Default)]
+pub struct MemoryBackend {
+    data: RwLock<BTreeMap<String, BTreeMap<String, Value>>>,
+}
+
+impl StateBackend for MemoryBackend {
+    fn get(&self, namespace: &str, key: &str) -> StateResult<Option<Value>> {
+        let data = self.data.read().unwrap();
+        Ok(data.get(namespace).and_then(|v| v.get(key)).cloned())
+    }
+
+    fn put(&self, namespace: &str, key: &str, value: Value) -> StateResult<()> {
+        let mut batch = StateBatch::default();
+        batch.put(namespace, key, value);
+        self.write_batch(batch)
+    }
+
+    fn delete(&self, namespace: &str, key: &str) -> StateResult<()> {
+        let mut batch = StateBatch::default();
+        batch.delete(namespace, key);
+        self.write_batch(batch)
+    }
+
+    fn keys(&self, namespace: &str) -> StateResult<Vec<String>> {
+        let data = self.data.read().unwrap();
+        Ok(data
+            .get(namespace)
+            .map(|v| v.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    fn namespaces(&self) -> StateResult<Vec<String>> {
+        let data = self.data.read().unwrap();
+        Ok(data.keys().cloned().collect())
+    }
+
+    fn write_batch(&self, batch: StateBatch) -> StateResult<()> {
+        let mut data = self.data.write().unwrap();
+        for operation in batch.into_operations() {
+            match operation {
+                BatchOperation::Put {
+                    namespace,
+                    key,
+                    value,
+                } => {
+                    data.entry(namespace).or_default().insert(key, value);
+                }
+                BatchOperation::Delete { namespace, key } => {
+                    if let Some(keys) = data.get_mut(&namespace) {
+                        keys.remove(&key);
+                        if keys.is_empty() {
+                            data.remove(&namespace);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use microkv::MicroKV;
+use serde_json::Value;
+
+use crate::backend::{BatchOperation, StateBackend, StateBatch};
+use crate::config::MicrokvConfig;
+use crate::error::{StateComponentError, StateResult};
+
+/// Suffix of the db name of the temp store, the store is written to `<db_name>.tmp.kv` then
+/// renamed to `<db_name>.kv`
+const TEMP_SUFFIX: &str = ".tmp";
+
+/// State stored in microkv file `<db_name>.kv`. The auto commit is off, every write persists
+/// the whole map once, so a batch is persisted atomically
+pub struct MicrokvBackend {
+    microkv: MicroKV,
+    base_path: PathBuf,
+    db_name: String,
+    /// Writes are serialized so that a commit doesn't persist a half written batch
+    write_lock: Mutex<()>,
+}
+
+impl MicrokvBackend {
+    pub fn open(base_path: &Path, db_name: &str) -> color_eyre::Result<Self> {
+        let config = MicrokvConfig {
+            base_path: base_path.to_path_buf(),
+            db_name: Some(db_name.to_string()),
+            auto_commit: false,
+        };
+        let microkv = crate::microkv::microkv_instance(&config)?;
+        Ok(Self {
+            microkv,
+            base_path: base_path.to_path_buf(),
+            db_name: db_name.to_string(),
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    pub fn microkv(&self) -> &MicroKV {
+        &self.microkv
+    }
+
+    fn apply(&self, operation: BatchOperation) -> StateResult<()> {
+        match operation {
+            BatchOperation::Put {
+                namespace,
+                key,
+                value,
+            } => self.microkv.namespace(namespace).put(key, &value),
+            BatchOperation::Delete { namespace, key } => {
+                self.microkv.namespace(namespace).delete(key)
+            }
+        }
+        .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))
+    }
+
+    /// Persist the map. microkv truncates the store before writing it, a crash in between
+    /// loses the state, so the map is committed to a temp store which is renamed over the store
+    fn commit(&self) -> StateResult<()> {
+        let temp_name = format!("{}{}", self.db_name, TEMP_SUFFIX);
+        let temp_path = self.base_path.join(format!("{temp_name}.kv"));
+        if temp_path.exists() {
+            std::fs::remove_file(&temp_path)
+                .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))?;
+        }
+        let temp = MicroKV::open_with_base_path(temp_name, self.base_path.clone())
+            .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))?;
+        for namespace in self.namespaces()? {
+            for key in self.keys(&namespace)? {
+                if let Some(value) = self.get(&namespace, &key)? {
+                    temp.namespace(&namespace)
+                        .put(key, &value)
+                        .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))?;
+                }
+            }
+        }
+        temp.commit()
+            .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))?;
+        std::fs::rename(
+            &temp_path,
+            self.base_path.join(format!("{}.kv", self.db_name)),
+        )
+        .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))
+    }
+}
+
+impl StateBackend for MicrokvBackend {
+    fn get(&self, namespace: &str, key: &str) -> StateResult<Option<Value>> {
+        self.microkv
+            .namespace(namespace)
+            .get(key)
+            .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))
+    }
+
+    fn put(&self, namespace: &str, key: &str, value: Value) -> StateResult<()> {
+        let mut batch = StateBatch::default();
+        batch.put(namespace, key, value);
+        self.write_batch(batch)
+    }
+
+    fn delete(&self, namespace: &str, key: &str) -> StateResult<()> {
+        let mut batch = StateBatch::default();
+        batch.delete(namespace, key);
+        self.write_batch(batch)
+    }
+
+    fn keys(&self, namespace: &str) -> StateResult<Vec<String>> {
+        self.microkv
+            .namespace(namespace)
+            .keys()
+            .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))
+    }
+
+    fn namespaces(&self) -> StateResult<Vec<String>> {
+        self.microkv
+            .namespaces()
+            .map_err(|e| StateComponentError::Microkv(format!("{e:?}")))
+    }
+
+    fn write_batch(&self, batch: StateBatch) -> StateResult<()> {
+        let _guard = self.write_lock.lock().unwrap();
+        // the previous values of written keys, they're restored if the batch fails
+        let mut previous = Vec::with_capacity(batch.len());
+        for operation in batch.operations() {
+            let (namespace, key) = operation.key();
+            previous.push(BatchOperation::of(
+                namespace,
+                key,
+                self.get(namespace, key)?,
+            ));
+        }
+
+        let result = batch
+            .into_operations()
+            .into_iter()
+            .try_for_each(|operation| self.apply(operation))
+            .and_then(|_| self.commit());
+        if result.is_err() {
+            // restore in reverse, so the value before the batch wins for a key written twice
+            for operation in previous.into_iter().rev() {
+                if let Err(e) = self.apply(operation) {
+                    tracing::error!(
+                        target: "component-state",
+                        "Failed to roll back the batch of {}: {:?}",
+                        self.db_name,
+                        e,
+                    );
+                }
+            }
+        }
+        result
+    }
+}
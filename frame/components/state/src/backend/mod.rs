@@ -0,0 +1,227 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::StateResult;
+
+pub use self::memory::MemoryBackend;
+pub use self::microkv::MicrokvBackend;
+pub use self::sled::SledBackend;
+
+mod memory;
+mod microkv;
+mod sled;
+
+/// Storage of bridge state, the keys are grouped by namespace and the values are json
+pub trait StateBackend: Send + Sync {
+    /// Read the value of key
+    fn get(&self, namespace: &str, key: &str) -> StateResult<Option<Value>>;
+
+    /// Write the value of key
+    fn put(&self, namespace: &str, key: &str, value: Value) -> StateResult<()>;
+
+    /// Remove the key
+    fn delete(&self, namespace: &str, key: &str) -> StateResult<()>;
+
+    /// All keys of the namespace
+    fn keys(&self, namespace: &str) -> StateResult<Vec<String>>;
+
+    /// All namespaces which have keys
+    fn namespaces(&self) -> StateResult<Vec<String>>;
+
+    /// Write all operations of the batch, either all or none of them are persisted
+    fn write_batch(&self, batch: StateBatch) -> StateResult<()>;
+}
+
+/// An operation of batch
+#[derive(Clone, Debug)]
+pub enum BatchOperation {
+    Put {
+        namespace: String,
+        key: String,
+        value: Value,
+    },
+    Delete {
+        namespace: String,
+        key: String,
+    },
+}
+
+impl BatchOperation {
+    /// Operation restoring the key to the value, it's deleted if there isn't a value
+    pub fn of(namespace: impl AsRef<str>, key: impl AsRef<str>, value: Option<Value>) -> Self {
+        let namespace = namespace.as_ref().to_string();
+        let key = key.as_ref().to_string();
+        match value {
+            Some(value) => Self::Put {
+                namespace,
+                key,
+                value,
+            },
+            None => Self::Delete { namespace, key },
+        }
+    }
+
+    /// The namespace and key written by the operation
+    pub fn key(&self) -> (&str, &str) {
+        match self {
+            Self::Put { namespace, key, .. } | Self::Delete { namespace, key } => (namespace, key),
+        }
+    }
+}
+
+/// Operations written atomically by [`StateBackend::write_batch`]
+#[derive(Clone, Debug, Default)]
+pub struct StateBatch {
+    operations: Vec<BatchOperation>,
+}
+
+impl StateBatch {
+    pub fn put(&mut self, namespace: impl AsRef<str>, key: impl AsRef<str>, value: Value) {
+        self.operations.push(BatchOperation::Put {
+            namespace: namespace.as_ref().to_string(),
+            key: key.as_ref().to_string(),
+            value,
+        });
+    }
+
+    pub fn delete(&mut self, namespace: impl AsRef<str>, key: impl AsRef<str>) {
+        self.operations.push(BatchOperation::Delete {
+            namespace: namespace.as_ref().to_string(),
+            key: key.as_ref().to_string(),
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    pub fn operations(&self) -> &[BatchOperation] {
+        &self.operations
+    }
+
+    pub fn into_operations(self) -> Vec<BatchOperation> {
+        self.operations
+    }
+}
+
+/// Kind of state backend
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Deserialize,
+    Serialize,
+    strum::EnumString,
+    strum::EnumVariantNames,
+)]
+#[strum(serialize_all = "kebab_case")]
+#[serde(rename_all = "kebab-case")]
+pub enum StateBackendKind {
+    /// Single file `<db_name>.kv`, the whole map is rewritten on every write
+    Microkv,
+    /// Directory `<db_name>.sled`, it's locked exclusively by the process which opens it, so
+    /// the state can't be read or written by `kv` commands while the bridge is running
+    Sled,
+    /// Not persisted, for tests
+    Memory,
+}
+
+impl StateBackendKind {
+    /// Path of the store in base path, none for memory
+    pub fn store_path(&self, base_path: &Path, db_name: &str) -> Option<PathBuf> {
+        match self {
+            Self::Microkv => Some(base_path.join(format!("{db_name}.kv"))),
+            Self::Sled => Some(base_path.join(format!("{db_name}.sled"))),
+            Self::Memory => None,
+        }
+    }
+
+    /// The persisted backend whose store exists, the newer sled is preferred
+    pub fn detect(base_path: &Path, db_name: &str) -> Option<Self> {
+        [Self::Sled, Self::Microkv].into_iter().find(|kind| {
+            kind.store_path(base_path, db_name)
+                .map(|path| path.exists())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Open the backend, the store is created if it doesn't exist
+    pub fn open(
+        &self,
+        base_path: &Path,
+        db_name: &str,
+    ) -> color_eyre::Result<Box<dyn StateBackend>> {
+        Ok(match self {
+            Self::Microkv => Box::new(MicrokvBackend::open(base_path, db_name)?),
+            Self::Sled => Box::new(SledBackend::open(base_path, db_name)?),
+            Self::Memory => Box::new(MemoryBackend::default()),
+        })
+    }
+}
+
+/// Copy all namespaces of the source backend to the target backend in a batch, returns the
+/// count of keys
+pub fn migrate(source: &dyn StateBackend, target: &dyn StateBackend) -> StateResult<usize> {
+    let mut batch = StateBatch::default();
+    for namespace in source.namespaces()? {
+        for key in source.keys(&namespace)? {
+            if let Some(value) = source.get(&namespace, &key)? {
+                batch.put(&namespace, key, value);
+            }
+        }
+    }
+    let count = batch.len();
+    target.write_batch(batch)?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_batch() {
+        let backend = MemoryBackend::default();
+        backend.put("scan", "current", json!(1)).unwrap();
+        backend.put("scan", "planned", json!(5)).unwrap();
+
+        let mut batch = StateBatch::default();
+        batch.put("scan", "current", json!(5));
+        batch.delete("scan", "planned");
+        backend.write_batch(batch).unwrap();
+        assert_eq!(backend.get("scan", "current").unwrap(), Some(json!(5)));
+        assert_eq!(backend.get("scan", "planned").unwrap(), None);
+        assert_eq!(backend.keys("scan").unwrap(), vec!["current".to_string()]);
+    }
+
+    #[test]
+    fn test_migrate() {
+        let source = MemoryBackend::default();
+        source.put("", "root", json!("value")).unwrap();
+        source.put("darwinia-ethereum", "a", json!(true)).unwrap();
+        source
+            .put("darwinia-ethereum", "b", json!({"x": 1}))
+            .unwrap();
+
+        let target = MemoryBackend::default();
+        assert_eq!(migrate(&source, &target).unwrap(), 3);
+        assert_eq!(
+            target.namespaces().unwrap(),
+            vec!["".to_string(), "darwinia-ethereum".to_string()]
+        );
+        assert_eq!(
+            target.get("darwinia-ethereum", "b").unwrap(),
+            Some(json!({"x": 1}))
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::backend::{BatchOperation, StateBackend, StateBatch};
+use crate::error::{StateComponentError, StateResult};
+
+/// Separator of namespace and key, all keys are stored in the default tree as
+/// `<namespace>\0<key>` so that a batch of any namespaces is applied atomically
+const SEPARATOR: u8 = 0;
+
+/// Message prefix of the io error returned by sled when the exclusive lock of the database
+/// is held by another process
+const LOCK_ERROR_PREFIX: &str = "could not acquire lock";
+
+/// State stored in sled database `<db_name>.sled`
+#[derive(Clone, Debug)]
+pub struct SledBackend {
+    db: sled::Db,
+}
+
+impl SledBackend {
+    /// Open the database, sled locks it exclusively so that it fails while a running bridge
+    /// holds the same store
+    pub fn open(base_path: &Path, db_name: &str) -> StateResult<Self> {
+        let path = base_path.join(format!("{db_name}.sled"));
+        let db = sled::open(&path).map_err(|e| match e {
+            sled::Error::Io(ref io) if io.to_string().starts_with(LOCK_ERROR_PREFIX) => {
+                StateComponentError::Locked(path.display().to_string())
+            }
+            e => e.into(),
+        })?;
+        Ok(Self { db })
+    }
+
+    fn storage_key(namespace: &str, key: &str) -> Vec<u8> {
+        let mut storage_key = Vec::with_capacity(namespace.len() + key.len() + 1);
+        storage_key.extend_from_slice(namespace.as_bytes());
+        storage_key.push(SEPARATOR);
+        storage_key.extend_from_slice(key.as_bytes());
+        storage_key
+    }
+
+    fn split_key(storage_key: &[u8]) -> Option<(String, String)> {
+        let index = storage_key.iter().position(|v| *v == SEPARATOR)?;
+        let namespace = String::from_utf8_lossy(&storage_key[..index]).to_string();
+        let key = String::from_utf8_lossy(&storage_key[index + 1..]).to_string();
+        Some((namespace, key))
+    }
+}
+
+impl StateBackend for SledBackend {
+    fn get(&self, namespace: &str, key: &str) -> StateResult<Option<Value>> {
+        match self.db.get(Self::storage_key(namespace, key))? {
+            Some(v) => Ok(Some(serde_json::from_slice(&v)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn put(&self, namespace: &str, key: &str, value: Value) -> StateResult<()> {
+        self.db.insert(
+            Self::storage_key(namespace, key),
+            serde_json::to_vec(&value)?,
+        )?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    fn delete(&self, namespace: &str, key: &str) -> StateResult<()> {
+        self.db.remove(Self::storage_key(namespace, key))?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    fn keys(&self, namespace: &str) -> StateResult<Vec<String>> {
+        let mut prefix = namespace.as_bytes().to_vec();
+        prefix.push(SEPARATOR);
+        let mut keys = Vec::new();
+        for item in self.db.scan_prefix(prefix) {
+            let (storage_key, _) = item?;
+            if let Some((_, key)) = Self::split_key(&storage_key) {
+                keys.push(key);
+            }
+        }
+        Ok(keys)
+    }
+
+    fn namespaces(&self) -> StateResult<Vec<String>> {
+        let mut namespaces: Vec<String> = Vec::new();
+        for item in self.db.iter() {
+            let (storage_key, _) = item?;
+            if let Some((namespace, _)) = Self::split_key(&storage_key) {
+                // keys are ordered, so the keys of a namespace are adjacent
+                if namespaces.last() != Some(&namespace) {
+                    namespaces.push(namespace);
+                }
+            }
+        }
+        Ok(namespaces)
+    }
+
+    fn write_batch(&self, batch: StateBatch) -> StateResult<()> {
+        let mut sled_batch = sled::Batch::default();
+        for operation in batch.into_operations() {
+            match operation {
+                BatchOperation::Put {
+                    namespace,
+                    key,
+                    value,
+                } => sled_batch.insert(
+                    Self::storage_key(&namespace, &key),
+                    serde_json::to_vec(&value)?,
+                ),
+                BatchOperation::Delete { namespace, key } => {
+                    sled_batch.remove(Self::storage_key(&namespace, &key))
+                }
+            }
+        }
+        self.db.apply_batch(sled_batch)?;
+        self.db.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let backend = SledBackend::open(dir.path(), "bridge").unwrap();
+            backend.put("", "root", json!("value")).unwrap();
+            backend.put("scan", "current", json!(1)).unwrap();
+            backend.put("scan", "planned", json!(5)).unwrap();
+
+            let mut batch = StateBatch::default();
+            batch.put("scan", "current", json!(5));
+            batch.delete("scan", "planned");
+            batch.put("scan.darwinia", "running", json!(true));
+            backend.write_batch(batch).unwrap();
+        }
+
+        // reopen the store
+        let backend = SledBackend::open(dir.path(), "bridge").unwrap();
+        assert_eq!(backend.get("", "root").unwrap(), Some(json!("value")));
+        assert_eq!(backend.get("scan", "current").unwrap(), Some(json!(5)));
+        assert_eq!(backend.get("scan", "planned").unwrap(), None);
+        assert_eq!(backend.keys("scan").unwrap(), vec!["current".to_string()]);
+        assert_eq!(
+            backend.namespaces().unwrap(),
+            vec![
+                "".to_string(),
+                "scan".to_string(),
+                "scan.darwinia".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_open_locked() {
+        let dir = tempfile::tempdir().unwrap();
+        let _backend = SledBackend::open(dir.path(), "bridge").unwrap();
+        assert!(matches!(
+            SledBackend::open(dir.path(), "bridge"),
+            Err(StateComponentError::Locked(_))
+        ));
+    }
+}
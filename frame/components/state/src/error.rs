@@ -1,8 +1,34 @@
 use thiserror::Error as ThisError;
 
-/// Ethereum component error
+/// State component error
 #[derive(ThisError, Debug)]
 pub enum StateComponentError {
     #[error("Microkv error: {0}")]
     Microkv(String),
+
+    #[error("Sled error: {0}")]
+    Sled(String),
+
+    #[error("The state {0} is locked by a running bridge, stop it first")]
+    Locked(String),
+
+    #[error("Serde error: {0}")]
+    Serde(String),
+
+    #[error("Migration error: {0}")]
+    Migration(String),
 }
+
+impl From<sled::Error> for StateComponentError {
+    fn from(e: sled::Error) -> Self {
+        Self::Sled(format!("{e:?}"))
+    }
+}
+
+impl From<serde_json::Error> for StateComponentError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serde(format!("{e:?}"))
+    }
+}
+
+pub type StateResult<T> = Result<T, StateComponentError>;
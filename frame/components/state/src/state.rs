@@ -1,23 +1,35 @@
 use std::fmt::{Debug, Formatter};
+use std::path::Path;
+use std::sync::Arc;
 
-use microkv::namespace::NamespaceMicroKV;
-use microkv::MicroKV;
+use serde::Serialize;
+use serde_json::Value;
 
 use support_types::constants;
 
-use crate::config::MicrokvConfig;
+use crate::backend::{self, StateBackend, StateBackendKind, StateBatch};
+use crate::error::{StateComponentError, StateResult};
+
+/// Suffix of the db name of the store written by migration, it's renamed to the target store
+/// once all keys are copied
+const MIGRATING_SUFFIX: &str = ".migrating";
 
 /// Bridge state options
 #[derive(Clone, Debug, Default)]
 pub struct StateOptions {
     /// Kv database store file name
     pub db_name: String,
+    /// Backend of state, detected by the existing store if not set, see
+    /// [`StateBackendKind::detect`], microkv is the default. The sled store is locked by the
+    /// running bridge, other processes fail to open it with [`StateComponentError::Locked`]
+    pub backend: Option<StateBackendKind>,
 }
 
 /// Bridger state
 #[derive(Clone)]
 pub struct BridgeState {
-    microkv: MicroKV,
+    kind: StateBackendKind,
+    backend: Arc<dyn StateBackend>,
 }
 
 lifeline::impl_storage_clone!(BridgeState);
@@ -25,38 +37,276 @@ lifeline::impl_storage_clone!(BridgeState);
 impl BridgeState {
     pub fn new(options: StateOptions) -> color_eyre::Result<Self> {
         let base_path = constants::bridger_home();
-        let config_microkv = MicrokvConfig {
-            base_path,
-            db_name: Some(options.db_name),
-            auto_commit: true,
-        };
-        let db_name = config_microkv
-            .db_name
-            .clone()
-            .unwrap_or_else(|| "database".to_string());
-        let store_path = &config_microkv.base_path.join(format!("{db_name}.kv"));
-        tracing::debug!(
-            target: "component-state",
-            "KVDB PATH: {} and the auto_commit is opened",
-            store_path.display()
-        );
-        let microkv = crate::microkv::microkv_instance(&config_microkv)?;
-        Ok(Self { microkv })
+        let kind = options
+            .backend
+            .or_else(|| StateBackendKind::detect(&base_path, &options.db_name))
+            .unwrap_or(StateBackendKind::Microkv);
+        if let Some(store_path) = kind.store_path(&base_path, &options.db_name) {
+            tracing::debug!(
+                target: "component-state",
+                "KVDB PATH: {} and the backend is {:?}",
+                store_path.display(),
+                kind,
+            );
+        }
+        let backend = kind.open(&base_path, &options.db_name)?;
+        Ok(Self {
+            kind,
+            backend: Arc::from(backend),
+        })
+    }
+
+    /// Copy the detected store to the backend of `to`, then the source store is renamed with
+    /// the suffix `.migrated` so that the migrated one is used. Returns the source backend and
+    /// the count of copied keys.
+    pub fn migrate(
+        options: StateOptions,
+        to: StateBackendKind,
+    ) -> color_eyre::Result<(StateBackendKind, usize)> {
+        migrate_in(&constants::bridger_home(), &options.db_name, to)
+    }
+
+    /// State with the backend, e.g. [`crate::backend::MemoryBackend`] for tests
+    pub fn with_backend(kind: StateBackendKind, backend: impl StateBackend + 'static) -> Self {
+        Self {
+            kind,
+            backend: Arc::new(backend),
+        }
     }
 }
 
 impl BridgeState {
-    pub fn microkv(&self) -> &MicroKV {
-        &self.microkv
+    pub fn kind(&self) -> StateBackendKind {
+        self.kind
+    }
+
+    pub fn backend(&self) -> &dyn StateBackend {
+        self.backend.as_ref()
     }
 
-    pub fn microkv_with_namespace(&self, namespace: impl AsRef<str>) -> NamespaceMicroKV {
-        self.microkv.namespace(namespace)
+    pub fn namespaces(&self) -> StateResult<Vec<String>> {
+        self.backend.namespaces()
+    }
+
+    pub fn namespace(&self, namespace: impl AsRef<str>) -> NamespaceState {
+        NamespaceState {
+            backend: self.backend.clone(),
+            namespace: namespace.as_ref().to_string(),
+        }
+    }
+}
+
+/// Migrate the store in base path. The keys are copied to a temp store which is renamed to the
+/// target store on success, so a failed migration never leaves a partial target store behind
+fn migrate_in(
+    base_path: &Path,
+    db_name: &str,
+    to: StateBackendKind,
+) -> color_eyre::Result<(StateBackendKind, usize)> {
+    let from = StateBackendKind::detect(base_path, db_name).ok_or_else(|| {
+        StateComponentError::Migration(format!("No state of {db_name} to migrate"))
+    })?;
+    if from == to {
+        return Err(StateComponentError::Migration(format!(
+            "The state of {db_name} is already stored by {from:?}"
+        ))
+        .into());
+    }
+    let temp_name = format!("{db_name}{MIGRATING_SUFFIX}");
+    let (source_path, target_path, temp_path) = match (
+        from.store_path(base_path, db_name),
+        to.store_path(base_path, db_name),
+        to.store_path(base_path, &temp_name),
+    ) {
+        (Some(source_path), Some(target_path), Some(temp_path)) => {
+            (source_path, target_path, temp_path)
+        }
+        _ => {
+            return Err(StateComponentError::Migration(format!(
+                "Can not migrate state from {from:?} to {to:?}"
+            ))
+            .into())
+        }
+    };
+    if target_path.exists() {
+        return Err(StateComponentError::Migration(format!(
+            "The target store {} already exists",
+            target_path.display()
+        ))
+        .into());
+    }
+
+    // left by a failed migration
+    remove_store(&temp_path)?;
+    let copied = from.open(base_path, db_name).and_then(|source| {
+        let target = to.open(base_path, &temp_name)?;
+        Ok(backend::migrate(source.as_ref(), target.as_ref())?)
+    });
+    let count = match copied {
+        Ok(v) => v,
+        Err(e) => {
+            if let Err(e) = remove_store(&temp_path) {
+                tracing::warn!(
+                    target: "component-state",
+                    "Failed to remove the temp store {}: {:?}",
+                    temp_path.display(),
+                    e,
+                );
+            }
+            return Err(e);
+        }
+    };
+    std::fs::rename(&temp_path, &target_path)?;
+    let mut migrated_path = source_path.clone().into_os_string();
+    migrated_path.push(".migrated");
+    std::fs::rename(&source_path, &migrated_path)?;
+    tracing::info!(
+        target: "component-state",
+        "Migrated {} keys of {} from {:?} to {:?}",
+        count,
+        db_name,
+        from,
+        to,
+    );
+    Ok((from, count))
+}
+
+/// Remove the store, a file of microkv or a directory of sled
+fn remove_store(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else if path.exists() {
+        std::fs::remove_file(path)
+    } else {
+        Ok(())
     }
 }
 
 impl Debug for BridgeState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("BridgeState { microkv: <...> }")
+        write!(f, "BridgeState {{ backend: {:?} }}", self.kind)
+    }
+}
+
+/// State of a namespace
+#[derive(Clone)]
+pub struct NamespaceState {
+    backend: Arc<dyn StateBackend>,
+    namespace: String,
+}
+
+impl NamespaceState {
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn get(&self, key: impl AsRef<str>) -> StateResult<Option<Value>> {
+        self.backend.get(&self.namespace, key.as_ref())
+    }
+
+    pub fn put(&self, key: impl AsRef<str>, value: &impl Serialize) -> StateResult<()> {
+        let value = serde_json::to_value(value)?;
+        self.backend.put(&self.namespace, key.as_ref(), value)
+    }
+
+    pub fn delete(&self, key: impl AsRef<str>) -> StateResult<()> {
+        self.backend.delete(&self.namespace, key.as_ref())
+    }
+
+    pub fn keys(&self) -> StateResult<Vec<String>> {
+        self.backend.keys(&self.namespace)
+    }
+
+    pub fn sorted_keys(&self) -> StateResult<Vec<String>> {
+        let mut keys = self.keys()?;
+        keys.sort();
+        Ok(keys)
+    }
+
+    /// Start a batch of this namespace, it's written by [`NamespaceBatch::commit`]
+    pub fn batch(&self) -> NamespaceBatch {
+        NamespaceBatch {
+            state: self.clone(),
+            batch: StateBatch::default(),
+        }
+    }
+}
+
+impl Debug for NamespaceState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NamespaceState {{ namespace: {} }}", self.namespace)
+    }
+}
+
+/// Writes of a namespace which are persisted atomically
+pub struct NamespaceBatch {
+    state: NamespaceState,
+    batch: StateBatch,
+}
+
+impl NamespaceBatch {
+    pub fn put(mut self, key: impl AsRef<str>, value: &impl Serialize) -> StateResult<Self> {
+        let value = serde_json::to_value(value)?;
+        self.batch.put(&self.state.namespace, key, value);
+        Ok(self)
+    }
+
+    pub fn delete(mut self, key: impl AsRef<str>) -> Self {
+        self.batch.delete(&self.state.namespace, key);
+        self
+    }
+
+    pub fn commit(self) -> StateResult<()> {
+        self.state.backend.write_batch(self.batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_migrate_to_sled() {
+        let dir = tempfile::tempdir().unwrap();
+        let base_path = dir.path();
+        {
+            let source = StateBackendKind::Microkv.open(base_path, "bridge").unwrap();
+            source.put("", "root", json!("value")).unwrap();
+            source.put("darwinia-ethereum", "a", json!(1)).unwrap();
+            source
+                .put("darwinia-ethereum", "b", json!({"x": 1}))
+                .unwrap();
+        }
+
+        let (from, count) = migrate_in(base_path, "bridge", StateBackendKind::Sled).unwrap();
+        assert_eq!(from, StateBackendKind::Microkv);
+        assert_eq!(count, 3);
+        assert!(!base_path.join("bridge.kv").exists());
+        assert!(base_path.join("bridge.kv.migrated").exists());
+        assert!(!base_path.join("bridge.migrating.sled").exists());
+        assert_eq!(
+            StateBackendKind::detect(base_path, "bridge"),
+            Some(StateBackendKind::Sled)
+        );
+
+        let target = StateBackendKind::Sled.open(base_path, "bridge").unwrap();
+        assert_eq!(target.get("", "root").unwrap(), Some(json!("value")));
+        assert_eq!(
+            target.get("darwinia-ethereum", "b").unwrap(),
+            Some(json!({"x": 1}))
+        );
+    }
+
+    #[test]
+    fn test_migrate_refused() {
+        let dir = tempfile::tempdir().unwrap();
+        let base_path = dir.path();
+        assert!(migrate_in(base_path, "bridge", StateBackendKind::Sled).is_err());
+
+        StateBackendKind::Sled.open(base_path, "bridge").unwrap();
+        assert!(migrate_in(base_path, "bridge", StateBackendKind::Sled).is_err());
+        assert!(migrate_in(base_path, "bridge", StateBackendKind::Memory).is_err());
     }
 }
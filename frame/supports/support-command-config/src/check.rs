@@ -0,0 +1,67 @@
+use std::fmt::Debug;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use support_keystore::Keystore;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Check the key of config can be decoded. `keystore:<id>` is decrypted when the keystore
+/// password is provided, otherwise only checks the key exists in keystore
+pub fn check_key<E: Debug>(
+    value: &str,
+    decode: impl FnOnce(&str) -> Result<(), E>,
+) -> Result<(), String> {
+    if let Some(id) = value.strip_prefix(support_keystore::KEY_REFERENCE_PREFIX) {
+        if !support_keystore::password::is_provided() {
+            Keystore::default()
+                .get(id.trim())
+                .map_err(|e| format!("{e:?}"))?;
+            return Ok(());
+        }
+    }
+    let value = support_keystore::resolve(value).map_err(|e| format!("{e:?}"))?;
+    decode(&value).map_err(|e| format!("{e:?}"))
+}
+
+/// Check the endpoint is reachable by connecting its host
+pub fn check_endpoint(endpoint: &str) -> Result<(), String> {
+    let url = url::Url::parse(endpoint).map_err(|e| format!("Wrong url: {e}"))?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| "Missing host of url".to_string())?;
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| format!("Unknown port of scheme {}", url.scheme()))?;
+    let addrs = (host, port)
+        .to_socket_addrs()
+        .map_err(|e| format!("Can not resolve {host}: {e}"))?;
+    let mut last_error = None;
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT) {
+            Ok(_) => return Ok(()),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(match last_error {
+        Some(e) => format!("Can not connect {host}:{port}: {e}"),
+        None => format!("Not found address of {host}"),
+    })
+}
+
+/// Endpoint and backup endpoints of a section, keyed by `<section>.endpoint` and
+/// `<section>.backup_endpoints.<index>`
+pub fn section_endpoints(
+    section: &str,
+    endpoint: &str,
+    backup_endpoints: &[String],
+) -> Vec<(String, String)> {
+    let mut endpoints = vec![(format!("{section}.endpoint"), endpoint.to_string())];
+    for (ix, backup_endpoint) in backup_endpoints.iter().enumerate() {
+        endpoints.push((
+            format!("{section}.backup_endpoints.{ix}"),
+            backup_endpoint.clone(),
+        ));
+    }
+    endpoints
+}
@@ -0,0 +1,7 @@
+pub use self::check::*;
+pub use self::handler::*;
+pub use self::types::*;
+
+mod check;
+mod handler;
+mod types;
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+use support_terminal::output::OutputFormat;
+
+/// Config options
+#[derive(Clone, Debug, Deserialize, Serialize, StructOpt)]
+pub enum ConfigOpts {
+    /// Write the commented config template to bridger home
+    Init {
+        /// Replace the existing config
+        #[structopt(long)]
+        force: bool,
+    },
+    /// Validate config, check types, keys and endpoints
+    Validate {
+        /// Don't check the endpoints are reachable
+        #[structopt(long)]
+        offline: bool,
+    },
+    /// Show config merged with `--config` file and env vars, the secrets are redacted
+    Show {
+        /// Output mode, support raw|json
+        #[structopt(short, long, default_value = "raw")]
+        output: OutputFormat,
+    },
+    /// Set a value of config, the config is validated before written
+    Set {
+        /// Dotted key, e.g. `darwinia.endpoint`
+        #[structopt()]
+        key: String,
+        /// Value, numbers, booleans and arrays are parsed, otherwise it's a string
+        #[structopt()]
+        value: String,
+    },
+}
+
+/// Checks of bridge config besides the types
+pub trait ConfigCheck {
+    /// Endpoints to check reachability, the pairs of dotted key and url
+    fn endpoints(&self) -> Vec<(String, String)>;
+
+    /// Decode the keys, the pairs of dotted key and result, see [`crate::check_key`]
+    fn check_keys(&self) -> Vec<(String, Result<(), String>)>;
+}
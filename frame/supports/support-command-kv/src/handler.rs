@@ -1,50 +1,83 @@
 use std::collections::HashMap;
 
-use microkv::namespace::NamespaceMicroKV;
-use microkv::MicroKV;
 use serde_json::Value;
 use term_table::row::Row;
 use term_table::table_cell::{Alignment, TableCell};
 use term_table::{Table, TableStyle};
 
-use component_state::state::{BridgeState, StateOptions};
+use component_state::backend::StateBackendKind;
+use component_state::state::{BridgeState, NamespaceState, StateOptions};
 use support_common::error::BridgerError;
 use support_terminal::output;
 use support_terminal::output::OutputFormat;
 
+use crate::schema::{KvSchema, KvValueType};
 use crate::types::KvOpts;
 
-/// Handle kv command
+/// Handle kv command, the schema is of the bridge namespace, it's none for other namespaces
 pub fn handle_kv(
     state_option: StateOptions,
     namespace: Option<String>,
+    schema: Option<&KvSchema>,
     opt: KvOpts,
 ) -> color_eyre::Result<()> {
     let namespace = namespace.unwrap_or_default();
-    let state = BridgeState::new(state_option)?;
-    let microkv = state.microkv_with_namespace(&namespace);
     match opt {
-        KvOpts::Namespaces => handle_namespaces(state.microkv()),
-        KvOpts::Put { kvs } => handle_put(&microkv, kvs, &namespace),
+        // migrated before opening the state, the store is renamed after migration
+        KvOpts::Migrate { to } => handle_migrate(state_option, to),
+        KvOpts::Namespaces => handle_namespaces(&BridgeState::new(state_option)?),
+        KvOpts::Put { kvs, force } => handle_put(
+            &namespace_state(state_option, &namespace)?,
+            schema,
+            kvs,
+            force,
+        ),
+        KvOpts::Describe { output } => {
+            handle_describe(&namespace_state(state_option, &namespace)?, schema, output)
+        }
         KvOpts::Get {
             keys,
             output,
             include_key,
-        } => handle_get(&microkv, keys, output, include_key),
-        KvOpts::Keys { sorted } => handle_keys(&microkv, sorted),
-        KvOpts::Remove { keys } => handle_remove(&microkv, keys),
+        } => handle_get(
+            &namespace_state(state_option, &namespace)?,
+            keys,
+            output,
+            include_key,
+        ),
+        KvOpts::Keys { sorted } => handle_keys(&namespace_state(state_option, &namespace)?, sorted),
+        KvOpts::Remove { keys } => handle_remove(&namespace_state(state_option, &namespace)?, keys),
     }
 }
 
-fn handle_namespaces(microkv: &MicroKV) -> color_eyre::Result<()> {
-    let namespaces = microkv.namespaces()?;
+fn namespace_state(
+    state_option: StateOptions,
+    namespace: &str,
+) -> color_eyre::Result<NamespaceState> {
+    Ok(BridgeState::new(state_option)?.namespace(namespace))
+}
+
+fn handle_migrate(state_option: StateOptions, to: StateBackendKind) -> color_eyre::Result<()> {
+    let (from, count) = BridgeState::migrate(state_option, to)?;
+    output::output_text(format!("Migrated {count} keys from {from:?} to {to:?}"));
+    output::output_ok();
+    Ok(())
+}
+
+fn handle_namespaces(state: &BridgeState) -> color_eyre::Result<()> {
+    let namespaces = state.namespaces()?;
     for ns in namespaces {
         output::output_text(ns);
     }
     Ok(())
 }
 
-fn handle_put(microkv: &NamespaceMicroKV, kvs: Vec<String>, namespace: &str) -> color_eyre::Result<()> {
+fn handle_put(
+    state: &NamespaceState,
+    schema: Option<&KvSchema>,
+    kvs: Vec<String>,
+    force: bool,
+) -> color_eyre::Result<()> {
     let mut keys = Vec::new();
     let mut values = Vec::new();
     for (ix, value) in kvs.iter().enumerate() {
@@ -58,41 +91,104 @@ fn handle_put(microkv: &NamespaceMicroKV, kvs: Vec<String>, namespace: &str) ->
         output::output_err_and_exit("The Key-Value length not same");
     }
 
-    let len = keys.len();
-    for i in 0..len {
-        let key = keys.get(i).unwrap();
-        let value = values.get(i).unwrap();
+    // check all values before writing, the values are written at once
+    let mut batch = state.batch();
+    for (key, value) in keys.iter().zip(values.iter()) {
         if key.is_empty() {
             continue;
         }
-        let value_type: Option<String> = try_get_value_type(namespace, key);
-        spec_serialize_value(microkv, key, value, value_type)?;
+        let value = match schema {
+            Some(schema) => {
+                if force && schema.find(key).is_none() {
+                    output::output_warning(format!("Unknown key {key}, store the value as string"));
+                }
+                schema.parse(key, value, force)?
+            }
+            // the keys of other namespaces aren't documented
+            None => KvValueType::String.parse(value)?,
+        };
+        batch = batch.put(key, &value)?;
     }
+    batch.commit()?;
     output::output_ok();
     Ok(())
 }
 
-fn try_get_value_type(namespace: &str, key: &str) -> Option<String> {
-    let schema = include_str!("schema.toml");
-    if let toml::Value::Table(table) = schema.parse::<toml::Value>().unwrap() {
-        if let Some(toml::Value::Table(ns)) = table.get(namespace) {
-            return ns
-                .get(key)
-                .map(|value| value.as_str().unwrap().to_owned() )
+fn handle_describe(
+    state: &NamespaceState,
+    schema: Option<&KvSchema>,
+    output_format: OutputFormat,
+) -> color_eyre::Result<()> {
+    let schema = schema.ok_or_else(|| {
+        BridgerError::Custom(format!(
+            "The keys of namespace {} aren't documented, only the bridge namespace is",
+            state.namespace()
+        ))
+    })?;
+    let stored_keys = state.sorted_keys()?;
+    let mut rows = Vec::new();
+    for kv_key in schema.keys() {
+        let keys = if kv_key.pattern.contains('*') {
+            stored_keys
+                .iter()
+                .filter(|key| kv_key.matches(key))
+                .cloned()
+                .collect::<Vec<String>>()
+        } else {
+            vec![kv_key.pattern.to_string()]
+        };
+        if keys.is_empty() {
+            rows.push((kv_key, kv_key.pattern.to_string(), None));
+        }
+        for key in keys {
+            let value = state.get(&key)?;
+            rows.push((kv_key, key, value));
         }
     }
-    None
+
+    match output_format {
+        OutputFormat::Json => {
+            let values = rows
+                .iter()
+                .map(|(kv_key, key, value)| {
+                    serde_json::json!({
+                        "key": key,
+                        "type": kv_key.value_type.to_string(),
+                        "description": kv_key.description,
+                        "value": value,
+                    })
+                })
+                .collect::<Vec<Value>>();
+            output::output_text(serde_json::to_string_pretty(&values)?);
+        }
+        OutputFormat::Raw | OutputFormat::Table => {
+            let mut table = Table::new();
+            table.max_column_width = 60;
+            table.style = TableStyle::simple();
+            for (kv_key, key, value) in rows {
+                let view = best_view_option(&value)?;
+                table.add_row(Row::new(vec![
+                    TableCell::new_with_alignment(key, 1, Alignment::Left),
+                    TableCell::new_with_alignment(kv_key.value_type, 1, Alignment::Left),
+                    TableCell::new_with_alignment(view, 1, Alignment::Left),
+                    TableCell::new_with_alignment(kv_key.description, 1, Alignment::Left),
+                ]));
+            }
+            output::output_text(table.render());
+        }
+    }
+    Ok(())
 }
 
 fn handle_get(
-    microkv: &NamespaceMicroKV,
+    state: &NamespaceState,
     keys: Vec<String>,
     output_format: OutputFormat,
     include_key: bool,
 ) -> color_eyre::Result<()> {
     let mut kvs = Vec::new();
     for key in keys {
-        let value = microkv.get(&key)?;
+        let value = state.get(&key)?;
         kvs.push((key, value));
     }
 
@@ -155,73 +251,24 @@ fn handle_get(
     Ok(())
 }
 
-fn handle_keys(microkv: &NamespaceMicroKV, sorted: bool) -> color_eyre::Result<()> {
+fn handle_keys(state: &NamespaceState, sorted: bool) -> color_eyre::Result<()> {
     let keys = if sorted {
-        microkv.sorted_keys()?
+        state.sorted_keys()?
     } else {
-        microkv.keys()?
+        state.keys()?
     };
     keys.iter().for_each(output::output_text);
     Ok(())
 }
 
-fn handle_remove(microkv: &NamespaceMicroKV, keys: Vec<String>) -> color_eyre::Result<()> {
+fn handle_remove(state: &NamespaceState, keys: Vec<String>) -> color_eyre::Result<()> {
     for key in keys {
-        microkv.delete(key)?;
+        state.delete(key)?;
     }
     output::output_ok();
     Ok(())
 }
 
-// kv serialize value.
-// todo: there need change to schema https://github.com/darwinia-network/bridger/issues/377
-fn spec_serialize_value(
-    microkv: &NamespaceMicroKV,
-    key: impl AsRef<str>,
-    value: impl AsRef<str>,
-    value_type: Option<impl AsRef<str>>
-) -> color_eyre::Result<()> {
-    let key = key.as_ref();
-    let value = value.as_ref();
-
-    let value = value.trim().to_string();
-    let value_type = match value_type {
-        None => {
-            output::output_warning(format!("Schema of '{key}' not found, use String as default"));
-            "String".to_owned()
-        },
-        Some(v) => v.as_ref().to_owned()
-    };
-
-    match value_type.as_ref() {
-        "String" | "string" | "str" => {
-            microkv.put(key, &value)?;
-        }
-        "isize" | "i8" | "i16" | "i32" | "i64" | "i128" => {
-            let value = value.parse::<isize>()?;
-            microkv.put(key, &value)?;
-        }
-        "usize" | "u8" | "u16" | "u32" | "u64" | "u128" => {
-            let value = value.parse::<usize>()?;
-            microkv.put(key, &value)?;
-        }
-        "f32" | "f64" => {
-            let value = value.parse::<f64>()?;
-            microkv.put(key, &value)?;
-        }
-        "bool" => {
-            let value = value.parse::<bool>()?;
-            microkv.put(key, &value)?;
-        }
-        _ => {
-            return Err(
-                BridgerError::Custom(format!("Not support value type: {value_type}")).into(),
-            );
-        }
-    }
-    Ok(())
-}
-
 fn best_view_option(value: &Option<Value>) -> color_eyre::Result<String> {
     match value {
         Some(v) => best_view(v),
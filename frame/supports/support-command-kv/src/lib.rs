@@ -1,5 +1,7 @@
 pub use self::handler::*;
+pub use self::schema::*;
 pub use self::types::*;
 
 mod handler;
+mod schema;
 mod types;
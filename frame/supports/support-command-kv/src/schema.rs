@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+
+use serde_json::Value;
+
+use support_common::error::BridgerError;
+
+/// Type of a kv value, the text of `kv put` is parsed by it
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KvValueType {
+    String,
+    Bool,
+    Unsigned,
+    Signed,
+    Float,
+    /// Json text, e.g. a record of journal
+    Json,
+}
+
+impl KvValueType {
+    /// Parse text to the value of this type
+    pub fn parse(&self, text: impl AsRef<str>) -> color_eyre::Result<Value> {
+        let text = text.as_ref().trim();
+        let value = match self {
+            Self::String => Value::from(text),
+            Self::Bool => Value::from(text.parse::<bool>()?),
+            Self::Unsigned => Value::from(text.parse::<u64>()?),
+            Self::Signed => Value::from(text.parse::<i64>()?),
+            Self::Float => Value::from(text.parse::<f64>()?),
+            Self::Json => serde_json::from_str(text)?,
+        };
+        Ok(value)
+    }
+}
+
+impl Display for KvValueType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::String => "string",
+            Self::Bool => "bool",
+            Self::Unsigned => "u64",
+            Self::Signed => "i64",
+            Self::Float => "f64",
+            Self::Json => "json",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A documented key, `*` of the pattern matches one segment between dots
+#[derive(Clone, Copy, Debug)]
+pub struct KvKey {
+    pub pattern: &'static str,
+    pub value_type: KvValueType,
+    pub description: &'static str,
+}
+
+impl KvKey {
+    pub const fn new(
+        pattern: &'static str,
+        value_type: KvValueType,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            pattern,
+            value_type,
+            description,
+        }
+    }
+
+    /// The key matches the pattern
+    pub fn matches(&self, key: &str) -> bool {
+        let mut segments = key.split('.');
+        let mut patterns = self.pattern.split('.');
+        loop {
+            match (patterns.next(), segments.next()) {
+                (None, None) => return true,
+                (Some("*"), Some(segment)) if !segment.is_empty() => {}
+                (Some(pattern), Some(segment)) if pattern == segment => {}
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// Kv schema registered by the bridge, keys of its namespace are checked by it
+#[derive(Clone, Copy, Debug)]
+pub struct KvSchema {
+    keys: &'static [KvKey],
+}
+
+impl KvSchema {
+    pub const fn new(keys: &'static [KvKey]) -> Self {
+        Self { keys }
+    }
+
+    pub fn keys(&self) -> &[KvKey] {
+        self.keys
+    }
+
+    /// Find the documented key
+    pub fn find(&self, key: impl AsRef<str>) -> Option<&KvKey> {
+        let key = key.as_ref();
+        self.keys.iter().find(|v| v.matches(key))
+    }
+
+    /// Parse the value of a key, the unknown key is refused unless it's forced, then the value
+    /// is stored as string
+    pub fn parse(
+        &self,
+        key: impl AsRef<str>,
+        value: impl AsRef<str>,
+        force: bool,
+    ) -> color_eyre::Result<Value> {
+        let key = key.as_ref();
+        let value = value.as_ref();
+        match self.find(key) {
+            Some(kv_key) => kv_key.value_type.parse(value).map_err(|e| {
+                BridgerError::Custom(format!(
+                    "The value of {key} should be {}: {e}",
+                    kv_key.value_type
+                ))
+                .into()
+            }),
+            None if force => Ok(KvValueType::String.parse(value)?),
+            None => Err(BridgerError::Custom(format!(
+                "Unknown key {key}, run `kv describe` for documented keys or use --force"
+            ))
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: KvSchema = KvSchema::new(&[
+        KvKey::new("scan.*.running", KvValueType::Bool, "Running"),
+        KvKey::new("scan.*.current", KvValueType::Unsigned, "Current"),
+    ]);
+
+    #[test]
+    fn test_matches() {
+        assert!(SCHEMA.find("scan.darwinia.running").is_some());
+        assert!(SCHEMA.find("scan.darwinia.runing").is_none());
+        assert!(SCHEMA.find("scan..running").is_none());
+        assert!(SCHEMA.find("scan.darwinia.collecting.running").is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            SCHEMA
+                .parse("scan.darwinia.current", " 10 ", false)
+                .unwrap(),
+            Value::from(10u64)
+        );
+        assert!(SCHEMA.parse("scan.darwinia.current", "-1", false).is_err());
+        assert!(SCHEMA.parse("scan.darwinia.runing", "true", false).is_err());
+        assert_eq!(
+            SCHEMA.parse("scan.darwinia.runing", "true", true).unwrap(),
+            Value::from("true")
+        );
+    }
+}
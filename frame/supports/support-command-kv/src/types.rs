@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
+use component_state::backend::StateBackendKind;
 use support_terminal::output::OutputFormat;
 
 /// Namespace kv options, the special namespace is allowed
@@ -19,11 +20,20 @@ pub struct NamespaceKvOpts {
 pub enum KvOpts {
     /// Show all namespaces
     Namespaces,
-    /// Put Key-Value to bridger database
+    /// Put Key-Value to bridger database, the values are checked by the kv schema of bridge
     Put {
         /// Keys and Values one by one
         #[structopt()]
         kvs: Vec<String>,
+        /// Put the keys which aren't in the schema, the values are stored as string
+        #[structopt(long)]
+        force: bool,
+    },
+    /// List documented keys of the kv schema with current values
+    Describe {
+        /// Output mode, support  raw|table|json
+        #[structopt(short, long, default_value = "table")]
+        output: OutputFormat,
     },
     /// Get Key-Value from bridger
     Get {
@@ -49,4 +59,11 @@ pub enum KvOpts {
         #[structopt()]
         keys: Vec<String>,
     },
+    /// Migrate the bridger database to another backend, the old store is kept with the
+    /// suffix `.migrated`
+    Migrate {
+        /// Target backend, support microkv|sled
+        #[structopt(long)]
+        to: StateBackendKind,
+    },
 }
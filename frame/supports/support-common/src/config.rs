@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use support_types::constants;
 
 use crate::error::BridgerError;
+use crate::overlay;
 
 /// The config names
 #[derive(Clone, Debug)]
@@ -41,6 +42,15 @@ impl Names {
             Self::BridgePangoroGoerli => "bridge-pangoro-goerli",
         }
     }
+
+    /// Prefix of env vars overriding the config, `BRIDGER` for bridger and
+    /// `BRIDGER_<BRIDGE>` for bridges, e.g. `BRIDGER_PANGOLIN_PANGORO`
+    pub fn env_prefix(&self) -> String {
+        match self.name().strip_prefix("bridge-") {
+            Some(bridge) => format!("BRIDGER_{}", bridge.replace('-', "_").to_uppercase()),
+            None => self.name().to_uppercase(),
+        }
+    }
 }
 
 /// Config helpers. store config to file or restore from file
@@ -97,9 +107,32 @@ impl Config {
         Self::new().persist(name.name(), config, Some(format))
     }
 
-    /// Restore config from file by name
+    /// Restore config by name, the file in `BRIDGER_HOME` is overlaid by the file of
+    /// `set_overlay_file` and the env vars `<PREFIX>__SECTION__KEY`, see `Names::env_prefix`.
+    /// An env var ends with `_FILE` is the path of a file containing the value.
     pub fn restore<T: DeserializeOwned>(name: Names) -> Result<T, BridgerError> {
-        Self::new().load(name.name())
+        Self::new().load(&name, true)
+    }
+
+    /// Restore config like `restore`, returns the dotted keys ignored by the config type as well
+    pub fn restore_with_unknown_keys<T: DeserializeOwned>(
+        name: Names,
+    ) -> Result<(T, Vec<String>), BridgerError> {
+        let mut unknown_keys = Vec::new();
+        let config = Self::new().load_with(&name, true, |c| {
+            serde_ignored::deserialize(c, |path| unknown_keys.push(path.to_string()))
+        })?;
+        Ok((config, unknown_keys))
+    }
+
+    /// Restore config from the file in `BRIDGER_HOME` only, used to edit the file
+    pub fn restore_file<T: DeserializeOwned>(name: Names) -> Result<T, BridgerError> {
+        Self::new().load(&name, false)
+    }
+
+    /// Set the config file merged over the config file in `BRIDGER_HOME`, e.g. from `--config`
+    pub fn set_overlay_file(path: impl Into<PathBuf>) {
+        overlay::set_overlay_file(path.into())
     }
 
     /// The config file is exists
@@ -109,6 +142,28 @@ impl Config {
             .unwrap_or_default()
             .is_some()
     }
+
+    /// The path and format of config file by name
+    pub fn file(name: Names) -> Result<Option<(PathBuf, ConfigFormat)>, BridgerError> {
+        Self::new().find_config_file(name.name())
+    }
+
+    /// Store the raw content as config file, e.g. a commented template. Returns the file path
+    pub fn store_raw(
+        name: Names,
+        content: impl AsRef<str>,
+        format: ConfigFormat,
+    ) -> Result<PathBuf, BridgerError> {
+        let config = Self::new();
+        if !config.base_path.exists() {
+            std::fs::create_dir_all(&config.base_path)?;
+        }
+        let path = config
+            .base_path
+            .join(format!("{}.{}", name.name(), format.extension()));
+        std::fs::write(&path, content.as_ref())?;
+        Ok(path)
+    }
 }
 
 impl Config {
@@ -200,8 +255,22 @@ impl Config {
         Ok(())
     }
 
-    fn load<T: DeserializeOwned>(&self, name: impl AsRef<str>) -> Result<T, BridgerError> {
-        if !self.base_path.exists() {
+    fn load<T: DeserializeOwned>(&self, name: &Names, overlay: bool) -> Result<T, BridgerError> {
+        self.load_with(name, overlay, |c| c.try_into::<T>())
+    }
+
+    fn load_with<T>(
+        &self,
+        name: &Names,
+        overlay: bool,
+        deserialize: impl FnOnce(config::Config) -> Result<T, config::ConfigError>,
+    ) -> Result<T, BridgerError> {
+        let overlay_file = if overlay {
+            overlay::overlay_file()
+        } else {
+            None
+        };
+        if !self.base_path.exists() && overlay_file.is_none() {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::NotFound,
                 format!("The config path {:?} not found", &self.base_path),
@@ -209,21 +278,39 @@ impl Config {
             .into());
         }
 
-        let (path, _) = self.find_config_file(name.as_ref())?.ok_or_else(|| {
-            BridgerError::Config(format!(
+        let path = self.find_config_file(name.name())?.map(|(path, _)| path);
+        if path.is_none() && overlay_file.is_none() {
+            return Err(BridgerError::Config(format!(
                 "Not found config file for name: {} in path: {}",
-                name.as_ref(),
+                name.name(),
                 self.base_path.display()
-            ))
-        })?;
+            )));
+        }
         let mut c = config::Config::default();
-        c.merge(config::File::from(path.clone()))?;
-        let tc = c.try_into::<T>().map_err(|e| {
+        if let Some(path) = &path {
+            c.merge(config::File::from(path.clone()))?;
+        }
+        if let Some(overlay_file) = &overlay_file {
+            if !overlay_file.is_file() {
+                return Err(BridgerError::Config(format!(
+                    "The config file {} not found",
+                    overlay_file.display()
+                )));
+            }
+            c.merge(config::File::from(overlay_file.clone()))?;
+        }
+        if overlay {
+            for (key, value) in overlay::env_overrides(&name.env_prefix())? {
+                c.set(&key, value)?;
+            }
+        }
+        let tc = deserialize(c).map_err(|e| {
             BridgerError::Config(format!(
-                "Failed to load config: {:?} in path: {:?} for name {}",
+                "Failed to load config: {:?} in path: {:?} (overlay: {:?}) for name {}",
                 e,
                 path,
-                name.as_ref()
+                overlay_file,
+                name.name()
             ))
         })?;
         Ok(tc)
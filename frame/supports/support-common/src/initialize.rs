@@ -49,6 +49,11 @@ fn init_log() -> color_eyre::Result<()> {
                     .unwrap_or_else(|_| EnvFilter::from(def_log_filter)),
             )
             .json()
+            // emit event fields at top level, and the fields recorded by the
+            // relay spans (bridge, lane, nonces, tx hash, ...) alongside them
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(true)
             .init();
         // tracing::subscriber::set_global_default(subscriber)
         //     .expect("setting default subscriber failed");
@@ -1,3 +1,6 @@
 pub mod config;
 pub mod error;
 pub mod initialize;
+pub mod serde_str;
+
+mod overlay;
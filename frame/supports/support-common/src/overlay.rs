@@ -0,0 +1,148 @@
+//! Overlays merged over the config file in `BRIDGER_HOME` before deserialization: the file
+//! given by `--config`, then the env vars named `<PREFIX>__SECTION__KEY`.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use crate::error::BridgerError;
+
+/// Separator of the prefix, sections and key in env var name
+const SEPARATOR: &str = "__";
+/// The env var with this suffix is the path of a file containing the value, e.g. a secret
+const FILE_SUFFIX: &str = "_FILE";
+
+static OVERLAY_FILE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+pub(crate) fn set_overlay_file(path: PathBuf) {
+    let mut data = OVERLAY_FILE.write().unwrap();
+    *data = Some(path);
+}
+
+pub(crate) fn overlay_file() -> Option<PathBuf> {
+    let data = OVERLAY_FILE.read().unwrap();
+    data.clone()
+}
+
+/// Config overrides from env vars, the key is the config path, e.g. `section.key`
+pub(crate) fn env_overrides(prefix: &str) -> Result<Vec<(String, String)>, BridgerError> {
+    overrides(prefix, std::env::vars())
+}
+
+fn overrides(
+    prefix: &str,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<Vec<(String, String)>, BridgerError> {
+    let prefix = format!("{prefix}{SEPARATOR}");
+    let mut values = BTreeMap::new();
+    for (var, value) in vars {
+        let name = match var.strip_prefix(&prefix) {
+            Some(v) if !v.is_empty() => v,
+            _ => continue,
+        };
+        let (name, value) = match name.strip_suffix(FILE_SUFFIX) {
+            Some(name) => {
+                let content = std::fs::read_to_string(&value).map_err(|e| {
+                    BridgerError::Config(format!("Can not read the file {value} of {var}: {e}"))
+                })?;
+                // secret files usually end with a newline
+                let content = content.trim_end_matches(&['\r', '\n'][..]).to_string();
+                (name, content)
+            }
+            None => (name, value),
+        };
+        let key = config_key(name)
+            .ok_or_else(|| BridgerError::Config(format!("Wrong config env var: {var}")))?;
+        if values.insert(key, value).is_some() {
+            return Err(BridgerError::Config(format!(
+                "Both {prefix}{name} and {prefix}{name}{FILE_SUFFIX} are set"
+            )));
+        }
+    }
+    Ok(values.into_iter().collect())
+}
+
+/// `SECTION__KEY` is `section.key`, and a number is the index of array, `LANES__0` is `lanes[0]`
+fn config_key(name: &str) -> Option<String> {
+    let mut key = String::new();
+    for segment in name.split(SEPARATOR) {
+        if segment.is_empty() {
+            return None;
+        }
+        if segment.chars().all(|c| c.is_ascii_digit()) {
+            if key.is_empty() {
+                return None;
+            }
+            key.push_str(&format!("[{segment}]"));
+        } else {
+            if !key.is_empty() {
+                key.push('.');
+            }
+            key.push_str(&segment.to_lowercase());
+        }
+    }
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_config_key() {
+        assert_eq!(
+            config_key("PANGOLIN__ENDPOINT").unwrap(),
+            "pangolin.endpoint"
+        );
+        assert_eq!(config_key("RELAY__LANES__0").unwrap(), "relay.lanes[0]");
+        assert_eq!(config_key("SIGNER").unwrap(), "signer");
+        assert!(config_key("0__KEY").is_none());
+        assert!(config_key("SECTION____KEY").is_none());
+    }
+
+    #[test]
+    fn test_overrides() {
+        let path = std::env::temp_dir().join("bridger-test-overlay-secret");
+        std::fs::write(&path, "0x1234\n").unwrap();
+        let values = overrides(
+            "BRIDGER_PANGOLIN_PANGORO",
+            vars(&[
+                ("BRIDGER_PANGOLIN_PANGORO__PANGOLIN__ENDPOINT", "wss://a"),
+                (
+                    "BRIDGER_PANGOLIN_PANGORO__PANGORO__SIGNER_FILE",
+                    path.to_str().unwrap(),
+                ),
+                ("BRIDGER_DARWINIA_CRAB__DARWINIA__ENDPOINT", "wss://b"),
+                ("BRIDGER_HOME", "/tmp"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(
+            values,
+            vars(&[
+                ("pangolin.endpoint", "wss://a"),
+                ("pangoro.signer", "0x1234"),
+            ])
+        );
+
+        let conflict = overrides(
+            "BRIDGER_PANGOLIN_PANGORO",
+            vars(&[
+                ("BRIDGER_PANGOLIN_PANGORO__PANGORO__SIGNER", "0x1"),
+                (
+                    "BRIDGER_PANGOLIN_PANGORO__PANGORO__SIGNER_FILE",
+                    path.to_str().unwrap(),
+                ),
+            ]),
+        );
+        assert!(conflict.is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
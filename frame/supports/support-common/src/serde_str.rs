@@ -0,0 +1,120 @@
+//! Numbers out of the integer range of toml, e.g. `u128` balances, are written as strings.
+//! Use it by `#[serde(default, with = "support_common::serde_str::option")]`, plain integers
+//! are still accepted when reading.
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserializer, Serializer};
+
+/// Optional number stored as string
+pub mod option {
+    use super::*;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.serialize_some(&v.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionVisitor(PhantomData))
+    }
+}
+
+struct OptionVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for OptionVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a number or a string of number")
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer
+            .deserialize_any(NumberVisitor(PhantomData))
+            .map(Some)
+    }
+}
+
+struct NumberVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for NumberVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a number or a string of number")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.trim().parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visit_str(&v.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    struct Balance {
+        #[serde(default, with = "super::option")]
+        reserve_balance: Option<u128>,
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let value: Balance =
+            toml::from_str(r#"reserve_balance = "100000000000000000000""#).unwrap();
+        assert_eq!(value.reserve_balance, Some(100_000_000_000_000_000_000));
+        let value: Balance = toml::from_str("reserve_balance = 1000").unwrap();
+        assert_eq!(value.reserve_balance, Some(1000));
+        let value: Balance = toml::from_str("").unwrap();
+        assert_eq!(value.reserve_balance, None);
+        assert!(toml::from_str::<Balance>(r#"reserve_balance = "-1""#).is_err());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let value = Balance {
+            reserve_balance: Some(100_000_000_000_000_000_000),
+        };
+        assert_eq!(
+            toml::to_string(&value).unwrap().trim(),
+            r#"reserve_balance = "100000000000000000000""#
+        );
+    }
+}
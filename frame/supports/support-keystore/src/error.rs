@@ -0,0 +1,30 @@
+#![allow(missing_docs)]
+
+use thiserror::Error as ThisError;
+
+pub type KeystoreResult<T> = Result<T, KeystoreError>;
+
+/// Error enum.
+#[derive(ThisError, Debug)]
+pub enum KeystoreError {
+    #[error("[custom] {0}")]
+    Custom(String),
+    #[error("Not found key {0} in keystore")]
+    NotFound(String),
+    #[error("Invalid key id {0}, only letters, digits, `_` and `-` are allowed")]
+    InvalidId(String),
+    #[error("The key {0} already exists in keystore")]
+    AlreadyExists(String),
+    #[error("Wrong password of key {0}")]
+    WrongPassword(String),
+    #[error("Invalid key file: {0}")]
+    InvalidKeyFile(String),
+    #[error("Unsupported key: {0}")]
+    Unsupported(String),
+    #[error("Missing keystore password, please set {0} or {1}")]
+    MissingPassword(&'static str, &'static str),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
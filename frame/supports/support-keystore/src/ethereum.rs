@@ -0,0 +1,42 @@
+//! Ethereum V3 keystore json
+
+use std::path::Path;
+
+use secp256k1::{PublicKey, Secp256k1, SecretKey};
+use tiny_keccak::{Hasher, Keccak};
+
+use crate::error::{KeystoreError, KeystoreResult};
+
+/// Encrypt secret key into `<dir>/<name>`, the address is kept in plaintext like geth does
+pub fn encrypt(dir: &Path, name: &str, secret: &[u8], password: &str) -> KeystoreResult<String> {
+    let address = address(secret)?;
+    let mut rng = rand::thread_rng();
+    eth_keystore::encrypt_key(dir, &mut rng, secret, password, Some(name))
+        .map_err(|e| KeystoreError::Custom(format!("Failed to encrypt key: {:?}", e)))?;
+
+    let path = dir.join(name);
+    let mut json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+    json["address"] = serde_json::Value::String(address.trim_start_matches("0x").to_string());
+    std::fs::write(&path, serde_json::to_string(&json)?)?;
+    Ok(address)
+}
+
+/// Decrypt secret key from keystore file
+pub fn decrypt(id: &str, path: &Path, password: &str) -> KeystoreResult<Vec<u8>> {
+    eth_keystore::decrypt_key(path, password).map_err(|e| match e {
+        eth_keystore::KeystoreError::MacMismatch => KeystoreError::WrongPassword(id.to_string()),
+        _ => KeystoreError::InvalidKeyFile(format!("{}: {:?}", path.display(), e)),
+    })
+}
+
+/// Ethereum address of the secret key
+pub fn address(secret: &[u8]) -> KeystoreResult<String> {
+    let secret = SecretKey::from_slice(secret)
+        .map_err(|e| KeystoreError::Unsupported(format!("Invalid secp256k1 key: {:?}", e)))?;
+    let public = PublicKey::from_secret_key(&Secp256k1::signing_only(), &secret);
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(&public.serialize_uncompressed()[1..]);
+    keccak.finalize(&mut hash);
+    Ok(array_bytes::bytes2hex("0x", &hash[12..]))
+}
@@ -0,0 +1,267 @@
+use std::path::{Path, PathBuf};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use support_types::constants;
+
+use crate::error::{KeystoreError, KeystoreResult};
+use crate::substrate::SubstrateKeyFile;
+use crate::{ethereum, password, substrate};
+
+/// Config values with this prefix reference a key in keystore, e.g. `keystore:relayer`
+pub const KEY_REFERENCE_PREFIX: &str = "keystore:";
+
+/// Key file format
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, strum::EnumString)]
+#[strum(serialize_all = "kebab_case")]
+pub enum KeyKind {
+    /// Ethereum V3 keystore json
+    Ethereum,
+    /// Password protected substrate key json
+    Substrate,
+}
+
+/// Key stored in keystore
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct KeyInfo {
+    pub id: String,
+    pub kind: KeyKind,
+    pub address: Option<String>,
+    pub path: PathBuf,
+}
+
+/// Encrypted keys stored in `BRIDGER_HOME/keystore`, one json file per key
+#[derive(Clone, Debug)]
+pub struct Keystore {
+    base_path: PathBuf,
+}
+
+impl Default for Keystore {
+    fn default() -> Self {
+        Self::new(constants::bridger_home().join("keystore"))
+    }
+}
+
+impl Keystore {
+    pub fn new(base_path: impl AsRef<Path>) -> Self {
+        Self {
+            base_path: base_path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// All keys in keystore
+    pub fn list(&self) -> KeystoreResult<Vec<KeyInfo>> {
+        if !self.base_path.exists() {
+            return Ok(vec![]);
+        }
+        let mut keys = vec![];
+        for entry in std::fs::read_dir(&self.base_path)? {
+            let path = entry?.path();
+            if path.extension().and_then(|v| v.to_str()) != Some("json") {
+                continue;
+            }
+            let id = match path.file_stem().and_then(|v| v.to_str()) {
+                Some(v) => v.to_string(),
+                None => continue,
+            };
+            match Self::key_info(id, path) {
+                Ok(v) => keys.push(v),
+                Err(e) => tracing::warn!(target: "keystore", "Skip invalid key file: {:?}", e),
+            }
+        }
+        keys.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(keys)
+    }
+
+    /// Get key info by id
+    pub fn get(&self, id: &str) -> KeystoreResult<KeyInfo> {
+        let path = self.key_path(id)?;
+        if !path.exists() {
+            return Err(KeystoreError::NotFound(id.to_string()));
+        }
+        Self::key_info(id.to_string(), path)
+    }
+
+    /// Encrypt the secp256k1 secret key as Ethereum V3 keystore json
+    pub fn import_secret(
+        &self,
+        id: &str,
+        secret: &[u8],
+        password: &str,
+    ) -> KeystoreResult<KeyInfo> {
+        self.ensure_absent(id)?;
+        ethereum::encrypt(&self.base_path, &format!("{}.json", id), secret, password)?;
+        self.get(id)
+    }
+
+    /// Import an existing keystore json, the password is checked before import
+    pub fn import_file(&self, id: &str, file: &Path, password: &str) -> KeystoreResult<KeyInfo> {
+        self.ensure_absent(id)?;
+        let key = Self::key_info(id.to_string(), file.to_path_buf())?;
+        Self::decrypt(&key, password)?;
+        std::fs::copy(file, self.key_path(id)?)?;
+        self.get(id)
+    }
+
+    /// Generate a new secp256k1 key
+    pub fn generate(&self, id: &str, password: &str) -> KeystoreResult<KeyInfo> {
+        let mut rng = rand::thread_rng();
+        let mut secret = [0u8; 32];
+        // out of the curve order is rare, draw again
+        loop {
+            rng.fill(&mut secret);
+            if ethereum::address(&secret).is_ok() {
+                break;
+            }
+        }
+        self.import_secret(id, &secret, password)
+    }
+
+    /// Decrypt the secret key
+    pub fn secret(&self, id: &str, password: &str) -> KeystoreResult<Vec<u8>> {
+        let key = self.get(id)?;
+        Self::decrypt(&key, password)
+    }
+}
+
+impl Keystore {
+    /// Ids are file names in keystore, only `[A-Za-z0-9_-]` is allowed
+    fn key_path(&self, id: &str) -> KeystoreResult<PathBuf> {
+        let valid = !id.is_empty()
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid {
+            return Err(KeystoreError::InvalidId(id.to_string()));
+        }
+        Ok(self.base_path.join(format!("{}.json", id)))
+    }
+
+    fn ensure_absent(&self, id: &str) -> KeystoreResult<()> {
+        if self.key_path(id)?.exists() {
+            return Err(KeystoreError::AlreadyExists(id.to_string()));
+        }
+        if !self.base_path.exists() {
+            std::fs::create_dir_all(&self.base_path)?;
+        }
+        Ok(())
+    }
+
+    fn key_info(id: String, path: PathBuf) -> KeystoreResult<KeyInfo> {
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        let kind = if json.get("crypto").is_some() || json.get("Crypto").is_some() {
+            KeyKind::Ethereum
+        } else if json.get("encoded").is_some() && json.get("encoding").is_some() {
+            KeyKind::Substrate
+        } else {
+            return Err(KeystoreError::InvalidKeyFile(format!("{}", path.display())));
+        };
+        let address = json.get("address").and_then(|v| v.as_str()).map(|v| {
+            if kind == KeyKind::Ethereum && !v.starts_with("0x") {
+                format!("0x{}", v)
+            } else {
+                v.to_string()
+            }
+        });
+        Ok(KeyInfo {
+            id,
+            kind,
+            address,
+            path,
+        })
+    }
+
+    fn decrypt(key: &KeyInfo, password: &str) -> KeystoreResult<Vec<u8>> {
+        match key.kind {
+            KeyKind::Ethereum => ethereum::decrypt(&key.id, &key.path, password),
+            KeyKind::Substrate => {
+                let file = SubstrateKeyFile::read(&key.path)?;
+                substrate::decrypt(&key.id, &file, password)
+            }
+        }
+    }
+}
+
+/// Ethereum address of the secret key
+pub fn address(secret: &[u8]) -> KeystoreResult<String> {
+    ethereum::address(secret)
+}
+
+/// Resolve a config value. A `keystore:<id>` reference is decrypted into a 0x prefixed
+/// hex private key with the password from env, other values are returned unchanged.
+pub fn resolve(value: &str) -> KeystoreResult<String> {
+    let id = match value.strip_prefix(KEY_REFERENCE_PREFIX) {
+        Some(v) => v.trim(),
+        None => return Ok(value.to_string()),
+    };
+    let secret = Keystore::default().secret(id, &password::password()?)?;
+    tracing::debug!(target: "keystore", "Loaded key {} from keystore", id);
+    Ok(array_bytes::bytes2hex("0x", &secret))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 32] = [
+        0x4c, 0x08, 0x83, 0xa6, 0x91, 0x02, 0x93, 0x7d, 0x62, 0x31, 0x47, 0x1b, 0x5d, 0xbb, 0x62,
+        0x04, 0xfe, 0x51, 0x29, 0x61, 0x70, 0x82, 0x79, 0x2a, 0xe4, 0x68, 0xd0, 0x1a, 0x3f, 0x36,
+        0x23, 0x18,
+    ];
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::new(dir.path());
+        let key = keystore
+            .import_secret("relayer", &SECRET, "password")
+            .unwrap();
+        assert_eq!(key.kind, KeyKind::Ethereum);
+        assert_eq!(key.address, Some(address(&SECRET).unwrap()));
+        assert_eq!(keystore.secret("relayer", "password").unwrap(), SECRET);
+        assert!(matches!(
+            keystore.import_secret("relayer", &SECRET, "password"),
+            Err(KeystoreError::AlreadyExists(_))
+        ));
+
+        let key = keystore.generate("generated", "password").unwrap();
+        let secret = keystore.secret("generated", "password").unwrap();
+        assert_eq!(key.address, Some(address(&secret).unwrap()));
+        assert_eq!(keystore.list().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_wrong_password() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::new(dir.path());
+        keystore
+            .import_secret("relayer", &SECRET, "password")
+            .unwrap();
+        assert!(matches!(
+            keystore.secret("relayer", "wrong"),
+            Err(KeystoreError::WrongPassword(_))
+        ));
+        assert!(matches!(
+            keystore.secret("missing", "password"),
+            Err(KeystoreError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::new(dir.path().join("keystore"));
+        for id in ["", "../relayer", "a/b", "a.b", "relayer key"] {
+            assert!(matches!(
+                keystore.import_secret(id, &SECRET, "password"),
+                Err(KeystoreError::InvalidId(_))
+            ));
+            assert!(matches!(keystore.get(id), Err(KeystoreError::InvalidId(_))));
+        }
+        assert!(!dir.path().join("relayer.json").exists());
+        keystore
+            .import_secret("relayer_1-a", &SECRET, "password")
+            .unwrap();
+    }
+}
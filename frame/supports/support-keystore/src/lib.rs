@@ -0,0 +1,8 @@
+pub use self::keystore::*;
+
+pub mod error;
+pub mod password;
+
+mod ethereum;
+mod keystore;
+mod substrate;
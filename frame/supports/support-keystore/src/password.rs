@@ -0,0 +1,30 @@
+use crate::error::{KeystoreError, KeystoreResult};
+
+/// Env var of the keystore password
+pub const ENV_KEYSTORE_PASSWORD: &str = "BRIDGER_KEYSTORE_PASSWORD";
+/// Env var of the path of a file containing the keystore password
+pub const ENV_KEYSTORE_PASSWORD_FILE: &str = "BRIDGER_KEYSTORE_PASSWORD_FILE";
+
+/// Read keystore password from env var, or from the password file
+pub fn password() -> KeystoreResult<String> {
+    if let Ok(password) = std::env::var(ENV_KEYSTORE_PASSWORD) {
+        return Ok(password);
+    }
+    match std::env::var(ENV_KEYSTORE_PASSWORD_FILE) {
+        Ok(path) => {
+            let content = std::fs::read_to_string(&path)?;
+            // password files usually end with a newline
+            Ok(content.trim_end_matches(&['\r', '\n'][..]).to_string())
+        }
+        Err(_) => Err(KeystoreError::MissingPassword(
+            ENV_KEYSTORE_PASSWORD,
+            ENV_KEYSTORE_PASSWORD_FILE,
+        )),
+    }
+}
+
+/// Whether a password is provided by env var or password file
+pub fn is_provided() -> bool {
+    std::env::var(ENV_KEYSTORE_PASSWORD).is_ok()
+        || std::env::var(ENV_KEYSTORE_PASSWORD_FILE).is_ok()
+}
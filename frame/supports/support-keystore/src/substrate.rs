@@ -0,0 +1,111 @@
+//! Password protected substrate key json, as exported by polkadot-js apps.
+//!
+//! Relayer keys are secp256k1, so only `ecdsa` and `ethereum` key pairs are supported.
+
+use std::path::Path;
+
+use serde::Deserialize;
+use crypto_secretbox::aead::generic_array::GenericArray;
+use crypto_secretbox::aead::{Aead, KeyInit};
+use crypto_secretbox::XSalsa20Poly1305;
+
+use crate::error::{KeystoreError, KeystoreResult};
+
+const PKCS8_HEADER: [u8; 16] = [48, 83, 2, 1, 1, 48, 5, 6, 3, 43, 101, 112, 4, 34, 4, 32];
+const PKCS8_DIVIDER: [u8; 5] = [161, 35, 3, 33, 0];
+const SALT_LENGTH: usize = 32;
+const SCRYPT_LENGTH: usize = SALT_LENGTH + 3 * 4;
+const NONCE_LENGTH: usize = 24;
+const KEY_LENGTH: usize = 32;
+const SECRET_LENGTH: usize = 32;
+
+#[derive(Debug, Deserialize)]
+pub struct SubstrateKeyFile {
+    pub encoded: String,
+    pub encoding: Encoding,
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Encoding {
+    pub content: Vec<String>,
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+}
+
+impl SubstrateKeyFile {
+    pub fn read(path: &Path) -> KeystoreResult<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+}
+
+/// Decrypt secret key from substrate key json
+pub fn decrypt(id: &str, file: &SubstrateKeyFile, password: &str) -> KeystoreResult<Vec<u8>> {
+    let types = &file.encoding.types;
+    if !types.iter().any(|v| v == "xsalsa20-poly1305") {
+        return Err(KeystoreError::Unsupported(format!(
+            "Unknown encoding {:?}",
+            types
+        )));
+    }
+    let encoded = base64::decode(&file.encoded)
+        .map_err(|e| KeystoreError::InvalidKeyFile(format!("{:?}", e)))?;
+
+    let mut key = [0u8; KEY_LENGTH];
+    let encrypted = if types.iter().any(|v| v == "scrypt") {
+        if encoded.len() < SCRYPT_LENGTH + NONCE_LENGTH {
+            return Err(KeystoreError::InvalidKeyFile(
+                "Encoded data too short".to_string(),
+            ));
+        }
+        let salt = &encoded[..SALT_LENGTH];
+        let n = read_u32(&encoded[SALT_LENGTH..SALT_LENGTH + 4]);
+        let p = read_u32(&encoded[SALT_LENGTH + 4..SALT_LENGTH + 8]);
+        let r = read_u32(&encoded[SALT_LENGTH + 8..SCRYPT_LENGTH]);
+        if !n.is_power_of_two() {
+            return Err(KeystoreError::InvalidKeyFile(format!(
+                "Invalid scrypt N {}",
+                n
+            )));
+        }
+        let params = scrypt::Params::new(n.trailing_zeros() as u8, r, p)
+            .map_err(|e| KeystoreError::InvalidKeyFile(format!("{:?}", e)))?;
+        scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+            .map_err(|e| KeystoreError::Custom(format!("{:?}", e)))?;
+        &encoded[SCRYPT_LENGTH..]
+    } else {
+        // legacy format, the password is used as key directly
+        let password = password.as_bytes();
+        let len = password.len().min(KEY_LENGTH);
+        key[..len].copy_from_slice(&password[..len]);
+        &encoded[..]
+    };
+    if encrypted.len() < NONCE_LENGTH {
+        return Err(KeystoreError::InvalidKeyFile(
+            "Encoded data too short".to_string(),
+        ));
+    }
+    let (nonce, ciphertext) = encrypted.split_at(NONCE_LENGTH);
+    let cipher = XSalsa20Poly1305::new(GenericArray::from_slice(&key));
+    let decrypted = cipher
+        .decrypt(GenericArray::from_slice(nonce), ciphertext)
+        .map_err(|_| KeystoreError::WrongPassword(id.to_string()))?;
+
+    let body = decrypted
+        .strip_prefix(&PKCS8_HEADER[..])
+        .ok_or_else(|| KeystoreError::InvalidKeyFile("Invalid pkcs8 header".to_string()))?;
+    // ecdsa secret is followed by the divider, sr25519/ed25519 secrets are longer
+    if body.get(SECRET_LENGTH..SECRET_LENGTH + PKCS8_DIVIDER.len()) != Some(&PKCS8_DIVIDER[..]) {
+        return Err(KeystoreError::Unsupported(
+            "Only ecdsa/ethereum substrate keys are supported".to_string(),
+        ));
+    }
+    let secret = &body[..SECRET_LENGTH];
+    Ok(secret.to_vec())
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    let mut buf = [0u8; 4];
+    buf.copy_from_slice(bytes);
+    u32::from_le_bytes(buf)
+}
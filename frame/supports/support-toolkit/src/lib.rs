@@ -1,7 +1,5 @@
 #[cfg(feature = "convert")]
 pub mod convert;
 pub mod error;
-#[cfg(feature = "log")]
-pub mod logk;
 pub mod timecount;
 pub mod url;
@@ -1,12 +1,13 @@
-use microkv::namespace::NamespaceMicroKV;
 use std::fmt::{Debug, Formatter};
 
+use component_state::state::NamespaceState;
+
 // discuss: https://github.com/darwinia-network/bridger/issues/284
 /// Tracker
 /// Block scan status
 #[derive(Clone)]
 pub struct Tracker {
-    microkv: NamespaceMicroKV,
+    state: NamespaceState,
     /// Current scan value, the next value is current+1
     key_current: String,
     /// Planned to execute, after to running the next value is planned+1
@@ -18,7 +19,7 @@ pub struct Tracker {
 impl Debug for Tracker {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str("Tracker {\n")?;
-        f.write_str("  microkv: ***,\n")?;
+        f.write_str("  state: ***,\n")?;
         f.write_str(&format!("  key_current: {}\n", self.key_current))?;
         f.write_str(&format!("  key_planned: {}\n", self.key_planned))?;
         f.write_str(&format!("  key_running: {}\n", self.key_running))?;
@@ -29,10 +30,10 @@ impl Debug for Tracker {
 
 impl Tracker {
     /// Create a new tracker, the key is prefix
-    pub fn new(microkv: NamespaceMicroKV, key: impl AsRef<str>) -> Self {
+    pub fn new(state: NamespaceState, key: impl AsRef<str>) -> Self {
         let key = key.as_ref();
         Self {
-            microkv,
+            state,
             key_current: format!("{key}.current"),
             key_planned: format!("{key}.planned"),
             key_running: format!("{key}.running"),
@@ -46,12 +47,12 @@ impl Tracker {
     }
 
     pub fn stop_running(&self) -> color_eyre::Result<()> {
-        self.microkv.put(&self.key_running, &false)?;
+        self.state.put(&self.key_running, &false)?;
         Ok(())
     }
 
     pub fn start_running(&self) -> color_eyre::Result<()> {
-        self.microkv.put(&self.key_running, &true)?;
+        self.state.put(&self.key_running, &true)?;
         Ok(())
     }
 
@@ -76,8 +77,12 @@ impl Tracker {
 
         match self.read_u64(&self.key_planned)? {
             Some(planned) => {
-                self.microkv.put(&self.key_current, &planned)?;
-                self.microkv.delete(&self.key_planned)?;
+                // move planned to current at once, a crash between them would scan again
+                self.state
+                    .batch()
+                    .put(&self.key_current, &planned)?
+                    .delete(&self.key_planned)
+                    .commit()?;
                 Ok(planned as usize)
             }
             None => {
@@ -95,12 +100,12 @@ impl Tracker {
 
     /// Update current
     pub fn finish(&self, block: usize) -> color_eyre::Result<()> {
-        self.microkv.put(&self.key_current, &block)?;
+        self.state.put(&self.key_current, &block)?;
         Ok(())
     }
 
     pub fn planned(&self, block: usize) -> color_eyre::Result<()> {
-        self.microkv.put(&self.key_planned, &block)?;
+        self.state.put(&self.key_planned, &block)?;
         Ok(())
     }
 }
@@ -109,7 +114,7 @@ impl Tracker {
     /// Read bool value by a key
     fn read_bool(&self, key: impl AsRef<str>) -> color_eyre::Result<bool> {
         let value = self
-            .microkv
+            .state
             .get(key.as_ref())?
             .unwrap_or(serde_json::Value::Bool(false));
         if value.is_boolean() {
@@ -123,7 +128,7 @@ impl Tracker {
     }
 
     fn read_u64(&self, key: impl AsRef<str>) -> color_eyre::Result<Option<u64>> {
-        let value = self.microkv.get(key.as_ref())?;
+        let value = self.state.get(key.as_ref())?;
         match value {
             Some(v) => {
                 if v.is_number() {
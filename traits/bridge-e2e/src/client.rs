@@ -4,18 +4,19 @@ use std::fmt::Debug;
 use client_contracts::inbound_types::MessageDispatched;
 use client_contracts::outbound_types::{ReceiveMessagesDeliveryProof, MessageAccepted};
 use client_contracts::BeaconLightClient;
+use client_contracts::{EvmSigner, FailoverHttp};
 use client_contracts::{inbound_types::ReceiveMessagesProof, Inbound, Outbound};
-use secp256k1::SecretKey;
 use subxt::Config;
 use support_etherscan::{EtherscanClient, Result as EtherscanResult};
 use web3::types::BlockNumber;
-use web3::{transports::Http, types::U256, Web3};
+use web3::{types::U256, Web3};
 
 use crate::error::E2EClientResult;
+use crate::types::EcdsaBlockEvents;
 
 pub trait Web3Client: Send + Sync {
     // Returns web3 client
-    fn get_web3(&self) -> &Web3<Http>;
+    fn get_web3(&self) -> &Web3<FailoverHttp>;
 }
 
 #[async_trait::async_trait]
@@ -48,7 +49,8 @@ pub trait GasPriceOracle: Web3Client {
 pub trait EthTruthLayerLightClient: GasPriceOracle {
     fn beacon_light_client(&self) -> &BeaconLightClient;
 
-    fn private_key(&self) -> &SecretKey;
+    // Signer of transactions
+    fn signer(&self) -> &EvmSigner;
 }
 
 #[async_trait::async_trait()]
@@ -72,6 +74,51 @@ pub trait EcdsaClient: Debug + Send + Sync + Clone + 'static {
         &self,
         signatures: Vec<u8>,
     ) -> E2EClientResult<<Self::SubxtConfig as Config>::Hash>;
+
+    // Returns state of your signature in the collecting round of the new message root
+    async fn new_message_root_signature_state(
+        &self,
+        message: &[u8],
+        your_address: &[u8; 20],
+    ) -> E2EClientResult<CollectingSignatureState>;
+
+    // Returns state of your signature in the collecting round of the authorities change
+    async fn authorities_change_signature_state(
+        &self,
+        message: &[u8],
+        your_address: &[u8; 20],
+    ) -> E2EClientResult<CollectingSignatureState>;
+
+    // Returns latest finalized block number
+    async fn finalized_block_number(&self) -> E2EClientResult<u32>;
+
+    // Returns ECDSA authority events of the block, none if the block doesn't exist
+    async fn ecdsa_events(&self, block_number: u32) -> E2EClientResult<Option<EcdsaBlockEvents>>;
+}
+
+/// State of an authority signature in the collecting round on chain
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CollectingSignatureState {
+    /// No round of the message is collecting signatures, it collected enough or was replaced
+    NotCollecting,
+    /// The signature of the authority is accepted in the round
+    Signed,
+    /// The authority hasn't signed the round yet
+    Unsigned,
+}
+
+impl CollectingSignatureState {
+    /// State of your address in the signatures of a collecting round
+    pub fn of<'a>(
+        mut signers: impl Iterator<Item = &'a [u8; 20]>,
+        your_address: &[u8; 20],
+    ) -> Self {
+        if signers.any(|signer| signer == your_address) {
+            Self::Signed
+        } else {
+            Self::Unsigned
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -85,7 +132,8 @@ pub trait MessageClient: GasPriceOracle {
     // Outbound contract
     fn outbound(&self) -> &Outbound;
 
-    fn private_key(&self) -> &SecretKey;
+    // Signer of transactions
+    fn signer(&self) -> &EvmSigner;
 
     // Retruns true to relay this message, or returns false to not relay this message
     async fn decide(&mut self, encoded_key: U256) -> E2EClientResult<bool>;
@@ -124,4 +172,26 @@ pub trait MessageEventsQuery {
 
 pub trait OnDemandHeader: Clone + From<u64> {
    fn block_number(&self) -> u64;
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collecting_signature_state_of() {
+        let signers = [[1u8; 20], [2u8; 20]];
+        assert_eq!(
+            CollectingSignatureState::of(signers.iter(), &[2u8; 20]),
+            CollectingSignatureState::Signed
+        );
+        assert_eq!(
+            CollectingSignatureState::of(signers.iter(), &[3u8; 20]),
+            CollectingSignatureState::Unsigned
+        );
+        assert_eq!(
+            CollectingSignatureState::of([].iter(), &[1u8; 20]),
+            CollectingSignatureState::Unsigned
+        );
+    }
+}
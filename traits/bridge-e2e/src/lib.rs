@@ -1,3 +1,4 @@
 pub mod client;
 pub mod error;
 pub mod strategy;
+pub mod types;
@@ -0,0 +1,54 @@
+/// ECDSA authority events of a block
+#[derive(Clone, Debug)]
+pub struct EcdsaBlockEvents {
+    pub block_number: u32,
+    pub block_hash: Vec<u8>,
+    /// Events with their index in the block
+    pub events: Vec<(u32, EcdsaEvent)>,
+}
+
+/// Event of the ECDSA authority pallet
+#[derive(Clone, Debug)]
+pub enum EcdsaEvent {
+    /// New message root found, collecting its signatures
+    CollectingNewMessageRootSignatures { message: [u8; 32] },
+    /// Collected enough signatures of the new message root
+    CollectedEnoughNewMessageRootSignatures {
+        commitment: EcdsaCommitment,
+        message: [u8; 32],
+        signatures: Vec<([u8; 20], [u8; 65])>,
+    },
+    /// Authorities changed, collecting signatures of the change
+    CollectingAuthoritiesChangeSignatures { message: [u8; 32] },
+    /// Collected enough signatures of the authorities change
+    CollectedEnoughAuthoritiesChangeSignatures {
+        operation: EcdsaOperation,
+        threshold: Option<u32>,
+        message: [u8; 32],
+        signatures: Vec<([u8; 20], [u8; 65])>,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct EcdsaCommitment {
+    pub block_number: u32,
+    pub message_root: [u8; 32],
+    pub nonce: u32,
+}
+
+/// Authorities change operation
+#[derive(Clone, Debug)]
+pub enum EcdsaOperation {
+    AddMember {
+        new: [u8; 20],
+    },
+    RemoveMember {
+        pre: [u8; 20],
+        old: [u8; 20],
+    },
+    SwapMembers {
+        pre: [u8; 20],
+        old: [u8; 20],
+        new: [u8; 20],
+    },
+}
@@ -2,7 +2,8 @@ use bp_messages::Weight;
 use core::fmt::Debug;
 use std::ops::RangeInclusive;
 
-use client_common_traits::ClientCommon;
+use client_common_traits::compat::{self, RuntimeItem};
+use client_common_traits::{ChainIdentity, ClientCommon};
 use codec::{Codec, Decode, Encode, EncodeLike};
 use sp_runtime::generic::{Block, SignedBlock};
 use sp_runtime::traits::{Extrinsic, MaybeSerializeDeserialize};
@@ -10,6 +11,7 @@ use subxt::rpc::Subscription;
 
 use crate::error::S2SClientResult;
 use crate::types::bp_runtime::Chain;
+use crate::types::MessagePayloadInfo;
 
 /// S2S bridge client types defined
 pub trait S2SClientBase: ClientCommon {
@@ -41,6 +43,12 @@ pub trait S2SClientGeneric: S2SClientBase {
         hash: Option<<Self::Chain as Chain>::Hash>,
     ) -> S2SClientResult<Option<<Self::Chain as Chain>::Header>>;
 
+    /// query block hash by number
+    async fn block_hash(
+        &self,
+        number: <Self::Chain as Chain>::BlockNumber,
+    ) -> S2SClientResult<Option<<Self::Chain as Chain>::Hash>>;
+
     /// query block by hash
     // -> SignedBlock<Block<<Self::Chain as Chain>::Header, Self::Extrinsic>>
     async fn block(
@@ -57,11 +65,39 @@ pub trait S2SClientGeneric: S2SClientBase {
 
     /// prepare initialization data
     async fn prepare_initialization_data(&self) -> S2SClientResult<Self::InitializationData>;
+
+    /// query identity of the chain behind every configured endpoint
+    async fn chain_identities(&self) -> Vec<(String, S2SClientResult<ChainIdentity>)>;
+
+    /// query spec version of the runtime
+    async fn runtime_spec_version(&self) -> S2SClientResult<u32>;
+
+    /// query the latest runtime metadata from node
+    async fn runtime_metadata(&self) -> S2SClientResult<subxt::Metadata>;
+
+    /// the connected endpoint is lagging or unreachable while another endpoint is healthy
+    fn is_endpoint_degraded(&self) -> bool;
+}
+
+/// S2S bridge runtime compatibility api
+#[async_trait::async_trait]
+pub trait S2SClientRuntime: S2SClientGeneric {
+    /// storages and calls of the codegen used by the bridge
+    fn runtime_items(&self) -> S2SClientResult<Vec<RuntimeItem>>;
+
+    /// compare the runtime items with the latest runtime metadata, returns the incompatible ones
+    async fn incompatible_runtime_items(&self) -> S2SClientResult<Vec<String>> {
+        let metadata = self.runtime_metadata().await?;
+        Ok(compat::incompatible_items(
+            &metadata,
+            &self.runtime_items()?,
+        ))
+    }
 }
 
 /// S2S bridge header/message api
 #[async_trait::async_trait]
-pub trait S2SClientRelay: S2SClientGeneric {
+pub trait S2SClientRelay: S2SClientRuntime {
     /// generate outbound messages storage key
     fn gen_outbound_messages_storage_key(&self, lane: [u8; 4], message_nonce: u64) -> Vec<u8>;
 
@@ -78,6 +114,12 @@ pub trait S2SClientRelay: S2SClientGeneric {
         nonces: RangeInclusive<u64>,
     ) -> S2SClientResult<u64>;
 
+    /// decode payload of message sent from this chain
+    fn decode_message_payload(&self, payload: &[u8]) -> S2SClientResult<MessagePayloadInfo>;
+
+    /// decode call dispatched at this chain by runtime metadata, returns it in debug format
+    fn decode_dispatch_call(&self, call: &[u8]) -> S2SClientResult<String>;
+
     /// query best target finalized at source
     async fn best_target_finalized(
         &self,
@@ -172,7 +214,7 @@ pub trait S2SParaBridgeClientSolochain: S2SClientRelay {
 /// S2S with parachain bridge api for relay chain
 #[cfg(feature = "bridge-parachain")]
 #[async_trait::async_trait]
-pub trait S2SParaBridgeClientRelaychain: S2SClientGeneric {
+pub trait S2SParaBridgeClientRelaychain: S2SClientRuntime {
     /// generate parachain head storage key
     fn gen_parachain_head_storage_key(&self, para_id: u32) -> Vec<u8>;
 
@@ -3,6 +3,23 @@ pub use bp_messages;
 pub use bp_runtime;
 pub use bridge_runtime_common;
 
+use serde::{Deserialize, Serialize};
+
+/// Decoded payload of an outbound message
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MessagePayloadInfo {
+    /// Runtime spec version of the target chain the message is built for
+    pub spec_version: u32,
+    /// Dispatch weight (ref time)
+    pub weight: u64,
+    /// Call origin in debug format
+    pub origin: String,
+    /// Where the dispatch fee is paid
+    pub dispatch_fee_payment: String,
+    /// Encoded call dispatched at the target chain
+    pub call: Vec<u8>,
+}
+
 #[cfg(feature = "bridge-parachain")]
 pub use self::bridge_parachain::*;
 
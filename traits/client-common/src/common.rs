@@ -4,6 +4,12 @@ pub trait ClientCommon: 'static + Send + Sync + Clone {
     /// chain name
     const CHAIN: &'static str;
 
+    /// runtime spec name which the runtime api is generated for, compared exactly, e.g. `Crab2`
+    const SPEC_NAME: &'static str;
+
+    /// genesis hash in hex of the chain, none if it's unknown, e.g. a testnet which is reset
+    const GENESIS_HASH: Option<&'static str>;
+
     /// chain types
     type Chain: Chain;
 }
@@ -0,0 +1,221 @@
+//! Compatibility of the static codegen with the runtime metadata of node.
+//!
+//! Every storage address and call payload of the codegen carries the hash of its types,
+//! they're compared with the hashes of the live metadata to find the items changed by a
+//! runtime upgrade before they fail with decoding errors.
+
+use std::fmt::{Display, Formatter};
+
+use codec::Decode;
+use subxt::storage::StorageAddress;
+use subxt::tx::TxPayload;
+use subxt::Metadata;
+
+/// Length of the zero bytes to decode a placeholder value
+const ZEROED_LEN: usize = 1024;
+
+/// Kind of runtime item
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RuntimeItemKind {
+    Storage,
+    Call,
+}
+
+/// A storage entry or call of the static codegen used by the client
+#[derive(Clone, Debug)]
+pub struct RuntimeItem {
+    pub kind: RuntimeItemKind,
+    pub pallet: String,
+    pub name: String,
+    /// Hash of the types at codegen, none if the address isn't validated
+    pub hash: Option<[u8; 32]>,
+}
+
+impl RuntimeItem {
+    pub fn storage(address: &impl StorageAddress) -> Self {
+        Self {
+            kind: RuntimeItemKind::Storage,
+            pallet: address.pallet_name().to_string(),
+            name: address.entry_name().to_string(),
+            hash: address.validation_hash(),
+        }
+    }
+
+    pub fn call(payload: &impl TxPayload) -> Option<Self> {
+        payload.validation_details().map(|details| Self {
+            kind: RuntimeItemKind::Call,
+            pallet: details.pallet_name.to_string(),
+            name: details.call_name.to_string(),
+            hash: Some(details.hash),
+        })
+    }
+
+    /// Check the item with the runtime metadata, returns the reason if it's incompatible
+    pub fn check(&self, metadata: &Metadata) -> Result<(), String> {
+        let live_hash = match self.kind {
+            RuntimeItemKind::Storage => metadata.storage_hash(&self.pallet, &self.name),
+            RuntimeItemKind::Call => metadata.call_hash(&self.pallet, &self.name),
+        }
+        .map_err(|e| format!("{self} is missing in runtime: {e:?}"))?;
+        match self.hash {
+            Some(hash) if hash != live_hash => Err(format!("{self} is changed by runtime")),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Display for RuntimeItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            RuntimeItemKind::Storage => "storage",
+            RuntimeItemKind::Call => "call",
+        };
+        write!(f, "{} {}.{}", kind, self.pallet, self.name)
+    }
+}
+
+/// Check the items with the runtime metadata, returns the reasons of incompatible items
+pub fn incompatible_items(metadata: &Metadata, items: &[RuntimeItem]) -> Vec<String> {
+    items
+        .iter()
+        .filter_map(|item| item.check(metadata).err())
+        .collect()
+}
+
+/// A value decoded from zero bytes, numbers are zero, sequences are empty and enums are the
+/// first variant. It's used to build the call payloads of codegen to read their hashes.
+pub fn zeroed<T: Decode>() -> Result<T, codec::Error> {
+    T::decode(&mut &[0u8; ZEROED_LEN][..])
+}
+
+#[cfg(test)]
+mod tests {
+    use codec::Encode;
+    use frame_metadata::v14::{
+        ExtrinsicMetadata, PalletCallMetadata, PalletMetadata, PalletStorageMetadata,
+        StorageEntryMetadata, StorageEntryModifier, StorageEntryType,
+    };
+    use frame_metadata::{RuntimeMetadataPrefixed, RuntimeMetadataV14};
+    use scale_info::{meta_type, TypeInfo};
+
+    use super::*;
+
+    #[allow(non_camel_case_types, dead_code)]
+    #[derive(Encode, TypeInfo)]
+    enum GrandpaCall {
+        initialize { init_data: u32 },
+    }
+
+    /// The runtime upgraded the argument of `initialize`
+    #[allow(non_camel_case_types, dead_code)]
+    #[derive(Encode, TypeInfo)]
+    enum UpgradedGrandpaCall {
+        initialize { init_data: u64 },
+    }
+
+    fn metadata<Call: TypeInfo + 'static>() -> Metadata {
+        let pallet = PalletMetadata {
+            name: "BridgeGrandpa",
+            storage: Some(PalletStorageMetadata {
+                prefix: "BridgeGrandpa",
+                entries: vec![StorageEntryMetadata {
+                    name: "BestFinalized",
+                    modifier: StorageEntryModifier::Default,
+                    ty: StorageEntryType::Plain(meta_type::<u64>()),
+                    default: vec![0; 8],
+                    docs: vec![],
+                }],
+            }),
+            calls: Some(PalletCallMetadata {
+                ty: meta_type::<Call>(),
+            }),
+            event: None,
+            constants: vec![],
+            error: None,
+            index: 7,
+        };
+        let extrinsic = ExtrinsicMetadata {
+            ty: meta_type::<()>(),
+            version: 4,
+            signed_extensions: vec![],
+        };
+        let metadata = RuntimeMetadataV14::new(vec![pallet], extrinsic, meta_type::<()>());
+        Metadata::try_from(RuntimeMetadataPrefixed::from(metadata)).unwrap()
+    }
+
+    fn item(kind: RuntimeItemKind, name: &str, metadata: &Metadata) -> RuntimeItem {
+        let hash = match kind {
+            RuntimeItemKind::Storage => metadata.storage_hash("BridgeGrandpa", name),
+            RuntimeItemKind::Call => metadata.call_hash("BridgeGrandpa", name),
+        }
+        .ok();
+        RuntimeItem {
+            kind,
+            pallet: "BridgeGrandpa".to_string(),
+            name: name.to_string(),
+            hash,
+        }
+    }
+
+    #[test]
+    fn test_check_runtime_item() {
+        let metadata = metadata::<GrandpaCall>();
+        let upgraded = metadata::<UpgradedGrandpaCall>();
+        let storage = item(RuntimeItemKind::Storage, "BestFinalized", &metadata);
+        let call = item(RuntimeItemKind::Call, "initialize", &metadata);
+        assert!(storage.check(&metadata).is_ok());
+        assert!(call.check(&metadata).is_ok());
+
+        assert!(storage.check(&upgraded).is_ok());
+        let reason = call.check(&upgraded).unwrap_err();
+        assert_eq!(
+            reason,
+            "call BridgeGrandpa.initialize is changed by runtime"
+        );
+        // the address isn't validated
+        let unvalidated = RuntimeItem { hash: None, ..call };
+        assert!(unvalidated.check(&upgraded).is_ok());
+    }
+
+    #[test]
+    fn test_check_missing_runtime_item() {
+        let metadata = metadata::<GrandpaCall>();
+        let missing = RuntimeItem {
+            kind: RuntimeItemKind::Call,
+            pallet: "BridgeGrandpa".to_string(),
+            name: "submit_finality_proof".to_string(),
+            hash: Some([0; 32]),
+        };
+        let reason = missing.check(&metadata).unwrap_err();
+        assert!(reason.starts_with("call BridgeGrandpa.submit_finality_proof is missing"));
+
+        let items = [
+            item(RuntimeItemKind::Storage, "BestFinalized", &metadata),
+            missing,
+        ];
+        assert_eq!(incompatible_items(&metadata, &items).len(), 1);
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Decode, PartialEq)]
+    enum InitData {
+        Header { number: u32, hash: [u8; 32] },
+        Empty,
+    }
+
+    #[test]
+    fn test_zeroed() {
+        assert_eq!(zeroed::<u64>().unwrap(), 0);
+        assert_eq!(zeroed::<Vec<u8>>().unwrap(), Vec::<u8>::new());
+        assert_eq!(zeroed::<Option<u32>>().unwrap(), None);
+        assert_eq!(
+            zeroed::<InitData>().unwrap(),
+            InitData::Header {
+                number: 0,
+                hash: [0; 32]
+            },
+        );
+        // the value is longer than the zero bytes
+        assert!(zeroed::<[u8; ZEROED_LEN + 1]>().is_err());
+    }
+}
@@ -0,0 +1,351 @@
+//! Connect to one of several substrate endpoints.
+//!
+//! Every endpoint keeps a health score. Failed connections and nodes lagging
+//! behind the others in best or finalized block are penalized, so the next
+//! connection prefers the healthiest endpoint. Endpoints are probed again
+//! periodically once connected, an endpoint found lagging or unreachable while
+//! another one is healthy is marked degraded, clients connected to it should
+//! reconnect.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use ::subxt::{Config, Error, OnlineClient};
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::rpc_params;
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use once_cell::sync::Lazy;
+
+use crate::ChainIdentity;
+
+const MAX_ATTEMPTS: u32 = 6;
+const CONNECT_TIMEOUT_SECS: u64 = 30;
+/// Interval between two evaluations of connected endpoints
+const EVALUATE_INTERVAL_SECS: u64 = 60;
+/// Allowed distance to the highest best/finalized block seen across endpoints
+const MAX_LAG_BLOCKS: u64 = 10;
+
+const SCORE_MAX: i64 = 100;
+const SCORE_MIN: i64 = -100;
+const SCORE_REWARD: i64 = 1;
+const SCORE_LAG_PENALTY: i64 = 5;
+const SCORE_FAILURE_PENALTY: i64 = 10;
+
+static HEALTH_SCORES: Lazy<Mutex<HashMap<String, i64>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+static DEGRADED: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+static WATCHED: Lazy<Mutex<HashSet<Vec<String>>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Connect to the healthiest endpoint, retry all endpoints with exponential back-off
+pub async fn connect<T: Config>(endpoints: &[String]) -> Result<(String, OnlineClient<T>), Error> {
+    if endpoints.is_empty() {
+        return Err(Error::Other("No endpoint configured".to_string()));
+    }
+    let mut attempts = 1;
+    let mut wait_secs = 1;
+    loop {
+        match try_connect::<T>(endpoints).await {
+            Ok(v) => {
+                watch(endpoints);
+                return Ok(v);
+            }
+            Err(err) => {
+                if attempts >= MAX_ATTEMPTS {
+                    return Err(err);
+                }
+                tracing::warn!(
+                    target: "client",
+                    "Failed to connect to any of {:?}, retry after {} seconds: {:?}",
+                    endpoints,
+                    wait_secs,
+                    err,
+                );
+                tokio::time::sleep(Duration::from_secs(wait_secs)).await;
+                attempts += 1;
+                wait_secs *= 2; // backoff
+            }
+        }
+    }
+}
+
+/// Report an endpoint whose connection was lost
+pub fn report_failure(endpoint: impl AsRef<str>) {
+    let mut scores = HEALTH_SCORES.lock().unwrap();
+    adjust_score(&mut scores, endpoint.as_ref(), -SCORE_FAILURE_PENALTY);
+}
+
+/// The endpoint was lagging or unreachable on the last evaluation while another endpoint
+/// is healthy, clients connected to it should reconnect
+pub fn is_degraded(endpoint: impl AsRef<str>) -> bool {
+    DEGRADED.lock().unwrap().contains(endpoint.as_ref())
+}
+
+/// Probe the best and finalized block of every endpoint, reward the healthy ones and
+/// penalize the lagging or unreachable ones. Returns healthy endpoints sorted by health.
+pub async fn evaluate(endpoints: &[String]) -> Vec<String> {
+    let mut probes = Vec::new();
+    for endpoint in endpoints {
+        match probe(endpoint).await {
+            Ok((best, finalized)) => probes.push((endpoint.clone(), best, finalized)),
+            Err(err) => {
+                tracing::warn!(target: "client", "Failed to query blocks from {}: {:?}", endpoint, err);
+                report_failure(endpoint);
+            }
+        }
+    }
+
+    let lagging = lagging_endpoints(&probes);
+    let healthy: Vec<String> = probes
+        .into_iter()
+        .map(|(endpoint, _, _)| endpoint)
+        .filter(|endpoint| !lagging.contains(endpoint))
+        .collect();
+    let mut scores = HEALTH_SCORES.lock().unwrap();
+    for endpoint in &lagging {
+        adjust_score(&mut scores, endpoint, -SCORE_LAG_PENALTY);
+    }
+    for endpoint in &healthy {
+        adjust_score(&mut scores, endpoint, SCORE_REWARD);
+    }
+    let mut degraded = DEGRADED.lock().unwrap();
+    for endpoint in endpoints {
+        // nothing better to switch to if no endpoint is healthy
+        if healthy.is_empty() || healthy.contains(endpoint) {
+            degraded.remove(endpoint);
+        } else {
+            degraded.insert(endpoint.clone());
+        }
+    }
+    sort_by_health(&healthy, &scores)
+}
+
+/// Connect every endpoint and query the identity of the chain behind it
+pub async fn identities<T: Config>(
+    endpoints: &[String],
+) -> Vec<(String, Result<ChainIdentity, Error>)> {
+    let mut identities = Vec::new();
+    for endpoint in endpoints {
+        let identity = match tokio::time::timeout(
+            Duration::from_secs(CONNECT_TIMEOUT_SECS),
+            OnlineClient::<T>::from_url(endpoint),
+        )
+        .await
+        {
+            Ok(Ok(client)) => identity(&client).await,
+            Ok(Err(err)) => Err(err),
+            Err(_) => Err(Error::Other(format!("Connect to {} timed out", endpoint))),
+        };
+        identities.push((endpoint.clone(), identity));
+    }
+    identities
+}
+
+/// Query the identity of the connected chain
+pub async fn identity<T: Config>(client: &OnlineClient<T>) -> Result<ChainIdentity, Error> {
+    let version = client.rpc().runtime_version(None).await?;
+    let spec_name = version
+        .other
+        .get("specName")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| Error::Other("Missing spec name in runtime version".to_string()))?;
+    Ok(ChainIdentity {
+        genesis_hash: format!("{:?}", client.genesis_hash()),
+        spec_name: spec_name.to_string(),
+        spec_version: version.spec_version,
+    })
+}
+
+async fn try_connect<T: Config>(endpoints: &[String]) -> Result<(String, OnlineClient<T>), Error> {
+    // nothing to compare with, skip the probe
+    let candidates = if endpoints.len() == 1 {
+        endpoints.to_vec()
+    } else {
+        let healthy = evaluate(endpoints).await;
+        if healthy.is_empty() {
+            sort_by_health(endpoints, &HEALTH_SCORES.lock().unwrap())
+        } else {
+            healthy
+        }
+    };
+
+    // only the chosen endpoint is fully connected, which downloads the metadata
+    let mut last_error = None;
+    for endpoint in candidates {
+        let connected = tokio::time::timeout(
+            Duration::from_secs(CONNECT_TIMEOUT_SECS),
+            OnlineClient::<T>::from_url(&endpoint),
+        )
+        .await
+        .unwrap_or_else(|_| Err(Error::Other(format!("Connect to {} timed out", endpoint))));
+        match connected {
+            Ok(client) => {
+                tracing::debug!(target: "client", "Connected to {}", endpoint);
+                return Ok((endpoint, client));
+            }
+            Err(err) => {
+                tracing::warn!(target: "client", "Failed to connect to {}: {:?}", endpoint, err);
+                report_failure(&endpoint);
+                last_error = Some(err);
+            }
+        }
+    }
+    Err(last_error.unwrap_or_else(|| Error::Other("No healthy endpoint available".to_string())))
+}
+
+/// Evaluate the endpoints periodically, every set of endpoints is watched once
+fn watch(endpoints: &[String]) {
+    if endpoints.len() < 2 || !WATCHED.lock().unwrap().insert(endpoints.to_vec()) {
+        return;
+    }
+    let endpoints = endpoints.to_vec();
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(EVALUATE_INTERVAL_SECS)).await;
+            evaluate(&endpoints).await;
+        }
+    });
+}
+
+/// Query the best and finalized block number by a plain rpc connection
+async fn probe(endpoint: &str) -> Result<(u64, u64), Error> {
+    let probe = async {
+        let client = WsClientBuilder::default()
+            .build(endpoint)
+            .await
+            .map_err(rpc_error)?;
+        block_numbers(&client).await
+    };
+    tokio::time::timeout(Duration::from_secs(CONNECT_TIMEOUT_SECS), probe)
+        .await
+        .unwrap_or_else(|_| Err(Error::Other(format!("Probe {} timed out", endpoint))))
+}
+
+async fn block_numbers(client: &WsClient) -> Result<(u64, u64), Error> {
+    let best: serde_json::Value = client
+        .request("chain_getHeader", rpc_params![])
+        .await
+        .map_err(rpc_error)?;
+    let finalized_hash: serde_json::Value = client
+        .request("chain_getFinalizedHead", rpc_params![])
+        .await
+        .map_err(rpc_error)?;
+    let finalized: serde_json::Value = client
+        .request("chain_getHeader", rpc_params![finalized_hash])
+        .await
+        .map_err(rpc_error)?;
+    Ok((header_number(&best)?, header_number(&finalized)?))
+}
+
+fn rpc_error(err: jsonrpsee::core::Error) -> Error {
+    Error::Other(format!("{:?}", err))
+}
+
+fn header_number(header: &serde_json::Value) -> Result<u64, Error> {
+    let number = header
+        .get("number")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| Error::Other(format!("Missing block number in header: {}", header)))?;
+    u64::from_str_radix(number.trim_start_matches("0x"), 16)
+        .map_err(|e| Error::Other(format!("Invalid block number {}: {:?}", number, e)))
+}
+
+/// Endpoints behind the highest best or finalized block by more than [`MAX_LAG_BLOCKS`],
+/// probes are `(endpoint, best, finalized)`
+fn lagging_endpoints(probes: &[(String, u64, u64)]) -> HashSet<String> {
+    let highest_best = probes.iter().map(|v| v.1).max().unwrap_or_default();
+    let highest_finalized = probes.iter().map(|v| v.2).max().unwrap_or_default();
+    probes
+        .iter()
+        .filter(|(endpoint, best, finalized)| {
+            let lagging = highest_best - best > MAX_LAG_BLOCKS
+                || highest_finalized - finalized > MAX_LAG_BLOCKS;
+            if lagging {
+                tracing::warn!(
+                    target: "client",
+                    "Endpoint {} is lagging, best: {} (highest {}), finalized: {} (highest {})",
+                    endpoint,
+                    best,
+                    highest_best,
+                    finalized,
+                    highest_finalized,
+                );
+            }
+            lagging
+        })
+        .map(|(endpoint, _, _)| endpoint.clone())
+        .collect()
+}
+
+fn sort_by_health(endpoints: &[String], scores: &HashMap<String, i64>) -> Vec<String> {
+    let mut sorted = endpoints.to_vec();
+    // stable sort, keep the configured order for equal scores
+    sorted.sort_by_key(|v| -scores.get(v).copied().unwrap_or_default());
+    sorted
+}
+
+fn adjust_score(scores: &mut HashMap<String, i64>, endpoint: &str, delta: i64) {
+    let score = scores.entry(endpoint.to_string()).or_default();
+    *score = (*score + delta).clamp(SCORE_MIN, SCORE_MAX);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoints(names: &[&str]) -> Vec<String> {
+        names.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_sort_by_health() {
+        let mut scores = HashMap::new();
+        scores.insert("b".to_string(), 5);
+        scores.insert("c".to_string(), -5);
+        assert_eq!(
+            sort_by_health(&endpoints(&["a", "b", "c", "d"]), &scores),
+            endpoints(&["b", "a", "d", "c"])
+        );
+        assert_eq!(
+            sort_by_health(&endpoints(&["d", "a"]), &HashMap::new()),
+            endpoints(&["d", "a"])
+        );
+    }
+
+    #[test]
+    fn test_adjust_score() {
+        let mut scores = HashMap::new();
+        adjust_score(&mut scores, "a", SCORE_REWARD);
+        adjust_score(&mut scores, "b", -SCORE_FAILURE_PENALTY);
+        assert_eq!(scores["a"], SCORE_REWARD);
+        assert_eq!(scores["b"], -SCORE_FAILURE_PENALTY);
+        for _ in 0..100 {
+            adjust_score(&mut scores, "a", SCORE_REWARD * 10);
+            adjust_score(&mut scores, "b", -SCORE_FAILURE_PENALTY);
+        }
+        assert_eq!(scores["a"], SCORE_MAX);
+        assert_eq!(scores["b"], SCORE_MIN);
+    }
+
+    #[test]
+    fn test_lagging_endpoints() {
+        let probes = vec![
+            ("a".to_string(), 100, 90),
+            ("b".to_string(), 100 - MAX_LAG_BLOCKS, 90),
+            ("c".to_string(), 100 - MAX_LAG_BLOCKS - 1, 90),
+            ("d".to_string(), 100, 90 - MAX_LAG_BLOCKS - 1),
+        ];
+        let lagging = lagging_endpoints(&probes);
+        assert_eq!(lagging.len(), 2);
+        assert!(lagging.contains("c"));
+        assert!(lagging.contains("d"));
+        assert!(lagging_endpoints(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_header_number() {
+        let header = serde_json::json!({ "number": "0x1f" });
+        assert_eq!(header_number(&header).unwrap(), 31);
+        assert!(header_number(&serde_json::json!({})).is_err());
+        assert!(header_number(&serde_json::json!({ "number": "0xzz" })).is_err());
+    }
+}
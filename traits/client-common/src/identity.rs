@@ -0,0 +1,101 @@
+/// Identity of the chain behind an endpoint
+#[derive(Clone, Debug)]
+pub struct ChainIdentity {
+    /// Genesis hash in hex
+    pub genesis_hash: String,
+    /// Runtime spec name
+    pub spec_name: String,
+    /// Runtime spec version
+    pub spec_version: u32,
+}
+
+impl ChainIdentity {
+    /// Whether the runtime is the expected one, see [`crate::ClientCommon::SPEC_NAME`]
+    pub fn is_runtime(&self, spec_name: &str) -> bool {
+        self.spec_name == spec_name
+    }
+
+    /// Check the chain behind the endpoint is the expected one, see
+    /// [`crate::ClientCommon::GENESIS_HASH`]. If the genesis hash is unknown, it's compared with
+    /// `seen_genesis_hash`, the genesis hash of the first endpoint running the expected runtime
+    pub fn check(
+        &self,
+        spec_name: &str,
+        genesis_hash: Option<&str>,
+        seen_genesis_hash: &mut Option<String>,
+    ) -> Result<String, String> {
+        if !self.is_runtime(spec_name) {
+            return Err(format!(
+                "Expect {} runtime, but it's {} (spec version {})",
+                spec_name, self.spec_name, self.spec_version
+            ));
+        }
+        let expected_genesis_hash = match genesis_hash {
+            Some(v) => v.to_string(),
+            None => seen_genesis_hash
+                .get_or_insert_with(|| self.genesis_hash.clone())
+                .clone(),
+        };
+        if !self.genesis_hash.eq_ignore_ascii_case(&expected_genesis_hash) {
+            return Err(format!(
+                "Expect genesis hash {}, but it's {}",
+                expected_genesis_hash, self.genesis_hash
+            ));
+        }
+        Ok(format!(
+            "{} (spec version {}), genesis hash {}",
+            self.spec_name, self.spec_version, self.genesis_hash
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(spec_name: &str, genesis_hash: &str) -> ChainIdentity {
+        ChainIdentity {
+            genesis_hash: genesis_hash.to_string(),
+            spec_name: spec_name.to_string(),
+            spec_version: 6000,
+        }
+    }
+
+    #[test]
+    fn test_is_runtime() {
+        assert!(identity("Crab2", "0x01").is_runtime("Crab2"));
+        assert!(!identity("Crab2", "0x01").is_runtime("crab"));
+        assert!(!identity("Crab2", "0x01").is_runtime("Crab"));
+    }
+
+    #[test]
+    fn test_check_known_genesis_hash() {
+        let mut seen = None;
+        assert!(identity("Crab2", "0xAB")
+            .check("Crab2", Some("0xab"), &mut seen)
+            .is_ok());
+        assert!(identity("Crab2", "0xcd")
+            .check("Crab2", Some("0xab"), &mut seen)
+            .is_err());
+        assert!(identity("Darwinia2", "0xab")
+            .check("Crab2", Some("0xab"), &mut seen)
+            .is_err());
+        assert!(seen.is_none());
+    }
+
+    #[test]
+    fn test_check_unknown_genesis_hash() {
+        let mut seen = None;
+        // the endpoint of another runtime isn't the reference
+        assert!(identity("Pangoro2", "0xcd")
+            .check("Pangolin2", None, &mut seen)
+            .is_err());
+        assert!(identity("Pangolin2", "0xab")
+            .check("Pangolin2", None, &mut seen)
+            .is_ok());
+        assert!(identity("Pangolin2", "0xcd")
+            .check("Pangolin2", None, &mut seen)
+            .is_err());
+        assert_eq!(seen.as_deref(), Some("0xab"));
+    }
+}
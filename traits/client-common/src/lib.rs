@@ -1,7 +1,14 @@
 pub use self::common::*;
+pub use self::identity::*;
 #[cfg(feature = "subxt-darwinia")]
 pub use self::subxt::*;
 
+#[cfg(feature = "subxt-endpoint")]
+pub mod compat;
+#[cfg(feature = "subxt-endpoint")]
+pub mod endpoint;
+
 mod common;
+mod identity;
 #[cfg(feature = "subxt-darwinia")]
 mod subxt;
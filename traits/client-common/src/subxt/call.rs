@@ -0,0 +1,103 @@
+use subxt::ext::scale_value;
+use subxt::{Error, Metadata};
+
+/// Decode the encoded runtime call by the metadata of node, so it's readable after runtime
+/// upgrades the static codegen doesn't know. It's shown as `Pallet.call { .. }`.
+pub fn decode_call(metadata: &Metadata, call: &[u8]) -> Result<String, Error> {
+    let (pallet_index, mut call_data) = call
+        .split_first()
+        .ok_or_else(|| Error::Other("The call is empty".to_string()))?;
+    let runtime_metadata = metadata.runtime_metadata();
+    let pallet = runtime_metadata
+        .pallets
+        .iter()
+        .find(|pallet| pallet.index == *pallet_index)
+        .ok_or_else(|| Error::Other(format!("No pallet of index {pallet_index} in runtime")))?;
+    let calls = pallet
+        .calls
+        .as_ref()
+        .ok_or_else(|| Error::Other(format!("The pallet {} has no calls", pallet.name)))?;
+    let value =
+        scale_value::scale::decode_as_type(&mut call_data, calls.ty.id(), &runtime_metadata.types)
+            .map_err(|e| {
+                Error::Other(format!("Failed to decode call of {}: {e:?}", pallet.name))
+            })?;
+    if !call_data.is_empty() {
+        return Err(Error::Other(format!(
+            "{} bytes left after decoding call of {}",
+            call_data.len(),
+            pallet.name
+        )));
+    }
+    Ok(format!("{}.{}", pallet.name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use codec::Encode;
+    use frame_metadata::v14::{ExtrinsicMetadata, PalletCallMetadata, PalletMetadata};
+    use frame_metadata::{RuntimeMetadataPrefixed, RuntimeMetadataV14};
+    use scale_info::{meta_type, TypeInfo};
+
+    use super::*;
+
+    #[allow(non_camel_case_types, dead_code)]
+    #[derive(Encode, TypeInfo)]
+    enum BalancesCall {
+        transfer { dest: u32, value: u128 },
+        remark { data: Vec<u8> },
+    }
+
+    fn metadata() -> Metadata {
+        let pallet = PalletMetadata {
+            name: "Balances",
+            storage: None,
+            calls: Some(PalletCallMetadata {
+                ty: meta_type::<BalancesCall>(),
+            }),
+            event: None,
+            constants: vec![],
+            error: None,
+            index: 5,
+        };
+        let extrinsic = ExtrinsicMetadata {
+            ty: meta_type::<()>(),
+            version: 4,
+            signed_extensions: vec![],
+        };
+        let metadata = RuntimeMetadataV14::new(vec![pallet], extrinsic, meta_type::<()>());
+        Metadata::try_from(RuntimeMetadataPrefixed::from(metadata)).unwrap()
+    }
+
+    #[test]
+    fn test_decode_call() {
+        let metadata = metadata();
+        let mut call = vec![5u8];
+        call.extend(
+            BalancesCall::transfer {
+                dest: 7,
+                value: 100,
+            }
+            .encode(),
+        );
+        let decoded = decode_call(&metadata, &call).unwrap();
+        assert!(decoded.starts_with("Balances."));
+        assert!(decoded.contains("transfer"));
+        assert!(decoded.contains("100"));
+    }
+
+    #[test]
+    fn test_decode_wrong_call() {
+        let metadata = metadata();
+        assert!(decode_call(&metadata, &[]).is_err());
+        // unknown pallet
+        assert!(decode_call(&metadata, &[6, 0]).is_err());
+        // unknown call
+        assert!(decode_call(&metadata, &[5, 9]).is_err());
+        // trailing bytes
+        let mut call = vec![5u8];
+        call.extend(BalancesCall::remark { data: vec![1] }.encode());
+        call.push(0);
+        assert!(decode_call(&metadata, &call).is_err());
+    }
+}
@@ -1 +1,3 @@
+pub mod call;
+pub mod submit;
 pub mod subxt_darwinia_like;
@@ -0,0 +1,137 @@
+use codec::{Decode, Encode};
+use sp_runtime::DispatchError;
+use subxt::metadata::Metadata;
+use subxt::tx::{ExtrinsicParams, TxEvents, TxPayload, TxProgress};
+use subxt::{Config, Error, OnlineClient};
+
+/// Signer of extrinsics. Signing can fail as the key may be in a remote signer, the error is
+/// returned instead of submitting an extrinsic with an invalid signature.
+pub trait ExtrinsicSigner<T: Config> {
+    /// account id of the signer
+    fn account_id(&self) -> &T::AccountId;
+
+    /// address of the signer
+    fn address(&self) -> T::Address;
+
+    /// sign the signer payload of extrinsic
+    fn sign(&self, signer_payload: &[u8]) -> Result<T::Signature, Error>;
+}
+
+/// Sign the call with default params and submit it
+pub async fn sign_and_submit_then_watch<T, Call, S>(
+    client: &OnlineClient<T>,
+    call: &Call,
+    signer: &S,
+) -> Result<TxProgress<T, OnlineClient<T>>, Error>
+where
+    T: Config,
+    Call: TxPayload,
+    S: ExtrinsicSigner<T>,
+    <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams: Default,
+{
+    let partial = client
+        .tx()
+        .create_partial_signed(call, signer.account_id(), Default::default())
+        .await?;
+    let signature = signer.sign(&partial.signer_payload())?;
+    partial
+        .sign_with_address_and_signature(&signer.address(), &signature)
+        .submit_and_watch()
+        .await
+}
+
+/// Sign and submit the call, then wait until it's finalized successfully. The call is wrapped
+/// in `proxy.proxy` when the real account is given, so the real account is the origin of the
+/// call. A proxy call succeeds even if the proxied call fails, so the result of the proxied
+/// call in `ProxyExecuted` is checked too.
+pub async fn sign_and_submit_finalized<T, Call, S>(
+    client: &OnlineClient<T>,
+    call: &Call,
+    signer: &S,
+    real: Option<&T::AccountId>,
+) -> Result<TxEvents<T>, Error>
+where
+    T: Config,
+    Call: TxPayload,
+    S: ExtrinsicSigner<T>,
+    <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams: Default,
+{
+    let real = match real {
+        Some(v) => v,
+        None => {
+            return sign_and_submit_then_watch(client, call, signer)
+                .await?
+                .wait_for_finalized_success()
+                .await
+        }
+    };
+    client.tx().validate(call)?;
+    let proxy_call = ProxyCall {
+        real: T::Address::from(real.clone()).encode(),
+        call: client.tx().call_data(call)?,
+    };
+    let events = sign_and_submit_then_watch(client, &proxy_call, signer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+    for event in events.iter() {
+        let event = event?;
+        if event.pallet_name() == "Proxy" && event.variant_name() == "ProxyExecuted" {
+            proxy_result(event.field_bytes())?;
+            return Ok(events);
+        }
+    }
+    Err(Error::Other(
+        "Missing ProxyExecuted event of the proxy call".to_string(),
+    ))
+}
+
+/// `proxy.proxy(real, None, call)` of an encoded call
+struct ProxyCall {
+    /// encoded address of the real account
+    real: Vec<u8>,
+    /// encoded call
+    call: Vec<u8>,
+}
+
+impl TxPayload for ProxyCall {
+    fn encode_call_data(&self, metadata: &Metadata, out: &mut Vec<u8>) -> Result<(), Error> {
+        let pallet = metadata.pallet("Proxy")?;
+        pallet.index().encode_to(out);
+        pallet.call_index("proxy")?.encode_to(out);
+        out.extend_from_slice(&self.real);
+        // any proxy type
+        None::<()>.encode_to(out);
+        out.extend_from_slice(&self.call);
+        Ok(())
+    }
+}
+
+/// Decode the `result: DispatchResult` field of `ProxyExecuted`
+fn proxy_result(field_bytes: &[u8]) -> Result<(), Error> {
+    Result::<(), DispatchError>::decode(&mut &field_bytes[..])
+        .map_err(Error::Codec)?
+        .map_err(|e| Error::Other(format!("The proxied call failed: {:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use sp_runtime::ModuleError;
+
+    use super::*;
+
+    #[test]
+    fn test_proxy_result() {
+        assert!(proxy_result(&Ok::<(), DispatchError>(()).encode()).is_ok());
+        let failed = Err::<(), DispatchError>(DispatchError::BadOrigin).encode();
+        assert!(matches!(proxy_result(&failed), Err(Error::Other(_))));
+        let failed = Err::<(), DispatchError>(DispatchError::Module(ModuleError {
+            index: 5,
+            error: [1, 0, 0, 0],
+            message: None,
+        }))
+        .encode();
+        assert!(matches!(proxy_result(&failed), Err(Error::Other(_))));
+        assert!(matches!(proxy_result(&[2]), Err(Error::Codec(_))));
+    }
+}
@@ -31,6 +31,24 @@ pub trait FeemarketApiRelay: FeemarketApiBase {
     >;
 }
 
+/// Relayer account api
+#[async_trait::async_trait]
+pub trait FeemarketApiBalance: FeemarketApiBase {
+    /// Query free balance of account
+    async fn free_balance(
+        &self,
+        account: <Self::Chain as Chain>::AccountId,
+    ) -> AbstractFeemarketResult<<Self::Chain as Chain>::Balance>;
+
+    /// Query relayer info (collateral and fee) registered in fee market
+    async fn relayer_info(
+        &self,
+        account: <Self::Chain as Chain>::AccountId,
+    ) -> AbstractFeemarketResult<
+        Option<Relayer<<Self::Chain as Chain>::AccountId, <Self::Chain as Chain>::Balance>>,
+    >;
+}
+
 #[async_trait::async_trait]
 pub trait FeemarketApiQuote: FeemarketApiBase {
     /// Query assigned relayers